@@ -2,6 +2,8 @@ mod bech32_address;
 mod code_hash;
 pub mod crypto;
 pub mod new_address;
+mod tx_hash;
 
 pub use bech32_address::Bech32Address;
 pub use code_hash::{CODE_HASH_LEN, code_hash};
+pub use tx_hash::{TX_HASH_LEN, TxHashInput, tx_hash};
@@ -0,0 +1,148 @@
+use blake2::{Blake2b, Digest, digest::consts::U32};
+
+pub const TX_HASH_LEN: usize = 32;
+
+const WIRE_TYPE_VARINT: u64 = 0;
+const WIRE_TYPE_LEN: u64 = 2;
+
+/// The transaction fields that make up the transaction hash, in raw form.
+///
+/// Empty and zero fields are left out of the encoding, same as in the Go protobuf serializer,
+/// except for the value, which is always present.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TxHashInput<'a> {
+    pub nonce: u64,
+    /// Big-endian magnitude of the EGLD value.
+    pub value: &'a [u8],
+    pub receiver: &'a [u8],
+    pub receiver_username: &'a [u8],
+    pub sender: &'a [u8],
+    pub sender_username: &'a [u8],
+    pub gas_price: u64,
+    pub gas_limit: u64,
+    pub data: &'a [u8],
+    pub chain_id: &'a [u8],
+    pub version: u32,
+    pub signature: &'a [u8],
+    pub options: u32,
+    pub guardian: &'a [u8],
+    pub guardian_signature: &'a [u8],
+    pub relayer: &'a [u8],
+    pub relayer_signature: &'a [u8],
+}
+
+impl TxHashInput<'_> {
+    /// The protobuf encoding of the transaction, as produced by mx-chain-go.
+    pub fn proto_encode(&self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        put_varint_field(&mut buffer, 1, self.nonce);
+        put_bytes_field(&mut buffer, 2, &big_int_caster_bytes(self.value));
+        put_bytes_field(&mut buffer, 3, self.receiver);
+        put_bytes_field(&mut buffer, 4, self.receiver_username);
+        put_bytes_field(&mut buffer, 5, self.sender);
+        put_bytes_field(&mut buffer, 6, self.sender_username);
+        put_varint_field(&mut buffer, 7, self.gas_price);
+        put_varint_field(&mut buffer, 8, self.gas_limit);
+        put_bytes_field(&mut buffer, 9, self.data);
+        put_bytes_field(&mut buffer, 10, self.chain_id);
+        put_varint_field(&mut buffer, 11, self.version as u64);
+        put_bytes_field(&mut buffer, 12, self.signature);
+        put_varint_field(&mut buffer, 13, self.options as u64);
+        put_bytes_field(&mut buffer, 14, self.guardian);
+        put_bytes_field(&mut buffer, 15, self.guardian_signature);
+        put_bytes_field(&mut buffer, 16, self.relayer);
+        put_bytes_field(&mut buffer, 17, self.relayer_signature);
+        buffer
+    }
+}
+
+/// Computes the hash of a transaction.
+///
+/// Uses Blake2b with a 256-bit (32-byte) digest, over the protobuf encoding of the transaction.
+pub fn tx_hash(input: &TxHashInput<'_>) -> [u8; TX_HASH_LEN] {
+    Blake2b::<U32>::digest(input.proto_encode()).into()
+}
+
+/// The big int caster in mx-chain-core-go prefixes the magnitude with a sign byte, and encodes zero as `[0, 0]`.
+fn big_int_caster_bytes(magnitude: &[u8]) -> Vec<u8> {
+    let first_non_zero = magnitude
+        .iter()
+        .position(|b| *b != 0)
+        .unwrap_or(magnitude.len());
+    let magnitude = &magnitude[first_non_zero..];
+    if magnitude.is_empty() {
+        return vec![0, 0];
+    }
+
+    let mut result = Vec::with_capacity(magnitude.len() + 1);
+    result.push(0);
+    result.extend_from_slice(magnitude);
+    result
+}
+
+fn put_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+fn put_varint_field(buffer: &mut Vec<u8>, field_number: u64, value: u64) {
+    if value == 0 {
+        return;
+    }
+    put_varint(buffer, (field_number << 3) | WIRE_TYPE_VARINT);
+    put_varint(buffer, value);
+}
+
+fn put_bytes_field(buffer: &mut Vec<u8>, field_number: u64, value: &[u8]) {
+    if value.is_empty() {
+        return;
+    }
+    put_varint(buffer, (field_number << 3) | WIRE_TYPE_LEN);
+    put_varint(buffer, value.len() as u64);
+    buffer.extend_from_slice(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_input() -> TxHashInput<'static> {
+        TxHashInput {
+            nonce: 1,
+            value: &[0x00, 0x0a],
+            receiver: b"rc",
+            sender: b"sn",
+            gas_price: 1_000_000_000,
+            gas_limit: 50_000,
+            data: b"test",
+            chain_id: b"T",
+            version: 2,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_proto_encode() {
+        assert_eq!(
+            hex::encode(sample_input().proto_encode()),
+            "08011202000a1a0272632a02736e388094ebdc0340d086034a04746573745201545802"
+        );
+    }
+
+    #[test]
+    fn test_proto_encode_zero_value() {
+        let input = TxHashInput::default();
+        assert_eq!(input.proto_encode(), vec![0x12, 0x02, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn test_tx_hash() {
+        assert_eq!(
+            hex::encode(tx_hash(&sample_input())),
+            "0bb738fffe9e8b04e37203b5793750b82cf3b49ef3a04dc496229451b146449d"
+        );
+    }
+}
@@ -41,6 +41,7 @@ impl EsdtTokenType {
 
     pub fn collection_from_string(token_type: &str) -> Self {
         match token_type {
+            "FungibleESDT" => EsdtTokenType::Fungible,
             "NonFungibleESDT" => EsdtTokenType::NonFungibleV2,
             "SemiFungibleESDT" => EsdtTokenType::SemiFungible,
             "MetaESDT" => EsdtTokenType::MetaFungible,
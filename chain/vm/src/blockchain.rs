@@ -1,7 +1,9 @@
 mod blockchain_mock;
+mod randomness_control;
 pub mod reserved;
 pub mod state;
 mod vm_config;
 
 pub use blockchain_mock::*;
+pub use randomness_control::*;
 pub use vm_config::*;
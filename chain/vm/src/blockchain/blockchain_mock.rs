@@ -1,5 +1,7 @@
 use std::{fmt::Debug, ops::Deref};
 
+use crate::chain_core::types::ShardConfig;
+
 use super::{VMConfigRef, state::BlockchainStateRef};
 
 #[derive(Default)]
@@ -15,3 +17,11 @@ impl Debug for BlockchainMock {
             .finish()
     }
 }
+
+impl BlockchainMock {
+    /// Async calls and callbacks between shards become pending cross-shard messages,
+    /// to be delivered explicitly, see `execution::deliver_cross_shard_message`.
+    pub fn enable_cross_shard(&mut self, shard_config: ShardConfig) {
+        self.vm.set_shard_config(Some(shard_config));
+    }
+}
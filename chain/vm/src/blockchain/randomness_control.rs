@@ -0,0 +1,51 @@
+use std::collections::VecDeque;
+
+/// Test-only control over the random numbers served to contracts.
+///
+/// By default, each transaction gets a random stream seeded from the block random seeds and the tx hash.
+/// This allows pinning the seed, scripting the exact bytes to be served next,
+/// and, on request, keeping a log of the random bytes consumed, so they can be replayed later.
+#[derive(Debug, Default)]
+pub struct RandomnessControl {
+    /// When set, replaces the block random seeds and the tx hash as seed of every new random stream.
+    pub pinned_seed: Option<Vec<u8>>,
+
+    /// Served before any bytes coming from the random stream.
+    pub scripted_bytes: VecDeque<u8>,
+
+    /// The random bytes served since recording started, one entry per request.
+    ///
+    /// Nothing is recorded while `None`, which is the default.
+    pub consumed: Option<Vec<Vec<u8>>>,
+}
+
+impl RandomnessControl {
+    pub fn script_bytes(&mut self, bytes: &[u8]) {
+        self.scripted_bytes.extend(bytes.iter().copied());
+    }
+
+    /// Pops at most `length` scripted bytes.
+    pub fn take_scripted(&mut self, length: usize) -> Vec<u8> {
+        let num_scripted = length.min(self.scripted_bytes.len());
+        self.scripted_bytes.drain(..num_scripted).collect()
+    }
+
+    pub fn start_recording(&mut self) {
+        self.consumed.get_or_insert_with(Vec::new);
+    }
+
+    /// Only has an effect once recording was started.
+    pub fn record_consumed(&mut self, bytes: &[u8]) {
+        if let Some(consumed) = &mut self.consumed {
+            consumed.push(bytes.to_vec());
+        }
+    }
+
+    /// Retrieves the bytes recorded so far, recording continues from scratch.
+    pub fn take_consumed(&mut self) -> Vec<Vec<u8>> {
+        self.consumed
+            .as_mut()
+            .map(core::mem::take)
+            .unwrap_or_default()
+    }
+}
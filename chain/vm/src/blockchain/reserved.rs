@@ -1,3 +1,6 @@
 pub const STORAGE_RESERVED_PREFIX: &[u8] = b"ELROND";
 
 pub const STORAGE_REWARD_KEY: &[u8] = b"ELRONDreward";
+
+/// Storage locks are kept in the account storage, under this prefix, followed by the locked key.
+pub const STORAGE_TIME_LOCK_PREFIX: &[u8] = b"ELRONDtimelock";
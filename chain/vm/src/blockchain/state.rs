@@ -2,17 +2,25 @@ mod account_data;
 mod block_info;
 mod blockchain_state;
 mod blockchain_state_account_util;
+mod cross_shard_queue;
 mod esdt_data;
 mod esdt_instance;
 mod esdt_instance_metadata;
 mod esdt_instances;
 mod esdt_roles;
+mod esdt_token_config;
+mod state_root_hash;
+mod storage_lock;
 
 pub use account_data::*;
 pub use block_info::*;
 pub use blockchain_state::{BlockchainState, BlockchainStateRef};
+pub use cross_shard_queue::*;
 pub use esdt_data::*;
 pub use esdt_instance::*;
 pub use esdt_instance_metadata::*;
 pub use esdt_instances::*;
 pub use esdt_roles::*;
+pub use esdt_token_config::*;
+pub use state_root_hash::compute_state_root_hash;
+pub use storage_lock::*;
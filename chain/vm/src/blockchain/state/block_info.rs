@@ -1,13 +1,26 @@
-use multiversx_chain_core::types::TimestampMillis;
+use std::collections::VecDeque;
+
+use multiversx_chain_core::types::{DurationMillis, TimestampMillis};
+
+use crate::crypto_functions::sha256;
 
 pub const DEFAULT_BLOCK_ROUND_TIME_MS: u64 = 6000;
 
+/// One day worth of rounds, at the default round time.
+pub const DEFAULT_EPOCH_LENGTH_ROUNDS: u64 = 14400;
+
 #[derive(Clone, Debug)]
 pub struct BlockConfig {
     pub previous_block_info: BlockInfo,
     pub current_block_info: BlockInfo,
     pub epoch_start_block_info: BlockInfo,
     pub block_round_time_ms: u64,
+    pub epoch_length_rounds: u64,
+
+    /// Most recent first: the hash of the current block comes first, then that of the previous block, and so on.
+    ///
+    /// Same convention as the `blockHashes` field in scenarios.
+    pub block_hashes: VecDeque<Vec<u8>>,
 }
 
 impl Default for BlockConfig {
@@ -17,10 +30,75 @@ impl Default for BlockConfig {
             current_block_info: Default::default(),
             epoch_start_block_info: Default::default(),
             block_round_time_ms: DEFAULT_BLOCK_ROUND_TIME_MS,
+            epoch_length_rounds: DEFAULT_EPOCH_LENGTH_ROUNDS,
+            block_hashes: VecDeque::new(),
         }
     }
 }
 
+impl BlockConfig {
+    /// Produces a new block in the next round.
+    ///
+    /// The current block becomes the previous block. Nonce, round and timestamp move forward,
+    /// and a new random seed is derived from the previous one.
+    /// A new epoch starts once the epoch length has elapsed since the epoch start block.
+    /// The hash of the new block is added to the block hash history.
+    pub fn advance_block(&mut self) {
+        let previous = self.current_block_info.clone();
+        let mut current = BlockInfo {
+            block_timestamp_millis: previous.block_timestamp_millis
+                + DurationMillis::new(self.block_round_time_ms),
+            block_nonce: previous.block_nonce + 1,
+            block_round: previous.block_round + 1,
+            block_epoch: previous.block_epoch,
+            block_random_seed: previous.next_random_seed(),
+        };
+
+        if self.epoch_length_rounds > 0
+            && current.block_round
+                >= self.epoch_start_block_info.block_round + self.epoch_length_rounds
+        {
+            current.block_epoch += 1;
+            self.epoch_start_block_info = current.clone();
+        }
+
+        let parent_hash = self.block_hashes.front().cloned().unwrap_or_default();
+        self.block_hashes
+            .push_front(current.block_hash(&parent_hash).to_vec());
+
+        self.previous_block_info = previous;
+        self.current_block_info = current;
+    }
+
+    /// Looks up the hash of a past block, or of the current block.
+    ///
+    /// Fails if the nonce is in the future, or if it goes further back than the known history.
+    pub fn block_hash(&self, nonce: u64) -> Option<&[u8]> {
+        let offset = self.current_block_info.block_nonce.checked_sub(nonce)?;
+        self.block_hashes
+            .get(usize::try_from(offset).ok()?)
+            .map(Vec::as_slice)
+    }
+
+    /// Produces `num_blocks` consecutive blocks, one per round.
+    pub fn advance_blocks(&mut self, num_blocks: u64) {
+        for _ in 0..num_blocks {
+            self.advance_block();
+        }
+    }
+
+    /// Produces as many blocks as needed for at least `duration` to pass.
+    pub fn advance_time(&mut self, duration: DurationMillis) {
+        self.advance_blocks(self.rounds_for_duration(duration));
+    }
+
+    /// The number of rounds needed to cover the given duration, rounded up.
+    pub fn rounds_for_duration(&self, duration: DurationMillis) -> u64 {
+        assert!(self.block_round_time_ms > 0, "block round time is zero");
+        duration.as_u64_millis().div_ceil(self.block_round_time_ms)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct BlockInfo {
     pub block_timestamp_millis: TimestampMillis,
@@ -40,6 +118,30 @@ impl BlockInfo {
             block_random_seed: Box::from([0u8; 48]),
         }
     }
+
+    /// Deterministically derives the random seed of the following block.
+    pub fn next_random_seed(&self) -> Box<[u8; 48]> {
+        let mut input = self.block_random_seed.to_vec();
+        input.extend_from_slice(&self.block_nonce.to_be_bytes());
+        let first = sha256(&input);
+        let second = sha256(&first);
+
+        let mut seed = [0u8; 48];
+        seed[..32].copy_from_slice(&first);
+        seed[32..].copy_from_slice(&second[..16]);
+        Box::from(seed)
+    }
+
+    /// Deterministic block hash, chained to the hash of the parent block.
+    pub fn block_hash(&self, parent_hash: &[u8]) -> [u8; 32] {
+        let mut input = parent_hash.to_vec();
+        input.extend_from_slice(&self.block_nonce.to_be_bytes());
+        input.extend_from_slice(&self.block_round.to_be_bytes());
+        input.extend_from_slice(&self.block_epoch.to_be_bytes());
+        input.extend_from_slice(&self.block_timestamp_millis.as_u64_millis().to_be_bytes());
+        input.extend_from_slice(&self.block_random_seed[..]);
+        sha256(&input)
+    }
 }
 
 impl Default for BlockInfo {
@@ -13,7 +13,7 @@ use crate::{
     system_sc::ESDT_SYSTEM_SC_ADDRESS, types::Address,
 };
 
-use super::{AccountData, BlockConfig};
+use super::{AccountData, BlockConfig, CrossShardQueue, EsdtTokenConfig};
 
 #[derive(Clone)]
 pub struct BlockchainState {
@@ -21,6 +21,10 @@ pub struct BlockchainState {
     pub new_addresses: HashMap<(Address, u64), Address>,
     pub block_config: BlockConfig,
     pub new_token_identifiers: Vec<String>,
+    pub esdt_tokens: HashMap<Vec<u8>, EsdtTokenConfig>,
+
+    /// Calls and callbacks travelling between shards. Only used in cross-shard mode.
+    pub cross_shard_queue: CrossShardQueue,
 }
 
 impl Default for BlockchainState {
@@ -30,6 +34,8 @@ impl Default for BlockchainState {
             new_addresses: Default::default(),
             block_config: Default::default(),
             new_token_identifiers: Default::default(),
+            esdt_tokens: Default::default(),
+            cross_shard_queue: Default::default(),
         };
 
         // pre-populating system SC(s)
@@ -99,6 +105,10 @@ impl BlockchainState {
         self.new_token_identifiers = token_identifiers;
     }
 
+    pub fn update_esdt_tokens(&mut self, esdt_tokens: HashMap<Vec<u8>, EsdtTokenConfig>) {
+        self.esdt_tokens.extend(esdt_tokens);
+    }
+
     fn add_empty_account(&mut self, address: Address) {
         self.accounts.insert(
             address.clone(),
@@ -124,6 +134,7 @@ impl Debug for BlockchainState {
             .field("accounts", &self.accounts)
             .field("new_addresses", &self.new_addresses)
             .field("block_config", &self.block_config)
+            .field("esdt_tokens", &self.esdt_tokens)
             .finish()
     }
 }
@@ -0,0 +1,119 @@
+use num_bigint::BigUint;
+
+use crate::{
+    chain_core::types::ShardId,
+    host::context::{AsyncCallTxData, Promise, TxResult},
+};
+
+use super::EsdtInstanceMetadata;
+
+/// What a cross-shard message carries: either a call, or the callback that answers it.
+#[derive(Clone, Debug)]
+pub enum CrossShardPayload {
+    AsyncCall(AsyncCallTxData),
+    AsyncCallback {
+        async_data: AsyncCallTxData,
+        async_result: TxResult,
+    },
+    Promise(Promise),
+    PromiseCallback {
+        promise: Promise,
+        async_result: TxResult,
+    },
+}
+
+impl CrossShardPayload {
+    pub fn is_callback(&self) -> bool {
+        matches!(
+            self,
+            CrossShardPayload::AsyncCallback { .. } | CrossShardPayload::PromiseCallback { .. }
+        )
+    }
+
+    /// The call data, also for callbacks, where it describes the call being answered.
+    pub fn async_data(&self) -> &AsyncCallTxData {
+        match self {
+            CrossShardPayload::AsyncCall(async_data)
+            | CrossShardPayload::AsyncCallback { async_data, .. } => async_data,
+            CrossShardPayload::Promise(promise)
+            | CrossShardPayload::PromiseCallback { promise, .. } => &promise.call,
+        }
+    }
+}
+
+/// ESDT tokens travelling with a cross-shard message.
+#[derive(Clone, Debug)]
+pub struct CrossShardEsdtValue {
+    pub token_identifier: Vec<u8>,
+    pub nonce: u64,
+    pub value: BigUint,
+    pub metadata: EsdtInstanceMetadata,
+}
+
+/// Funds travelling with a cross-shard message.
+///
+/// They leave the balance of the sender when the message is sent, and are only credited on arrival:
+/// - calls carry their payment;
+/// - callbacks carry whatever the call sent back, or the refunded payment, if the call failed.
+#[derive(Clone, Debug, Default)]
+pub struct CrossShardFunds {
+    pub egld_value: BigUint,
+    pub esdt_values: Vec<CrossShardEsdtValue>,
+}
+
+/// A call or callback that left its shard, and that was not yet executed on the destination shard.
+#[derive(Clone, Debug)]
+pub struct CrossShardMessage {
+    pub id: u64,
+    pub source_shard: ShardId,
+    pub destination_shard: ShardId,
+    pub payload: CrossShardPayload,
+    pub funds: CrossShardFunds,
+}
+
+/// Messages pending between shards, in cross-shard mode. They are kept in the order in which they were sent.
+#[derive(Clone, Debug, Default)]
+pub struct CrossShardQueue {
+    next_id: u64,
+    messages: Vec<CrossShardMessage>,
+}
+
+impl CrossShardQueue {
+    /// Adds a new message, returns its id. Ids are never reused.
+    pub fn push(
+        &mut self,
+        source_shard: ShardId,
+        destination_shard: ShardId,
+        payload: CrossShardPayload,
+        funds: CrossShardFunds,
+    ) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.messages.push(CrossShardMessage {
+            id,
+            source_shard,
+            destination_shard,
+            payload,
+            funds,
+        });
+        id
+    }
+
+    pub fn messages(&self) -> &[CrossShardMessage] {
+        &self.messages
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    pub fn ids(&self) -> Vec<u64> {
+        self.messages.iter().map(|message| message.id).collect()
+    }
+
+    /// Removes the message from the queue, if present.
+    pub fn take(&mut self, id: u64) -> Option<CrossShardMessage> {
+        let index = self.messages.iter().position(|message| message.id == id)?;
+        Some(self.messages.remove(index))
+    }
+}
@@ -146,19 +146,69 @@ impl AccountEsdt {
             .attributes = new_attribute_bytes;
     }
 
+    pub fn set_frozen(&mut self, token_identifier: &[u8], frozen: bool) {
+        self.0.entry(token_identifier.to_vec()).or_default().frozen = frozen;
+    }
+
+    /// Freezes or unfreezes a single token nonce.
+    ///
+    /// Just like on the real chain, the nonce does not need to be held by the account.
+    pub fn set_instance_frozen(&mut self, token_identifier: &[u8], nonce: u64, frozen: bool) {
+        self.0
+            .entry(token_identifier.to_vec())
+            .or_default()
+            .instances
+            .get_mut_by_nonce_or_default(nonce)
+            .frozen = frozen;
+    }
+
+    /// A nonce is frozen if either the whole token is frozen for the account, or that nonce alone.
+    pub fn is_frozen(&self, token_identifier: &[u8], nonce: u64) -> bool {
+        if let Some(esdt_data) = self.get_by_identifier(token_identifier) {
+            esdt_data.frozen
+                || esdt_data
+                    .instances
+                    .get_by_nonce(nonce)
+                    .is_some_and(|instance| instance.frozen)
+        } else {
+            false
+        }
+    }
+
+    /// Clears the balance of a token nonce, returns the amount that was removed.
+    pub fn wipe(&mut self, token_identifier: &[u8], nonce: u64) -> BigUint {
+        if let Some(instance) = self
+            .0
+            .get_mut(token_identifier)
+            .and_then(|esdt_data| esdt_data.instances.get_mut_by_nonce(nonce))
+        {
+            std::mem::take(&mut instance.balance)
+        } else {
+            BigUint::zero()
+        }
+    }
+
     pub fn iter(&self) -> Iter<'_, Vec<u8>, EsdtData> {
         self.0.iter()
     }
 
     pub fn set_special_role(&mut self, token_identifier: &[u8], role: &[u8]) {
-        if let Some(esdt_data) = self.get_mut_by_identifier(token_identifier) {
-            let roles = esdt_data.roles.get();
-            if !roles.contains(role.to_vec().as_ref()) {
-                let mut new_roles = roles;
-                new_roles.push(role.to_vec());
-                esdt_data.roles = EsdtRoles::new(new_roles);
-            }
-        }
+        self.0
+            .entry(token_identifier.to_vec())
+            .or_default()
+            .roles
+            .add(role);
+    }
+
+    /// Returns false if the role was not set.
+    pub fn unset_special_role(&mut self, token_identifier: &[u8], role: &[u8]) -> bool {
+        self.get_mut_by_identifier(token_identifier)
+            .is_some_and(|esdt_data| esdt_data.roles.remove(role))
+    }
+
+    pub fn has_special_role(&self, token_identifier: &[u8], role: &[u8]) -> bool {
+        self.get_by_identifier(token_identifier)
+            .is_some_and(|esdt_data| esdt_data.roles.contains(role))
     }
 
     pub fn register_and_set_roles(&mut self, token_identifier: &[u8], token_type: VMTokenType) {
@@ -181,7 +231,7 @@ impl AccountEsdt {
         );
     }
 
-    fn get_all_roles_for_token_type(token_type: VMTokenType) -> Vec<Vec<u8>> {
+    pub fn get_all_roles_for_token_type(token_type: VMTokenType) -> Vec<Vec<u8>> {
         match token_type {
             VMTokenType::NonFungible => vec![
                 "ESDTRoleNFTCreate".as_bytes().to_vec(),
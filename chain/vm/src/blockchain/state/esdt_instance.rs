@@ -9,6 +9,9 @@ pub struct EsdtInstance {
     pub nonce: u64,
     pub balance: BigUint,
     pub metadata: EsdtInstanceMetadata,
+
+    /// Set via `freezeSingleNFT`, only blocks this particular nonce.
+    pub frozen: bool,
 }
 
 impl EsdtInstance {
@@ -17,6 +20,7 @@ impl EsdtInstance {
             nonce,
             balance: BigUint::zero(),
             metadata: EsdtInstanceMetadata::default(),
+            frozen: false,
         }
     }
 
@@ -25,6 +29,7 @@ impl EsdtInstance {
             nonce: 0,
             balance,
             metadata: EsdtInstanceMetadata::default(),
+            frozen: false,
         }
     }
 
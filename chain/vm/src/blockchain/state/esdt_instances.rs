@@ -44,6 +44,7 @@ impl EsdtInstances {
             nonce,
             balance: BigUint::zero(),
             metadata: metadata.clone(),
+            frozen: false,
         });
         if instance.balance.is_zero() {
             instance.metadata = metadata;
@@ -65,6 +66,7 @@ impl EsdtInstances {
                 nonce,
                 balance: value.clone(),
                 metadata,
+                frozen: false,
             });
     }
 
@@ -84,6 +86,13 @@ impl EsdtInstances {
         self.0.get_mut(&nonce)
     }
 
+    /// Inserts an empty instance if the nonce is missing.
+    pub fn get_mut_by_nonce_or_default(&mut self, nonce: u64) -> &mut EsdtInstance {
+        self.0
+            .entry(nonce)
+            .or_insert_with(|| EsdtInstance::default(nonce))
+    }
+
     pub fn get_instances(&self) -> &BTreeMap<u64, EsdtInstance> {
         &self.0
     }
@@ -15,6 +15,24 @@ impl EsdtRoles {
     pub fn get(&self) -> Vec<Vec<u8>> {
         self.0.clone()
     }
+
+    pub fn contains(&self, role: &[u8]) -> bool {
+        self.0.iter().any(|r| r.as_slice() == role)
+    }
+
+    /// Does nothing if the role is already present.
+    pub fn add(&mut self, role: &[u8]) {
+        if !self.contains(role) {
+            self.0.push(role.to_vec());
+        }
+    }
+
+    /// Returns false if the role was not present.
+    pub fn remove(&mut self, role: &[u8]) -> bool {
+        let len_before = self.0.len();
+        self.0.retain(|r| r.as_slice() != role);
+        self.0.len() < len_before
+    }
 }
 
 impl fmt::Display for EsdtRoles {
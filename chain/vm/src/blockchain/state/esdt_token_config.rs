@@ -0,0 +1,163 @@
+use num_bigint::BigUint;
+
+use crate::types::{Address, VMTokenType};
+
+/// Token-level data, as kept by the ESDT system SC.
+///
+/// Unlike `EsdtData`, which belongs to an account, this applies to the token as a whole.
+#[derive(Clone, Default, Debug)]
+pub struct EsdtTokenConfig {
+    /// Only set for tokens issued via the system SC.
+    pub owner: Option<Address>,
+    pub token_name: Vec<u8>,
+    /// Only set for tokens issued via the system SC.
+    pub token_type: Option<VMTokenType>,
+    pub num_decimals: u32,
+    pub minted_value: BigUint,
+    pub burnt_value: BigUint,
+    pub properties: EsdtTokenProperties,
+    pub special_roles: Vec<EsdtTokenRoleHolder>,
+    pub paused: bool,
+    pub num_wiped: u64,
+    pub nft_create_stopped: bool,
+    /// Anyone holding the token can burn it, without needing a burn role.
+    pub burn_role_global: bool,
+}
+
+/// The flags set at issue, which determine what the owner can do with the token later on.
+#[derive(Clone, Debug)]
+pub struct EsdtTokenProperties {
+    pub can_freeze: bool,
+    pub can_wipe: bool,
+    pub can_pause: bool,
+    pub can_mint: bool,
+    pub can_burn: bool,
+    pub can_change_owner: bool,
+    pub can_upgrade: bool,
+    pub can_add_special_roles: bool,
+    pub can_transfer_nft_create_role: bool,
+    /// Allows more than one NFT create role holder.
+    pub can_create_multi_shard: bool,
+}
+
+impl Default for EsdtTokenProperties {
+    /// Same defaults as the protocol, when the flags are not given at issue.
+    fn default() -> Self {
+        EsdtTokenProperties {
+            can_freeze: false,
+            can_wipe: false,
+            can_pause: false,
+            can_mint: false,
+            can_burn: false,
+            can_change_owner: false,
+            can_upgrade: true,
+            can_add_special_roles: true,
+            can_transfer_nft_create_role: false,
+            can_create_multi_shard: false,
+        }
+    }
+}
+
+impl EsdtTokenProperties {
+    /// All flags set, as for `registerAndSetAllRoles`.
+    pub fn all() -> Self {
+        EsdtTokenProperties {
+            can_freeze: true,
+            can_wipe: true,
+            can_pause: true,
+            can_mint: true,
+            can_burn: true,
+            can_change_owner: true,
+            can_upgrade: true,
+            can_add_special_roles: true,
+            can_transfer_nft_create_role: true,
+            can_create_multi_shard: false,
+        }
+    }
+
+    /// Sets a property by its system SC argument name, e.g. `canFreeze`.
+    ///
+    /// Returns false if the name is not recognized.
+    pub fn set_by_name(&mut self, name: &[u8], value: bool) -> bool {
+        let field = match name {
+            b"canFreeze" => &mut self.can_freeze,
+            b"canWipe" => &mut self.can_wipe,
+            b"canPause" => &mut self.can_pause,
+            b"canMint" => &mut self.can_mint,
+            b"canBurn" => &mut self.can_burn,
+            b"canChangeOwner" => &mut self.can_change_owner,
+            b"canUpgrade" => &mut self.can_upgrade,
+            b"canAddSpecialRoles" => &mut self.can_add_special_roles,
+            b"canTransferNFTCreateRole" => &mut self.can_transfer_nft_create_role,
+            b"canCreateMultiShard" => &mut self.can_create_multi_shard,
+            _ => return false,
+        };
+        *field = value;
+        true
+    }
+}
+
+/// An address with special roles for a token, as seen by the system SC.
+#[derive(Clone, Debug)]
+pub struct EsdtTokenRoleHolder {
+    pub address: Address,
+    pub roles: Vec<Vec<u8>>,
+}
+
+impl EsdtTokenConfig {
+    /// The token type, as named by the protocol, e.g. `FungibleESDT`.
+    pub fn token_type_name(&self) -> &'static str {
+        match self.token_type {
+            Some(VMTokenType::Fungible) => "FungibleESDT",
+            Some(VMTokenType::SemiFungible) => "SemiFungibleESDT",
+            Some(VMTokenType::Meta) => "MetaESDT",
+            Some(VMTokenType::NonFungible) => "NonFungibleESDT",
+            None => "",
+        }
+    }
+
+    pub fn add_special_role(&mut self, address: &Address, role: &[u8]) {
+        if let Some(holder) = self
+            .special_roles
+            .iter_mut()
+            .find(|holder| &holder.address == address)
+        {
+            if !holder.roles.iter().any(|r| r.as_slice() == role) {
+                holder.roles.push(role.to_vec());
+            }
+        } else {
+            self.special_roles.push(EsdtTokenRoleHolder {
+                address: address.clone(),
+                roles: vec![role.to_vec()],
+            });
+        }
+    }
+
+    /// Returns false if the role was not set for the address.
+    pub fn remove_special_role(&mut self, address: &Address, role: &[u8]) -> bool {
+        let Some(index) = self
+            .special_roles
+            .iter()
+            .position(|holder| &holder.address == address)
+        else {
+            return false;
+        };
+
+        let holder = &mut self.special_roles[index];
+        let len_before = holder.roles.len();
+        holder.roles.retain(|r| r.as_slice() != role);
+        let removed = holder.roles.len() < len_before;
+        if holder.roles.is_empty() {
+            self.special_roles.remove(index);
+        }
+        removed
+    }
+
+    pub fn addresses_with_role(&self, role: &[u8]) -> Vec<Address> {
+        self.special_roles
+            .iter()
+            .filter(|holder| holder.roles.iter().any(|r| r.as_slice() == role))
+            .map(|holder| holder.address.clone())
+            .collect()
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{chain_core::std::crypto::SHA256_RESULT_LEN, crypto_functions::sha256, types::Address};
+
+use super::{AccountData, BlockchainState, EsdtData};
+
+impl BlockchainState {
+    /// Deterministic commitment to all the accounts in the state, see `compute_state_root_hash`.
+    pub fn state_root_hash(&self) -> [u8; SHA256_RESULT_LEN] {
+        compute_state_root_hash(self.accounts.values())
+    }
+}
+
+/// Hashes the accounts in address order, each account hash covering
+/// nonce, balances, ESDT tokens, storage, code, owner and developer rewards.
+///
+/// It is not the trie root hash computed by the protocol,
+/// but it changes whenever anything in the accounts changes, and only then.
+pub fn compute_state_root_hash<'a, I>(accounts: I) -> [u8; SHA256_RESULT_LEN]
+where
+    I: IntoIterator<Item = &'a AccountData>,
+{
+    let mut account_hashes: Vec<(&Address, [u8; SHA256_RESULT_LEN])> = accounts
+        .into_iter()
+        .map(|account| (&account.address, account_hash(account)))
+        .collect();
+    account_hashes.sort_by(|a, b| a.0.as_bytes().cmp(b.0.as_bytes()));
+
+    let mut encoded = Vec::new();
+    for (address, hash) in account_hashes {
+        encoded.extend_from_slice(address.as_bytes());
+        encoded.extend_from_slice(&hash);
+    }
+    sha256(&encoded)
+}
+
+fn account_hash(account: &AccountData) -> [u8; SHA256_RESULT_LEN] {
+    let mut encoded = Vec::new();
+    encoded.extend_from_slice(&account.nonce.to_be_bytes());
+    push_bytes(&mut encoded, &account.egld_balance.to_bytes_be());
+    push_bytes(&mut encoded, &account.username);
+    push_bytes(
+        &mut encoded,
+        account.contract_path.as_deref().unwrap_or_default(),
+    );
+    encoded.extend_from_slice(&account.code_metadata.to_byte_array());
+    push_bytes(
+        &mut encoded,
+        account
+            .contract_owner
+            .as_ref()
+            .map(Address::as_bytes)
+            .unwrap_or_default(),
+    );
+    push_bytes(&mut encoded, &account.developer_rewards.to_bytes_be());
+
+    let mut storage: Vec<(&Vec<u8>, &Vec<u8>)> = account
+        .storage
+        .iter()
+        .filter(|(_, value)| !value.is_empty())
+        .collect();
+    storage.sort();
+    push_count(&mut encoded, storage.len());
+    for (key, value) in storage {
+        push_bytes(&mut encoded, key);
+        push_bytes(&mut encoded, value);
+    }
+
+    let mut esdt: Vec<(&Vec<u8>, &EsdtData)> = account.esdt.iter().collect();
+    esdt.sort_by(|a, b| a.0.cmp(b.0));
+    push_count(&mut encoded, esdt.len());
+    for (token_identifier, esdt_data) in esdt {
+        push_bytes(&mut encoded, token_identifier);
+        push_esdt_data(&mut encoded, esdt_data);
+    }
+
+    sha256(&encoded)
+}
+
+fn push_esdt_data(encoded: &mut Vec<u8>, esdt_data: &EsdtData) {
+    encoded.extend_from_slice(&esdt_data.last_nonce.to_be_bytes());
+    encoded.push(esdt_data.frozen as u8);
+    let roles = esdt_data.roles.get();
+    push_count(encoded, roles.len());
+    for role in roles {
+        push_bytes(encoded, &role);
+    }
+    let instances = esdt_data.instances.get_instances();
+    push_count(encoded, instances.len());
+    for (nonce, instance) in instances {
+        encoded.extend_from_slice(&nonce.to_be_bytes());
+        push_bytes(encoded, &instance.balance.to_bytes_be());
+        encoded.push(instance.frozen as u8);
+
+        let metadata = &instance.metadata;
+        push_bytes(encoded, &metadata.name);
+        push_bytes(
+            encoded,
+            metadata
+                .creator
+                .as_ref()
+                .map(Address::as_bytes)
+                .unwrap_or_default(),
+        );
+        encoded.extend_from_slice(&metadata.royalties.to_be_bytes());
+        push_bytes(encoded, metadata.hash.as_deref().unwrap_or_default());
+        push_count(encoded, metadata.uri.len());
+        for uri in &metadata.uri {
+            push_bytes(encoded, uri);
+        }
+        push_bytes(encoded, &metadata.attributes);
+    }
+}
+
+/// Length-prefixed, so that consecutive fields cannot be confused.
+fn push_bytes(encoded: &mut Vec<u8>, bytes: &[u8]) {
+    push_count(encoded, bytes.len());
+    encoded.extend_from_slice(bytes);
+}
+
+fn push_count(encoded: &mut Vec<u8>, count: usize) {
+    encoded.extend_from_slice(&(count as u32).to_be_bytes());
+}
@@ -0,0 +1,54 @@
+use crate::{
+    blockchain::reserved::STORAGE_TIME_LOCK_PREFIX,
+    types::{Address, top_decode_u64, top_encode_u64},
+};
+
+use super::{BlockInfo, BlockchainState};
+
+/// The protected storage key that holds the lock of a regular storage key.
+pub fn storage_lock_key(key: &[u8]) -> Vec<u8> {
+    [STORAGE_TIME_LOCK_PREFIX, key].concat()
+}
+
+/// Same serialization as the Go VM: minimal big-endian, the sign is discarded.
+pub fn storage_lock_to_bytes(lock_timestamp: i64) -> Vec<u8> {
+    top_encode_u64(lock_timestamp.unsigned_abs())
+}
+
+pub fn storage_lock_from_bytes(bytes: &[u8]) -> i64 {
+    top_decode_u64(bytes) as i64
+}
+
+impl BlockInfo {
+    /// A storage lock holds for as long as its timestamp (in seconds) is after the block timestamp.
+    pub fn is_storage_lock_active(&self, lock_timestamp: i64) -> bool {
+        lock_timestamp > self.block_timestamp_millis.to_seconds().as_u64_seconds() as i64
+    }
+}
+
+impl BlockchainState {
+    pub fn get_storage_lock(&self, address: &Address, key: &[u8]) -> i64 {
+        self.accounts
+            .get(address)
+            .and_then(|account| account.storage.get(&storage_lock_key(key)))
+            .map(|value| storage_lock_from_bytes(value))
+            .unwrap_or_default()
+    }
+
+    pub fn set_storage_lock(&mut self, address: &Address, key: &[u8], lock_timestamp: i64) {
+        let account = self
+            .accounts
+            .get_mut(address)
+            .unwrap_or_else(|| panic!("Account not found: {address}"));
+        account
+            .storage
+            .insert(storage_lock_key(key), storage_lock_to_bytes(lock_timestamp));
+    }
+
+    /// Checks the lock against the current block timestamp.
+    pub fn is_storage_locked(&self, address: &Address, key: &[u8]) -> bool {
+        self.block_config
+            .current_block_info
+            .is_storage_lock_active(self.get_storage_lock(address, key))
+    }
+}
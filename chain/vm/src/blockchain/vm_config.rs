@@ -1,12 +1,35 @@
-use std::{ops::Deref, sync::Arc};
+use std::{
+    ops::Deref,
+    sync::{Arc, Mutex, MutexGuard},
+};
 
-use crate::{builtin_functions::BuiltinFunctionContainer, schedule::GasSchedule};
+use crate::{
+    builtin_functions::BuiltinFunctionContainer,
+    chain_core::types::{ShardConfig, ShardId},
+    schedule::GasSchedule,
+    types::Address,
+};
+
+use super::RandomnessControl;
 
 #[derive(Default)]
 pub struct VMConfig {
     pub builtin_functions: BuiltinFunctionContainer,
     pub gas_schedule: GasSchedule,
     pub insert_ghost_accounts: bool,
+
+    /// When set, builtin functions consume gas according to the gas schedule,
+    /// and executors that do not meter instructions (such as the contract debugger) keep track of the gas used by VM hooks.
+    pub gas_metering: bool,
+
+    /// Enables the cross-shard mode: async calls and callbacks between different shards
+    /// are not executed right away, they get queued in the blockchain state instead.
+    pub shard_config: Option<ShardConfig>,
+
+    /// Lets tests pin, script and replay the random numbers served to contracts.
+    ///
+    /// Can be changed between transactions, without exclusive access to the configuration.
+    pub randomness: Mutex<RandomnessControl>,
 }
 
 #[derive(Clone, Default)]
@@ -16,6 +39,19 @@ impl VMConfig {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// The source and destination shards, if the addresses are in different shards and the cross-shard mode is on.
+    pub fn cross_shard_route(&self, from: &Address, to: &Address) -> Option<(ShardId, ShardId)> {
+        let shard_config = self.shard_config.as_ref()?;
+        if shard_config.same_shard(from, to) {
+            return None;
+        }
+        Some((shard_config.compute_id(from), shard_config.compute_id(to)))
+    }
+
+    pub fn randomness_lock(&self) -> MutexGuard<'_, RandomnessControl> {
+        self.randomness.lock().unwrap()
+    }
 }
 
 impl VMConfigRef {
@@ -33,6 +69,16 @@ impl VMConfigRef {
         let vm_config = Arc::get_mut(&mut self.0).expect("cannot configure VM during execution");
         vm_config.insert_ghost_accounts = insert_ghost_accounts;
     }
+
+    pub fn set_gas_metering(&mut self, gas_metering: bool) {
+        let vm_config = Arc::get_mut(&mut self.0).expect("cannot configure VM during execution");
+        vm_config.gas_metering = gas_metering;
+    }
+
+    pub fn set_shard_config(&mut self, shard_config: Option<ShardConfig>) {
+        let vm_config = Arc::get_mut(&mut self.0).expect("cannot configure VM during execution");
+        vm_config.shard_config = shard_config;
+    }
 }
 
 impl Deref for VMConfigRef {
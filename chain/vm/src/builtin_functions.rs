@@ -1,4 +1,5 @@
 mod builtin_func_container;
+mod builtin_func_gas;
 mod builtin_func_trait;
 mod esdt_nft;
 mod general;
@@ -1,18 +1,23 @@
 use super::{
     BuiltinFunctionEsdtTransferInfo,
+    builtin_func_gas::builtin_function_gas_cost,
     builtin_func_trait::BuiltinFunction,
     esdt_nft::{
-        ESDTLocalBurn, ESDTLocalMint, ESDTMetadataRecreate, ESDTMetadataUpdate, ESDTNftAddQuantity,
-        ESDTNftAddUri, ESDTNftBurn, ESDTNftCreate, ESDTNftUpdateAttributes,
+        ESDTLocalBurn, ESDTLocalMint, ESDTMetadataRecreate, ESDTMetadataUpdate, ESDTModifyCreator,
+        ESDTModifyRoyalties, ESDTNftAddQuantity, ESDTNftAddUri, ESDTNftBurn, ESDTNftCreate,
+        ESDTNftUpdateAttributes, ESDTSetNewURIs,
     },
     general::{ChangeOwner, ClaimDeveloperRewards, DeleteUsername, SetUsername, UpgradeContract},
     transfer::{ESDTMultiTransfer, ESDTNftTransfer, ESDTTransfer},
 };
 
 use crate::{
-    host::context::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    chain_core::types::ReturnCode,
+    host::context::{BlockchainUpdate, GasUsed, TxCache, TxInput, TxResult},
     host::runtime::{RuntimeInstanceCallLambda, RuntimeRef},
+    schedule::GasCostCategory,
     types::EsdtLocalRole,
+    vm_err_msg,
 };
 
 use crate::chain_core::builtin_func_names::*;
@@ -112,6 +117,15 @@ impl<'a> BuiltinFunctionCall<'a> {
             ESDT_METADATA_UPDATE_FUNC_NAME => {
                 self.check_role_and_execute(EsdtLocalRole::NftRecreate, ESDTMetadataUpdate, f)
             }
+            ESDT_MODIFY_ROYALTIES_FUNC_NAME => {
+                self.check_role_and_execute(EsdtLocalRole::ModifyRoyalties, ESDTModifyRoyalties, f)
+            }
+            ESDT_SET_NEW_URIS_FUNC_NAME => {
+                self.check_role_and_execute(EsdtLocalRole::SetNewUri, ESDTSetNewURIs, f)
+            }
+            ESDT_MODIFY_CREATOR_FUNC_NAME => {
+                self.check_role_and_execute(EsdtLocalRole::ModifyCreator, ESDTModifyCreator, f)
+            }
 
             ESDT_MULTI_TRANSFER_FUNC_NAME => self.execute_bf(ESDTMultiTransfer, f),
             ESDT_NFT_TRANSFER_FUNC_NAME => self.execute_bf(ESDTNftTransfer, f),
@@ -133,7 +147,47 @@ impl<'a> BuiltinFunctionCall<'a> {
         B: BuiltinFunction,
         F: RuntimeInstanceCallLambda,
     {
-        builtin_func.execute(self.tx_input, self.tx_cache, self.runtime, f)
+        if self.runtime.vm_ref.gas_metering {
+            self.execute_bf_metered(builtin_func, f)
+        } else {
+            builtin_func.execute(self.tx_input, self.tx_cache, self.runtime, f)
+        }
+    }
+
+    /// Charges the builtin function cost up front, then passes the remaining gas on to the contract call, if any.
+    fn execute_bf_metered<B, F>(mut self, builtin_func: B, f: F) -> (TxResult, BlockchainUpdate)
+    where
+        B: BuiltinFunction,
+        F: RuntimeInstanceCallLambda,
+    {
+        let gas_limit = self.tx_input.gas_limit;
+        let num_transfers = builtin_func
+            .extract_esdt_transfers(&self.tx_input)
+            .transfers
+            .len();
+        let gas_cost = builtin_function_gas_cost(
+            self.tx_input.func_name.as_str(),
+            num_transfers,
+            &self.runtime.vm_ref.gas_schedule,
+        );
+        if gas_cost > gas_limit {
+            let mut tx_result =
+                TxResult::from_error(ReturnCode::OutOfGas, vm_err_msg::NOT_ENOUGH_GAS);
+            tx_result.gas_used = GasUsed::AllGas(gas_limit);
+            return (tx_result, BlockchainUpdate::empty());
+        }
+
+        self.tx_input.gas_limit -= gas_cost;
+        let (mut tx_result, blockchain_updates) =
+            builtin_func.execute(self.tx_input, self.tx_cache, self.runtime, f);
+
+        tx_result.add_gas_breakdown(GasCostCategory::BuiltinFunction, gas_cost);
+        tx_result.gas_used = if tx_result.result_status.is_success() {
+            GasUsed::SomeGas(gas_cost + tx_result.gas_used.as_u64())
+        } else {
+            GasUsed::AllGas(gas_limit)
+        };
+        (tx_result, blockchain_updates)
     }
 
     fn check_role_and_execute<B, F>(
@@ -150,7 +204,7 @@ impl<'a> BuiltinFunctionCall<'a> {
             self.execute_bf(builtin_func, f)
         } else {
             (
-                TxResult::from_vm_error("action is not allowed"),
+                TxResult::from_vm_error(vm_err_msg::ACTION_NOT_ALLOWED),
                 BlockchainUpdate::empty(),
             )
         }
@@ -158,11 +212,8 @@ impl<'a> BuiltinFunctionCall<'a> {
 }
 
 fn check_allowed_to_execute(role: EsdtLocalRole, tx_input: &TxInput, tx_cache: &TxCache) -> bool {
-    let token_identifier = tx_input.args[0].clone();
-    let available_roles = tx_cache.with_account_mut(&tx_input.to, |account| {
-        account.esdt.get_roles(&token_identifier)
-    });
-    available_roles
-        .iter()
-        .any(|available_role| available_role.as_slice() == role.name().as_bytes())
+    let token_identifier = tx_input.args[0].as_slice();
+    tx_cache
+        .check_esdt_role(&tx_input.to, token_identifier, role)
+        .is_ok()
 }
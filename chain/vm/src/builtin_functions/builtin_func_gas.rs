@@ -0,0 +1,60 @@
+use crate::{chain_core::builtin_func_names::*, schedule::GasSchedule};
+
+/// The base gas cost of a builtin function, as configured in the `BuiltInCost` section of the gas schedule.
+///
+/// Multi-transfers are charged once per transferred token, so the number of transfers needs to be provided.
+///
+/// Builtin functions that have no entry in the gas schedule cost nothing.
+pub fn builtin_function_gas_cost(
+    func_name: &str,
+    num_transfers: usize,
+    gas_schedule: &GasSchedule,
+) -> u64 {
+    let builtin_cost = &gas_schedule.builtin_cost;
+    match func_name {
+        ESDT_LOCAL_MINT_FUNC_NAME => builtin_cost.esdt_local_mint,
+        ESDT_LOCAL_BURN_FUNC_NAME => builtin_cost.esdt_local_burn,
+        ESDT_NFT_CREATE_FUNC_NAME => builtin_cost.esdtnft_create,
+        ESDT_NFT_BURN_FUNC_NAME => builtin_cost.esdtnft_burn,
+        ESDT_NFT_ADD_QUANTITY_FUNC_NAME => builtin_cost.esdtnft_add_quantity,
+        ESDT_NFT_ADD_URI_FUNC_NAME => builtin_cost.esdtnft_add_uri,
+        ESDT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME => builtin_cost.esdtnft_update_attributes,
+        ESDT_METADATA_RECREATE_FUNC_NAME => builtin_cost.esdtnft_recreate,
+        ESDT_METADATA_UPDATE_FUNC_NAME => builtin_cost.esdtnft_update,
+        ESDT_MODIFY_ROYALTIES_FUNC_NAME => builtin_cost.esdt_modify_royalties,
+        ESDT_SET_NEW_URIS_FUNC_NAME => builtin_cost.esdtnft_set_new_ur_is,
+        ESDT_MODIFY_CREATOR_FUNC_NAME => builtin_cost.esdt_modify_creator,
+        ESDT_TRANSFER_FUNC_NAME => builtin_cost.esdt_transfer,
+        ESDT_NFT_TRANSFER_FUNC_NAME => builtin_cost.esdtnft_transfer,
+        ESDT_MULTI_TRANSFER_FUNC_NAME => builtin_cost
+            .multi_esdtnft_transfer
+            .saturating_mul(num_transfers as u64),
+        CHANGE_OWNER_BUILTIN_FUNC_NAME => builtin_cost.change_owner_address,
+        CLAIM_DEVELOPER_REWARDS_FUNC_NAME => builtin_cost.claim_developer_rewards,
+        SET_USERNAME_FUNC_NAME => builtin_cost.save_user_name,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schedule::GasScheduleVersion;
+
+    #[test]
+    fn builtin_function_gas_cost_v9() {
+        let gas_schedule = GasScheduleVersion::V9.load_gas_schedule();
+        assert_eq!(
+            builtin_function_gas_cost(ESDT_TRANSFER_FUNC_NAME, 1, &gas_schedule),
+            200000
+        );
+        assert_eq!(
+            builtin_function_gas_cost(ESDT_MULTI_TRANSFER_FUNC_NAME, 3, &gas_schedule),
+            600000
+        );
+        assert_eq!(
+            builtin_function_gas_cost(UPGRADE_CONTRACT_FUNC_NAME, 0, &gas_schedule),
+            0
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use multiversx_chain_core::types::ReturnCode;
+
+use crate::{
+    chain_core::builtin_func_names::ESDT_MODIFY_CREATOR_FUNC_NAME,
+    host::context::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
+    host::runtime::{RuntimeInstanceCallLambda, RuntimeRef},
+    types::{top_decode_u64, top_encode_u64},
+};
+
+use super::super::builtin_func_trait::BuiltinFunction;
+
+/// Makes the caller the new creator of the NFT.
+pub struct ESDTModifyCreator;
+
+impl BuiltinFunction for ESDTModifyCreator {
+    fn name(&self) -> &str {
+        ESDT_MODIFY_CREATOR_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _runtime: &RuntimeRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: RuntimeInstanceCallLambda,
+    {
+        if tx_input.args.len() != 2 {
+            let err_result = TxResult::from_vm_error("ESDTModifyCreator expects 2 arguments");
+            return (err_result, BlockchainUpdate::empty());
+        }
+
+        let token_identifier = tx_input.args[0].clone();
+        let nonce = top_decode_u64(tx_input.args[1].as_slice());
+        let new_creator = tx_input.from.clone();
+
+        let found = tx_cache.with_account_mut(&tx_input.from, |account| {
+            account
+                .esdt
+                .get_mut_by_identifier(&token_identifier)
+                .and_then(|esdt_data| esdt_data.instances.get_mut_by_nonce(nonce))
+                .map(|instance| instance.metadata.creator = Some(new_creator.clone()))
+                .is_some()
+        });
+        if !found {
+            let err_result = TxResult::from_vm_error("ESDTModifyCreator: token not found");
+            return (err_result, BlockchainUpdate::empty());
+        }
+
+        let esdt_modify_creator_log = TxLog {
+            address: tx_input.from,
+            endpoint: ESDT_MODIFY_CREATOR_FUNC_NAME.into(),
+            topics: vec![
+                token_identifier,
+                top_encode_u64(nonce),
+                Vec::new(), // value = 0
+                new_creator.to_vec(),
+            ],
+            data: vec![],
+        };
+
+        let tx_result = TxResult {
+            result_status: ReturnCode::Success,
+            result_logs: vec![esdt_modify_creator_log],
+            ..Default::default()
+        };
+
+        (tx_result, tx_cache.into_blockchain_updates())
+    }
+}
@@ -0,0 +1,79 @@
+use multiversx_chain_core::types::ReturnCode;
+
+use crate::{
+    chain_core::builtin_func_names::ESDT_MODIFY_ROYALTIES_FUNC_NAME,
+    host::context::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
+    host::runtime::{RuntimeInstanceCallLambda, RuntimeRef},
+    types::{top_decode_u64, top_encode_u64},
+};
+
+use super::super::builtin_func_trait::BuiltinFunction;
+
+/// Royalties are expressed in hundredths of a percent, so 10000 means 100%.
+const MAX_ROYALTIES: u64 = 10_000;
+
+pub struct ESDTModifyRoyalties;
+
+impl BuiltinFunction for ESDTModifyRoyalties {
+    fn name(&self) -> &str {
+        ESDT_MODIFY_ROYALTIES_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _runtime: &RuntimeRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: RuntimeInstanceCallLambda,
+    {
+        if tx_input.args.len() != 3 {
+            let err_result = TxResult::from_vm_error("ESDTModifyRoyalties expects 3 arguments");
+            return (err_result, BlockchainUpdate::empty());
+        }
+
+        let token_identifier = tx_input.args[0].clone();
+        let nonce = top_decode_u64(tx_input.args[1].as_slice());
+        let royalties = top_decode_u64(tx_input.args[2].as_slice());
+
+        if royalties > MAX_ROYALTIES {
+            let err_result = TxResult::from_vm_error("invalid royalties value");
+            return (err_result, BlockchainUpdate::empty());
+        }
+
+        let found = tx_cache.with_account_mut(&tx_input.from, |account| {
+            account
+                .esdt
+                .get_mut_by_identifier(&token_identifier)
+                .and_then(|esdt_data| esdt_data.instances.get_mut_by_nonce(nonce))
+                .map(|instance| instance.metadata.royalties = royalties)
+                .is_some()
+        });
+        if !found {
+            let err_result = TxResult::from_vm_error("ESDTModifyRoyalties: token not found");
+            return (err_result, BlockchainUpdate::empty());
+        }
+
+        let esdt_modify_royalties_log = TxLog {
+            address: tx_input.from,
+            endpoint: ESDT_MODIFY_ROYALTIES_FUNC_NAME.into(),
+            topics: vec![
+                token_identifier,
+                top_encode_u64(nonce),
+                Vec::new(), // value = 0
+                top_encode_u64(royalties),
+            ],
+            data: vec![],
+        };
+
+        let tx_result = TxResult {
+            result_status: ReturnCode::Success,
+            result_logs: vec![esdt_modify_royalties_log],
+            ..Default::default()
+        };
+
+        (tx_result, tx_cache.into_blockchain_updates())
+    }
+}
@@ -0,0 +1,77 @@
+use multiversx_chain_core::types::ReturnCode;
+
+use crate::{
+    chain_core::builtin_func_names::ESDT_SET_NEW_URIS_FUNC_NAME,
+    host::context::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
+    host::runtime::{RuntimeInstanceCallLambda, RuntimeRef},
+    types::{top_decode_u64, top_encode_u64},
+};
+
+use super::super::builtin_func_trait::BuiltinFunction;
+
+pub struct ESDTSetNewURIs;
+
+impl BuiltinFunction for ESDTSetNewURIs {
+    fn name(&self) -> &str {
+        ESDT_SET_NEW_URIS_FUNC_NAME
+    }
+
+    fn execute<F>(
+        &self,
+        tx_input: TxInput,
+        tx_cache: TxCache,
+        _runtime: &RuntimeRef,
+        _f: F,
+    ) -> (TxResult, BlockchainUpdate)
+    where
+        F: RuntimeInstanceCallLambda,
+    {
+        if tx_input.args.len() < 3 {
+            let err_result = TxResult::from_vm_error("ESDTSetNewURIs expects at least 3 arguments");
+            return (err_result, BlockchainUpdate::empty());
+        }
+
+        let token_identifier = tx_input.args[0].clone();
+        let nonce = top_decode_u64(tx_input.args[1].as_slice());
+        // a single empty URI is sent when clearing them all
+        let new_uris: Vec<Vec<u8>> = tx_input.args[2..]
+            .iter()
+            .filter(|uri| !uri.is_empty())
+            .cloned()
+            .collect();
+
+        let found = tx_cache.with_account_mut(&tx_input.from, |account| {
+            account
+                .esdt
+                .get_mut_by_identifier(&token_identifier)
+                .and_then(|esdt_data| esdt_data.instances.get_mut_by_nonce(nonce))
+                .map(|instance| instance.metadata.uri = new_uris.clone())
+                .is_some()
+        });
+        if !found {
+            let err_result = TxResult::from_vm_error("ESDTSetNewURIs: token not found");
+            return (err_result, BlockchainUpdate::empty());
+        }
+
+        let mut topics = vec![
+            token_identifier,
+            top_encode_u64(nonce),
+            Vec::new(), // value = 0
+        ];
+        topics.extend(new_uris);
+        let esdt_set_new_uris_log = TxLog {
+            address: tx_input.from,
+            endpoint: ESDT_SET_NEW_URIS_FUNC_NAME.into(),
+            topics,
+            data: vec![],
+        };
+
+        let tx_result = TxResult {
+            result_status: ReturnCode::Success,
+            result_logs: vec![esdt_set_new_uris_log],
+            ..Default::default()
+        };
+
+        (tx_result, tx_cache.into_blockchain_updates())
+    }
+}
@@ -60,6 +60,10 @@ impl BuiltinFunction for UpgradeContract {
             gas_price: tx_input.gas_price,
             tx_hash: tx_input.tx_hash,
             call_type: CallType::UpgradeFromSource,
+            original_caller: tx_input.original_caller,
+            original_tx_hash: tx_input.original_tx_hash,
+            prev_tx_hash: tx_input.prev_tx_hash,
+            relayer: tx_input.relayer,
             ..Default::default()
         };
 
@@ -68,16 +68,32 @@ pub(super) fn execute_transfer_builtin_func<F>(
 where
     F: RuntimeInstanceCallLambda,
 {
+    let esdt_values = process_raw_esdt_transfers(parsed_tx.raw_esdt_transfers);
+    for esdt_value in &esdt_values {
+        if let Err(err) = tx_cache.check_esdt_transfer_allowed(
+            &tx_input.from,
+            &parsed_tx.destination,
+            &esdt_value.token_identifier,
+            esdt_value.nonce,
+        ) {
+            return (TxResult::from_panic_obj(&err), BlockchainUpdate::empty());
+        }
+    }
+
     let exec_input = TxInput {
         from: tx_input.from,
         to: parsed_tx.destination,
         egld_value: BigUint::zero(),
-        esdt_values: process_raw_esdt_transfers(parsed_tx.raw_esdt_transfers),
+        esdt_values,
         func_name: parsed_tx.func_name,
         args: parsed_tx.args,
         gas_limit: tx_input.gas_limit,
         gas_price: tx_input.gas_price,
         tx_hash: tx_input.tx_hash,
+        original_caller: tx_input.original_caller,
+        original_tx_hash: tx_input.original_tx_hash,
+        prev_tx_hash: tx_input.prev_tx_hash,
+        relayer: tx_input.relayer,
         ..Default::default()
     };
 
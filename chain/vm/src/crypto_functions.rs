@@ -34,3 +34,107 @@ pub fn verify_ed25519(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
     let result = verifying_key.verify(message, &sig);
     result.is_ok()
 }
+
+pub const RIPEMD160_RESULT_LEN: usize = 20;
+
+pub fn ripemd160(data: &[u8]) -> [u8; RIPEMD160_RESULT_LEN] {
+    use ripemd::{Digest, Ripemd160};
+
+    Ripemd160::digest(data).into()
+}
+
+/// Message hash types accepted by `verify_custom_secp256k1`, same codes as `MessageHashType` in the framework.
+pub const ECDSA_PLAIN_MSG: u8 = 0;
+pub const ECDSA_SHA256: u8 = 1;
+pub const ECDSA_DOUBLE_SHA256: u8 = 2;
+pub const ECDSA_KECCAK256: u8 = 3;
+pub const ECDSA_RIPEMD160: u8 = 4;
+
+fn secp256k1_message_hash(message: &[u8], hash_type: u8) -> Option<Vec<u8>> {
+    match hash_type {
+        ECDSA_PLAIN_MSG => Some(message.to_vec()),
+        ECDSA_SHA256 => Some(sha256(message).to_vec()),
+        ECDSA_DOUBLE_SHA256 => Some(sha256(&sha256(message)).to_vec()),
+        ECDSA_KECCAK256 => Some(keccak256(message).to_vec()),
+        ECDSA_RIPEMD160 => Some(ripemd160(message).to_vec()),
+        _ => None,
+    }
+}
+
+/// Verifies a DER-encoded secp256k1 signature, against a compressed or uncompressed public key.
+///
+/// The message is hashed according to `hash_type` first.
+pub fn verify_secp256k1(key: &[u8], message: &[u8], signature: &[u8], hash_type: u8) -> bool {
+    use k256::ecdsa::{Signature, VerifyingKey, signature::hazmat::PrehashVerifier};
+
+    let Some(message_hash) = secp256k1_message_hash(message, hash_type) else {
+        return false;
+    };
+    let Ok(verifying_key) = VerifyingKey::from_sec1_bytes(key) else {
+        return false;
+    };
+    let Ok(sig) = Signature::from_der(signature) else {
+        return false;
+    };
+
+    verifying_key.verify_prehash(&message_hash, &sig).is_ok()
+}
+
+/// Encodes the `r` and `s` components of a signature as a DER sequence.
+///
+/// Only short form DER lengths are produced, `None` if the components are too long for them.
+pub fn encode_secp256k1_der_signature(r: &[u8], s: &[u8]) -> Option<Vec<u8>> {
+    let r = der_integer(r)?;
+    let s = der_integer(s)?;
+
+    let mut result = Vec::with_capacity(2 + r.len() + s.len());
+    result.push(0x30);
+    result.push(der_short_length(r.len() + s.len())?);
+    result.extend_from_slice(&r);
+    result.extend_from_slice(&s);
+    Some(result)
+}
+
+/// Minimal DER encoding of an unsigned big-endian integer.
+fn der_integer(bytes: &[u8]) -> Option<Vec<u8>> {
+    let first_non_zero = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    let trimmed = &bytes[first_non_zero..];
+
+    let mut value = Vec::with_capacity(trimmed.len() + 1);
+    if trimmed.first().is_none_or(|b| b & 0x80 != 0) {
+        value.push(0);
+    }
+    value.extend_from_slice(trimmed);
+
+    let mut result = Vec::with_capacity(value.len() + 2);
+    result.push(0x02);
+    result.push(der_short_length(value.len())?);
+    result.extend_from_slice(&value);
+    Some(result)
+}
+
+/// Lengths up to 127 fit in a single byte, longer ones would need the long form.
+fn der_short_length(length: usize) -> Option<u8> {
+    u8::try_from(length).ok().filter(|length| *length < 0x80)
+}
+
+/// Verifies a secp256r1 (P-256) signature over the SHA-256 hash of the message.
+///
+/// The signature can be either the raw 64-byte `r || s`, or DER-encoded.
+pub fn verify_secp256r1(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    use p256::ecdsa::{Signature, VerifyingKey, signature::Verifier};
+
+    let Ok(verifying_key) = VerifyingKey::from_sec1_bytes(key) else {
+        return false;
+    };
+    let sig_result = if signature.len() == 64 {
+        Signature::from_slice(signature)
+    } else {
+        Signature::from_der(signature)
+    };
+    let Ok(sig) = sig_result else {
+        return false;
+    };
+
+    verifying_key.verify(message, &sig).is_ok()
+}
@@ -1,41 +1,63 @@
 use rand::Rng;
 use rand_seeder::{SipHasher, SipRng};
 
+use crate::blockchain::VMConfigRef;
+
 use super::{TxCache, TxInput};
 
-#[derive(Debug)]
 pub struct BlockchainRng {
     pub rng: SipRng,
+    vm_ref: VMConfigRef,
+}
+
+impl core::fmt::Debug for BlockchainRng {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BlockchainRng")
+            .field("rng", &self.rng)
+            .finish()
+    }
 }
 
 impl BlockchainRng {
-    pub fn new(tx_input: &TxInput, tx_cache: &TxCache) -> Self {
-        let mut seed = Vec::new();
-        seed.extend_from_slice(
-            &tx_cache
-                .blockchain_ref()
-                .block_config
-                .previous_block_info
-                .block_random_seed[..],
-        );
-        seed.extend_from_slice(
-            &tx_cache
-                .blockchain_ref()
-                .block_config
-                .current_block_info
-                .block_random_seed[..],
-        );
-        seed.extend_from_slice(tx_input.tx_hash.as_bytes());
+    pub fn new(tx_input: &TxInput, tx_cache: &TxCache, vm_ref: VMConfigRef) -> Self {
+        let pinned_seed = vm_ref.randomness_lock().pinned_seed.clone();
+        let seed = pinned_seed.unwrap_or_else(|| block_seed(tx_input, tx_cache));
 
         let hasher = SipHasher::from(&seed);
         Self {
             rng: hasher.into_rng(),
+            vm_ref,
         }
     }
 
+    /// Scripted bytes are served first, the rest comes from the random stream.
     pub fn next_bytes(&mut self, length: usize) -> Vec<u8> {
-        let mut bytes = vec![0; length];
-        self.rng.fill_bytes(&mut bytes);
+        let mut randomness = self.vm_ref.randomness_lock();
+        let mut bytes = randomness.take_scripted(length);
+        let num_scripted = bytes.len();
+        bytes.resize(length, 0);
+        self.rng.fill_bytes(&mut bytes[num_scripted..]);
+        randomness.record_consumed(&bytes);
         bytes
     }
 }
+
+fn block_seed(tx_input: &TxInput, tx_cache: &TxCache) -> Vec<u8> {
+    let mut seed = Vec::new();
+    seed.extend_from_slice(
+        &tx_cache
+            .blockchain_ref()
+            .block_config
+            .previous_block_info
+            .block_random_seed[..],
+    );
+    seed.extend_from_slice(
+        &tx_cache
+            .blockchain_ref()
+            .block_config
+            .current_block_info
+            .block_random_seed[..],
+    );
+    seed.extend_from_slice(tx_input.tx_hash.as_bytes());
+    seed
+}
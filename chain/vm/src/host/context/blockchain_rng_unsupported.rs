@@ -1,10 +1,12 @@
+use crate::blockchain::VMConfigRef;
+
 use super::{TxCache, TxInput};
 
 #[derive(Debug)]
 pub struct BlockchainRng;
 
 impl BlockchainRng {
-    pub fn new(_tx_input: &TxInput, _tx_cache: &TxCache) -> Self {
+    pub fn new(_tx_input: &TxInput, _tx_cache: &TxCache, _vm_ref: VMConfigRef) -> Self {
         BlockchainRng
     }
 
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use crate::{
-    blockchain::state::{AccountData, BlockchainState},
+    blockchain::state::{AccountData, BlockchainState, EsdtTokenConfig},
     types::Address,
 };
 
@@ -9,6 +9,7 @@ use crate::{
 pub struct BlockchainUpdate {
     pub accounts: HashMap<Address, AccountData>,
     pub new_token_identifiers: Option<Vec<String>>,
+    pub esdt_tokens: HashMap<Vec<u8>, EsdtTokenConfig>,
 }
 
 impl BlockchainUpdate {
@@ -18,6 +19,7 @@ impl BlockchainUpdate {
 
     pub fn apply(self, blockchain: &mut BlockchainState) {
         blockchain.update_accounts(self.accounts);
+        blockchain.update_esdt_tokens(self.esdt_tokens);
 
         if let Some(token_identifiers) = self.new_token_identifiers {
             blockchain.update_new_token_identifiers(token_identifiers);
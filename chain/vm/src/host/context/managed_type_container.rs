@@ -1,9 +1,12 @@
+mod elliptic_curve;
 mod handle_map;
 mod tx_big_float;
 mod tx_big_int;
+mod tx_elliptic_curve;
 mod tx_managed_buffer;
 mod tx_managed_map;
 
+pub use elliptic_curve::EllipticCurve;
 pub use handle_map::HandleMap;
 use num_bigint::BigInt;
 pub use tx_big_int::{
@@ -22,6 +25,7 @@ pub struct ManagedTypeContainer {
     pub(crate) big_float_map: HandleMap<f64>,
     pub(crate) managed_buffer_map: HandleMap<ManagedBufferImpl>,
     pub(crate) managed_map_map: HandleMap<ManagedMapImpl>,
+    pub(crate) elliptic_curve_map: HandleMap<EllipticCurve>,
 }
 
 impl ManagedTypeContainer {
@@ -31,6 +35,7 @@ impl ManagedTypeContainer {
             big_float_map: HandleMap::new(),
             managed_buffer_map: HandleMap::new(),
             managed_map_map: HandleMap::new(),
+            elliptic_curve_map: HandleMap::new(),
         }
     }
 }
@@ -0,0 +1,430 @@
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+/// Affine point, `None` is the point at infinity.
+type Point = Option<(BigUint, BigUint)>;
+
+/// One of the NIST curves supported by the VM: `y² = x³ - 3x + b` over the prime field of order `p`.
+///
+/// Mirrors the generic curve implementation in Go (`elliptic.CurveParams`), including its conventions:
+/// the point at infinity is represented as (0, 0), scalars are big-endian byte arrays.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EllipticCurve {
+    pub name: &'static str,
+    /// P, the order of the underlying field.
+    pub field_order: BigUint,
+    /// N, the order of the base point.
+    pub base_point_order: BigUint,
+    /// B, the constant of the curve equation.
+    pub eq_constant: BigUint,
+    pub x_base_point: BigUint,
+    pub y_base_point: BigUint,
+    pub size_of_field: u32,
+}
+
+fn hex_to_big_uint(hex_str: &str) -> BigUint {
+    BigUint::parse_bytes(hex_str.as_bytes(), 16).expect("invalid curve constant")
+}
+
+impl EllipticCurve {
+    pub fn p224() -> Self {
+        EllipticCurve {
+            name: "p224",
+            field_order: hex_to_big_uint(
+                "ffffffffffffffffffffffffffffffff000000000000000000000001",
+            ),
+            base_point_order: hex_to_big_uint(
+                "ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3d",
+            ),
+            eq_constant: hex_to_big_uint(
+                "b4050a850c04b3abf54132565044b0b7d7bfd8ba270b39432355ffb4",
+            ),
+            x_base_point: hex_to_big_uint(
+                "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+            ),
+            y_base_point: hex_to_big_uint(
+                "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+            ),
+            size_of_field: 224,
+        }
+    }
+
+    pub fn p256() -> Self {
+        EllipticCurve {
+            name: "p256",
+            field_order: hex_to_big_uint(
+                "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            ),
+            base_point_order: hex_to_big_uint(
+                "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+            ),
+            eq_constant: hex_to_big_uint(
+                "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+            ),
+            x_base_point: hex_to_big_uint(
+                "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+            ),
+            y_base_point: hex_to_big_uint(
+                "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+            ),
+            size_of_field: 256,
+        }
+    }
+
+    pub fn p384() -> Self {
+        EllipticCurve {
+            name: "p384",
+            field_order: hex_to_big_uint(
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff",
+            ),
+            base_point_order: hex_to_big_uint(
+                "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+            ),
+            eq_constant: hex_to_big_uint(
+                "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+            ),
+            x_base_point: hex_to_big_uint(
+                "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+            ),
+            y_base_point: hex_to_big_uint(
+                "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+            ),
+            size_of_field: 384,
+        }
+    }
+
+    pub fn p521() -> Self {
+        EllipticCurve {
+            name: "p521",
+            field_order: hex_to_big_uint(
+                "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            ),
+            base_point_order: hex_to_big_uint(
+                "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+            ),
+            eq_constant: hex_to_big_uint(
+                "0051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00",
+            ),
+            x_base_point: hex_to_big_uint(
+                "00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+            ),
+            y_base_point: hex_to_big_uint(
+                "011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+            ),
+            size_of_field: 521,
+        }
+    }
+
+    /// Only the names accepted by the VM: "p224", "p256", "p384" and "p521".
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        match name {
+            b"p224" => Some(Self::p224()),
+            b"p256" => Some(Self::p256()),
+            b"p384" => Some(Self::p384()),
+            b"p521" => Some(Self::p521()),
+            _ => None,
+        }
+    }
+
+    /// Length of a field element, also the length of a private key.
+    pub fn byte_length(&self) -> usize {
+        (self.size_of_field as usize).div_ceil(8)
+    }
+
+    /// Length of the uncompressed encoding of a point.
+    pub fn marshal_length(&self) -> usize {
+        1 + 2 * self.byte_length()
+    }
+
+    /// Length of the compressed encoding of a point.
+    pub fn marshal_compressed_length(&self) -> usize {
+        1 + self.byte_length()
+    }
+
+    pub fn is_on_curve(&self, x: &BigUint, y: &BigUint) -> bool {
+        if x >= &self.field_order || y >= &self.field_order {
+            return false;
+        }
+        (y * y) % &self.field_order == self.polynomial(x)
+    }
+
+    pub fn add(
+        &self,
+        x1: &BigUint,
+        y1: &BigUint,
+        x2: &BigUint,
+        y2: &BigUint,
+    ) -> (BigUint, BigUint) {
+        let sum = self.point_add(&self.to_point(x1, y1), &self.to_point(x2, y2));
+        Self::from_point(sum)
+    }
+
+    pub fn double(&self, x: &BigUint, y: &BigUint) -> (BigUint, BigUint) {
+        Self::from_point(self.point_double(&self.to_point(x, y)))
+    }
+
+    /// Multiplies the point by the big-endian scalar `k`.
+    pub fn scalar_mult(&self, x: &BigUint, y: &BigUint, k: &[u8]) -> (BigUint, BigUint) {
+        let point = self.to_point(x, y);
+        let mut result: Point = None;
+        for byte in k {
+            for bit in (0..8).rev() {
+                result = self.point_double(&result);
+                if (byte >> bit) & 1 == 1 {
+                    result = self.point_add(&result, &point);
+                }
+            }
+        }
+        Self::from_point(result)
+    }
+
+    /// Multiplies the base point by the big-endian scalar `k`.
+    pub fn scalar_base_mult(&self, k: &[u8]) -> (BigUint, BigUint) {
+        self.scalar_mult(&self.x_base_point, &self.y_base_point, k)
+    }
+
+    /// Uncompressed SEC 1 encoding: `0x04 || x || y`.
+    pub fn marshal(&self, x: &BigUint, y: &BigUint) -> Vec<u8> {
+        let mut result = Vec::with_capacity(self.marshal_length());
+        result.push(4);
+        result.extend_from_slice(&self.field_element_bytes(x));
+        result.extend_from_slice(&self.field_element_bytes(y));
+        result
+    }
+
+    /// Compressed SEC 1 encoding: `0x02 | y parity || x`.
+    pub fn marshal_compressed(&self, x: &BigUint, y: &BigUint) -> Vec<u8> {
+        let mut result = Vec::with_capacity(self.marshal_compressed_length());
+        result.push(2 | u8::from(y.bit(0)));
+        result.extend_from_slice(&self.field_element_bytes(x));
+        result
+    }
+
+    /// Decodes an uncompressed point, `None` if the encoding is invalid or the point is not on the curve.
+    pub fn unmarshal(&self, data: &[u8]) -> Option<(BigUint, BigUint)> {
+        let byte_length = self.byte_length();
+        if data.len() != self.marshal_length() || data[0] != 4 {
+            return None;
+        }
+        let x = BigUint::from_bytes_be(&data[1..1 + byte_length]);
+        let y = BigUint::from_bytes_be(&data[1 + byte_length..]);
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    /// Decodes a compressed point, `None` if the encoding is invalid or there is no such point on the curve.
+    pub fn unmarshal_compressed(&self, data: &[u8]) -> Option<(BigUint, BigUint)> {
+        if data.len() != self.marshal_compressed_length() || (data[0] != 2 && data[0] != 3) {
+            return None;
+        }
+        let x = BigUint::from_bytes_be(&data[1..]);
+        if x >= self.field_order {
+            return None;
+        }
+        let mut y = mod_sqrt(&self.polynomial(&x), &self.field_order)?;
+        if y.bit(0) != (data[0] & 1 == 1) {
+            y = (&self.field_order - y) % &self.field_order;
+        }
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    /// `x³ - 3x + b`.
+    fn polynomial(&self, x: &BigUint) -> BigUint {
+        let p = &self.field_order;
+        let x3 = x * x * x;
+        let three_x = (x * 3u32) % p;
+        (x3 + &self.eq_constant + p - three_x) % p
+    }
+
+    fn field_element_bytes(&self, value: &BigUint) -> Vec<u8> {
+        let bytes = value.to_bytes_be();
+        let byte_length = self.byte_length();
+        if value.is_zero() {
+            return vec![0; byte_length];
+        }
+        if bytes.len() >= byte_length {
+            return bytes[bytes.len() - byte_length..].to_vec();
+        }
+        let mut result = vec![0; byte_length - bytes.len()];
+        result.extend_from_slice(&bytes);
+        result
+    }
+
+    fn to_point(&self, x: &BigUint, y: &BigUint) -> Point {
+        if x.is_zero() && y.is_zero() {
+            None
+        } else {
+            Some((x % &self.field_order, y % &self.field_order))
+        }
+    }
+
+    fn from_point(point: Point) -> (BigUint, BigUint) {
+        point.unwrap_or_else(|| (BigUint::zero(), BigUint::zero()))
+    }
+
+    fn sub_mod(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        let p = &self.field_order;
+        (a + p - (b % p)) % p
+    }
+
+    fn div_mod(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        let p = &self.field_order;
+        let b_inverse = b.modinv(p).expect("field order is prime");
+        (a * b_inverse) % p
+    }
+
+    fn point_add(&self, a: &Point, b: &Point) -> Point {
+        let (x1, y1) = match a {
+            Some(point) => point,
+            None => return b.clone(),
+        };
+        let (x2, y2) = match b {
+            Some(point) => point,
+            None => return a.clone(),
+        };
+        let p = &self.field_order;
+
+        if x1 == x2 {
+            if ((y1 + y2) % p).is_zero() {
+                return None;
+            }
+            return self.point_double(a);
+        }
+
+        let lambda = self.div_mod(&self.sub_mod(y2, y1), &self.sub_mod(x2, x1));
+        let x3 = self.sub_mod(&self.sub_mod(&(&lambda * &lambda), x1), x2);
+        let y3 = self.sub_mod(&(&lambda * self.sub_mod(x1, &x3)), y1);
+        Some((x3, y3))
+    }
+
+    fn point_double(&self, a: &Point) -> Point {
+        let (x, y) = a.as_ref()?;
+        if y.is_zero() {
+            return None;
+        }
+
+        // a = -3, so the slope is (3x² - 3) / 2y
+        let numerator = self.sub_mod(&(x * x * 3u32), &BigUint::from(3u32));
+        let lambda = self.div_mod(&numerator, &(y * 2u32));
+        let x3 = self.sub_mod(&(&lambda * &lambda), &(x * 2u32));
+        let y3 = self.sub_mod(&(&lambda * self.sub_mod(x, &x3)), y);
+        Some((x3, y3))
+    }
+}
+
+/// Square root modulo an odd prime (Tonelli-Shanks), `None` if `a` is not a quadratic residue.
+fn mod_sqrt(a: &BigUint, p: &BigUint) -> Option<BigUint> {
+    let a = a % p;
+    if a.is_zero() {
+        return Some(a);
+    }
+
+    let one = BigUint::one();
+    let p_minus_one = p - &one;
+    if a.modpow(&(&p_minus_one >> 1), p) != one {
+        return None;
+    }
+
+    // p - 1 = q * 2^s, with q odd
+    let s = p_minus_one.trailing_zeros().expect("p is odd");
+    let q = &p_minus_one >> s;
+
+    let mut z = BigUint::from(2u32);
+    while z.modpow(&(&p_minus_one >> 1), p) != p_minus_one {
+        z += 1u32;
+    }
+
+    let mut m = s;
+    let mut c = z.modpow(&q, p);
+    let mut t = a.modpow(&q, p);
+    let mut r = a.modpow(&((&q + &one) >> 1), p);
+    while t != one {
+        let mut i = 0;
+        let mut t_pow = t.clone();
+        while t_pow != one {
+            t_pow = (&t_pow * &t_pow) % p;
+            i += 1;
+        }
+        let b = c.modpow(&(BigUint::one() << (m - i - 1)), p);
+        m = i;
+        c = (&b * &b) % p;
+        t = (t * &c) % p;
+        r = (r * b) % p;
+    }
+    Some(r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_curves() -> Vec<EllipticCurve> {
+        vec![
+            EllipticCurve::p224(),
+            EllipticCurve::p256(),
+            EllipticCurve::p384(),
+            EllipticCurve::p521(),
+        ]
+    }
+
+    #[test]
+    fn test_base_point_on_curve() {
+        for curve in all_curves() {
+            assert!(curve.is_on_curve(&curve.x_base_point, &curve.y_base_point));
+            assert!(!curve.is_on_curve(&BigUint::zero(), &BigUint::zero()));
+        }
+    }
+
+    #[test]
+    fn test_base_point_order() {
+        for curve in all_curves() {
+            let n = curve.base_point_order.to_bytes_be();
+            assert_eq!(
+                curve.scalar_base_mult(&n),
+                (BigUint::zero(), BigUint::zero())
+            );
+        }
+    }
+
+    #[test]
+    fn test_add_double_scalar_mult_consistent() {
+        for curve in all_curves() {
+            let (gx, gy) = (&curve.x_base_point, &curve.y_base_point);
+            let (x2, y2) = curve.double(gx, gy);
+            assert_eq!(curve.add(gx, gy, gx, gy), (x2.clone(), y2.clone()));
+            assert_eq!(curve.scalar_base_mult(&[2]), (x2.clone(), y2.clone()));
+
+            let (x3, y3) = curve.add(&x2, &y2, gx, gy);
+            assert!(curve.is_on_curve(&x3, &y3));
+            assert_eq!(curve.scalar_base_mult(&[3]), (x3, y3));
+        }
+    }
+
+    #[test]
+    fn test_marshal_roundtrip() {
+        for curve in all_curves() {
+            let (x, y) = curve.scalar_base_mult(&[0x12, 0x34, 0x56]);
+
+            let marshalled = curve.marshal(&x, &y);
+            assert_eq!(marshalled.len(), curve.marshal_length());
+            assert_eq!(curve.unmarshal(&marshalled), Some((x.clone(), y.clone())));
+
+            let compressed = curve.marshal_compressed(&x, &y);
+            assert_eq!(compressed.len(), curve.marshal_compressed_length());
+            assert_eq!(curve.unmarshal_compressed(&compressed), Some((x, y)));
+        }
+    }
+
+    #[test]
+    fn test_unmarshal_invalid() {
+        let curve = EllipticCurve::p256();
+        assert_eq!(curve.unmarshal(&[4, 0, 0]), None);
+        assert_eq!(curve.unmarshal(&vec![0; curve.marshal_length()]), None);
+        assert_eq!(curve.unmarshal_compressed(&[2, 0, 0]), None);
+    }
+}
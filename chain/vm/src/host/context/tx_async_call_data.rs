@@ -8,6 +8,9 @@ use num_bigint::BigUint;
 
 use super::{CallType, CallbackPayments, Promise, TxFunctionName};
 
+/// Gas limit of nested calls and callbacks when gas metering is disabled.
+pub const UNMETERED_NESTED_CALL_GAS_LIMIT: u64 = 1000;
+
 #[derive(Debug, Clone)]
 pub struct AsyncCallTxData {
     pub from: Address,
@@ -15,7 +18,12 @@ pub struct AsyncCallTxData {
     pub call_value: BigUint,
     pub endpoint_name: TxFunctionName,
     pub arguments: Vec<Vec<u8>>,
+    pub gas_limit: u64,
     pub tx_hash: H256,
+    pub original_tx_hash: H256,
+    pub prev_tx_hash: H256,
+    pub original_caller: Address,
+    pub relayer: Option<Address>,
 }
 
 pub fn async_call_tx_input(async_call: &AsyncCallTxData, call_type: CallType) -> TxInput {
@@ -27,9 +35,13 @@ pub fn async_call_tx_input(async_call: &AsyncCallTxData, call_type: CallType) ->
         func_name: async_call.endpoint_name.clone(),
         args: async_call.arguments.clone(),
         call_type,
-        gas_limit: 1000,
+        gas_limit: async_call.gas_limit,
         gas_price: 0,
         tx_hash: async_call.tx_hash.clone(),
+        original_tx_hash: Some(async_call.original_tx_hash.clone()),
+        prev_tx_hash: Some(async_call.prev_tx_hash.clone()),
+        original_caller: Some(async_call.original_caller.clone()),
+        relayer: async_call.relayer.clone(),
         ..Default::default()
     }
 }
@@ -42,7 +54,7 @@ fn result_status_bytes(result_status: u64) -> Vec<u8> {
     }
 }
 
-fn real_recipient(
+pub(crate) fn real_recipient(
     async_data: &AsyncCallTxData,
     builtin_functions: &BuiltinFunctionContainer,
 ) -> Address {
@@ -72,10 +84,14 @@ pub fn async_callback_tx_input(
         func_name: TxFunctionName::CALLBACK,
         args,
         call_type: CallType::AsyncCallback,
-        gas_limit: 1000,
+        gas_limit: async_data.gas_limit,
         gas_price: 0,
         tx_hash: async_data.tx_hash.clone(),
+        original_tx_hash: Some(async_data.original_tx_hash.clone()),
+        prev_tx_hash: Some(async_data.prev_tx_hash.clone()),
         callback_payments,
+        original_caller: Some(async_data.original_caller.clone()),
+        relayer: async_data.relayer.clone(),
         ..Default::default()
     }
 }
@@ -8,7 +8,7 @@ use colored::Colorize;
 use multiversx_chain_core::std::new_address;
 
 use crate::{
-    blockchain::state::{AccountData, BlockchainState},
+    blockchain::state::{AccountData, BlockchainState, EsdtTokenConfig},
     display_util::address_hex,
     types::Address,
 };
@@ -19,6 +19,7 @@ pub struct TxCache {
     source_ref: Arc<dyn TxCacheSource>,
     pub(super) accounts: Mutex<HashMap<Address, AccountData>>,
     pub(super) new_token_identifiers: Mutex<Option<Vec<String>>>,
+    pub(super) esdt_tokens: Mutex<HashMap<Vec<u8>, EsdtTokenConfig>>,
 }
 
 impl fmt::Debug for TxCache {
@@ -35,6 +36,7 @@ impl TxCache {
             source_ref,
             accounts: Mutex::new(HashMap::new()),
             new_token_identifiers: Mutex::new(None),
+            esdt_tokens: Mutex::new(HashMap::new()),
         }
     }
 
@@ -130,14 +132,51 @@ impl TxCache {
         *self.new_token_identifiers.lock().unwrap() = Some(token_identifiers);
     }
 
+    fn load_esdt_token_config_if_necessary(&self, token_identifier: &[u8]) {
+        let mut esdt_tokens_mut = self.esdt_tokens.lock().unwrap();
+        if !esdt_tokens_mut.contains_key(token_identifier) {
+            if let Some(config) = self.source_ref.load_esdt_token_config(token_identifier) {
+                esdt_tokens_mut.insert(token_identifier.to_vec(), config);
+            }
+        }
+    }
+
+    /// Will provide a clone.
+    pub fn get_esdt_token_config(&self, token_identifier: &[u8]) -> Option<EsdtTokenConfig> {
+        self.load_esdt_token_config_if_necessary(token_identifier);
+        self.esdt_tokens
+            .lock()
+            .unwrap()
+            .get(token_identifier)
+            .cloned()
+    }
+
+    /// Tokens unknown to the system SC (e.g. only set up via set state) get a default config.
+    pub fn with_esdt_token_config_mut<R, F>(&self, token_identifier: &[u8], f: F) -> R
+    where
+        F: FnOnce(&mut EsdtTokenConfig) -> R,
+    {
+        self.load_esdt_token_config_if_necessary(token_identifier);
+        let mut esdt_tokens = self.esdt_tokens.lock().unwrap();
+        let config = esdt_tokens.entry(token_identifier.to_vec()).or_default();
+        f(config)
+    }
+
+    pub fn is_esdt_paused(&self, token_identifier: &[u8]) -> bool {
+        self.get_esdt_token_config(token_identifier)
+            .is_some_and(|config| config.paused)
+    }
+
     pub fn into_blockchain_updates(self) -> BlockchainUpdate {
         BlockchainUpdate {
             accounts: self.accounts.into_inner().unwrap(),
             new_token_identifiers: self.new_token_identifiers.into_inner().unwrap(),
+            esdt_tokens: self.esdt_tokens.into_inner().unwrap(),
         }
     }
 
     pub fn commit_updates(&self, updates: BlockchainUpdate) {
         self.accounts.lock().unwrap().extend(updates.accounts);
+        self.esdt_tokens.lock().unwrap().extend(updates.esdt_tokens);
     }
 }
@@ -2,8 +2,11 @@ use multiversx_chain_core::EGLD_000000_TOKEN_IDENTIFIER;
 use num_bigint::BigUint;
 
 use crate::{
-    blockchain::state::EsdtInstanceMetadata, host::context::TxPanic,
-    system_sc::is_system_sc_address, types::Address,
+    blockchain::state::EsdtInstanceMetadata,
+    host::context::TxPanic,
+    system_sc::is_system_sc_address,
+    types::{Address, EsdtLocalRole},
+    vm_err_msg,
 };
 
 use super::TxCache;
@@ -120,6 +123,75 @@ impl TxCache {
         }
         Ok(())
     }
+
+    pub fn is_esdt_frozen(
+        &self,
+        address: &Address,
+        esdt_token_identifier: &[u8],
+        nonce: u64,
+    ) -> bool {
+        self.with_account_or_else(
+            address,
+            |account| account.esdt.is_frozen(esdt_token_identifier, nonce),
+            || false,
+        )
+    }
+
+    /// Checks that the token is not paused, and that it is frozen neither for the sender, nor for the recipient.
+    pub fn check_esdt_transfer_allowed(
+        &self,
+        from: &Address,
+        to: &Address,
+        esdt_token_identifier: &[u8],
+        nonce: u64,
+    ) -> Result<(), TxPanic> {
+        if esdt_token_identifier == EGLD_000000_TOKEN_IDENTIFIER.as_bytes() {
+            return Ok(());
+        }
+
+        if self.is_esdt_paused(esdt_token_identifier) {
+            return Err(TxPanic::vm_error(vm_err_msg::ESDT_TOKEN_IS_PAUSED));
+        }
+
+        if self.is_esdt_frozen(from, esdt_token_identifier, nonce)
+            || self.is_esdt_frozen(to, esdt_token_identifier, nonce)
+        {
+            return Err(TxPanic::vm_error(vm_err_msg::ESDT_IS_FROZEN_FOR_ACCOUNT));
+        }
+
+        Ok(())
+    }
+
+    /// Burn roles are not required if the burn role was set globally for the token.
+    pub fn check_esdt_role(
+        &self,
+        address: &Address,
+        esdt_token_identifier: &[u8],
+        role: EsdtLocalRole,
+    ) -> Result<(), TxPanic> {
+        if matches!(role, EsdtLocalRole::Burn | EsdtLocalRole::NftBurn)
+            && self
+                .get_esdt_token_config(esdt_token_identifier)
+                .is_some_and(|config| config.burn_role_global)
+        {
+            return Ok(());
+        }
+
+        let has_role = self.with_account_or_else(
+            address,
+            |account| {
+                account
+                    .esdt
+                    .has_special_role(esdt_token_identifier, role.as_role_name())
+            },
+            || false,
+        );
+        if !has_role {
+            return Err(TxPanic::vm_error(vm_err_msg::ACTION_NOT_ALLOWED));
+        }
+
+        Ok(())
+    }
 }
 
 fn err_insufficient_funds() -> TxPanic {
@@ -1,5 +1,5 @@
 use crate::{
-    blockchain::state::{AccountData, BlockchainState},
+    blockchain::state::{AccountData, BlockchainState, EsdtTokenConfig},
     types::Address,
 };
 
@@ -8,6 +8,8 @@ use super::TxCache;
 pub trait TxCacheSource: Send + Sync {
     fn load_account(&self, address: &Address) -> Option<AccountData>;
 
+    fn load_esdt_token_config(&self, token_identifier: &[u8]) -> Option<EsdtTokenConfig>;
+
     fn blockchain_ref(&self) -> &BlockchainState;
 }
 
@@ -16,6 +18,10 @@ impl TxCacheSource for TxCache {
         Some(self.with_account(address, AccountData::clone))
     }
 
+    fn load_esdt_token_config(&self, token_identifier: &[u8]) -> Option<EsdtTokenConfig> {
+        self.get_esdt_token_config(token_identifier)
+    }
+
     fn blockchain_ref(&self) -> &BlockchainState {
         self.blockchain_ref()
     }
@@ -26,6 +32,10 @@ impl TxCacheSource for BlockchainState {
         self.accounts.get(address).cloned()
     }
 
+    fn load_esdt_token_config(&self, token_identifier: &[u8]) -> Option<EsdtTokenConfig> {
+        self.esdt_tokens.get(token_identifier).cloned()
+    }
+
     fn blockchain_ref(&self) -> &BlockchainState {
         self
     }
@@ -30,7 +30,11 @@ pub struct TxContext {
 
 impl TxContext {
     pub fn new(runtime_ref: RuntimeRef, tx_input: TxInput, tx_cache: TxCache) -> Self {
-        let b_rng = Mutex::new(BlockchainRng::new(&tx_input, &tx_cache));
+        let b_rng = Mutex::new(BlockchainRng::new(
+            &tx_input,
+            &tx_cache,
+            runtime_ref.vm_ref.clone(),
+        ));
         TxContext {
             runtime_ref,
             tx_input_box: Box::new(tx_input),
@@ -65,8 +69,8 @@ impl TxContext {
             ..Default::default()
         };
 
-        let b_rng = Mutex::new(BlockchainRng::new(&tx_input, &tx_cache));
         let vm_ref = VMConfigRef::new();
+        let b_rng = Mutex::new(BlockchainRng::new(&tx_input, &tx_cache, vm_ref.clone()));
         TxContext {
             runtime_ref: RuntimeRef::new(vm_ref, Box::new(FailingExecutor)),
             tx_input_box: Box::new(tx_input),
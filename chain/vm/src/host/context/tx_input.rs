@@ -24,6 +24,24 @@ pub struct TxInput {
     pub tx_hash: H256,
     pub promise_callback_closure_data: Option<Vec<u8>>,
     pub callback_payments: CallbackPayments,
+
+    /// The sender of the original transaction, preserved across nested calls.
+    ///
+    /// When missing, the caller is considered to be the original caller.
+    pub original_caller: Option<Address>,
+
+    /// The hash of the original transaction, preserved across nested calls and shards.
+    ///
+    /// When missing, the tx hash is considered to be the original tx hash.
+    pub original_tx_hash: Option<H256>,
+
+    /// The hash of the transaction that produced this one, when it arrived from another shard.
+    ///
+    /// When missing, the tx hash is considered to be the previous tx hash.
+    pub prev_tx_hash: Option<H256>,
+
+    /// The relayer of a relayed transaction (relayed v3), if any. The relayer pays the gas.
+    pub relayer: Option<Address>,
 }
 
 impl Default for TxInput {
@@ -42,6 +60,10 @@ impl Default for TxInput {
             tx_hash: H256::zero(),
             promise_callback_closure_data: None,
             callback_payments: Default::default(),
+            original_caller: None,
+            original_tx_hash: None,
+            prev_tx_hash: None,
+            relayer: None,
         }
     }
 }
@@ -109,6 +131,26 @@ impl TxInput {
         }
     }
 
+    /// The sender of the original transaction.
+    pub fn original_caller(&self) -> &Address {
+        self.original_caller.as_ref().unwrap_or(&self.from)
+    }
+
+    /// The hash of the original transaction.
+    pub fn original_tx_hash(&self) -> &H256 {
+        self.original_tx_hash.as_ref().unwrap_or(&self.tx_hash)
+    }
+
+    /// The hash of the transaction that produced the current one.
+    pub fn prev_tx_hash(&self) -> &H256 {
+        self.prev_tx_hash.as_ref().unwrap_or(&self.tx_hash)
+    }
+
+    /// The account paying for the gas: the relayer, if present, otherwise the sender.
+    pub fn gas_payer(&self) -> &Address {
+        self.relayer.as_ref().unwrap_or(&self.from)
+    }
+
     pub fn get_argument_vec_u8(&self, arg_index: i32) -> Vec<u8> {
         let arg_idx_usize = arg_index as usize;
         assert!(arg_idx_usize < self.args.len(), "Tx arg index out of range");
@@ -1,9 +1,10 @@
-use std::fmt;
+use std::{collections::BTreeMap, fmt};
 
 use multiversx_chain_core::types::ReturnCode;
 
 use crate::{
     host::context::{TxFunctionName, TxInput},
+    schedule::GasCostCategory,
     vm_err_msg,
 };
 
@@ -34,6 +35,11 @@ pub struct TxResult {
 
     pub gas_used: GasUsed,
 
+    /// Gas consumed by the contract, grouped by category.
+    ///
+    /// Only filled in when the VM meters gas.
+    pub gas_breakdown: BTreeMap<GasCostCategory, u64>,
+
     /// Accumulates errors when they occur.
     ///
     /// It mimics the behavior of the Go VM,
@@ -63,6 +69,7 @@ impl Default for TxResult {
             esdt_transfer_log: None,
             result_logs: Vec::new(),
             gas_used: GasUsed::Unknown,
+            gas_breakdown: BTreeMap::new(),
             error_trace: Vec::new(),
             pending_calls: TxResultCalls::empty(),
             all_calls: Vec::new(),
@@ -164,6 +171,11 @@ impl TxResult {
             self.result_logs.push(transfer_log);
         }
         self.error_trace.append(&mut old_value.error_trace);
+        self.gas_breakdown = old_value.gas_breakdown;
+    }
+
+    pub fn add_gas_breakdown(&mut self, category: GasCostCategory, gas: u64) {
+        *self.gas_breakdown.entry(category).or_default() += gas;
     }
 
     pub fn assert_ok(&self) {
@@ -1,9 +1,11 @@
 mod exec_call;
 mod exec_create;
+mod exec_cross_shard;
 mod exec_general_tx;
 mod exec_query;
 
 pub use exec_call::*;
 pub use exec_create::*;
+pub use exec_cross_shard::*;
 pub(crate) use exec_general_tx::*;
 pub use exec_query::*;
@@ -1,7 +1,7 @@
 use crate::{
     blockchain::{
         VMConfig,
-        state::{AccountData, AccountEsdt, BlockchainStateRef},
+        state::{AccountData, AccountEsdt, BlockchainStateRef, CrossShardPayload},
     },
     host::{
         context::{
@@ -17,7 +17,9 @@ use num_bigint::BigUint;
 use num_traits::Zero;
 use std::collections::HashMap;
 
-use super::execute_builtin_function_or_default;
+use super::{
+    async_call_cross_shard_route, execute_builtin_function_or_default, queue_cross_shard_call,
+};
 
 /// Executes transaction and commits changes back to the underlying blockchain state.
 ///
@@ -31,7 +33,7 @@ pub fn commit_call<F>(
 where
     F: RuntimeInstanceCallLambda,
 {
-    state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
+    state.subtract_tx_gas(tx_input.gas_payer(), tx_input.gas_limit, tx_input.gas_price);
 
     let tx_cache = TxCache::new(state.get_arc());
     let (mut tx_result, blockchain_updates) =
@@ -50,6 +52,8 @@ where
 /// Executes transaction and commits changes back to the underlying blockchain state.
 ///
 /// Then executes all asyncs recursively, and commits them as well.
+///
+/// In cross-shard mode, the asyncs that go to other shards are only queued, see `deliver_cross_shard_message`.
 pub fn commit_call_with_async_and_callback<F>(
     tx_input: TxInput,
     state: &mut BlockchainStateRef,
@@ -69,19 +73,33 @@ where
     // the async call also gets reset
     if tx_result.result_status.is_success() {
         if let Some(async_data) = pending_calls.async_call {
-            let (async_result, callback_result) =
-                commit_async_call_and_callback(async_data, state, runtime);
-
-            tx_result = merge_async_results(tx_result, async_result);
-            tx_result = merge_async_results(tx_result, callback_result);
-
-            return tx_result;
+            if let Some(route) = async_call_cross_shard_route(&async_data, runtime) {
+                queue_cross_shard_call(
+                    route,
+                    CrossShardPayload::AsyncCall(async_data),
+                    state,
+                    runtime,
+                );
+            } else {
+                let (async_result, callback_result) =
+                    commit_async_call_and_callback(async_data, state, runtime);
+
+                tx_result = merge_async_results(tx_result, async_result);
+                tx_result = merge_async_results(tx_result, callback_result);
+
+                return tx_result;
+            }
         }
     }
 
     // calling all promises
     // the promises are also reset
     for promise in pending_calls.promises {
+        if let Some(route) = async_call_cross_shard_route(&promise.call, runtime) {
+            queue_cross_shard_call(route, CrossShardPayload::Promise(promise), state, runtime);
+            continue;
+        }
+
         let (async_result, callback_result) =
             commit_promise_call_and_callback(&promise, state, runtime);
 
@@ -99,40 +117,14 @@ fn commit_async_call_and_callback(
     runtime: &RuntimeRef,
 ) -> (TxResult, TxResult) {
     if state.account_exists(&async_data.to) {
-        let async_input = async_call_tx_input(&async_data, CallType::AsyncCall);
-
-        let async_result = commit_call_with_async_and_callback(
-            async_input,
-            state,
-            runtime,
-            RuntimeInstanceCallLambdaDefault,
-        );
-
-        let callback_input = async_callback_tx_input(
-            &async_data,
-            &async_result,
-            &runtime.vm_ref.builtin_functions,
-        );
-        let callback_result = commit_call(
-            callback_input,
-            state,
-            runtime,
-            RuntimeInstanceCallLambdaDefault,
-        );
-        assert!(
-            callback_result.pending_calls.async_call.is_none(),
-            "successive asyncs currently not supported"
-        );
+        let async_result = commit_async_call(&async_data, state, runtime);
+        let callback_result = commit_async_callback(&async_data, &async_result, state, runtime);
         (async_result, callback_result)
     } else {
-        let result = insert_ghost_account(&runtime.vm_ref, &async_data, state);
-        match result {
-            Ok(blockchain_updates) => {
-                state.commit_updates(blockchain_updates);
-                (TxResult::empty(), TxResult::empty())
-            }
-            Err(err) => (TxResult::from_panic_obj(&err), TxResult::empty()),
-        }
+        (
+            commit_ghost_account(&async_data, state, runtime),
+            TxResult::empty(),
+        )
     }
 }
 
@@ -143,28 +135,54 @@ fn commit_promise_call_and_callback(
     runtime: &RuntimeRef,
 ) -> (TxResult, TxResult) {
     if state.account_exists(&promise.call.to) {
-        let async_input = async_call_tx_input(&promise.call, CallType::AsyncCall);
-        let async_result = commit_call_with_async_and_callback(
-            async_input,
-            state,
-            runtime,
-            RuntimeInstanceCallLambdaDefault,
-        );
+        let async_result = commit_async_call(&promise.call, state, runtime);
         let callback_result = commit_promises_callback(&async_result, promise, state, runtime);
         (async_result, callback_result)
     } else {
-        let result = insert_ghost_account(&runtime.vm_ref, &promise.call, state);
-        match result {
-            Ok(blockchain_updates) => {
-                state.commit_updates(blockchain_updates);
-                (TxResult::empty(), TxResult::empty())
-            }
-            Err(err) => (TxResult::from_panic_obj(&err), TxResult::empty()),
-        }
+        (
+            commit_ghost_account(&promise.call, state, runtime),
+            TxResult::empty(),
+        )
     }
 }
 
-fn commit_promises_callback(
+/// The call part of both asyncs and promises. Further asyncs are executed recursively.
+pub(super) fn commit_async_call(
+    async_data: &AsyncCallTxData,
+    state: &mut BlockchainStateRef,
+    runtime: &RuntimeRef,
+) -> TxResult {
+    let async_input = async_call_tx_input(async_data, CallType::AsyncCall);
+    commit_call_with_async_and_callback(
+        async_input,
+        state,
+        runtime,
+        RuntimeInstanceCallLambdaDefault,
+    )
+}
+
+pub(super) fn commit_async_callback(
+    async_data: &AsyncCallTxData,
+    async_result: &TxResult,
+    state: &mut BlockchainStateRef,
+    runtime: &RuntimeRef,
+) -> TxResult {
+    let callback_input =
+        async_callback_tx_input(async_data, async_result, &runtime.vm_ref.builtin_functions);
+    let callback_result = commit_call(
+        callback_input,
+        state,
+        runtime,
+        RuntimeInstanceCallLambdaDefault,
+    );
+    assert!(
+        callback_result.pending_calls.async_call.is_none(),
+        "successive asyncs currently not supported"
+    );
+    callback_result
+}
+
+pub(super) fn commit_promises_callback(
     async_result: &TxResult,
     promise: &Promise,
     state: &mut BlockchainStateRef,
@@ -188,6 +206,20 @@ fn commit_promises_callback(
     callback_result
 }
 
+pub(super) fn commit_ghost_account(
+    async_data: &AsyncCallTxData,
+    state: &mut BlockchainStateRef,
+    runtime: &RuntimeRef,
+) -> TxResult {
+    match insert_ghost_account(&runtime.vm_ref, async_data, state) {
+        Ok(blockchain_updates) => {
+            state.commit_updates(blockchain_updates);
+            TxResult::empty()
+        }
+        Err(err) => TxResult::from_panic_obj(&err),
+    }
+}
+
 /// When calling a contract that is unknown to the state, we insert a ghost account.
 fn insert_ghost_account(
     vm_config: &VMConfig,
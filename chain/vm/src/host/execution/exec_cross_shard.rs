@@ -0,0 +1,325 @@
+use multiversx_chain_core::{EGLD_000000_TOKEN_IDENTIFIER, types::ReturnCode};
+use num_bigint::BigUint;
+
+use crate::{
+    blockchain::state::{
+        AccountData, BlockchainStateRef, CrossShardEsdtValue, CrossShardFunds, CrossShardMessage,
+        CrossShardPayload,
+    },
+    chain_core::types::ShardId,
+    crypto_functions::sha256,
+    host::{
+        context::{
+            AsyncCallTxData, CallType, TxCache, TxPanic, TxResult, TxTokenTransfer,
+            async_call_tx_input, real_recipient,
+        },
+        runtime::RuntimeRef,
+    },
+    types::Address,
+};
+
+use super::{
+    commit_async_call, commit_async_callback, commit_ghost_account, commit_promises_callback,
+};
+
+/// The source and destination shards of an async call, if it needs to travel between shards.
+///
+/// Token transfers are routed to their real recipient, not to the builtin function address.
+pub(super) fn async_call_cross_shard_route(
+    async_data: &AsyncCallTxData,
+    runtime: &RuntimeRef,
+) -> Option<(ShardId, ShardId)> {
+    runtime.vm_ref.shard_config.as_ref()?;
+    let destination = real_recipient(async_data, &runtime.vm_ref.builtin_functions);
+    runtime
+        .vm_ref
+        .cross_shard_route(&async_data.from, &destination)
+}
+
+/// Sends a call to another shard.
+///
+/// Its payment leaves the balance of the caller right away, and travels with the message.
+pub(super) fn queue_cross_shard_call(
+    (source_shard, destination_shard): (ShardId, ShardId),
+    payload: CrossShardPayload,
+    state: &mut BlockchainStateRef,
+    runtime: &RuntimeRef,
+) {
+    let funds = take_payment(payload.async_data(), state, runtime);
+    state
+        .cross_shard_queue
+        .push(source_shard, destination_shard, payload, funds);
+}
+
+/// Arriving on another shard, a call or callback becomes a new smart contract result, with its own hash.
+///
+/// The hash of the emitting transaction becomes the previous tx hash, the original tx hash is preserved.
+fn cross_shard_scr(mut async_data: AsyncCallTxData, message_id: u64) -> AsyncCallTxData {
+    let mut hash_input = async_data.tx_hash.to_vec();
+    hash_input.extend_from_slice(&message_id.to_be_bytes());
+    async_data.prev_tx_hash =
+        std::mem::replace(&mut async_data.tx_hash, sha256(&hash_input).into());
+    async_data
+}
+
+fn take_cross_shard_message(message_id: u64, state: &mut BlockchainStateRef) -> CrossShardMessage {
+    state
+        .cross_shard_queue
+        .take(message_id)
+        .unwrap_or_else(|| panic!("cross-shard message {message_id} not found"))
+}
+
+/// Executes a pending cross-shard message on its destination shard, and removes it from the queue.
+///
+/// Delivering a call queues its callback, which then needs to travel back to the shard of the caller.
+/// The funds sent to the caller during the call travel with the callback, and are only credited when it gets delivered.
+pub fn deliver_cross_shard_message(
+    message_id: u64,
+    state: &mut BlockchainStateRef,
+    runtime: &RuntimeRef,
+) -> TxResult {
+    let message = take_cross_shard_message(message_id, state);
+    match message.payload {
+        CrossShardPayload::AsyncCall(async_data) => {
+            let async_data = cross_shard_scr(async_data, message_id);
+            let (async_result, callback_funds) =
+                commit_cross_shard_call(&async_data, &message.funds, state, runtime);
+            if let Some(callback_funds) = callback_funds {
+                state.cross_shard_queue.push(
+                    message.destination_shard,
+                    message.source_shard,
+                    CrossShardPayload::AsyncCallback {
+                        async_data,
+                        async_result: async_result.clone(),
+                    },
+                    callback_funds,
+                );
+            }
+            async_result
+        }
+        CrossShardPayload::AsyncCallback {
+            async_data,
+            async_result,
+        } => {
+            let async_data = cross_shard_scr(async_data, message_id);
+            credit_funds(&async_data.from, &message.funds, state);
+            commit_async_callback(&async_data, &async_result, state, runtime)
+        }
+        CrossShardPayload::Promise(mut promise) => {
+            promise.call = cross_shard_scr(promise.call, message_id);
+            let (async_result, callback_funds) =
+                commit_cross_shard_call(&promise.call, &message.funds, state, runtime);
+            if let Some(callback_funds) = callback_funds {
+                if promise.has_callback() {
+                    state.cross_shard_queue.push(
+                        message.destination_shard,
+                        message.source_shard,
+                        CrossShardPayload::PromiseCallback {
+                            promise,
+                            async_result: async_result.clone(),
+                        },
+                        callback_funds,
+                    );
+                } else {
+                    credit_funds(&promise.call.from, &callback_funds, state);
+                }
+            }
+            async_result
+        }
+        CrossShardPayload::PromiseCallback {
+            mut promise,
+            async_result,
+        } => {
+            promise.call = cross_shard_scr(promise.call, message_id);
+            credit_funds(&promise.call.from, &message.funds, state);
+            commit_promises_callback(&async_result, &promise, state, runtime)
+        }
+    }
+}
+
+/// Simulates a call failing on its destination shard, with a user error.
+///
+/// Nothing gets executed on the destination shard, only the callback is queued, with the error.
+/// The payment is refunded along with the callback, or right away, if there is no callback.
+pub fn fail_cross_shard_message(
+    message_id: u64,
+    error_message: &str,
+    state: &mut BlockchainStateRef,
+) -> TxResult {
+    let message = take_cross_shard_message(message_id, state);
+    let caller = message.payload.async_data().from.clone();
+    let async_result = TxResult::from_error(ReturnCode::UserError, error_message);
+    let callback_payload = match message.payload {
+        CrossShardPayload::AsyncCall(async_data) => Some(CrossShardPayload::AsyncCallback {
+            async_data,
+            async_result: async_result.clone(),
+        }),
+        CrossShardPayload::Promise(promise) => {
+            promise
+                .has_callback()
+                .then(|| CrossShardPayload::PromiseCallback {
+                    promise,
+                    async_result: async_result.clone(),
+                })
+        }
+        _ => panic!("cross-shard message {message_id} is a callback, only calls can fail"),
+    };
+
+    if let Some(callback_payload) = callback_payload {
+        state.cross_shard_queue.push(
+            message.destination_shard,
+            message.source_shard,
+            callback_payload,
+            message.funds,
+        );
+    } else {
+        credit_funds(&caller, &message.funds, state);
+    }
+    async_result
+}
+
+/// Executes a call on its destination shard, with the payment carried by its message.
+///
+/// The payment is first credited back to the caller, so that the call transfers it as usual.
+/// Whatever the caller received afterwards, including the payment of a failed call,
+/// is taken from it again, to travel back with the callback.
+///
+/// Calls to unknown accounts create ghost accounts instead, and get no callback.
+fn commit_cross_shard_call(
+    async_data: &AsyncCallTxData,
+    funds: &CrossShardFunds,
+    state: &mut BlockchainStateRef,
+    runtime: &RuntimeRef,
+) -> (TxResult, Option<CrossShardFunds>) {
+    let caller_before = state.accounts.get(&async_data.from).cloned();
+    credit_funds(&async_data.from, funds, state);
+    if !state.account_exists(&async_data.to) {
+        return (commit_ghost_account(async_data, state, runtime), None);
+    }
+
+    let async_result = commit_async_call(async_data, state, runtime);
+    let callback_funds = take_received_funds(&async_data.from, caller_before, state);
+    (async_result, Some(callback_funds))
+}
+
+/// Takes the EGLD value and the transferred tokens of a call from the caller.
+///
+/// Nothing is taken if the caller cannot afford them, the call then fails on arrival.
+fn take_payment(
+    async_data: &AsyncCallTxData,
+    state: &mut BlockchainStateRef,
+    runtime: &RuntimeRef,
+) -> CrossShardFunds {
+    let tx_input = async_call_tx_input(async_data, CallType::AsyncCall);
+    let transfers = runtime
+        .vm_ref
+        .builtin_functions
+        .extract_token_transfers(&tx_input)
+        .transfers;
+
+    let tx_cache = TxCache::new(state.get_arc());
+    match subtract_payment(
+        &tx_cache,
+        &async_data.from,
+        &async_data.call_value,
+        transfers,
+    ) {
+        Ok(funds) => {
+            let blockchain_updates = tx_cache.into_blockchain_updates();
+            state.commit_updates(blockchain_updates);
+            funds
+        }
+        Err(_) => CrossShardFunds::default(),
+    }
+}
+
+fn subtract_payment(
+    tx_cache: &TxCache,
+    address: &Address,
+    egld_value: &BigUint,
+    transfers: Vec<TxTokenTransfer>,
+) -> Result<CrossShardFunds, TxPanic> {
+    let mut funds = CrossShardFunds {
+        egld_value: egld_value.clone(),
+        esdt_values: Vec::new(),
+    };
+    for transfer in transfers {
+        if transfer.token_identifier == EGLD_000000_TOKEN_IDENTIFIER.as_bytes() {
+            funds.egld_value += transfer.value;
+            continue;
+        }
+
+        let metadata = tx_cache.subtract_esdt_balance(
+            address,
+            &transfer.token_identifier,
+            transfer.nonce,
+            &transfer.value,
+        )?;
+        funds.esdt_values.push(CrossShardEsdtValue {
+            token_identifier: transfer.token_identifier,
+            nonce: transfer.nonce,
+            value: transfer.value,
+            metadata,
+        });
+    }
+    tx_cache.subtract_egld_balance(address, &funds.egld_value)?;
+    Ok(funds)
+}
+
+fn credit_funds(address: &Address, funds: &CrossShardFunds, state: &mut BlockchainStateRef) {
+    let tx_cache = TxCache::new(state.get_arc());
+    tx_cache.increase_egld_balance(address, &funds.egld_value);
+    for esdt in &funds.esdt_values {
+        tx_cache.increase_esdt_balance(
+            address,
+            &esdt.token_identifier,
+            esdt.nonce,
+            &esdt.value,
+            esdt.metadata.clone(),
+        );
+    }
+    let blockchain_updates = tx_cache.into_blockchain_updates();
+    state.commit_updates(blockchain_updates);
+}
+
+/// Takes from the account all balance increases since the `before` snapshot.
+fn take_received_funds(
+    address: &Address,
+    before: Option<AccountData>,
+    state: &mut BlockchainStateRef,
+) -> CrossShardFunds {
+    let mut funds = CrossShardFunds::default();
+    let (Some(before), Some(after)) = (before, state.accounts.get(address)) else {
+        return funds;
+    };
+
+    if after.egld_balance > before.egld_balance {
+        funds.egld_value = &after.egld_balance - &before.egld_balance;
+    }
+    for (token_identifier, esdt_data) in after.esdt.iter() {
+        for (nonce, instance) in esdt_data.instances.get_instances() {
+            let balance_before = before.esdt.get_esdt_balance(token_identifier, *nonce);
+            if instance.balance > balance_before {
+                funds.esdt_values.push(CrossShardEsdtValue {
+                    token_identifier: token_identifier.clone(),
+                    nonce: *nonce,
+                    value: &instance.balance - &balance_before,
+                    metadata: instance.metadata.clone(),
+                });
+            }
+        }
+    }
+
+    let tx_cache = TxCache::new(state.get_arc());
+    tx_cache
+        .subtract_egld_balance(address, &funds.egld_value)
+        .expect("received EGLD not found");
+    for esdt in &funds.esdt_values {
+        tx_cache
+            .subtract_esdt_balance(address, &esdt.token_identifier, esdt.nonce, &esdt.value)
+            .expect("received tokens not found");
+    }
+    let blockchain_updates = tx_cache.into_blockchain_updates();
+    state.commit_updates(blockchain_updates);
+    funds
+}
@@ -4,6 +4,7 @@ use crate::{
         context::{TxCache, TxContext, TxInput, TxResult},
         runtime::{RuntimeInstanceCallLambda, RuntimeRef},
     },
+    system_sc::{execute_system_sc, is_system_sc_address},
 };
 
 /// Executes VM query and discards any changes to the blockchain state.
@@ -17,6 +18,11 @@ where
     F: RuntimeInstanceCallLambda,
 {
     let tx_cache = TxCache::new(state.get_arc());
+    if is_system_sc_address(&tx_input.to) {
+        let (tx_result, _) = execute_system_sc(tx_input, tx_cache);
+        return tx_result;
+    }
+
     let tx_context = TxContext::new(runtime.clone(), tx_input, tx_cache);
     let tx_context = runtime.execute(tx_context, f);
     let (tx_result, _) = tx_context.into_results();
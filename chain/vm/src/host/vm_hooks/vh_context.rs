@@ -3,11 +3,11 @@ use std::{fmt::Debug, sync::MutexGuard};
 use multiversx_chain_vm_executor::{MemLength, MemPtr, VMHooksEarlyExit};
 
 use crate::{
-    blockchain::state::{AccountData, BlockConfig},
+    blockchain::state::{AccountData, BlockConfig, EsdtTokenConfig},
     host::context::{
         BackTransfers, ManagedTypeContainer, TxErrorTrace, TxFunctionName, TxInput, TxLog, TxResult,
     },
-    schedule::GasSchedule,
+    schedule::{GasCostCategory, GasSchedule},
     types::{Address, H256, VMCodeMetadata},
 };
 
@@ -31,7 +31,12 @@ pub trait VMHooksContext: Debug {
 
     fn gas_schedule(&self) -> &GasSchedule;
 
-    fn use_gas(&mut self, gas: u64) -> Result<(), VMHooksEarlyExit>;
+    fn use_gas(&mut self, category: GasCostCategory, gas: u64) -> Result<(), VMHooksEarlyExit>;
+
+    /// Set when the VM is configured to meter gas, in which case nested calls get the actual gas left.
+    fn gas_metering(&self) -> bool {
+        false
+    }
 
     fn input_ref(&self) -> &TxInput;
 
@@ -81,8 +86,17 @@ pub trait VMHooksContext: Debug {
 
     fn storage_write(&mut self, key: &[u8], value: &[u8]) -> Result<(), VMHooksEarlyExit>;
 
+    /// Writes under a protected key, only for data managed by the VM itself (e.g. storage locks).
+    ///
+    /// Skips the reserved key check, but not the read-only check.
+    fn storage_write_protected(&mut self, key: &[u8], value: &[u8])
+    -> Result<(), VMHooksEarlyExit>;
+
     fn get_block_config(&self) -> &BlockConfig;
 
+    /// Commitment to the accounts as they were at the start of the transaction.
+    fn state_root_hash(&self) -> [u8; 32];
+
     fn back_transfers_lock(&self) -> MutexGuard<'_, BackTransfers>;
 
     /// For ownership reasons, needs to return a clone.
@@ -92,6 +106,9 @@ pub trait VMHooksContext: Debug {
 
     fn account_code(&self, address: &Address) -> Vec<u8>;
 
+    /// Token-level data, as kept by the ESDT system SC.
+    fn esdt_token_config(&self, token_identifier: &[u8]) -> Option<EsdtTokenConfig>;
+
     fn perform_async_call(
         &mut self,
         to: Address,
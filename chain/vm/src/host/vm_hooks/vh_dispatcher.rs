@@ -1,6 +1,8 @@
 use multiversx_chain_vm_executor::{MemLength, MemPtr, VMHooks, VMHooksEarlyExit};
 
-use super::{VMHooksContext, VMHooksHandler};
+use crate::{crypto_functions, types::Address, vm_err_msg};
+
+use super::{VMHooksContext, VMHooksHandler, vh_early_exit::early_exit_vm_error};
 
 pub(super) const RESULT_TRUE: i32 = 1;
 pub(super) const RESULT_FALSE: i32 = 0;
@@ -23,6 +25,52 @@ impl<C: VMHooksContext> VMHooksDispatcher<C> {
     pub fn get_handler(&self) -> &VMHooksHandler<C> {
         &self.handler
     }
+
+    /// Arguments of the legacy call hooks: the lengths come first, as little endian i32,
+    /// followed by all the argument bytes, concatenated.
+    ///
+    /// Negative lengths, or a negative argument count, are rejected.
+    ///
+    /// ## Safety
+    ///
+    /// The offsets must point to valid instance memory.
+    unsafe fn load_arguments_from_memory(
+        &self,
+        num_arguments: i32,
+        arguments_length_offset: MemPtr,
+        data_offset: MemPtr,
+    ) -> Result<Vec<Vec<u8>>, VMHooksEarlyExit> {
+        if num_arguments < 0 {
+            return Err(early_exit_vm_error(vm_err_msg::ARG_OUT_OF_RANGE));
+        }
+
+        unsafe {
+            let lengths_bytes = self
+                .handler
+                .context
+                .memory_load(arguments_length_offset, num_arguments as MemLength * 4);
+            let lengths = lengths_bytes
+                .chunks_exact(4)
+                .map(|chunk| {
+                    usize::try_from(i32::from_le_bytes(chunk.try_into().unwrap()))
+                        .map_err(|_| early_exit_vm_error(vm_err_msg::ARG_OUT_OF_RANGE))
+                })
+                .collect::<Result<Vec<usize>, _>>()?;
+            let total_length: usize = lengths.iter().sum();
+            let data = self
+                .handler
+                .context
+                .memory_load(data_offset, total_length as MemLength);
+
+            let mut arguments = Vec::with_capacity(lengths.len());
+            let mut start = 0;
+            for length in lengths {
+                arguments.push(data[start..start + length].to_vec());
+                start += length;
+            }
+            Ok(arguments)
+        }
+    }
 }
 
 fn map_bool_to_i32(result: Result<bool, VMHooksEarlyExit>) -> Result<i32, VMHooksEarlyExit> {
@@ -84,7 +132,13 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         nonce: i64,
         result_offset: MemPtr,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: get_block_hash")
+        let block_hash = self.handler.get_block_hash(nonce)?;
+        unsafe {
+            self.handler
+                .context
+                .memory_store(result_offset, &block_hash);
+        }
+        Ok(0)
     }
 
     fn get_esdt_balance(
@@ -363,7 +417,10 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         key_length: MemLength,
         lock_timestamp: i64,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: set_storage_lock")
+        unsafe {
+            let key_bytes = self.handler.context.memory_load(key_offset, key_length);
+            self.handler.set_storage_lock(&key_bytes, lock_timestamp)
+        }
     }
 
     fn get_storage_lock(
@@ -371,7 +428,10 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         key_offset: MemPtr,
         key_length: MemLength,
     ) -> Result<i64, VMHooksEarlyExit> {
-        panic!("Unavailable: get_storage_lock")
+        unsafe {
+            let key_bytes = self.handler.context.memory_load(key_offset, key_length);
+            self.handler.get_storage_lock(&key_bytes)
+        }
     }
 
     fn is_storage_locked(
@@ -379,7 +439,10 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         key_offset: MemPtr,
         key_length: MemLength,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: is_storage_locked")
+        unsafe {
+            let key_bytes = self.handler.context.memory_load(key_offset, key_length);
+            map_bool_to_i32(self.handler.is_storage_locked(&key_bytes))
+        }
     }
 
     fn clear_storage_lock(
@@ -387,7 +450,10 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         key_offset: MemPtr,
         key_length: MemLength,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: clear_storage_lock")
+        unsafe {
+            let key_bytes = self.handler.context.memory_load(key_offset, key_length);
+            self.handler.clear_storage_lock(&key_bytes)
+        }
     }
 
     fn get_caller(&mut self, result_offset: MemPtr) -> Result<(), VMHooksEarlyExit> {
@@ -460,7 +526,7 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
     }
 
     fn get_num_esdt_transfers(&mut self) -> Result<i32, VMHooksEarlyExit> {
-        Ok(self.handler.esdt_num_transfers() as i32)
+        self.handler.get_num_esdt_transfers().map(|n| n as i32)
     }
 
     fn get_call_value_token_name(
@@ -526,7 +592,11 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
     }
 
     fn get_state_root_hash(&mut self, pointer: MemPtr) -> Result<(), VMHooksEarlyExit> {
-        panic!("Unavailable: get_state_root_hash");
+        let state_root_hash = self.handler.get_state_root_hash()?;
+        unsafe {
+            self.handler.context.memory_store(pointer, &state_root_hash);
+        }
+        Ok(())
     }
 
     fn get_prev_block_timestamp(&mut self) -> Result<i64, VMHooksEarlyExit> {
@@ -598,7 +668,25 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         arguments_length_offset: MemPtr,
         data_offset: MemPtr,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: execute_read_only")
+        unsafe {
+            let address_bytes = self.handler.context.memory_load(address_offset, 32);
+            let function_bytes = self
+                .handler
+                .context
+                .memory_load(function_offset, function_length);
+            let arguments = self.load_arguments_from_memory(
+                num_arguments,
+                arguments_length_offset,
+                data_offset,
+            )?;
+            self.handler.execute_read_only(
+                gas_limit as u64,
+                Address::from_slice(&address_bytes),
+                function_bytes.into(),
+                arguments,
+            )?;
+        }
+        Ok(RESULT_OK)
     }
 
     fn create_contract(
@@ -655,15 +743,33 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
     }
 
     fn get_original_tx_hash(&mut self, data_offset: MemPtr) -> Result<(), VMHooksEarlyExit> {
-        panic!("Unavailable: get_original_tx_hash");
+        let tx_hash = self.handler.get_original_tx_hash()?;
+        unsafe {
+            self.handler
+                .context
+                .memory_store(data_offset, tx_hash.as_bytes());
+        }
+        Ok(())
     }
 
     fn get_current_tx_hash(&mut self, data_offset: MemPtr) -> Result<(), VMHooksEarlyExit> {
-        panic!("Unavailable: get_current_tx_hash");
+        let tx_hash = self.handler.get_current_tx_hash()?;
+        unsafe {
+            self.handler
+                .context
+                .memory_store(data_offset, tx_hash.as_bytes());
+        }
+        Ok(())
     }
 
     fn get_prev_tx_hash(&mut self, data_offset: MemPtr) -> Result<(), VMHooksEarlyExit> {
-        panic!("Unavailable: get_prev_tx_hash");
+        let tx_hash = self.handler.get_prev_tx_hash()?;
+        unsafe {
+            self.handler
+                .context
+                .memory_store(data_offset, tx_hash.as_bytes());
+        }
+        Ok(())
     }
 
     fn managed_sc_address(&mut self, destination_handle: i32) -> Result<(), VMHooksEarlyExit> {
@@ -691,11 +797,11 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
     }
 
     fn managed_get_original_tx_hash(&mut self, result_handle: i32) -> Result<(), VMHooksEarlyExit> {
-        self.handler.get_tx_hash(result_handle)
+        self.handler.managed_get_original_tx_hash(result_handle)
     }
 
     fn managed_get_state_root_hash(&mut self, result_handle: i32) -> Result<(), VMHooksEarlyExit> {
-        panic!("Unavailable: managed_get_state_root_hash");
+        self.handler.managed_get_state_root_hash(result_handle)
     }
 
     fn managed_get_block_random_seed(
@@ -1104,8 +1210,8 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         map_bool_to_i32(Ok(false))
     }
 
-    fn managed_is_esdt_paused(&mut self, _token_id_handle: i32) -> Result<i32, VMHooksEarlyExit> {
-        map_bool_to_i32(Ok(false))
+    fn managed_is_esdt_paused(&mut self, token_id_handle: i32) -> Result<i32, VMHooksEarlyExit> {
+        map_bool_to_i32(self.handler.check_esdt_paused(token_id_handle))
     }
 
     fn managed_buffer_to_hex(
@@ -2135,7 +2241,10 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         input_handle: i32,
         output_handle: i32,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: managed_ripemd160")
+        match self.handler.ripemd160_managed(output_handle, input_handle) {
+            Ok(_) => Ok(RESULT_OK),
+            Err(e) => Err(e),
+        }
     }
 
     fn verify_bls(
@@ -2203,7 +2312,15 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         sig_handle: i32,
         hash_type: i32,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: managed_verify_custom_secp256k1")
+        match self.handler.verify_secp256k1_managed(
+            key_handle,
+            message_handle,
+            sig_handle,
+            hash_type as u8,
+        ) {
+            Ok(_) => Ok(RESULT_OK),
+            Err(e) => Err(e),
+        }
     }
 
     fn verify_secp256k1(
@@ -2223,7 +2340,15 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         message_handle: i32,
         sig_handle: i32,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: managed_verify_secp256k1")
+        match self.handler.verify_secp256k1_managed(
+            key_handle,
+            message_handle,
+            sig_handle,
+            crypto_functions::ECDSA_DOUBLE_SHA256,
+        ) {
+            Ok(_) => Ok(RESULT_OK),
+            Err(e) => Err(e),
+        }
     }
 
     fn encode_secp256k1_der_signature(
@@ -2243,7 +2368,13 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         s_handle: i32,
         sig_handle: i32,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: managed_encode_secp256k1_der_signature")
+        match self
+            .handler
+            .encode_secp256k1_der_signature_managed(r_handle, s_handle, sig_handle)
+        {
+            Ok(_) => Ok(RESULT_OK),
+            Err(e) => Err(e),
+        }
     }
 
     fn add_ec(
@@ -2256,7 +2387,15 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         snd_point_xhandle: i32,
         snd_point_yhandle: i32,
     ) -> Result<(), VMHooksEarlyExit> {
-        panic!("Unavailable: add_ec");
+        self.handler.ec_add(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            fst_point_xhandle,
+            fst_point_yhandle,
+            snd_point_xhandle,
+            snd_point_yhandle,
+        )
     }
 
     fn double_ec(
@@ -2267,7 +2406,13 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         point_xhandle: i32,
         point_yhandle: i32,
     ) -> Result<(), VMHooksEarlyExit> {
-        panic!("Unavailable: double_ec");
+        self.handler.ec_double(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            point_xhandle,
+            point_yhandle,
+        )
     }
 
     fn is_on_curve_ec(
@@ -2276,7 +2421,10 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         point_xhandle: i32,
         point_yhandle: i32,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: is_on_curve_ec")
+        let on_curve = self
+            .handler
+            .ec_is_on_curve(ec_handle, point_xhandle, point_yhandle)?;
+        Ok(if on_curve { RESULT_TRUE } else { RESULT_FALSE })
     }
 
     fn scalar_base_mult_ec(
@@ -2287,7 +2435,10 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         data_offset: MemPtr,
         length: MemLength,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: scalar_base_mult_ec")
+        let data = unsafe { self.handler.context.memory_load(data_offset, length) };
+        self.handler
+            .ec_scalar_base_mult(x_result_handle, y_result_handle, ec_handle, &data)?;
+        Ok(RESULT_OK)
     }
 
     fn managed_scalar_base_mult_ec(
@@ -2297,7 +2448,13 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         ec_handle: i32,
         data_handle: i32,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: managed_scalar_base_mult_ec")
+        self.handler.ec_scalar_base_mult_managed(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            data_handle,
+        )?;
+        Ok(RESULT_OK)
     }
 
     fn scalar_mult_ec(
@@ -2310,7 +2467,16 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         data_offset: MemPtr,
         length: MemLength,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: scalar_mult_ec")
+        let data = unsafe { self.handler.context.memory_load(data_offset, length) };
+        self.handler.ec_scalar_mult(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            point_xhandle,
+            point_yhandle,
+            &data,
+        )?;
+        Ok(RESULT_OK)
     }
 
     fn managed_scalar_mult_ec(
@@ -2322,7 +2488,15 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         point_yhandle: i32,
         data_handle: i32,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: managed_scalar_mult_ec")
+        self.handler.ec_scalar_mult_managed(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            point_xhandle,
+            point_yhandle,
+            data_handle,
+        )?;
+        Ok(RESULT_OK)
     }
 
     fn marshal_ec(
@@ -2332,7 +2506,13 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         ec_handle: i32,
         result_offset: MemPtr,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: marshal_ec")
+        let result = self
+            .handler
+            .ec_marshal(x_pair_handle, y_pair_handle, ec_handle)?;
+        unsafe {
+            self.handler.context.memory_store(result_offset, &result);
+        }
+        Ok(result.len() as i32)
     }
 
     fn managed_marshal_ec(
@@ -2342,7 +2522,13 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         ec_handle: i32,
         result_handle: i32,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: managed_marshal_ec")
+        let result_len = self.handler.ec_marshal_managed(
+            x_pair_handle,
+            y_pair_handle,
+            ec_handle,
+            result_handle,
+        )?;
+        Ok(result_len as i32)
     }
 
     fn marshal_compressed_ec(
@@ -2352,7 +2538,13 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         ec_handle: i32,
         result_offset: MemPtr,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: marshal_compressed_ec")
+        let result = self
+            .handler
+            .ec_marshal_compressed(x_pair_handle, y_pair_handle, ec_handle)?;
+        unsafe {
+            self.handler.context.memory_store(result_offset, &result);
+        }
+        Ok(result.len() as i32)
     }
 
     fn managed_marshal_compressed_ec(
@@ -2362,7 +2554,13 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         ec_handle: i32,
         result_handle: i32,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: managed_marshal_compressed_ec")
+        let result_len = self.handler.ec_marshal_compressed_managed(
+            x_pair_handle,
+            y_pair_handle,
+            ec_handle,
+            result_handle,
+        )?;
+        Ok(result_len as i32)
     }
 
     fn unmarshal_ec(
@@ -2373,7 +2571,10 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         data_offset: MemPtr,
         length: MemLength,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: unmarshal_ec")
+        let data = unsafe { self.handler.context.memory_load(data_offset, length) };
+        self.handler
+            .ec_unmarshal(x_result_handle, y_result_handle, ec_handle, &data)?;
+        Ok(RESULT_OK)
     }
 
     fn managed_unmarshal_ec(
@@ -2383,7 +2584,13 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         ec_handle: i32,
         data_handle: i32,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: managed_unmarshal_ec")
+        self.handler.ec_unmarshal_managed(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            data_handle,
+        )?;
+        Ok(RESULT_OK)
     }
 
     fn unmarshal_compressed_ec(
@@ -2394,7 +2601,10 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         data_offset: MemPtr,
         length: MemLength,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: unmarshal_compressed_ec")
+        let data = unsafe { self.handler.context.memory_load(data_offset, length) };
+        self.handler
+            .ec_unmarshal_compressed(x_result_handle, y_result_handle, ec_handle, &data)?;
+        Ok(RESULT_OK)
     }
 
     fn managed_unmarshal_compressed_ec(
@@ -2404,7 +2614,13 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         ec_handle: i32,
         data_handle: i32,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: managed_unmarshal_compressed_ec")
+        self.handler.ec_unmarshal_compressed_managed(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            data_handle,
+        )?;
+        Ok(RESULT_OK)
     }
 
     fn generate_key_ec(
@@ -2414,7 +2630,8 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         ec_handle: i32,
         result_offset: MemPtr,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: generate_key_ec")
+        self.handler.ec_generate_key()?;
+        Ok(RESULT_OK)
     }
 
     fn managed_generate_key_ec(
@@ -2424,7 +2641,8 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         ec_handle: i32,
         result_handle: i32,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: managed_generate_key_ec")
+        self.handler.ec_generate_key()?;
+        Ok(RESULT_OK)
     }
 
     fn create_ec(
@@ -2432,19 +2650,20 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         data_offset: MemPtr,
         data_length: MemLength,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: create_ec")
+        let name = unsafe { self.handler.context.memory_load(data_offset, data_length) };
+        self.handler.ec_create(&name)
     }
 
     fn managed_create_ec(&mut self, data_handle: i32) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: managed_create_ec")
+        self.handler.ec_create_managed(data_handle)
     }
 
     fn get_curve_length_ec(&mut self, ec_handle: i32) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: get_curve_length_ec")
+        Ok(self.handler.ec_curve_length(ec_handle)? as i32)
     }
 
     fn get_priv_key_byte_length_ec(&mut self, ec_handle: i32) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: get_priv_key_byte_length_ec")
+        Ok(self.handler.ec_private_key_byte_length(ec_handle)? as i32)
     }
 
     fn elliptic_curve_get_values(
@@ -2456,7 +2675,15 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         x_base_point_handle: i32,
         y_base_point_handle: i32,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: elliptic_curve_get_values")
+        self.handler.ec_get_values(
+            ec_handle,
+            field_order_handle,
+            base_point_order_handle,
+            eq_constant_handle,
+            x_base_point_handle,
+            y_base_point_handle,
+        )?;
+        Ok(RESULT_OK)
     }
 
     fn is_reserved_function_name(&mut self, name_handle: i32) -> Result<i32, VMHooksEarlyExit> {
@@ -2467,14 +2694,14 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         &mut self,
         destination_handle: i32,
     ) -> Result<(), VMHooksEarlyExit> {
-        panic!("Unavailable: managed_get_original_caller_addr")
+        self.handler.managed_original_caller(destination_handle)
     }
 
     fn managed_get_relayer_addr(
         &mut self,
         destination_handle: i32,
     ) -> Result<(), VMHooksEarlyExit> {
-        panic!("Unavailable: managed_get_relayer_addr")
+        self.handler.managed_relayer(destination_handle)
     }
 
     fn managed_multi_transfer_esdt_nft_execute_by_user(
@@ -2495,7 +2722,13 @@ impl<C: VMHooksContext> VMHooks for VMHooksDispatcher<C> {
         message_handle: i32,
         sig_handle: i32,
     ) -> Result<i32, VMHooksEarlyExit> {
-        panic!("Unavailable: managed_verify_secp256r1")
+        match self
+            .handler
+            .verify_secp256r1_managed(key_handle, message_handle, sig_handle)
+        {
+            Ok(_) => Ok(RESULT_OK),
+            Err(e) => Err(e),
+        }
     }
 
     fn managed_verify_blssignature_share(
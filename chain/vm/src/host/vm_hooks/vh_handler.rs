@@ -12,7 +12,11 @@ mod vh_storage;
 use multiversx_chain_core::types::ReturnCode;
 use multiversx_chain_vm_executor::VMHooksEarlyExit;
 
-use crate::{blockchain::state::AccountData, schedule::GasSchedule, vm_err_msg};
+use crate::{
+    blockchain::state::AccountData,
+    schedule::{GasCostCategory, GasSchedule},
+    vm_err_msg,
+};
 
 use super::VMHooksContext;
 
@@ -33,8 +37,8 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     /// Consume amount of gas. Provided for convenience.
-    fn use_gas(&mut self, gas: u64) -> Result<(), VMHooksEarlyExit> {
-        self.context.use_gas(gas)
+    fn use_gas(&mut self, category: GasCostCategory, gas: u64) -> Result<(), VMHooksEarlyExit> {
+        self.context.use_gas(category, gas)
     }
 
     /// Consume gas computed as `multiplier * base_cost`.
@@ -42,6 +46,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     /// Returns an [`ExecutionFailed`](ReturnCode::ExecutionFailed) early exit if the multiplication overflows.
     fn use_gas_checked_mul(
         &mut self,
+        category: GasCostCategory,
         multiplier: usize,
         base_cost: u64,
     ) -> Result<(), VMHooksEarlyExit> {
@@ -49,12 +54,13 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
             return Err(VMHooksEarlyExit::new(ReturnCode::ExecutionFailed.as_u64())
                 .with_message(vm_err_msg::MULTIPLICATION_OVERFLOW.to_string()));
         };
-        self.context.use_gas(gas)
+        self.context.use_gas(category, gas)
     }
 
     /// Shortcut for consuming gas for data copies, based on copied data length.
     fn use_gas_for_data_copy(&mut self, num_bytes_copied: usize) -> Result<(), VMHooksEarlyExit> {
         self.use_gas_checked_mul(
+            GasCostCategory::BaseOperation,
             num_bytes_copied,
             self.context
                 .gas_schedule()
@@ -2,7 +2,8 @@ use crate::{
     blockchain::state::{EsdtData, EsdtInstance},
     chain_core::builtin_func_names::*,
     host::vm_hooks::VMHooksContext,
-    types::{Address, EsdtLocalRole, EsdtLocalRoleFlags, RawHandle},
+    schedule::GasCostCategory,
+    types::{Address, EsdtLocalRole, EsdtLocalRoleFlags, H256, RawHandle},
 };
 use multiversx_chain_core::types::{EsdtTokenType, ReturnCode};
 use multiversx_chain_vm_executor::VMHooksEarlyExit;
@@ -34,14 +35,20 @@ const VM_BUILTIN_FUNCTION_NAMES: [&str; 16] = [
 
 impl<C: VMHooksContext> VMHooksHandler<C> {
     pub fn is_contract_address(&mut self, address_bytes: &[u8]) -> Result<bool, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.is_smart_contract)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.is_smart_contract,
+        )?;
 
         let address = Address::from_slice(address_bytes);
         Ok(&address == self.context.current_address())
     }
 
     pub fn managed_caller(&mut self, dest_handle: RawHandle) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_caller)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_caller,
+        )?;
 
         self.context
             .m_types_lock()
@@ -49,8 +56,47 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         Ok(())
     }
 
+    pub fn managed_original_caller(
+        &mut self,
+        dest_handle: RawHandle,
+    ) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_caller,
+        )?;
+
+        self.context.m_types_lock().mb_set(
+            dest_handle,
+            self.context.input_ref().original_caller().to_vec(),
+        );
+        Ok(())
+    }
+
+    /// Yields an empty buffer if the transaction is not relayed.
+    pub fn managed_relayer(&mut self, dest_handle: RawHandle) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_caller,
+        )?;
+
+        let relayer_bytes = self
+            .context
+            .input_ref()
+            .relayer
+            .as_ref()
+            .map(|relayer| relayer.to_vec())
+            .unwrap_or_default();
+        self.context
+            .m_types_lock()
+            .mb_set(dest_handle, relayer_bytes);
+        Ok(())
+    }
+
     pub fn managed_sc_address(&mut self, dest_handle: RawHandle) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_sc_address)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_sc_address,
+        )?;
 
         self.context
             .m_types_lock()
@@ -62,7 +108,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         &mut self,
         dest_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_owner_address)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_owner_address,
+        )?;
 
         self.context.m_types_lock().mb_set(
             dest_handle,
@@ -75,14 +124,20 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn get_shard_of_address(&mut self, address_bytes: &[u8]) -> Result<i32, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_shard_of_address)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_shard_of_address,
+        )?;
 
         let address = Address::from_slice(address_bytes);
         Ok(address.shard_of_3().as_u32() as i32)
     }
 
     pub fn is_smart_contract(&mut self, address_bytes: &[u8]) -> Result<bool, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.is_smart_contract)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.is_smart_contract,
+        )?;
 
         Ok(Address::from_slice(address_bytes).is_smart_contract_address())
     }
@@ -92,7 +147,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         address_bytes: &[u8],
         dest: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_int_api_cost.big_int_set_int_64)?;
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_set_int_64,
+        )?;
 
         assert!(
             self.is_contract_address(address_bytes)?,
@@ -105,23 +163,56 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         Ok(())
     }
 
-    pub fn get_tx_hash(&mut self, dest: RawHandle) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_current_tx_hash)?;
+    pub fn get_original_tx_hash(&mut self) -> Result<H256, VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_original_tx_hash,
+        )?;
 
-        self.context
-            .m_types_lock()
-            .mb_set(dest, self.context.input_ref().tx_hash.to_vec());
+        Ok(self.context.input_ref().original_tx_hash().clone())
+    }
+
+    pub fn get_current_tx_hash(&mut self) -> Result<H256, VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_current_tx_hash,
+        )?;
+
+        Ok(self.context.tx_hash())
+    }
+
+    pub fn get_prev_tx_hash(&mut self) -> Result<H256, VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_prev_tx_hash,
+        )?;
+
+        Ok(self.context.input_ref().prev_tx_hash().clone())
+    }
+
+    pub fn managed_get_original_tx_hash(
+        &mut self,
+        dest: RawHandle,
+    ) -> Result<(), VMHooksEarlyExit> {
+        let tx_hash = self.get_original_tx_hash()?;
+        self.context.m_types_lock().mb_set(dest, tx_hash.to_vec());
         Ok(())
     }
 
     pub fn get_gas_left(&mut self) -> Result<i64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_gas_left)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_gas_left,
+        )?;
 
         Ok(self.context.input_ref().gas_limit as i64)
     }
 
     pub fn get_block_timestamp(&mut self) -> Result<i64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_block_time_stamp)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_block_time_stamp,
+        )?;
 
         Ok(self
             .context
@@ -133,7 +224,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn get_block_timestamp_ms(&mut self) -> Result<i64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_block_time_stamp)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_block_time_stamp,
+        )?;
 
         Ok(self
             .context
@@ -144,7 +238,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn get_block_nonce(&mut self) -> Result<i64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_block_nonce)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_block_nonce,
+        )?;
 
         Ok(self
             .context
@@ -154,7 +251,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn get_block_round(&mut self) -> Result<i64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_block_round)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_block_round,
+        )?;
 
         Ok(self
             .context
@@ -164,7 +264,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn get_block_epoch(&mut self) -> Result<i64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_block_epoch)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_block_epoch,
+        )?;
 
         Ok(self
             .context
@@ -174,7 +277,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn get_block_random_seed(&mut self, dest: RawHandle) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_block_random_seed)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_block_random_seed,
+        )?;
 
         self.context.m_types_lock().mb_set(
             dest,
@@ -187,8 +293,43 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         Ok(())
     }
 
+    /// Empty if the block is not in the known history.
+    pub fn get_block_hash(&mut self, nonce: i64) -> Result<Vec<u8>, VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_block_hash,
+        )?;
+
+        let block_hash = u64::try_from(nonce)
+            .ok()
+            .and_then(|nonce| self.context.get_block_config().block_hash(nonce))
+            .map(<[u8]>::to_vec)
+            .unwrap_or_default();
+        Ok(block_hash)
+    }
+
+    pub fn get_state_root_hash(&mut self) -> Result<[u8; 32], VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_state_root_hash,
+        )?;
+
+        Ok(self.context.state_root_hash())
+    }
+
+    pub fn managed_get_state_root_hash(&mut self, dest: RawHandle) -> Result<(), VMHooksEarlyExit> {
+        let state_root_hash = self.get_state_root_hash()?;
+        self.context
+            .m_types_lock()
+            .mb_set(dest, state_root_hash.to_vec());
+        Ok(())
+    }
+
     pub fn get_prev_block_timestamp(&mut self) -> Result<i64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_block_time_stamp)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_block_time_stamp,
+        )?;
 
         Ok(self
             .context
@@ -200,7 +341,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn get_prev_block_timestamp_ms(&mut self) -> Result<i64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_block_time_stamp)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_block_time_stamp,
+        )?;
 
         Ok(self
             .context
@@ -211,7 +355,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn get_prev_block_nonce(&mut self) -> Result<i64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_block_nonce)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_block_nonce,
+        )?;
 
         Ok(self
             .context
@@ -221,7 +368,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn get_prev_block_round(&mut self) -> Result<i64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_block_round)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_block_round,
+        )?;
 
         Ok(self
             .context
@@ -231,7 +381,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn get_prev_block_epoch(&mut self) -> Result<i64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_block_epoch)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_block_epoch,
+        )?;
 
         Ok(self
             .context
@@ -241,7 +394,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn get_epoch_start_block_timestamp_ms(&mut self) -> Result<i64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_block_time_stamp)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_block_time_stamp,
+        )?;
 
         Ok(self
             .context
@@ -252,7 +408,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn get_epoch_start_block_nonce(&mut self) -> Result<i64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_block_nonce)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_block_nonce,
+        )?;
 
         Ok(self
             .context
@@ -262,7 +421,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn get_epoch_start_block_round(&mut self) -> Result<i64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_block_round)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_block_round,
+        )?;
 
         Ok(self
             .context
@@ -272,7 +434,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn get_prev_block_random_seed(&mut self, dest: RawHandle) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_block_random_seed)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_block_random_seed,
+        )?;
 
         self.context.m_types_lock().mb_set(
             dest,
@@ -286,7 +451,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn get_block_round_time_ms(&mut self) -> Result<i64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_block_round)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_block_round,
+        )?;
 
         Ok(self.context.get_block_config().block_round_time_ms as i64)
     }
@@ -296,6 +464,11 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         address_bytes: &[u8],
         token_id_bytes: &[u8],
     ) -> Result<i64, VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.storage_load,
+        )?;
+
         assert!(
             self.is_contract_address(address_bytes)?,
             "get_current_esdt_nft_nonce not yet implemented for accounts other than the contract itself"
@@ -315,7 +488,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         nonce: u64,
         dest: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_int_api_cost.big_int_set_int_64)?;
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_set_int_64,
+        )?;
 
         assert!(
             self.is_contract_address(address_bytes)?,
@@ -339,11 +515,13 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         response_handle: i32,
     ) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_get_bytes,
         )?;
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_set_bytes,
@@ -381,7 +559,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         address_handle: i32,
         code_hash_handle: i32,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_code_hash)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_code_hash,
+        )?;
 
         let address = Address::from_slice(self.context.m_types_lock().mb_get(address_handle));
         let code_hash = self.get_code_hash(&address);
@@ -395,7 +576,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         &mut self,
         function_name_handle: i32,
     ) -> Result<bool, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.is_builtin_function)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.is_builtin_function,
+        )?;
 
         Ok(VM_BUILTIN_FUNCTION_NAMES.contains(
             &self
@@ -422,6 +606,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         uris_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_get_bytes,
@@ -470,6 +655,11 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         nonce: i64,
         type_handle: i32,
     ) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_external_balance,
+        )?;
+
         // TODO: model the token type properly in the VM
         let token_type = EsdtTokenType::based_on_token_nonce(nonce as u64);
         self.context.m_types_lock().bi_overwrite(
@@ -484,7 +674,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         esdt_transfer_value_handle: RawHandle,
         call_value_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_int_api_cost.big_int_set_int_64)?;
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_set_int_64,
+        )?;
 
         let back_transfers = self.context.back_transfers_lock();
         let mut m_types = self.context.m_types_lock();
@@ -504,9 +697,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         &mut self,
         address_handle: RawHandle,
         token_id_handle: RawHandle,
-        _nonce: u64,
+        nonce: u64,
     ) -> Result<bool, VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             2 * self
                 .gas_schedule()
                 .managed_buffer_api_cost
@@ -516,20 +710,37 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         let address = Address::from_slice(self.context.m_types_lock().mb_get(address_handle));
         let token_id_bytes = self.context.m_types_lock().mb_get(token_id_handle).to_vec();
         if let Some(account) = self.context.account_data(&address) {
-            if let Some(esdt_data) = account.esdt.get_by_identifier(token_id_bytes.as_slice()) {
-                return Ok(esdt_data.frozen);
-            }
+            return Ok(account.esdt.is_frozen(token_id_bytes.as_slice(), nonce));
         }
 
         // Might be better to return Err and check
         Ok(false)
     }
 
+    pub fn check_esdt_paused(
+        &mut self,
+        token_id_handle: RawHandle,
+    ) -> Result<bool, VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::ManagedBufferApi,
+            self.gas_schedule()
+                .managed_buffer_api_cost
+                .m_buffer_get_bytes,
+        )?;
+
+        let token_id_bytes = self.context.m_types_lock().mb_get(token_id_handle).to_vec();
+        Ok(self
+            .context
+            .esdt_token_config(token_id_bytes.as_slice())
+            .is_some_and(|config| config.paused))
+    }
+
     pub fn get_esdt_local_roles_bits(
         &mut self,
         token_id_handle: RawHandle,
     ) -> Result<i64, VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_get_bytes,
@@ -550,7 +761,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         &mut self,
         token_id_handle: RawHandle,
     ) -> Result<bool, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_argument)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_argument,
+        )?;
 
         let m_types = self.context.m_types_lock();
         let token_id = m_types.mb_get(token_id_handle);
@@ -573,7 +787,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     ) -> Result<(), VMHooksEarlyExit> {
         let mut m_types = self.context.m_types_lock();
         m_types.bi_overwrite(value_handle, instance.balance.clone().into());
-        if esdt_data.frozen {
+        if esdt_data.frozen || instance.frozen {
             m_types.mb_set(properties_handle, vec![1, 0]);
         } else {
             m_types.mb_set(properties_handle, vec![0, 0]);
@@ -615,8 +829,12 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         uris_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
         if ESDT_TOKEN_DATA_FUNC_RESETS_VALUES {
-            self.use_gas(3 * self.gas_schedule().big_int_api_cost.big_int_set_int_64)?;
             self.use_gas(
+                GasCostCategory::BigIntApi,
+                3 * self.gas_schedule().big_int_api_cost.big_int_set_int_64,
+            )?;
+            self.use_gas(
+                GasCostCategory::ManagedBufferApi,
                 5 * self
                     .gas_schedule()
                     .managed_buffer_api_cost
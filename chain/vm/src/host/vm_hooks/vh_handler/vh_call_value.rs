@@ -3,6 +3,7 @@ use crate::{
         context::TxTokenTransfer,
         vm_hooks::{VMHooksContext, vh_early_exit::early_exit_vm_error},
     },
+    schedule::GasCostCategory,
     types::RawHandle,
     vm_err_msg,
 };
@@ -14,7 +15,10 @@ use super::VMHooksHandler;
 
 impl<C: VMHooksContext> VMHooksHandler<C> {
     pub fn check_not_payable(&mut self) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_call_value)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_call_value,
+        )?;
 
         if self.context.input_ref().egld_value > num_bigint::BigUint::zero() {
             return Err(early_exit_vm_error(vm_err_msg::NON_PAYABLE_FUNC_EGLD));
@@ -26,7 +30,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn load_egld_value(&mut self, dest: RawHandle) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_int_api_cost.big_int_set_int_64)?;
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_set_int_64,
+        )?;
 
         let value = self.context.input_ref().received_egld().clone();
         self.context.m_types_lock().bi_overwrite(dest, value.into());
@@ -47,7 +54,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         Ok(())
     }
 
-    pub fn load_all_transfers(&self, dest_handle: RawHandle) -> Result<(), VMHooksEarlyExit> {
+    pub fn load_all_transfers(&mut self, dest_handle: RawHandle) -> Result<(), VMHooksEarlyExit> {
         let direct_egld_value = self.context.input_ref().received_egld().clone();
         let transfers = if !direct_egld_value.is_zero() {
             vec![TxTokenTransfer {
@@ -58,12 +65,24 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         } else {
             self.context.input_ref().received_esdt().to_owned()
         };
-        self.context
+        let num_bytes_copied = self
+            .context
             .m_types_lock()
             .mb_set_vec_of_esdt_payments(dest_handle, &transfers);
+        self.use_gas_for_data_copy(num_bytes_copied)?;
+
         Ok(())
     }
 
+    pub fn get_num_esdt_transfers(&mut self) -> Result<usize, VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_call_value,
+        )?;
+
+        Ok(self.esdt_num_transfers())
+    }
+
     pub fn esdt_num_transfers(&mut self) -> usize {
         self.context.input_ref().received_esdt().len()
     }
@@ -3,6 +3,7 @@ use multiversx_chain_vm_executor::VMHooksEarlyExit;
 use crate::{
     crypto_functions,
     host::vm_hooks::{VMHooksContext, vh_early_exit::early_exit_vm_error},
+    schedule::GasCostCategory,
     types::RawHandle,
     vm_err_msg,
 };
@@ -15,7 +16,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         dest: RawHandle,
         data_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().crypto_api_cost.sha_256)?;
+        self.use_gas(
+            GasCostCategory::CryptoApi,
+            self.gas_schedule().crypto_api_cost.sha_256,
+        )?;
 
         // default implementation used in debugger
         // the VM has a dedicated hook
@@ -32,7 +36,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         dest: RawHandle,
         data_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().crypto_api_cost.keccak_256)?;
+        self.use_gas(
+            GasCostCategory::CryptoApi,
+            self.gas_schedule().crypto_api_cost.keccak_256,
+        )?;
 
         // default implementation used in debugger
         // the VM has a dedicated hook
@@ -44,6 +51,24 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         Ok(())
     }
 
+    pub fn ripemd160_managed(
+        &mut self,
+        dest: RawHandle,
+        data_handle: RawHandle,
+    ) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::CryptoApi,
+            self.gas_schedule().crypto_api_cost.ripemd_160,
+        )?;
+
+        let mut types = self.context.m_types_lock();
+        let data = types.mb_get(data_handle);
+        let result_bytes = crypto_functions::ripemd160(data);
+        types.mb_set(dest, result_bytes[..].to_vec());
+
+        Ok(())
+    }
+
     /// Should crash if the signature is invalid.
     pub fn verify_ed25519_managed(
         &mut self,
@@ -52,7 +77,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         signature: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
         let sig_valid = {
-            self.use_gas(self.gas_schedule().crypto_api_cost.verify_ed_25519)?;
+            self.use_gas(
+                GasCostCategory::CryptoApi,
+                self.gas_schedule().crypto_api_cost.verify_ed_25519,
+            )?;
 
             let types = self.context.m_types_lock();
             let key = types.mb_get(key);
@@ -67,6 +95,83 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         Ok(())
     }
 
+    /// Should crash if the signature is invalid.
+    ///
+    /// The hash type codes are the ones from `crypto_functions`, e.g. `ECDSA_DOUBLE_SHA256`.
+    pub fn verify_secp256k1_managed(
+        &mut self,
+        key: RawHandle,
+        message: RawHandle,
+        signature: RawHandle,
+        hash_type: u8,
+    ) -> Result<(), VMHooksEarlyExit> {
+        let sig_valid = {
+            self.use_gas(
+                GasCostCategory::CryptoApi,
+                self.gas_schedule().crypto_api_cost.verify_secp_256_k_1,
+            )?;
+
+            let types = self.context.m_types_lock();
+            let key = types.mb_get(key);
+            let message = types.mb_get(message);
+            let signature = types.mb_get(signature);
+            crypto_functions::verify_secp256k1(key, message, signature, hash_type)
+        };
+        if !sig_valid {
+            return Err(early_exit_vm_error(vm_err_msg::CRYPTO_SECP256K1_ERROR));
+        }
+
+        Ok(())
+    }
+
+    pub fn encode_secp256k1_der_signature_managed(
+        &mut self,
+        r_handle: RawHandle,
+        s_handle: RawHandle,
+        dest: RawHandle,
+    ) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::CryptoApi,
+            self.gas_schedule().crypto_api_cost.encode_der_sig,
+        )?;
+
+        let mut types = self.context.m_types_lock();
+        let r = types.mb_get(r_handle);
+        let s = types.mb_get(s_handle);
+        let Some(signature) = crypto_functions::encode_secp256k1_der_signature(r, s) else {
+            return Err(early_exit_vm_error(vm_err_msg::CRYPTO_INVALID_SIGNATURE));
+        };
+        types.mb_set(dest, signature);
+
+        Ok(())
+    }
+
+    /// Should crash if the signature is invalid.
+    pub fn verify_secp256r1_managed(
+        &mut self,
+        key: RawHandle,
+        message: RawHandle,
+        signature: RawHandle,
+    ) -> Result<(), VMHooksEarlyExit> {
+        let sig_valid = {
+            self.use_gas(
+                GasCostCategory::CryptoApi,
+                self.gas_schedule().crypto_api_cost.verify_secp_256_r_1,
+            )?;
+
+            let types = self.context.m_types_lock();
+            let key = types.mb_get(key);
+            let message = types.mb_get(message);
+            let signature = types.mb_get(signature);
+            crypto_functions::verify_secp256r1(key, message, signature)
+        };
+        if !sig_valid {
+            return Err(early_exit_vm_error(vm_err_msg::CRYPTO_SECP256K1_ERROR));
+        }
+
+        Ok(())
+    }
+
     #[cfg(feature = "bls")]
     pub fn verify_bls_managed(
         &self,
@@ -4,6 +4,7 @@ use num_traits::ToPrimitive;
 
 use crate::host::vm_hooks::VMHooksContext;
 use crate::host::vm_hooks::vh_early_exit::early_exit_vm_error;
+use crate::schedule::GasCostCategory;
 use crate::vm_err_msg;
 
 use crate::types::RawHandle;
@@ -14,13 +15,19 @@ use super::VMHooksHandler;
 /// The smart contract code doesn't have access to these methods directly.
 impl<C: VMHooksContext> VMHooksHandler<C> {
     pub fn get_num_arguments(&mut self) -> Result<i32, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_num_arguments)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_num_arguments,
+        )?;
 
         Ok(self.context.input_ref().args.len() as i32)
     }
 
     pub fn get_argument_len(&mut self, arg_index: i32) -> Result<usize, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.get_argument)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.get_argument,
+        )?;
 
         let arg = self.context.input_ref().get_argument_vec_u8(arg_index);
         Ok(arg.len())
@@ -32,6 +39,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         dest: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_set_bytes,
@@ -49,6 +57,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         dest: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::BigIntApi,
             self.gas_schedule()
                 .big_int_api_cost
                 .big_int_set_unsigned_bytes,
@@ -68,6 +77,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         dest: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::BigIntApi,
             self.gas_schedule()
                 .big_int_api_cost
                 .big_int_set_signed_bytes,
@@ -82,7 +92,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn get_argument_i64(&mut self, arg_index: i32) -> Result<i64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.int_64_get_argument)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.int_64_get_argument,
+        )?;
 
         // specific implementation provided, in order to simulate the VM error (status 10 instead of 4)
         let bytes = self.context.input_ref().get_argument_vec_u8(arg_index);
@@ -95,7 +108,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn get_argument_u64(&mut self, arg_index: i32) -> Result<u64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.int_64_get_argument)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.int_64_get_argument,
+        )?;
 
         // specific implementation provided, in order to simulate the VM error (status 10 instead of 4)
         let bytes = self.context.input_ref().get_argument_vec_u8(arg_index);
@@ -119,6 +135,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
 
         if has_closure_data {
             self.use_gas(
+                GasCostCategory::ManagedBufferApi,
                 self.gas_schedule()
                     .managed_buffer_api_cost
                     .m_buffer_set_bytes,
@@ -1,7 +1,7 @@
 use multiversx_chain_vm_executor::VMHooksEarlyExit;
 use num_bigint::{BigInt, BigUint};
 
-use crate::{host::vm_hooks::VMHooksContext, types::RawHandle};
+use crate::{host::vm_hooks::VMHooksContext, schedule::GasCostCategory, types::RawHandle};
 
 use super::VMHooksHandler;
 
@@ -9,6 +9,16 @@ use super::VMHooksHandler;
 /// The smart contract code doesn't have access to these methods directly.
 impl<C: VMHooksContext> VMHooksHandler<C> {
     pub fn finish_slice_u8(&mut self, slice: &[u8]) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.finish,
+        )?;
+        self.use_gas_checked_mul(
+            GasCostCategory::BaseOperation,
+            slice.len(),
+            self.gas_schedule().base_operation_cost.persist_per_byte,
+        )?;
+
         let mut v = vec![0u8; slice.len()];
         v.copy_from_slice(slice);
         let mut tx_result = self.context.result_lock();
@@ -18,6 +28,11 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn finish_big_int_raw(&mut self, handle: RawHandle) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_finish_signed,
+        )?;
+
         let bi_bytes = self.bi_get_signed_bytes(handle)?;
         let mut tx_result = self.context.result_lock();
 
@@ -27,6 +42,11 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn finish_big_uint_raw(&mut self, handle: RawHandle) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_finish_unsigned,
+        )?;
+
         let bu_bytes = self.bi_get_unsigned_bytes(handle)?;
         let mut tx_result = self.context.result_lock();
         tx_result.result_values.push(bu_bytes);
@@ -35,14 +55,20 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn finish_managed_buffer_raw(&mut self, handle: RawHandle) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().managed_buffer_api_cost.m_buffer_finish)?;
+        self.use_gas(
+            GasCostCategory::ManagedBufferApi,
+            self.gas_schedule().managed_buffer_api_cost.m_buffer_finish,
+        )?;
 
         let bytes = self.context.m_types_lock().mb_get_owned(handle);
         self.finish_slice_u8(&bytes)
     }
 
     pub fn finish_i64(&mut self, value: i64) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.int_64_finish)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.int_64_finish,
+        )?;
 
         if value == 0 {
             self.finish_slice_u8(&[])
@@ -52,7 +78,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn finish_u64(&mut self, value: u64) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.int_64_finish)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.int_64_finish,
+        )?;
 
         if value == 0 {
             self.finish_slice_u8(&[])
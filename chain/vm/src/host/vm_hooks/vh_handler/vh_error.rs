@@ -1,14 +1,18 @@
 use multiversx_chain_core::types::ReturnCode;
 use multiversx_chain_vm_executor::VMHooksEarlyExit;
 
-use crate::{host::vm_hooks::VMHooksContext, types::RawHandle};
+use crate::{host::vm_hooks::VMHooksContext, schedule::GasCostCategory, types::RawHandle};
 
 use super::VMHooksHandler;
 
 impl<C: VMHooksContext> VMHooksHandler<C> {
     pub fn signal_error(&mut self, message: &[u8]) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.signal_error)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.signal_error,
+        )?;
         self.use_gas_checked_mul(
+            GasCostCategory::BaseOperation,
             message.len(),
             self.gas_schedule().base_operation_cost.persist_per_byte,
         )?;
@@ -1,6 +1,9 @@
 use multiversx_chain_vm_executor::VMHooksEarlyExit;
 
-use crate::{host::context::TxLog, host::vm_hooks::VMHooksContext, types::RawHandle};
+use crate::{
+    host::context::TxLog, host::vm_hooks::VMHooksContext, schedule::GasCostCategory,
+    types::RawHandle,
+};
 
 use super::VMHooksHandler;
 
@@ -10,7 +13,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         topics_handle: RawHandle,
         data_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.log)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.log,
+        )?;
 
         let (topics, topic_bytes_copied) = self
             .context
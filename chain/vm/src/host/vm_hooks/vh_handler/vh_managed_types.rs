@@ -1,5 +1,6 @@
 mod vh_big_float;
 mod vh_big_int;
+mod vh_elliptic_curve;
 mod vh_managed_buffer;
 mod vh_managed_map;
 
@@ -11,6 +12,7 @@ use crate::{
         context::{big_int_signed_bytes, big_int_to_i64, big_uint_to_u64, big_uint_unsigned_bytes},
         vm_hooks::{VMHooksContext, vh_early_exit::early_exit_vm_error},
     },
+    schedule::GasCostCategory,
     types::RawHandle,
     vm_err_msg,
 };
@@ -27,6 +29,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         bi_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_to_big_int_unsigned,
@@ -46,6 +49,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         bi_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_to_big_int_signed,
@@ -65,6 +69,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         bi_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_from_big_int_unsigned,
@@ -82,6 +87,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         bi_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_from_big_int_signed,
@@ -98,6 +104,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         buffer_handle: RawHandle,
     ) -> Result<i64, VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_to_small_int_unsigned,
@@ -116,6 +123,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         buffer_handle: RawHandle,
     ) -> Result<i64, VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_to_small_int_signed,
@@ -135,6 +143,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         value: u64,
     ) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_from_small_int_unsigned,
@@ -156,6 +165,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         value: i64,
     ) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_from_small_int_signed,
@@ -172,8 +182,12 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         bi_handle: RawHandle,
         str_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_int_api_cost.big_int_get_int_64)?;
         self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_get_int_64,
+        )?;
+        self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_set_bytes,
@@ -194,6 +208,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         length: usize,
     ) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_set_random,
@@ -1,5 +1,6 @@
 use crate::{
     host::vm_hooks::{VMHooksContext, VMHooksHandler, vh_early_exit::early_exit_vm_error},
+    schedule::GasCostCategory,
     types::RawHandle,
     vm_err_msg,
 };
@@ -20,7 +21,10 @@ macro_rules! binary_op_method {
             x: RawHandle,
             y: RawHandle,
         ) -> Result<(), VMHooksEarlyExit> {
-            self.use_gas(self.gas_schedule().big_float_api_cost.$gas_cost_field)?;
+            self.use_gas(
+                GasCostCategory::BigFloatApi,
+                self.gas_schedule().big_float_api_cost.$gas_cost_field,
+            )?;
 
             let bf_x = self.context.m_types_lock().bf_get_f64(x);
             let bf_y = self.context.m_types_lock().bf_get_f64(y);
@@ -39,7 +43,10 @@ macro_rules! unary_op_method {
             dest: RawHandle,
             x: RawHandle,
         ) -> Result<(), VMHooksEarlyExit> {
-            self.use_gas(self.gas_schedule().big_float_api_cost.$gas_cost_field)?;
+            self.use_gas(
+                GasCostCategory::BigFloatApi,
+                self.gas_schedule().big_float_api_cost.$gas_cost_field,
+            )?;
 
             let bf_x = self.context.m_types_lock().bf_get_f64(x);
             let result = bf_x.$rust_op_name();
@@ -56,7 +63,10 @@ macro_rules! unary_op_method_big_int_handle {
             dest: RawHandle,
             x: RawHandle,
         ) -> Result<(), VMHooksEarlyExit> {
-            self.use_gas(self.gas_schedule().big_float_api_cost.$gas_cost_field)?;
+            self.use_gas(
+                GasCostCategory::BigFloatApi,
+                self.gas_schedule().big_float_api_cost.$gas_cost_field,
+            )?;
 
             let bf_x = self.context.m_types_lock().bf_get_f64(x);
             let result = bf_x.$rust_op_name();
@@ -81,6 +91,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         }
 
         self.use_gas(
+            GasCostCategory::BigFloatApi,
             self.gas_schedule()
                 .big_float_api_cost
                 .big_float_new_from_parts,
@@ -106,6 +117,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         denominator: i64,
     ) -> Result<RawHandle, VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::BigFloatApi,
             self.gas_schedule()
                 .big_float_api_cost
                 .big_float_new_from_parts,
@@ -133,6 +145,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         exponent: i64,
     ) -> Result<RawHandle, VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::BigFloatApi,
             self.gas_schedule()
                 .big_float_api_cost
                 .big_float_new_from_parts,
@@ -162,7 +175,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     unary_op_method!(bf_neg, neg, big_float_neg);
 
     pub fn bf_cmp(&mut self, x: RawHandle, y: RawHandle) -> Result<i32, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_float_api_cost.big_float_cmp)?;
+        self.use_gas(
+            GasCostCategory::BigFloatApi,
+            self.gas_schedule().big_float_api_cost.big_float_cmp,
+        )?;
 
         let bf_x = self.context.m_types_lock().bf_get_f64(x);
         let bf_y = self.context.m_types_lock().bf_get_f64(y);
@@ -176,7 +192,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn bf_sign(&mut self, x: RawHandle) -> Result<i32, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_float_api_cost.big_float_abs)?;
+        self.use_gas(
+            GasCostCategory::BigFloatApi,
+            self.gas_schedule().big_float_api_cost.big_float_abs,
+        )?;
 
         let bf = self.context.m_types_lock().bf_get_f64(x);
         if bf.is_zero() {
@@ -191,7 +210,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn bf_clone(&mut self, dest: RawHandle, x: RawHandle) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_float_api_cost.big_float_clone)?;
+        self.use_gas(
+            GasCostCategory::BigFloatApi,
+            self.gas_schedule().big_float_api_cost.big_float_clone,
+        )?;
 
         let value = self.context.m_types_lock().bf_get_f64(x);
         self.context.m_types_lock().bf_overwrite(dest, value);
@@ -200,7 +222,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn bf_sqrt(&mut self, dest: RawHandle, x: RawHandle) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_float_api_cost.big_float_sqrt)?;
+        self.use_gas(
+            GasCostCategory::BigFloatApi,
+            self.gas_schedule().big_float_api_cost.big_float_sqrt,
+        )?;
 
         let bf_x = self.context.m_types_lock().bf_get_f64(x);
         if bf_x < 0f64 {
@@ -218,7 +243,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         x: RawHandle,
         exp: i32,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_float_api_cost.big_float_pow)?;
+        self.use_gas(
+            GasCostCategory::BigFloatApi,
+            self.gas_schedule().big_float_api_cost.big_float_pow,
+        )?;
 
         let value = self.context.m_types_lock().bf_get_f64(x);
         self.context
@@ -233,7 +261,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     unary_op_method_big_int_handle!(bf_trunc, trunc, big_float_truncate);
 
     pub fn bf_is_bi(&mut self, x: RawHandle) -> Result<bool, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_float_api_cost.big_float_is_int)?;
+        self.use_gas(
+            GasCostCategory::BigFloatApi,
+            self.gas_schedule().big_float_api_cost.big_float_is_int,
+        )?;
 
         let bf_x = self.context.m_types_lock().bf_get_f64(x);
         let trunc_x = bf_x.trunc();
@@ -243,7 +274,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn bf_set_i64(&mut self, dest: RawHandle, value: i64) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_float_api_cost.big_float_set_int_64)?;
+        self.use_gas(
+            GasCostCategory::BigFloatApi,
+            self.gas_schedule().big_float_api_cost.big_float_set_int_64,
+        )?;
 
         let f64_value = value.to_f64().unwrap();
         self.context.m_types_lock().bf_overwrite(dest, f64_value);
@@ -252,7 +286,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn bf_set_bi(&mut self, dest: RawHandle, bi: RawHandle) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_float_api_cost.big_float_set_big_int)?;
+        self.use_gas(
+            GasCostCategory::BigFloatApi,
+            self.gas_schedule().big_float_api_cost.big_float_set_big_int,
+        )?;
 
         let f64_value = self
             .context
@@ -267,7 +304,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn bf_get_const_pi(&mut self, dest: RawHandle) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_float_api_cost.big_float_get_const)?;
+        self.use_gas(
+            GasCostCategory::BigFloatApi,
+            self.gas_schedule().big_float_api_cost.big_float_get_const,
+        )?;
 
         self.context
             .m_types_lock()
@@ -277,7 +317,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn bf_get_const_e(&mut self, dest: RawHandle) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_float_api_cost.big_float_get_const)?;
+        self.use_gas(
+            GasCostCategory::BigFloatApi,
+            self.gas_schedule().big_float_api_cost.big_float_get_const,
+        )?;
 
         self.context
             .m_types_lock()
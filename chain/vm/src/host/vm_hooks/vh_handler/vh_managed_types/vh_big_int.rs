@@ -3,6 +3,7 @@ use crate::{
         context::big_int_to_i64,
         vm_hooks::{VMHooksContext, VMHooksHandler, vh_early_exit::early_exit_vm_error},
     },
+    schedule::GasCostCategory,
     types::RawHandle,
     vm_err_msg,
 };
@@ -17,7 +18,10 @@ use std::convert::TryInto;
 /// Provides VM hook implementations for methods that deal big ints.
 impl<C: VMHooksContext> VMHooksHandler<C> {
     pub fn bi_new(&mut self, value: i64) -> Result<RawHandle, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_int_api_cost.big_int_new)?;
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_new,
+        )?;
 
         Ok(self
             .context
@@ -30,7 +34,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         destination: RawHandle,
         value: i64,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_int_api_cost.big_int_set_int_64)?;
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_set_int_64,
+        )?;
 
         self.context
             .m_types_lock()
@@ -43,6 +50,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         handle: RawHandle,
     ) -> Result<usize, VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::BigIntApi,
             self.gas_schedule()
                 .big_int_api_cost
                 .big_int_unsigned_byte_length,
@@ -60,6 +68,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         handle: RawHandle,
     ) -> Result<Vec<u8>, VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::BigIntApi,
             self.gas_schedule()
                 .big_int_api_cost
                 .big_int_get_unsigned_bytes,
@@ -74,6 +83,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         bytes: &[u8],
     ) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::BigIntApi,
             self.gas_schedule()
                 .big_int_api_cost
                 .big_int_set_unsigned_bytes,
@@ -88,6 +98,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
 
     pub fn bi_get_signed_bytes(&mut self, handle: RawHandle) -> Result<Vec<u8>, VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::BigIntApi,
             self.gas_schedule()
                 .big_int_api_cost
                 .big_int_get_signed_bytes,
@@ -102,6 +113,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         bytes: &[u8],
     ) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::BigIntApi,
             self.gas_schedule()
                 .big_int_api_cost
                 .big_int_set_signed_bytes,
@@ -115,7 +127,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn bi_is_int64(&mut self, destination_handle: RawHandle) -> Result<i32, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_int_api_cost.big_int_is_int_64)?;
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_is_int_64,
+        )?;
 
         if self
             .context
@@ -130,7 +145,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn bi_get_int64(&mut self, destination_handle: RawHandle) -> Result<i64, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_int_api_cost.big_int_get_int_64)?;
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_get_int_64,
+        )?;
 
         let opt_i64 = self.context.m_types_lock().bi_to_i64(destination_handle);
 
@@ -151,7 +169,10 @@ macro_rules! binary_op_method {
             x: RawHandle,
             y: RawHandle,
         ) -> Result<(), VMHooksEarlyExit> {
-            self.use_gas(self.gas_schedule().big_int_api_cost.$gas_cost_field)?;
+            self.use_gas(
+                GasCostCategory::BigIntApi,
+                self.gas_schedule().big_int_api_cost.$gas_cost_field,
+            )?;
 
             let bi_x = self.context.m_types_lock().bi_get(x);
             let bi_y = self.context.m_types_lock().bi_get(y);
@@ -174,7 +195,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         x: RawHandle,
         y: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_int_api_cost.big_int_t_div)?;
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_t_div,
+        )?;
 
         let bi_x = self.context.m_types_lock().bi_get(x);
         let bi_y = self.context.m_types_lock().bi_get(y);
@@ -195,7 +219,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         x: RawHandle,
         y: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_int_api_cost.big_int_t_mod)?;
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_t_mod,
+        )?;
 
         let bi_x = self.context.m_types_lock().bi_get(x);
         let bi_y = self.context.m_types_lock().bi_get(y);
@@ -218,7 +245,10 @@ macro_rules! unary_op_method {
             dest: RawHandle,
             x: RawHandle,
         ) -> Result<(), VMHooksEarlyExit> {
-            self.use_gas(self.gas_schedule().big_int_api_cost.$gas_cost_field)?;
+            self.use_gas(
+                GasCostCategory::BigIntApi,
+                self.gas_schedule().big_int_api_cost.$gas_cost_field,
+            )?;
 
             let bi_x = self.context.m_types_lock().bi_get(x);
             let result = bi_x.$rust_op_name();
@@ -237,7 +267,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
 
 impl<C: VMHooksContext> VMHooksHandler<C> {
     pub fn bi_sign(&mut self, x: RawHandle) -> Result<i32, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_int_api_cost.big_int_sign)?;
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_sign,
+        )?;
 
         let bi = self.context.m_types_lock().bi_get(x);
         match bi.sign() {
@@ -248,7 +281,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn bi_cmp(&mut self, x: RawHandle, y: RawHandle) -> Result<i32, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_int_api_cost.big_int_cmp)?;
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_cmp,
+        )?;
 
         let bi_x = self.context.m_types_lock().bi_get(x);
         let bi_y = self.context.m_types_lock().bi_get(y);
@@ -265,7 +301,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         x: RawHandle,
         y: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_int_api_cost.big_int_pow)?;
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_pow,
+        )?;
 
         let bi_x = self.context.m_types_lock().bi_get(x);
         let bi_y = self.context.m_types_lock().bi_get(y);
@@ -277,7 +316,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn bi_log2(&mut self, x: RawHandle) -> Result<i32, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_int_api_cost.big_int_log)?;
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_log,
+        )?;
 
         let bi_x = self.context.m_types_lock().bi_get(x);
         Ok(bi_x.bits() as i32 - 1)
@@ -292,7 +334,10 @@ macro_rules! binary_bitwise_op_method {
             x: RawHandle,
             y: RawHandle,
         ) -> Result<(), VMHooksEarlyExit> {
-            self.use_gas(self.gas_schedule().big_int_api_cost.$gas_cost_field)?;
+            self.use_gas(
+                GasCostCategory::BigIntApi,
+                self.gas_schedule().big_int_api_cost.$gas_cost_field,
+            )?;
 
             let bi_x = self.context.m_types_lock().bi_get(x);
             if bi_x.sign() == num_bigint::Sign::Minus {
@@ -327,7 +372,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         x: RawHandle,
         bits: usize,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_int_api_cost.big_int_shr)?;
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_shr,
+        )?;
 
         let bi_x = self.context.m_types_lock().bi_get(x);
         if bi_x.sign() == num_bigint::Sign::Minus {
@@ -347,7 +395,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         x: RawHandle,
         bits: usize,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().big_int_api_cost.big_int_shl)?;
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_shl,
+        )?;
 
         let bi_x = self.context.m_types_lock().bi_get(x);
         if bi_x.sign() == num_bigint::Sign::Minus {
@@ -0,0 +1,384 @@
+use multiversx_chain_vm_executor::VMHooksEarlyExit;
+use num_bigint::{BigInt, BigUint};
+
+use crate::{
+    host::{
+        context::EllipticCurve,
+        vm_hooks::{VMHooksContext, VMHooksHandler, vh_early_exit::early_exit_vm_error},
+    },
+    schedule::GasCostCategory,
+    types::RawHandle,
+    vm_err_msg,
+};
+
+impl<C: VMHooksContext> VMHooksHandler<C> {
+    fn ec_get_curve(&self, ec_handle: RawHandle) -> EllipticCurve {
+        self.context.m_types_lock().ec_get(ec_handle).clone()
+    }
+
+    /// Loads a point from 2 big int handles, crashes if it is not on the curve.
+    fn ec_get_point_on_curve(
+        &self,
+        curve: &EllipticCurve,
+        x_handle: RawHandle,
+        y_handle: RawHandle,
+    ) -> Result<(BigUint, BigUint), VMHooksEarlyExit> {
+        let (x, y) = {
+            let types = self.context.m_types_lock();
+            (
+                types.bi_get(x_handle).to_biguint(),
+                types.bi_get(y_handle).to_biguint(),
+            )
+        };
+        match (x, y) {
+            (Some(x), Some(y)) if curve.is_on_curve(&x, &y) => Ok((x, y)),
+            _ => Err(early_exit_vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE)),
+        }
+    }
+
+    fn ec_set_point(&self, x_handle: RawHandle, y_handle: RawHandle, point: (BigUint, BigUint)) {
+        let mut types = self.context.m_types_lock();
+        types.bi_overwrite(x_handle, BigInt::from(point.0));
+        types.bi_overwrite(y_handle, BigInt::from(point.1));
+    }
+
+    pub fn ec_create(&mut self, name: &[u8]) -> Result<RawHandle, VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::CryptoApi,
+            self.gas_schedule().crypto_api_cost.elliptic_curve_new,
+        )?;
+
+        let Some(curve) = EllipticCurve::from_name(name) else {
+            return Err(early_exit_vm_error(vm_err_msg::EC_CURVE_NOT_SUPPORTED));
+        };
+        Ok(self.context.m_types_lock().ec_new(curve))
+    }
+
+    pub fn ec_create_managed(
+        &mut self,
+        name_handle: RawHandle,
+    ) -> Result<RawHandle, VMHooksEarlyExit> {
+        let name = self.context.m_types_lock().mb_to_bytes(name_handle);
+        self.ec_create(&name)
+    }
+
+    pub fn ec_get_values(
+        &mut self,
+        ec_handle: RawHandle,
+        field_order_handle: RawHandle,
+        base_point_order_handle: RawHandle,
+        eq_constant_handle: RawHandle,
+        x_base_point_handle: RawHandle,
+        y_base_point_handle: RawHandle,
+    ) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_get_int_64,
+        )?;
+
+        let curve = self.ec_get_curve(ec_handle);
+        let mut types = self.context.m_types_lock();
+        types.bi_overwrite(field_order_handle, BigInt::from(curve.field_order));
+        types.bi_overwrite(
+            base_point_order_handle,
+            BigInt::from(curve.base_point_order),
+        );
+        types.bi_overwrite(eq_constant_handle, BigInt::from(curve.eq_constant));
+        types.bi_overwrite(x_base_point_handle, BigInt::from(curve.x_base_point));
+        types.bi_overwrite(y_base_point_handle, BigInt::from(curve.y_base_point));
+        Ok(())
+    }
+
+    pub fn ec_curve_length(&mut self, ec_handle: RawHandle) -> Result<u32, VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_get_int_64,
+        )?;
+
+        Ok(self.ec_get_curve(ec_handle).size_of_field)
+    }
+
+    pub fn ec_private_key_byte_length(
+        &mut self,
+        ec_handle: RawHandle,
+    ) -> Result<usize, VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BigIntApi,
+            self.gas_schedule().big_int_api_cost.big_int_get_int_64,
+        )?;
+
+        Ok(self.ec_get_curve(ec_handle).byte_length())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn ec_add(
+        &mut self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        fst_point_x_handle: RawHandle,
+        fst_point_y_handle: RawHandle,
+        snd_point_x_handle: RawHandle,
+        snd_point_y_handle: RawHandle,
+    ) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::CryptoApi,
+            self.gas_schedule().crypto_api_cost.add_ecc,
+        )?;
+
+        let curve = self.ec_get_curve(ec_handle);
+        let (x1, y1) =
+            self.ec_get_point_on_curve(&curve, fst_point_x_handle, fst_point_y_handle)?;
+        let (x2, y2) =
+            self.ec_get_point_on_curve(&curve, snd_point_x_handle, snd_point_y_handle)?;
+        self.ec_set_point(
+            x_result_handle,
+            y_result_handle,
+            curve.add(&x1, &y1, &x2, &y2),
+        );
+        Ok(())
+    }
+
+    pub fn ec_double(
+        &mut self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        point_x_handle: RawHandle,
+        point_y_handle: RawHandle,
+    ) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::CryptoApi,
+            self.gas_schedule().crypto_api_cost.double_ecc,
+        )?;
+
+        let curve = self.ec_get_curve(ec_handle);
+        let (x, y) = self.ec_get_point_on_curve(&curve, point_x_handle, point_y_handle)?;
+        self.ec_set_point(x_result_handle, y_result_handle, curve.double(&x, &y));
+        Ok(())
+    }
+
+    pub fn ec_is_on_curve(
+        &mut self,
+        ec_handle: RawHandle,
+        point_x_handle: RawHandle,
+        point_y_handle: RawHandle,
+    ) -> Result<bool, VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::CryptoApi,
+            self.gas_schedule().crypto_api_cost.is_on_curve_ecc,
+        )?;
+
+        let curve = self.ec_get_curve(ec_handle);
+        Ok(self
+            .ec_get_point_on_curve(&curve, point_x_handle, point_y_handle)
+            .is_ok())
+    }
+
+    pub fn ec_scalar_base_mult(
+        &mut self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data: &[u8],
+    ) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::CryptoApi,
+            self.gas_schedule().crypto_api_cost.scalar_mult_ecc,
+        )?;
+
+        let curve = self.ec_get_curve(ec_handle);
+        self.ec_set_point(
+            x_result_handle,
+            y_result_handle,
+            curve.scalar_base_mult(data),
+        );
+        Ok(())
+    }
+
+    pub fn ec_scalar_base_mult_managed(
+        &mut self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data_handle: RawHandle,
+    ) -> Result<(), VMHooksEarlyExit> {
+        let data = self.context.m_types_lock().mb_to_bytes(data_handle);
+        self.ec_scalar_base_mult(x_result_handle, y_result_handle, ec_handle, &data)
+    }
+
+    pub fn ec_scalar_mult(
+        &mut self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        point_x_handle: RawHandle,
+        point_y_handle: RawHandle,
+        data: &[u8],
+    ) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::CryptoApi,
+            self.gas_schedule().crypto_api_cost.scalar_mult_ecc,
+        )?;
+
+        let curve = self.ec_get_curve(ec_handle);
+        let (x, y) = self.ec_get_point_on_curve(&curve, point_x_handle, point_y_handle)?;
+        self.ec_set_point(
+            x_result_handle,
+            y_result_handle,
+            curve.scalar_mult(&x, &y, data),
+        );
+        Ok(())
+    }
+
+    pub fn ec_scalar_mult_managed(
+        &mut self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        point_x_handle: RawHandle,
+        point_y_handle: RawHandle,
+        data_handle: RawHandle,
+    ) -> Result<(), VMHooksEarlyExit> {
+        let data = self.context.m_types_lock().mb_to_bytes(data_handle);
+        self.ec_scalar_mult(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            point_x_handle,
+            point_y_handle,
+            &data,
+        )
+    }
+
+    pub fn ec_marshal(
+        &mut self,
+        x_pair_handle: RawHandle,
+        y_pair_handle: RawHandle,
+        ec_handle: RawHandle,
+    ) -> Result<Vec<u8>, VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::CryptoApi,
+            self.gas_schedule().crypto_api_cost.marshal_ecc,
+        )?;
+
+        let curve = self.ec_get_curve(ec_handle);
+        let (x, y) = self.ec_get_point_on_curve(&curve, x_pair_handle, y_pair_handle)?;
+        Ok(curve.marshal(&x, &y))
+    }
+
+    pub fn ec_marshal_managed(
+        &mut self,
+        x_pair_handle: RawHandle,
+        y_pair_handle: RawHandle,
+        ec_handle: RawHandle,
+        result_handle: RawHandle,
+    ) -> Result<usize, VMHooksEarlyExit> {
+        let result = self.ec_marshal(x_pair_handle, y_pair_handle, ec_handle)?;
+        let result_len = result.len();
+        self.context.m_types_lock().mb_set(result_handle, result);
+        Ok(result_len)
+    }
+
+    pub fn ec_marshal_compressed(
+        &mut self,
+        x_pair_handle: RawHandle,
+        y_pair_handle: RawHandle,
+        ec_handle: RawHandle,
+    ) -> Result<Vec<u8>, VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::CryptoApi,
+            self.gas_schedule().crypto_api_cost.marshal_compressed_ecc,
+        )?;
+
+        let curve = self.ec_get_curve(ec_handle);
+        let (x, y) = self.ec_get_point_on_curve(&curve, x_pair_handle, y_pair_handle)?;
+        Ok(curve.marshal_compressed(&x, &y))
+    }
+
+    pub fn ec_marshal_compressed_managed(
+        &mut self,
+        x_pair_handle: RawHandle,
+        y_pair_handle: RawHandle,
+        ec_handle: RawHandle,
+        result_handle: RawHandle,
+    ) -> Result<usize, VMHooksEarlyExit> {
+        let result = self.ec_marshal_compressed(x_pair_handle, y_pair_handle, ec_handle)?;
+        let result_len = result.len();
+        self.context.m_types_lock().mb_set(result_handle, result);
+        Ok(result_len)
+    }
+
+    pub fn ec_unmarshal(
+        &mut self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data: &[u8],
+    ) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::CryptoApi,
+            self.gas_schedule().crypto_api_cost.unmarshal_ecc,
+        )?;
+
+        let curve = self.ec_get_curve(ec_handle);
+        if data.len() != curve.marshal_length() {
+            return Err(early_exit_vm_error(vm_err_msg::EC_BUFFER_LENGTH_INCORRECT));
+        }
+        let Some(point) = curve.unmarshal(data) else {
+            return Err(early_exit_vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE));
+        };
+        self.ec_set_point(x_result_handle, y_result_handle, point);
+        Ok(())
+    }
+
+    pub fn ec_unmarshal_managed(
+        &mut self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data_handle: RawHandle,
+    ) -> Result<(), VMHooksEarlyExit> {
+        let data = self.context.m_types_lock().mb_to_bytes(data_handle);
+        self.ec_unmarshal(x_result_handle, y_result_handle, ec_handle, &data)
+    }
+
+    pub fn ec_unmarshal_compressed(
+        &mut self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data: &[u8],
+    ) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::CryptoApi,
+            self.gas_schedule().crypto_api_cost.unmarshal_compressed_ecc,
+        )?;
+
+        let curve = self.ec_get_curve(ec_handle);
+        if data.len() != curve.marshal_compressed_length() {
+            return Err(early_exit_vm_error(vm_err_msg::EC_BUFFER_LENGTH_INCORRECT));
+        }
+        let Some(point) = curve.unmarshal_compressed(data) else {
+            return Err(early_exit_vm_error(vm_err_msg::EC_POINT_NOT_ON_CURVE));
+        };
+        self.ec_set_point(x_result_handle, y_result_handle, point);
+        Ok(())
+    }
+
+    pub fn ec_unmarshal_compressed_managed(
+        &mut self,
+        x_result_handle: RawHandle,
+        y_result_handle: RawHandle,
+        ec_handle: RawHandle,
+        data_handle: RawHandle,
+    ) -> Result<(), VMHooksEarlyExit> {
+        let data = self.context.m_types_lock().mb_to_bytes(data_handle);
+        self.ec_unmarshal_compressed(x_result_handle, y_result_handle, ec_handle, &data)
+    }
+
+    /// Key generation is deactivated on the real chain, so the debugger does not allow it either.
+    pub fn ec_generate_key(&mut self) -> Result<(), VMHooksEarlyExit> {
+        Err(early_exit_vm_error(vm_err_msg::VM_HOOK_DEACTIVATED))
+    }
+}
@@ -2,18 +2,23 @@ use multiversx_chain_vm_executor::{MemPtr, VMHooksEarlyExit};
 
 use crate::host::vm_hooks::vh_dispatcher::{RESULT_ERROR, RESULT_OK};
 use crate::host::vm_hooks::{VMHooksContext, VMHooksHandler};
+use crate::schedule::GasCostCategory;
 use crate::types::RawHandle;
 
 /// Provides VM hook implementations for methods that deal managed buffers.
 impl<C: VMHooksContext> VMHooksHandler<C> {
     pub fn mb_new_empty(&mut self) -> Result<RawHandle, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().managed_buffer_api_cost.m_buffer_new)?;
+        self.use_gas(
+            GasCostCategory::ManagedBufferApi,
+            self.gas_schedule().managed_buffer_api_cost.m_buffer_new,
+        )?;
 
         Ok(self.context.m_types_lock().mb_new(Vec::new()))
     }
 
     pub fn mb_new_from_bytes(&mut self, bytes: &[u8]) -> Result<RawHandle, VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_new_from_bytes,
@@ -24,6 +29,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
 
     pub fn mb_len(&mut self, handle: RawHandle) -> Result<usize, VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_get_length,
@@ -34,6 +40,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
 
     pub fn mb_set(&mut self, handle: RawHandle, value: &[u8]) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_set_bytes,
@@ -46,6 +53,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
 
     pub fn mb_get_bytes(&mut self, source_handle: RawHandle) -> Result<Vec<u8>, VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_get_bytes,
@@ -62,6 +70,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         result_offset: MemPtr,
     ) -> Result<i32, VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_get_byte_slice,
@@ -90,6 +99,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         dest_handle: RawHandle,
     ) -> Result<i32, VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_copy_byte_slice,
@@ -114,6 +124,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         source_slice: &[u8],
     ) -> Result<i32, VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_set_bytes,
@@ -135,7 +146,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         accumulator_handle: RawHandle,
         data_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().managed_buffer_api_cost.m_buffer_append)?;
+        self.use_gas(
+            GasCostCategory::ManagedBufferApi,
+            self.gas_schedule().managed_buffer_api_cost.m_buffer_append,
+        )?;
 
         let mut data = self.context.m_types_lock().mb_get(data_handle).to_vec();
         self.context
@@ -153,6 +167,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         bytes: &[u8],
     ) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_append_bytes,
@@ -171,6 +186,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         handle2: RawHandle,
     ) -> Result<i32, VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             2 * self
                 .gas_schedule()
                 .managed_buffer_api_cost
@@ -193,12 +209,14 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         dest_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_get_bytes,
         )?;
 
         self.use_gas(
+            GasCostCategory::ManagedBufferApi,
             self.gas_schedule()
                 .managed_buffer_api_cost
                 .m_buffer_set_bytes,
@@ -2,12 +2,16 @@ use multiversx_chain_vm_executor::VMHooksEarlyExit;
 
 use crate::{
     host::vm_hooks::{VMHooksContext, VMHooksHandler},
+    schedule::GasCostCategory,
     types::RawHandle,
 };
 
 impl<C: VMHooksContext> VMHooksHandler<C> {
     pub fn mm_new(&mut self) -> Result<RawHandle, VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().managed_map_api_cost.managed_map_new)?;
+        self.use_gas(
+            GasCostCategory::ManagedMapApi,
+            self.gas_schedule().managed_map_api_cost.managed_map_new,
+        )?;
         Ok(self.context.m_types_lock().mm_new())
     }
 
@@ -17,7 +21,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         key_handle: RawHandle,
         out_value_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().managed_map_api_cost.managed_map_get)?;
+        self.use_gas(
+            GasCostCategory::ManagedMapApi,
+            self.gas_schedule().managed_map_api_cost.managed_map_get,
+        )?;
         let key = self.context.m_types_lock().mb_get(key_handle).to_vec();
         let value = self
             .context
@@ -33,7 +40,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         key_handle: RawHandle,
         value_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().managed_map_api_cost.managed_map_put)?;
+        self.use_gas(
+            GasCostCategory::ManagedMapApi,
+            self.gas_schedule().managed_map_api_cost.managed_map_put,
+        )?;
         let key = self.context.m_types_lock().mb_get(key_handle).to_vec();
         let value = self.context.m_types_lock().mb_get(value_handle).to_vec();
         self.context
@@ -48,7 +58,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         key_handle: RawHandle,
         out_value_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().managed_map_api_cost.managed_map_remove)?;
+        self.use_gas(
+            GasCostCategory::ManagedMapApi,
+            self.gas_schedule().managed_map_api_cost.managed_map_remove,
+        )?;
         let key = self.context.m_types_lock().mb_get(key_handle).to_vec();
         let value = self
             .context
@@ -64,6 +77,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         key_handle: RawHandle,
     ) -> Result<bool, VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::ManagedMapApi,
             self.gas_schedule()
                 .managed_map_api_cost
                 .managed_map_contains,
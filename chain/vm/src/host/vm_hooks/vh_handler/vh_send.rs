@@ -4,9 +4,13 @@ use crate::{
         UPGRADE_CONTRACT_FUNC_NAME,
     },
     host::{
-        context::{AsyncCallTxData, Promise, TxFunctionName, TxTokenTransfer},
+        context::{
+            AsyncCallTxData, Promise, TxFunctionName, TxTokenTransfer,
+            UNMETERED_NESTED_CALL_GAS_LIMIT,
+        },
         vm_hooks::{VMHooksContext, vh_early_exit::early_exit_vm_error},
     },
+    schedule::GasCostCategory,
     types::{Address, RawHandle, VMCodeMetadata, top_encode_big_uint, top_encode_u64},
     vm_err_msg,
 };
@@ -28,6 +32,14 @@ fn append_endpoint_name_and_args(
 }
 
 impl<C: VMHooksContext> VMHooksHandler<C> {
+    /// Transfers, async calls, deploys and upgrades all alter the state, so none of them are allowed in read-only mode.
+    fn check_not_readonly_call(&self) -> Result<(), VMHooksEarlyExit> {
+        if self.context.input_ref().readonly {
+            return Err(early_exit_vm_error(vm_err_msg::CALL_READONLY));
+        }
+        Ok(())
+    }
+
     fn perform_transfer_execute_esdt(
         &mut self,
         to: Address,
@@ -135,6 +147,12 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         endpoint_name_handle: RawHandle,
         arg_buffer_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.transfer_value,
+        )?;
+        self.check_not_readonly_call()?;
+
         let recipient = self.context.m_types_lock().mb_to_address(to_handle);
         let egld_value = self.context.m_types_lock().bu_get(amount_handle);
         let endpoint_name = self
@@ -155,6 +173,8 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         endpoint_name_handle: RawHandle,
         arg_buffer_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
+        self.check_not_readonly_call()?;
+
         let to = self.context.m_types_lock().mb_to_address(to_handle);
         let (payments, num_bytes_copied) = self
             .context
@@ -220,6 +240,12 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         endpoint_name_handle: RawHandle,
         arg_buffer_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.async_call_step,
+        )?;
+        self.check_not_readonly_call()?;
+
         let to = self.context.m_types_lock().mb_to_address(to_handle);
         let egld_value = self.context.m_types_lock().bu_get(egld_value_handle);
         let endpoint_name = self
@@ -241,10 +267,16 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         arg_buffer_handle: RawHandle,
         success_callback: &[u8],
         error_callback: &[u8],
-        _gas: u64,
+        gas: u64,
         _extra_gas_for_callback: u64,
         callback_closure_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.create_async_call,
+        )?;
+        self.check_not_readonly_call()?;
+
         let contract_address = self.context.current_address().clone();
         let to = self.context.m_types_lock().mb_to_address(to_handle);
         let egld_value = self.context.m_types_lock().bu_get(egld_value_handle);
@@ -265,13 +297,24 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
             .mb_get(callback_closure_handle)
             .to_vec();
 
+        let gas_limit = if self.context.gas_metering() {
+            gas
+        } else {
+            UNMETERED_NESTED_CALL_GAS_LIMIT
+        };
+
         let call = AsyncCallTxData {
             from: contract_address,
             to,
             call_value: egld_value,
             endpoint_name,
             arguments: arg_buffer,
+            gas_limit,
             tx_hash,
+            original_tx_hash: self.context.input_ref().original_tx_hash().clone(),
+            prev_tx_hash: self.context.input_ref().prev_tx_hash().clone(),
+            original_caller: self.context.input_ref().original_caller().clone(),
+            relayer: self.context.input_ref().relayer.clone(),
         };
 
         let promise = Promise {
@@ -299,7 +342,11 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         new_address_handle: RawHandle,
         result_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.create_contract)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.create_contract,
+        )?;
+        self.check_not_readonly_call()?;
 
         let egld_value = self.context.m_types_lock().bu_get(egld_value_handle);
         let code = self.context.m_types_lock().mb_get(code_handle).to_vec();
@@ -332,7 +379,11 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         new_address_handle: RawHandle,
         result_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.create_contract)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.create_contract,
+        )?;
+        self.check_not_readonly_call()?;
 
         let egld_value = self.context.m_types_lock().bu_get(egld_value_handle);
         let source_contract_address = self
@@ -371,7 +422,11 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         code_metadata_handle: RawHandle,
         arg_buffer_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.create_contract)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.create_contract,
+        )?;
+        self.check_not_readonly_call()?;
 
         let to = self.context.m_types_lock().mb_to_address(sc_address_handle);
         let egld_value = self.context.m_types_lock().bu_get(egld_value_handle);
@@ -404,7 +459,11 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         code_metadata_handle: RawHandle,
         arg_buffer_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.create_contract)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.create_contract,
+        )?;
+        self.check_not_readonly_call()?;
 
         let to = self.context.m_types_lock().mb_to_address(sc_address_handle);
         let egld_value = self.context.m_types_lock().bu_get(egld_value_handle);
@@ -435,6 +494,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         result_handle: RawHandle,
     ) -> Result<(ReturnCode, String), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::BaseOpsApi,
             self.gas_schedule()
                 .base_ops_api_cost
                 .execute_on_dest_context,
@@ -442,6 +502,9 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
 
         let to = self.context.m_types_lock().mb_to_address(to_handle);
         let egld_value = self.context.m_types_lock().bu_get(egld_value_handle);
+        if !egld_value.is_zero() {
+            self.check_not_readonly_call()?;
+        }
         let endpoint_name = self
             .context
             .m_types_lock()
@@ -521,7 +584,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         arg_buffer_handle: RawHandle,
         result_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.execute_read_only)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.execute_read_only,
+        )?;
 
         let to = self.context.m_types_lock().mb_to_address(to_handle);
         let endpoint_name = self
@@ -539,6 +605,28 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         Ok(())
     }
 
+    /// Legacy variant, arguments come from instance memory, results are only added to the return data.
+    pub fn execute_read_only(
+        &mut self,
+        _gas: u64,
+        to: Address,
+        endpoint_name: TxFunctionName,
+        arguments: Vec<Vec<u8>>,
+    ) -> Result<(), VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.execute_read_only,
+        )?;
+        let num_bytes_copied = arguments.iter().map(|arg| arg.len()).sum();
+        self.use_gas_for_data_copy(num_bytes_copied)?;
+
+        let _ =
+            self.context
+                .perform_execute_on_dest_context_readonly(to, endpoint_name, arguments)?;
+
+        Ok(())
+    }
+
     fn load_arg_data(
         &mut self,
         arg_buffer_handle: RawHandle,
@@ -566,7 +654,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
     }
 
     pub fn clean_return_data(&mut self) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.clean_return_data)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.clean_return_data,
+        )?;
 
         let mut tx_result = self.context.result_lock();
         tx_result.result_values.clear();
@@ -575,6 +666,7 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
 
     pub fn delete_from_return_data(&mut self, index: usize) -> Result<(), VMHooksEarlyExit> {
         self.use_gas(
+            GasCostCategory::BaseOpsApi,
             self.gas_schedule()
                 .base_ops_api_cost
                 .delete_from_return_data,
@@ -1,19 +1,42 @@
 use multiversx_chain_vm_executor::VMHooksEarlyExit;
 
 use crate::{
+    blockchain::state::{storage_lock_from_bytes, storage_lock_key, storage_lock_to_bytes},
     host::vm_hooks::VMHooksContext,
+    schedule::GasCostCategory,
     types::{Address, RawHandle},
 };
 
 use super::VMHooksHandler;
 
+/// Storage statuses, as returned by the Go VM on storage writes.
+const STORAGE_UNCHANGED: i32 = 0;
+const STORAGE_MODIFIED: i32 = 1;
+const STORAGE_ADDED: i32 = 2;
+const STORAGE_DELETED: i32 = 3;
+
+fn storage_status(old_value: &[u8], new_value: &[u8]) -> i32 {
+    if old_value == new_value {
+        STORAGE_UNCHANGED
+    } else if new_value.is_empty() {
+        STORAGE_DELETED
+    } else if old_value.is_empty() {
+        STORAGE_ADDED
+    } else {
+        STORAGE_MODIFIED
+    }
+}
+
 impl<C: VMHooksContext> VMHooksHandler<C> {
     pub fn storage_load_managed_buffer_raw(
         &mut self,
         key_handle: RawHandle,
         dest: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.storage_load)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.storage_load,
+        )?;
 
         let value = self
             .context
@@ -29,7 +52,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         key_handle: RawHandle,
         dest: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.storage_load)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.storage_load,
+        )?;
 
         let address = Address::from_slice(self.context.m_types_lock().mb_get(address_handle));
         let value = self
@@ -45,7 +71,10 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         key_handle: RawHandle,
         value_handle: RawHandle,
     ) -> Result<(), VMHooksEarlyExit> {
-        self.use_gas(self.gas_schedule().base_ops_api_cost.storage_store)?;
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.storage_store,
+        )?;
 
         let types = self.context.m_types_lock();
         let key_bytes = types.mb_get_owned(key_handle);
@@ -53,4 +82,45 @@ impl<C: VMHooksContext> VMHooksHandler<C> {
         std::mem::drop(types);
         self.context.storage_write(&key_bytes, &value_bytes)
     }
+
+    pub fn set_storage_lock(
+        &mut self,
+        key: &[u8],
+        lock_timestamp: i64,
+    ) -> Result<i32, VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.int_64_storage_store,
+        )?;
+
+        let lock_key = storage_lock_key(key);
+        let old_value = self.context.storage_read(&lock_key);
+        let new_value = storage_lock_to_bytes(lock_timestamp);
+        self.context
+            .storage_write_protected(&lock_key, &new_value)?;
+        Ok(storage_status(&old_value, &new_value))
+    }
+
+    pub fn get_storage_lock(&mut self, key: &[u8]) -> Result<i64, VMHooksEarlyExit> {
+        self.use_gas(
+            GasCostCategory::BaseOpsApi,
+            self.gas_schedule().base_ops_api_cost.int_64_storage_load,
+        )?;
+
+        let value = self.context.storage_read(&storage_lock_key(key));
+        Ok(storage_lock_from_bytes(&value))
+    }
+
+    pub fn is_storage_locked(&mut self, key: &[u8]) -> Result<bool, VMHooksEarlyExit> {
+        let lock_timestamp = self.get_storage_lock(key)?;
+        Ok(self
+            .context
+            .get_block_config()
+            .current_block_info
+            .is_storage_lock_active(lock_timestamp))
+    }
+
+    pub fn clear_storage_lock(&mut self, key: &[u8]) -> Result<i32, VMHooksEarlyExit> {
+        self.set_storage_lock(key, 0)
+    }
 }
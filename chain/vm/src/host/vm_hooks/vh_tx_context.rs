@@ -6,14 +6,15 @@ use multiversx_chain_vm_executor::{InstanceState, MemLength, MemPtr, VMHooksEarl
 use num_bigint::BigUint;
 use num_traits::Zero;
 
-use crate::blockchain::state::BlockConfig;
+use crate::blockchain::state::{BlockConfig, EsdtTokenConfig};
 use crate::host::runtime::RuntimeInstanceCallLambdaDefault;
-use crate::schedule::GasSchedule;
+use crate::schedule::{GasCostCategory, GasSchedule};
 use crate::{
     blockchain::{reserved::STORAGE_RESERVED_PREFIX, state::AccountData},
     host::context::{
         AsyncCallTxData, BackTransfers, BlockchainUpdate, CallType, ManagedTypeContainer, TxCache,
-        TxContextRef, TxFunctionName, TxInput, TxResult, async_call_tx_input,
+        TxContextRef, TxFunctionName, TxInput, TxResult, UNMETERED_NESTED_CALL_GAS_LIMIT,
+        async_call_tx_input,
     },
     host::execution,
     types::{Address, VMCodeMetadata},
@@ -64,7 +65,7 @@ impl<S: InstanceState> VMHooksContext for TxVMHooksContext<S> {
         &self.tx_context_ref.0.runtime_ref.vm_ref.gas_schedule
     }
 
-    fn use_gas(&mut self, gas: u64) -> Result<(), VMHooksEarlyExit> {
+    fn use_gas(&mut self, category: GasCostCategory, gas: u64) -> Result<(), VMHooksEarlyExit> {
         let gas_limit = self.input_ref().gas_limit;
         let state_ref = &mut self.instance_state_ref;
         let prev_gas_used = state_ref
@@ -82,10 +83,17 @@ impl<S: InstanceState> VMHooksContext for TxVMHooksContext<S> {
             state_ref
                 .set_points_used(next_gas_used)
                 .expect("error setting points used in instance");
+            if self.gas_metering() {
+                self.result_lock().add_gas_breakdown(category, gas);
+            }
             Ok(())
         }
     }
 
+    fn gas_metering(&self) -> bool {
+        self.tx_context_ref.runtime_ref.vm_ref.gas_metering
+    }
+
     fn input_ref(&self) -> &TxInput {
         self.tx_context_ref.input_ref()
     }
@@ -106,6 +114,14 @@ impl<S: InstanceState> VMHooksContext for TxVMHooksContext<S> {
 
     fn storage_write(&mut self, key: &[u8], value: &[u8]) -> Result<(), VMHooksEarlyExit> {
         self.check_reserved_key(key)?;
+        self.storage_write_protected(key, value)
+    }
+
+    fn storage_write_protected(
+        &mut self,
+        key: &[u8],
+        value: &[u8],
+    ) -> Result<(), VMHooksEarlyExit> {
         self.check_not_readonly()?;
 
         self.tx_context_ref.with_contract_account_mut(|account| {
@@ -118,6 +134,10 @@ impl<S: InstanceState> VMHooksContext for TxVMHooksContext<S> {
         &self.tx_context_ref.blockchain_ref().block_config
     }
 
+    fn state_root_hash(&self) -> [u8; 32] {
+        self.tx_context_ref.blockchain_ref().state_root_hash()
+    }
+
     fn back_transfers_lock(&self) -> MutexGuard<'_, BackTransfers> {
         self.tx_context_ref.back_transfers_lock()
     }
@@ -134,6 +154,12 @@ impl<S: InstanceState> VMHooksContext for TxVMHooksContext<S> {
             .unwrap_or_else(|| panic!("Account is not a smart contract, it has no code"))
     }
 
+    fn esdt_token_config(&self, token_identifier: &[u8]) -> Option<EsdtTokenConfig> {
+        self.tx_context_ref
+            .blockchain_cache()
+            .get_esdt_token_config(token_identifier)
+    }
+
     fn perform_async_call(
         &mut self,
         to: Address,
@@ -157,7 +183,9 @@ impl<S: InstanceState> VMHooksContext for TxVMHooksContext<S> {
         arguments: Vec<Vec<u8>>,
     ) -> Result<TxResult, VMHooksEarlyExit> {
         let async_call_data = self.create_async_call_data(to, egld_value, func_name, arguments);
-        let tx_input = async_call_tx_input(&async_call_data, CallType::ExecuteOnDestContext);
+        let mut tx_input = async_call_tx_input(&async_call_data, CallType::ExecuteOnDestContext);
+        // read-only mode extends to all nested sync calls
+        tx_input.readonly = self.input_ref().readonly;
         let tx_cache = TxCache::new(self.tx_context_ref.blockchain_cache_arc());
         let (tx_result, blockchain_updates) = execution::execute_builtin_function_or_default(
             tx_input,
@@ -171,6 +199,7 @@ impl<S: InstanceState> VMHooksContext for TxVMHooksContext<S> {
         } else {
             self.sync_call_post_processing_err(&tx_result);
         }
+        self.use_nested_call_gas(&tx_result)?;
 
         Ok(tx_result)
     }
@@ -193,6 +222,8 @@ impl<S: InstanceState> VMHooksContext for TxVMHooksContext<S> {
             RuntimeInstanceCallLambdaDefault,
         );
 
+        self.use_nested_call_gas(&tx_result)?;
+
         if tx_result.result_status.is_success() {
             self.sync_call_post_processing_ok(&tx_result, blockchain_updates);
             Ok(tx_result.result_values)
@@ -212,7 +243,7 @@ impl<S: InstanceState> VMHooksContext for TxVMHooksContext<S> {
         code_metadata: VMCodeMetadata,
         args: Vec<Vec<u8>>,
     ) -> Result<(Address, Vec<Vec<u8>>), VMHooksEarlyExit> {
-        let contract_address = self.current_address();
+        let contract_address = self.current_address().clone();
         let tx_hash = self.tx_hash();
         let tx_input = TxInput {
             from: contract_address.clone(),
@@ -221,14 +252,18 @@ impl<S: InstanceState> VMHooksContext for TxVMHooksContext<S> {
             esdt_values: Vec::new(),
             func_name: TxFunctionName::INIT,
             args,
-            gas_limit: 1000,
+            gas_limit: self.nested_call_gas_limit(),
             gas_price: 0,
             tx_hash,
+            original_tx_hash: Some(self.tx_context_ref.input_ref().original_tx_hash().clone()),
+            prev_tx_hash: Some(self.tx_context_ref.input_ref().prev_tx_hash().clone()),
+            original_caller: Some(self.tx_context_ref.input_ref().original_caller().clone()),
+            relayer: self.tx_context_ref.input_ref().relayer.clone(),
             ..Default::default()
         };
 
         let tx_cache = TxCache::new(self.tx_context_ref.blockchain_cache_arc());
-        tx_cache.increase_account_nonce(contract_address);
+        tx_cache.increase_account_nonce(&contract_address);
         let (tx_result, new_address, blockchain_updates) = execution::execute_deploy(
             tx_input,
             contract_code,
@@ -237,6 +272,7 @@ impl<S: InstanceState> VMHooksContext for TxVMHooksContext<S> {
             &self.tx_context_ref.runtime_ref,
             RuntimeInstanceCallLambdaDefault,
         );
+        self.use_nested_call_gas(&tx_result)?;
 
         match tx_result.result_status {
             ReturnCode::Success => {
@@ -275,6 +311,7 @@ impl<S: InstanceState> VMHooksContext for TxVMHooksContext<S> {
             &self.tx_context_ref.runtime_ref,
             RuntimeInstanceCallLambdaDefault,
         );
+        self.use_nested_call_gas(&tx_result)?;
 
         match tx_result.result_status {
             ReturnCode::Success => {
@@ -300,22 +337,54 @@ impl<S: InstanceState> VMHooksContext for TxVMHooksContext<S> {
 }
 
 impl<S: InstanceState> TxVMHooksContext<S> {
+    /// Gas limit of a nested call.
+    ///
+    /// With gas metering, nested calls receive all the gas not yet consumed by the current instance,
+    /// the gas arguments of the VM hooks are ignored.
+    /// Without it, they get a fixed limit, as before metering was introduced.
+    fn nested_call_gas_limit(&mut self) -> u64 {
+        if !self.gas_metering() {
+            return UNMETERED_NESTED_CALL_GAS_LIMIT;
+        }
+
+        let points_used = self
+            .instance_state_ref
+            .get_points_used()
+            .expect("error fetching points used from instance state");
+        self.input_ref().gas_limit.saturating_sub(points_used)
+    }
+
+    /// With gas metering, the gas spent by a nested call is charged to the caller.
+    fn use_nested_call_gas(&mut self, tx_result: &TxResult) -> Result<(), VMHooksEarlyExit> {
+        if !self.gas_metering() {
+            return Ok(());
+        }
+
+        self.use_gas(GasCostCategory::NestedCall, tx_result.gas_used.as_u64())
+    }
+
     fn create_async_call_data(
-        &self,
+        &mut self,
         to: Address,
         egld_value: num_bigint::BigUint,
         func_name: TxFunctionName,
         arguments: Vec<Vec<u8>>,
     ) -> AsyncCallTxData {
-        let contract_address = &self.tx_context_ref.input_ref().to;
+        let contract_address = self.tx_context_ref.input_ref().to.clone();
         let tx_hash = self.tx_hash();
+        let gas_limit = self.nested_call_gas_limit();
         AsyncCallTxData {
-            from: contract_address.clone(),
+            from: contract_address,
             to,
             call_value: egld_value,
             endpoint_name: func_name,
             arguments,
+            gas_limit,
             tx_hash,
+            original_tx_hash: self.tx_context_ref.input_ref().original_tx_hash().clone(),
+            prev_tx_hash: self.tx_context_ref.input_ref().prev_tx_hash().clone(),
+            original_caller: self.tx_context_ref.input_ref().original_caller().clone(),
+            relayer: self.tx_context_ref.input_ref().relayer.clone(),
         }
     }
 
@@ -355,7 +424,7 @@ impl<S: InstanceState> TxVMHooksContext<S> {
         Ok(())
     }
 
-    /// TODO: only checked on storage writes, needs more checks for calls, transfers, etc.
+    /// Storage writes. Transfers and calls are checked in the VM hooks handler.
     fn check_not_readonly(&mut self) -> Result<(), VMHooksEarlyExit> {
         if self.tx_context_ref.input_ref().readonly {
             return Err(early_exit_vm_error(vm_err_msg::WRITE_READONLY));
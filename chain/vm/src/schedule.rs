@@ -1,6 +1,8 @@
+mod gas_cost_category;
 mod gas_schedule;
 mod gas_schedule_sections;
 mod gas_schedule_version;
 
+pub use gas_cost_category::GasCostCategory;
 pub use gas_schedule::GasSchedule;
 pub use gas_schedule_version::GasScheduleVersion;
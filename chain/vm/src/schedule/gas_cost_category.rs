@@ -0,0 +1,42 @@
+use std::fmt;
+
+/// Groups gas costs by origin, mostly following the sections of the gas schedule.
+///
+/// Used for reporting only, it has no effect on how much gas gets consumed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GasCostCategory {
+    BaseOperation,
+    BaseOpsApi,
+    BigIntApi,
+    BigFloatApi,
+    CryptoApi,
+    ManagedBufferApi,
+    ManagedMapApi,
+    BuiltinFunction,
+
+    /// Gas consumed by sync calls, deploys and transfer-execute calls launched from the contract.
+    NestedCall,
+}
+
+impl GasCostCategory {
+    /// Name used in reports. Where applicable, it is the name of the gas schedule section.
+    pub fn name(&self) -> &'static str {
+        match self {
+            GasCostCategory::BaseOperation => "BaseOperationCost",
+            GasCostCategory::BaseOpsApi => "BaseOpsAPICost",
+            GasCostCategory::BigIntApi => "BigIntAPICost",
+            GasCostCategory::BigFloatApi => "BigFloatAPICost",
+            GasCostCategory::CryptoApi => "CryptoAPICost",
+            GasCostCategory::ManagedBufferApi => "ManagedBufferAPICost",
+            GasCostCategory::ManagedMapApi => "ManagedMapAPICost",
+            GasCostCategory::BuiltinFunction => "BuiltInCost",
+            GasCostCategory::NestedCall => "NestedCalls",
+        }
+    }
+}
+
+impl fmt::Display for GasCostCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -1,13 +1,21 @@
+mod system_sc_freeze_wipe;
 mod system_sc_issue;
+mod system_sc_pause;
+mod system_sc_queries;
 mod system_sc_special_roles;
+mod system_sc_token_changes;
 mod system_sc_unimplemented;
 
 use crate::{
     host::context::{BlockchainUpdate, TxCache, TxInput, TxResult},
     types::Address,
 };
+use system_sc_freeze_wipe::*;
 use system_sc_issue::*;
+use system_sc_pause::*;
+use system_sc_queries::*;
 use system_sc_special_roles::*;
+use system_sc_token_changes::*;
 use system_sc_unimplemented::*;
 
 /// Address of the system smart contract that manages ESDT.
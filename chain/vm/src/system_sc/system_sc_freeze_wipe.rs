@@ -0,0 +1,263 @@
+use crate::{
+    host::context::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
+    types::{Address, top_decode_u64, top_encode_big_uint, top_encode_u64},
+    vm_err_msg,
+};
+
+use super::system_sc_special_roles::check_owner_and_property;
+
+const ESDT_FREEZE_LOG_IDENTIFIER: &str = "ESDTFreeze";
+const ESDT_UNFREEZE_LOG_IDENTIFIER: &str = "ESDTUnFreeze";
+const ESDT_WIPE_LOG_IDENTIFIER: &str = "ESDTWipe";
+
+const INVALID_FREEZE_ADDRESS: &str = "invalid address to freeze/unfreeze";
+const INVALID_WIPE_ADDRESS: &str = "invalid address to wipe";
+
+/// Freezes the balance of a token for an account.
+pub fn freeze(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    toggle_freeze(tx_input, tx_cache, true)
+}
+
+/// Unfreezes the balance of a token for an account.
+pub fn unfreeze(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    toggle_freeze(tx_input, tx_cache, false)
+}
+
+/// Freezes a single NFT/SFT nonce for an account.
+pub fn freeze_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    toggle_freeze_single_nft(tx_input, tx_cache, true)
+}
+
+/// Unfreezes a single NFT/SFT nonce for an account.
+pub fn unfreeze_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    toggle_freeze_single_nft(tx_input, tx_cache, false)
+}
+
+/// Wipes out the fungible balance of a frozen account.
+pub fn wipe(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() < 2 {
+        return (
+            TxResult::from_vm_error("wipe too few arguments"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    let address = match parse_address(&tx_input.args[1], INVALID_WIPE_ADDRESS) {
+        Ok(address) => address,
+        Err(message) => return (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    };
+
+    wipe_nonce(tx_input, tx_cache, token_identifier, 0, address)
+}
+
+/// Wipes out a single NFT/SFT nonce held by a frozen account.
+pub fn wipe_single_nft(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() < 3 {
+        return (
+            TxResult::from_vm_error("wipeSingleNFT too few arguments"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    let nonce = top_decode_u64(tx_input.args[1].as_slice());
+    let address = match parse_address(&tx_input.args[2], INVALID_WIPE_ADDRESS) {
+        Ok(address) => address,
+        Err(message) => return (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    };
+
+    wipe_nonce(tx_input, tx_cache, token_identifier, nonce, address)
+}
+
+fn toggle_freeze(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    frozen: bool,
+) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() < 2 {
+        return (
+            TxResult::from_vm_error(format!("{} too few arguments", tx_input.func_name.as_str())),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    let address = match parse_address(&tx_input.args[1], INVALID_FREEZE_ADDRESS) {
+        Ok(address) => address,
+        Err(message) => return (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    };
+
+    if let Err(message) = check_freeze_allowed(&tx_input, &tx_cache, &token_identifier) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    tx_cache.with_account_mut(&address, |account| {
+        account.esdt.set_frozen(&token_identifier, frozen);
+    });
+
+    let tx_result = TxResult {
+        result_logs: vec![freeze_log(
+            &tx_input,
+            &token_identifier,
+            0,
+            &address,
+            frozen,
+        )],
+        ..Default::default()
+    };
+
+    (tx_result, tx_cache.into_blockchain_updates())
+}
+
+fn toggle_freeze_single_nft(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    frozen: bool,
+) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() < 3 {
+        return (
+            TxResult::from_vm_error(format!("{} too few arguments", tx_input.func_name.as_str())),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    let nonce = top_decode_u64(tx_input.args[1].as_slice());
+    let address = match parse_address(&tx_input.args[2], INVALID_FREEZE_ADDRESS) {
+        Ok(address) => address,
+        Err(message) => return (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    };
+
+    if nonce == 0 {
+        return (
+            TxResult::from_vm_error("invalid nonce"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    if let Err(message) = check_freeze_allowed(&tx_input, &tx_cache, &token_identifier) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    tx_cache.with_account_mut(&address, |account| {
+        account
+            .esdt
+            .set_instance_frozen(&token_identifier, nonce, frozen);
+    });
+
+    let tx_result = TxResult {
+        result_logs: vec![freeze_log(
+            &tx_input,
+            &token_identifier,
+            nonce,
+            &address,
+            frozen,
+        )],
+        ..Default::default()
+    };
+
+    (tx_result, tx_cache.into_blockchain_updates())
+}
+
+fn wipe_nonce(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    token_identifier: Vec<u8>,
+    nonce: u64,
+    address: Address,
+) -> (TxResult, BlockchainUpdate) {
+    if let Err(message) = check_owner_and_property(
+        &tx_input,
+        &tx_cache,
+        &token_identifier,
+        |properties| properties.can_wipe,
+        "cannot wipe",
+    ) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    if !tx_cache.is_esdt_frozen(&address, &token_identifier, nonce) {
+        return (
+            TxResult::from_vm_error(vm_err_msg::CANNOT_WIPE_ACCOUNT_NOT_FROZEN),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let wiped_amount = tx_cache.with_account_mut(&address, |account| {
+        account.esdt.wipe(&token_identifier, nonce)
+    });
+    tx_cache.with_esdt_token_config_mut(&token_identifier, |config| {
+        config.num_wiped += 1;
+    });
+
+    let wipe_log = TxLog {
+        address: tx_input.to.clone(),
+        endpoint: ESDT_WIPE_LOG_IDENTIFIER.into(),
+        topics: vec![
+            token_identifier,
+            top_encode_u64(nonce),
+            top_encode_big_uint(&wiped_amount),
+            address.to_vec(),
+        ],
+        data: vec![],
+    };
+
+    let tx_result = TxResult {
+        result_logs: vec![wipe_log],
+        ..Default::default()
+    };
+
+    (tx_result, tx_cache.into_blockchain_updates())
+}
+
+fn check_freeze_allowed(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+) -> Result<(), &'static str> {
+    check_owner_and_property(
+        tx_input,
+        tx_cache,
+        token_identifier,
+        |properties| properties.can_freeze,
+        "cannot freeze",
+    )
+}
+
+/// Address arguments come from the transaction, they are not guaranteed to be well-formed.
+pub(super) fn parse_address(
+    arg: &[u8],
+    error_message: &'static str,
+) -> Result<Address, &'static str> {
+    if arg.len() != Address::len_bytes() {
+        return Err(error_message);
+    }
+    Ok(Address::from_slice(arg))
+}
+
+fn freeze_log(
+    tx_input: &TxInput,
+    token_identifier: &[u8],
+    nonce: u64,
+    address: &Address,
+    frozen: bool,
+) -> TxLog {
+    let endpoint = if frozen {
+        ESDT_FREEZE_LOG_IDENTIFIER
+    } else {
+        ESDT_UNFREEZE_LOG_IDENTIFIER
+    };
+
+    TxLog {
+        address: tx_input.to.clone(),
+        endpoint: endpoint.into(),
+        topics: vec![
+            token_identifier.to_vec(),
+            top_encode_u64(nonce),
+            Vec::new(), // value = 0
+            address.to_vec(),
+        ],
+        data: vec![],
+    }
+}
@@ -1,13 +1,13 @@
 use num_bigint::BigUint;
 
 use crate::{
+    blockchain::state::{AccountEsdt, EsdtTokenConfig, EsdtTokenProperties},
     crypto_functions::keccak256,
-    host::context::{BlockchainUpdate, TxCache, TxInput, TxResult},
-    types::{VMTokenType, top_decode_u64},
+    host::context::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
+    types::{VMTokenType, top_decode_u64, top_encode_u64},
 };
 
 /// Issues a new fungible token.
-#[allow(unused_variables)]
 pub fn issue(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 4 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
@@ -18,12 +18,37 @@ pub fn issue(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdat
     let total_supply = BigUint::from_bytes_be(tx_input.args[2].clone().as_ref());
     let decimals = top_decode_u64(tx_input.args[3].clone().as_ref()) as u32;
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::Fungible)
+    let mut properties = EsdtTokenProperties::default();
+    if let Err(message) = parse_token_properties(&mut properties, &tx_input.args[4..]) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    let config = EsdtTokenConfig {
+        token_name: name,
+        num_decimals: decimals,
+        minted_value: total_supply,
+        properties,
+        ..Default::default()
+    };
+
+    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::Fungible, config)
 }
 
 /// Issues a new semi-fungible token.
-#[allow(unused_variables)]
 pub fn issue_semi_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    issue_nft_collection(tx_input, tx_cache, VMTokenType::SemiFungible)
+}
+
+/// Issues a new non-fungible token.
+pub fn issue_non_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    issue_nft_collection(tx_input, tx_cache, VMTokenType::NonFungible)
+}
+
+fn issue_nft_collection(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    token_type: VMTokenType,
+) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 2 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
         return (tx_result, BlockchainUpdate::empty());
@@ -31,24 +56,46 @@ pub fn issue_semi_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, B
     let name = tx_input.args[0].clone();
     let ticker = tx_input.args[1].clone();
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::SemiFungible)
+    let mut properties = EsdtTokenProperties::default();
+    if let Err(message) = parse_token_properties(&mut properties, &tx_input.args[2..]) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    let config = EsdtTokenConfig {
+        token_name: name,
+        properties,
+        ..Default::default()
+    };
+
+    register_and_set_roles(tx_input, tx_cache, ticker, token_type, config)
 }
 
-/// Issues a new non-fungible token.
-#[allow(unused_variables)]
-pub fn issue_non_fungible(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
-    if tx_input.args.len() < 2 {
+/// Issues a new meta-ESDT token.
+pub fn register_meta_esdt(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() < 3 {
         let tx_result = TxResult::from_vm_error("not enough arguments");
         return (tx_result, BlockchainUpdate::empty());
     }
     let name = tx_input.args[0].clone();
     let ticker = tx_input.args[1].clone();
+    let decimals = top_decode_u64(tx_input.args[2].clone().as_ref()) as u32;
+
+    let mut properties = EsdtTokenProperties::default();
+    if let Err(message) = parse_token_properties(&mut properties, &tx_input.args[3..]) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    let config = EsdtTokenConfig {
+        token_name: name,
+        num_decimals: decimals,
+        properties,
+        ..Default::default()
+    };
 
-    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::NonFungible)
+    register_and_set_roles(tx_input, tx_cache, ticker, VMTokenType::Meta, config)
 }
 
 // Issues a new token and sets all roles for its type.
-#[allow(unused_variables)]
 pub fn register_and_set_all_roles(
     tx_input: TxInput,
     tx_cache: TxCache,
@@ -63,7 +110,37 @@ pub fn register_and_set_all_roles(
     let token_type = VMTokenType::from_system_sc_arg(&tx_input.args[2]);
     let decimals = top_decode_u64(tx_input.args[3].clone().as_ref()) as u32;
 
-    register_and_set_roles(tx_input, tx_cache, ticker, token_type)
+    let config = EsdtTokenConfig {
+        token_name: name,
+        num_decimals: decimals,
+        properties: EsdtTokenProperties::all(),
+        ..Default::default()
+    };
+
+    register_and_set_roles(tx_input, tx_cache, ticker, token_type, config)
+}
+
+/// Applies the optional `name@value` property pairs that can follow the issue arguments.
+pub(super) fn parse_token_properties(
+    properties: &mut EsdtTokenProperties,
+    args: &[Vec<u8>],
+) -> Result<(), &'static str> {
+    if args.len() % 2 != 0 {
+        return Err("invalid number of arguments");
+    }
+
+    for pair in args.chunks(2) {
+        let value = match pair[1].as_slice() {
+            b"true" => true,
+            b"false" => false,
+            _ => return Err("invalid argument"),
+        };
+        if !properties.set_by_name(&pair[0], value) {
+            return Err("invalid argument");
+        }
+    }
+
+    Ok(())
 }
 
 fn register_and_set_roles(
@@ -71,6 +148,7 @@ fn register_and_set_roles(
     tx_cache: TxCache,
     ticker: Vec<u8>,
     token_type: VMTokenType,
+    mut config: EsdtTokenConfig,
 ) -> (TxResult, BlockchainUpdate) {
     let mut new_token_identifiers = tx_cache.get_new_token_identifiers();
 
@@ -90,8 +168,33 @@ fn register_and_set_roles(
     });
     tx_cache.set_new_token_identifiers(new_token_identifiers);
 
+    config.owner = Some(tx_input.from.clone());
+    config.token_type = Some(token_type);
+    for role in AccountEsdt::get_all_roles_for_token_type(token_type) {
+        config.add_special_role(&tx_input.from, &role);
+    }
+
+    // same log as the protocol, also used to retrieve the new token identifier
+    let issue_log = TxLog {
+        address: tx_input.from.clone(),
+        endpoint: tx_input.func_name.clone(),
+        topics: vec![
+            token_identifier.clone(),
+            config.token_name.clone(),
+            ticker,
+            config.token_type_name().as_bytes().to_vec(),
+            top_encode_u64(config.num_decimals as u64),
+        ],
+        data: vec![],
+    };
+
+    tx_cache.with_esdt_token_config_mut(&token_identifier, |token_config| {
+        *token_config = config;
+    });
+
     let tx_result = TxResult {
         result_values: vec![token_identifier],
+        result_logs: vec![issue_log],
         ..Default::default()
     };
 
@@ -0,0 +1,73 @@
+use crate::host::context::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult};
+
+use super::system_sc_special_roles::check_owner_and_property;
+
+const ESDT_PAUSE_LOG_IDENTIFIER: &str = "ESDTPause";
+const ESDT_UNPAUSE_LOG_IDENTIFIER: &str = "ESDTUnPause";
+
+/// Suspends all transfers of a token.
+pub fn pause(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    toggle_pause(tx_input, tx_cache, true)
+}
+
+/// Resumes the transfers of a paused token.
+pub fn unpause(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    toggle_pause(tx_input, tx_cache, false)
+}
+
+fn toggle_pause(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    paused: bool,
+) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() != 1 {
+        return (
+            TxResult::from_vm_error("invalid number of arguments, wanted 1"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+
+    if let Err(message) = check_owner_and_property(
+        &tx_input,
+        &tx_cache,
+        &token_identifier,
+        |properties| properties.can_pause,
+        "cannot pause/un-pause",
+    ) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    if tx_cache.is_esdt_paused(&token_identifier) == paused {
+        let message = if paused {
+            "cannot pause an already paused contract"
+        } else {
+            "cannot unPause an already un-paused contract"
+        };
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    tx_cache.with_esdt_token_config_mut(&token_identifier, |config| {
+        config.paused = paused;
+    });
+
+    let endpoint = if paused {
+        ESDT_PAUSE_LOG_IDENTIFIER
+    } else {
+        ESDT_UNPAUSE_LOG_IDENTIFIER
+    };
+    let pause_log = TxLog {
+        address: tx_input.to.clone(),
+        endpoint: endpoint.into(),
+        topics: vec![token_identifier],
+        data: vec![],
+    };
+
+    let tx_result = TxResult {
+        result_logs: vec![pause_log],
+        ..Default::default()
+    };
+
+    (tx_result, tx_cache.into_blockchain_updates())
+}
@@ -0,0 +1,146 @@
+use num_bigint::BigUint;
+
+use crate::{
+    blockchain::state::EsdtTokenConfig,
+    host::context::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    types::{Address, top_encode_big_uint, top_encode_u64},
+};
+
+/// 0.05 EGLD, as on mainnet.
+const BASE_ISSUING_COST: u64 = 50_000_000_000_000_000;
+const MIN_TOKEN_NAME_LENGTH: u64 = 3;
+const MAX_TOKEN_NAME_LENGTH: u64 = 20;
+
+/// Lists the token data, in the same format as the protocol.
+pub fn get_token_properties(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let config = match load_issued_token(&tx_input, &tx_cache) {
+        Ok(config) => config,
+        Err(message) => return (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    };
+
+    let properties = &config.properties;
+    let result_values = vec![
+        config.token_name.clone(),
+        config.token_type_name().as_bytes().to_vec(),
+        config
+            .owner
+            .as_ref()
+            .map(Address::to_vec)
+            .unwrap_or_default(),
+        config.minted_value.to_string().into_bytes(),
+        config.burnt_value.to_string().into_bytes(),
+        format!("NumDecimals-{}", config.num_decimals).into_bytes(),
+        format!("IsPaused-{}", config.paused).into_bytes(),
+        format!("CanUpgrade-{}", properties.can_upgrade).into_bytes(),
+        format!("CanMint-{}", properties.can_mint).into_bytes(),
+        format!("CanBurn-{}", properties.can_burn).into_bytes(),
+        format!("CanChangeOwner-{}", properties.can_change_owner).into_bytes(),
+        format!("CanPause-{}", properties.can_pause).into_bytes(),
+        format!("CanFreeze-{}", properties.can_freeze).into_bytes(),
+        format!("CanWipe-{}", properties.can_wipe).into_bytes(),
+        format!("CanAddSpecialRoles-{}", properties.can_add_special_roles).into_bytes(),
+        format!(
+            "CanTransferNFTCreateRole-{}",
+            properties.can_transfer_nft_create_role
+        )
+        .into_bytes(),
+        format!("NFTCreateStopped-{}", config.nft_create_stopped).into_bytes(),
+        format!("NumWiped-{}", config.num_wiped).into_bytes(),
+    ];
+
+    let tx_result = TxResult {
+        result_values,
+        ..Default::default()
+    };
+    (tx_result, BlockchainUpdate::empty())
+}
+
+/// One `<bech32 address>:<role>,<role>,...` entry for each address holding roles.
+pub fn get_special_roles(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    let config = match load_issued_token(&tx_input, &tx_cache) {
+        Ok(config) => config,
+        Err(message) => return (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    };
+
+    let result_values = config
+        .special_roles
+        .iter()
+        .map(|holder| {
+            let roles: Vec<String> = holder
+                .roles
+                .iter()
+                .map(|role| String::from_utf8_lossy(role).into_owned())
+                .collect();
+            format!(
+                "{}:{}",
+                holder.address.to_bech32_default().to_bech32_str(),
+                roles.join(",")
+            )
+            .into_bytes()
+        })
+        .collect();
+
+    let tx_result = TxResult {
+        result_values,
+        ..Default::default()
+    };
+    (tx_result, BlockchainUpdate::empty())
+}
+
+/// Each address holding roles, followed by its roles, all flattened into the results.
+pub fn get_all_addresses_and_roles(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let config = match load_issued_token(&tx_input, &tx_cache) {
+        Ok(config) => config,
+        Err(message) => return (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    };
+
+    let mut result_values = Vec::new();
+    for holder in &config.special_roles {
+        result_values.push(holder.address.to_vec());
+        result_values.extend(holder.roles.iter().cloned());
+    }
+
+    let tx_result = TxResult {
+        result_values,
+        ..Default::default()
+    };
+    (tx_result, BlockchainUpdate::empty())
+}
+
+/// The ESDT system SC has no owner in the VM, so the zero address is returned in its place.
+pub fn get_contract_config(tx_input: TxInput, _tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if !tx_input.args.is_empty() {
+        return (
+            TxResult::from_vm_error("invalid number of arguments, wanted 0"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let tx_result = TxResult {
+        result_values: vec![
+            Address::zero().to_vec(),
+            top_encode_big_uint(&BigUint::from(BASE_ISSUING_COST)),
+            top_encode_u64(MIN_TOKEN_NAME_LENGTH),
+            top_encode_u64(MAX_TOKEN_NAME_LENGTH),
+        ],
+        ..Default::default()
+    };
+    (tx_result, BlockchainUpdate::empty())
+}
+
+fn load_issued_token(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+) -> Result<EsdtTokenConfig, &'static str> {
+    if tx_input.args.len() != 1 {
+        return Err("invalid number of arguments, wanted 1");
+    }
+
+    tx_cache
+        .get_esdt_token_config(&tx_input.args[0])
+        .filter(|config| config.owner.is_some())
+        .ok_or("no ticker with given name")
+}
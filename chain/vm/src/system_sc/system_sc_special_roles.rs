@@ -1,8 +1,14 @@
 use crate::{
+    blockchain::state::EsdtTokenProperties,
     host::context::{BlockchainUpdate, TxCache, TxInput, TxResult},
-    types::Address,
+    types::{Address, EsdtLocalRole},
 };
 
+use super::system_sc_freeze_wipe::parse_address;
+
+const INVALID_ROLE_ADDRESS: &str = "invalid address to set/unset special role";
+const INVALID_CREATOR_ADDRESS: &str = "invalid address to transfer NFT create role";
+
 pub fn set_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     if tx_input.args.len() < 3 {
         return (
@@ -12,12 +18,342 @@ pub fn set_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Bloc
     }
 
     let token_identifier = tx_input.args[0].clone();
-    let address = Address::from_slice(tx_input.args[1].as_slice());
-    let role = tx_input.args[2].clone();
+    let address = match parse_address(&tx_input.args[1], INVALID_ROLE_ADDRESS) {
+        Ok(address) => address,
+        Err(message) => return (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    };
+    let roles = &tx_input.args[2..];
+
+    if let Err(message) = check_owner(&tx_input, &tx_cache, &token_identifier) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    let nft_create_role = EsdtLocalRole::NftCreate.as_role_name();
+    if roles.iter().any(|role| role.as_slice() == nft_create_role) {
+        if let Err(message) =
+            check_nft_create_role_available(&tx_cache, &token_identifier, &address)
+        {
+            return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+        }
+    }
+
+    tx_cache.with_account_mut(&address, |account| {
+        for role in roles {
+            account.esdt.set_special_role(&token_identifier, role);
+        }
+    });
+    tx_cache.with_esdt_token_config_mut(&token_identifier, |config| {
+        for role in roles {
+            config.add_special_role(&address, role);
+        }
+    });
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+pub fn unset_special_role(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() < 3 {
+        return (
+            TxResult::from_vm_error("unSetSpecialRole too few arguments"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    let address = match parse_address(&tx_input.args[1], INVALID_ROLE_ADDRESS) {
+        Ok(address) => address,
+        Err(message) => return (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    };
+    let roles = &tx_input.args[2..];
+
+    if let Err(message) = check_owner(&tx_input, &tx_cache, &token_identifier) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    let all_roles_set = tx_cache.with_account_mut(&address, |account| {
+        roles
+            .iter()
+            .all(|role| account.esdt.has_special_role(&token_identifier, role))
+    });
+    if !all_roles_set {
+        return (
+            TxResult::from_vm_error("special role not set for address"),
+            BlockchainUpdate::empty(),
+        );
+    }
 
     tx_cache.with_account_mut(&address, |account| {
-        account.esdt.set_special_role(&token_identifier, &role);
+        for role in roles {
+            account.esdt.unset_special_role(&token_identifier, role);
+        }
+    });
+    tx_cache.with_esdt_token_config_mut(&token_identifier, |config| {
+        for role in roles {
+            config.remove_special_role(&address, role);
+        }
+    });
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+/// Moves the NFT create role, together with the last created nonce, to another address.
+pub fn transfer_nft_create_role(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() != 3 {
+        return (
+            TxResult::from_vm_error("invalid number of arguments, wanted 3"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    let old_creator = match parse_address(&tx_input.args[1], INVALID_CREATOR_ADDRESS) {
+        Ok(address) => address,
+        Err(message) => return (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    };
+    let new_creator = match parse_address(&tx_input.args[2], INVALID_CREATOR_ADDRESS) {
+        Ok(address) => address,
+        Err(message) => return (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    };
+    let nft_create_role = EsdtLocalRole::NftCreate.as_role_name();
+
+    if let Err(message) = check_owner(&tx_input, &tx_cache, &token_identifier) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+    if tx_cache
+        .get_esdt_token_config(&token_identifier)
+        .is_some_and(|config| !config.properties.can_transfer_nft_create_role)
+    {
+        return (
+            TxResult::from_vm_error("NFT create role transfer is not allowed"),
+            BlockchainUpdate::empty(),
+        );
+    }
+    if old_creator == new_creator {
+        return (
+            TxResult::from_vm_error("invalid argument, wanted different addresses"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let last_nonce = tx_cache.with_account_mut(&old_creator, |account| {
+        if !account
+            .esdt
+            .unset_special_role(&token_identifier, nft_create_role)
+        {
+            return None;
+        }
+        account
+            .esdt
+            .get_mut_by_identifier(&token_identifier)
+            .map(|esdt_data| std::mem::take(&mut esdt_data.last_nonce))
+    });
+    let Some(last_nonce) = last_nonce else {
+        return (
+            TxResult::from_vm_error("old address does not have NFT create role"),
+            BlockchainUpdate::empty(),
+        );
+    };
+
+    tx_cache.with_account_mut(&new_creator, |account| {
+        account
+            .esdt
+            .set_special_role(&token_identifier, nft_create_role);
+        if let Some(esdt_data) = account.esdt.get_mut_by_identifier(&token_identifier) {
+            esdt_data.last_nonce = esdt_data.last_nonce.max(last_nonce);
+        }
+    });
+    tx_cache.with_esdt_token_config_mut(&token_identifier, |config| {
+        config.remove_special_role(&old_creator, nft_create_role);
+        config.add_special_role(&new_creator, nft_create_role);
     });
 
     (TxResult::empty(), tx_cache.into_blockchain_updates())
 }
+
+/// Permanently disables NFT creation, by removing the NFT create role from all holders.
+pub fn stop_nft_create(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() != 1 {
+        return (
+            TxResult::from_vm_error("invalid number of arguments, wanted 1"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    let nft_create_role = EsdtLocalRole::NftCreate.as_role_name();
+
+    if let Err(message) = check_owner(&tx_input, &tx_cache, &token_identifier) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    let holders = tx_cache.with_esdt_token_config_mut(&token_identifier, |config| {
+        if config.nft_create_stopped {
+            return None;
+        }
+        config.nft_create_stopped = true;
+        let holders = config.addresses_with_role(nft_create_role);
+        for holder in &holders {
+            config.remove_special_role(holder, nft_create_role);
+        }
+        Some(holders)
+    });
+    let Some(holders) = holders else {
+        return (
+            TxResult::from_vm_error("NFT create was already stopped"),
+            BlockchainUpdate::empty(),
+        );
+    };
+
+    for holder in &holders {
+        tx_cache.with_account_mut(holder, |account| {
+            account
+                .esdt
+                .unset_special_role(&token_identifier, nft_create_role);
+        });
+    }
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+/// Allows all holders to burn the token, without a burn role.
+pub fn set_burn_role_globally(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    toggle_burn_role_globally(tx_input, tx_cache, true)
+}
+
+/// Reverts to only allowing burn role holders to burn the token.
+pub fn unset_burn_role_globally(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    toggle_burn_role_globally(tx_input, tx_cache, false)
+}
+
+/// Allows multiple NFT create role holders, one per shard on the real chain.
+pub fn change_to_multi_shard_create(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() != 1 {
+        return (
+            TxResult::from_vm_error("invalid number of arguments, wanted 1"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+
+    if let Err(message) = check_owner(&tx_input, &tx_cache, &token_identifier) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    let changed = tx_cache.with_esdt_token_config_mut(&token_identifier, |config| {
+        let already_set = config.properties.can_create_multi_shard;
+        config.properties.can_create_multi_shard = true;
+        !already_set
+    });
+    if !changed {
+        return (
+            TxResult::from_vm_error("token is already multi shard create"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+fn toggle_burn_role_globally(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    burn_role_global: bool,
+) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() != 1 {
+        return (
+            TxResult::from_vm_error("invalid number of arguments, wanted 1"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+
+    if let Err(message) = check_owner(&tx_input, &tx_cache, &token_identifier) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    let changed = tx_cache.with_esdt_token_config_mut(&token_identifier, |config| {
+        let changed = config.burn_role_global != burn_role_global;
+        config.burn_role_global = burn_role_global;
+        changed
+    });
+    if !changed {
+        let message = if burn_role_global {
+            "cannot set burn role globally as it was already set"
+        } else {
+            "cannot unset burn role globally as it was not set"
+        };
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+/// There can only be one NFT create role holder, unless the token was changed to multi shard create.
+fn check_nft_create_role_available(
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+    address: &Address,
+) -> Result<(), &'static str> {
+    let Some(config) = tx_cache.get_esdt_token_config(token_identifier) else {
+        return Ok(());
+    };
+
+    if config.nft_create_stopped {
+        return Err("cannot add NFT create role as NFT creation was stopped");
+    }
+
+    let holders = config.addresses_with_role(EsdtLocalRole::NftCreate.as_role_name());
+    if !config.properties.can_create_multi_shard && holders.iter().any(|holder| holder != address) {
+        return Err("NFT create role already exists");
+    }
+
+    Ok(())
+}
+
+/// Only tokens issued via the system SC have a known owner, all others are not checked.
+pub(super) fn check_owner(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+) -> Result<(), &'static str> {
+    let owner = tx_cache
+        .get_esdt_token_config(token_identifier)
+        .and_then(|config| config.owner);
+    match owner {
+        Some(owner) if owner != tx_input.from => Err("can be called by owner only"),
+        _ => Ok(()),
+    }
+}
+
+/// Besides the owner, also checks the property that allows the operation, e.g. `canFreeze`.
+///
+/// Only tokens issued via the system SC have known properties, all others are not checked.
+pub(super) fn check_owner_and_property(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+    property: fn(&EsdtTokenProperties) -> bool,
+    error_message: &'static str,
+) -> Result<(), &'static str> {
+    check_owner(tx_input, tx_cache, token_identifier)?;
+    match tx_cache.get_esdt_token_config(token_identifier) {
+        Some(config) if config.owner.is_some() && !property(&config.properties) => {
+            Err(error_message)
+        }
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{
+    blockchain::state::EsdtTokenConfig,
+    host::context::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    types::{VMTokenType, top_decode_u64},
+};
+
+use super::{
+    system_sc_freeze_wipe::parse_address, system_sc_issue::parse_token_properties,
+    system_sc_special_roles::check_owner,
+};
+
+/// Converts a semi-fungible token to a meta-ESDT, with the given number of decimals.
+pub fn change_sft_to_meta_esdt(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() != 2 {
+        return (
+            TxResult::from_vm_error("invalid number of arguments, wanted 2"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    let num_decimals = top_decode_u64(tx_input.args[1].as_slice()) as u32;
+
+    let config = match load_owned_token(&tx_input, &tx_cache, &token_identifier) {
+        Ok(config) => config,
+        Err(message) => return (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    };
+    if config.token_type != Some(VMTokenType::SemiFungible) {
+        return (
+            TxResult::from_vm_error("change can happen to semi fungible tokens only"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    tx_cache.with_esdt_token_config_mut(&token_identifier, |config| {
+        config.token_type = Some(VMTokenType::Meta);
+        config.num_decimals = num_decimals;
+    });
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+/// Hands the token management over to another address. Roles are not affected.
+pub fn transfer_ownership(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() != 2 {
+        return (
+            TxResult::from_vm_error("invalid number of arguments, wanted 2"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    let new_owner = match parse_address(&tx_input.args[1], "invalid new owner address") {
+        Ok(address) => address,
+        Err(message) => return (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    };
+
+    let config = match load_owned_token(&tx_input, &tx_cache, &token_identifier) {
+        Ok(config) => config,
+        Err(message) => return (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    };
+    if !config.properties.can_change_owner {
+        return (
+            TxResult::from_vm_error("cannot change owner of the token"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    tx_cache.with_esdt_token_config_mut(&token_identifier, |config| {
+        config.owner = Some(new_owner);
+    });
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+/// Changes the token properties, with the same `name@value` pairs as at issue.
+pub fn control_changes(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    if tx_input.args.len() < 3 {
+        return (
+            TxResult::from_vm_error("not enough arguments"),
+            BlockchainUpdate::empty(),
+        );
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+
+    let mut config = match load_owned_token(&tx_input, &tx_cache, &token_identifier) {
+        Ok(config) => config,
+        Err(message) => return (TxResult::from_vm_error(message), BlockchainUpdate::empty()),
+    };
+    if !config.properties.can_upgrade {
+        return (
+            TxResult::from_vm_error("token is not upgradable"),
+            BlockchainUpdate::empty(),
+        );
+    }
+    if let Err(message) = parse_token_properties(&mut config.properties, &tx_input.args[1..]) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    tx_cache.with_esdt_token_config_mut(&token_identifier, |token_config| {
+        *token_config = config;
+    });
+
+    (TxResult::empty(), tx_cache.into_blockchain_updates())
+}
+
+fn load_owned_token(
+    tx_input: &TxInput,
+    tx_cache: &TxCache,
+    token_identifier: &[u8],
+) -> Result<EsdtTokenConfig, &'static str> {
+    let config = tx_cache
+        .get_esdt_token_config(token_identifier)
+        .filter(|config| config.owner.is_some())
+        .ok_or("no ticker with given name")?;
+    check_owner(tx_input, tx_cache, token_identifier)?;
+    Ok(config)
+}
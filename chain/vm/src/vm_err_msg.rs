@@ -15,6 +15,7 @@ pub const NUMBER_IS_NOT_NORMAL: &str =
 pub const CANNOT_COMPARE_VALUES: &str = "values are not comparable";
 pub const WRITE_RESERVED: &str = "cannot write to storage under reserved key";
 pub const WRITE_READONLY: &str = "cannot write on read only mode";
+pub const CALL_READONLY: &str = "operation not permitted in read only mode";
 
 pub const ERROR_SIGNALLED_BY_SMARTCONTRACT: &str = "error signalled by smartcontract";
 
@@ -29,4 +30,19 @@ pub const PROMISES_TOKENIZE_FAILED: &str = "tokenize failed";
 pub const CRYPTO_INVALID_SIGNATURE: &str = "invalid signature";
 pub const CRYPTO_ED25519_ERROR: &str = "ed25519 verify error";
 
+/// Also used for secp256r1, to match the Go VM.
+pub const CRYPTO_SECP256K1_ERROR: &str = "secp256k1 verify error";
+
+pub const EC_CURVE_NOT_SUPPORTED: &str = "elliptic curve not supported";
+pub const EC_POINT_NOT_ON_CURVE: &str = "point is not on curve";
+pub const EC_BUFFER_LENGTH_INCORRECT: &str = "length of buffer is not correct";
+
+pub const VM_HOOK_DEACTIVATED: &str = "VM hook is deactivated";
+
 pub const MULTIPLICATION_OVERFLOW: &str = "multiplication overflow";
+
+pub const ESDT_IS_FROZEN_FOR_ACCOUNT: &str = "ESDT is frozen for this account";
+pub const ESDT_TOKEN_IS_PAUSED: &str = "esdt token is paused";
+pub const CANNOT_WIPE_ACCOUNT_NOT_FROZEN: &str =
+    "cannot wipe because the account is not frozen for this esdt token";
+pub const ACTION_NOT_ALLOWED: &str = "action is not allowed";
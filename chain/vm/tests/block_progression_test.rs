@@ -0,0 +1,112 @@
+use multiversx_chain_vm::{
+    blockchain::state::{BlockConfig, DEFAULT_EPOCH_LENGTH_ROUNDS},
+    chain_core::types::{DurationMillis, TimestampMillis},
+};
+
+#[test]
+fn advance_block_moves_all_fields() {
+    let mut block_config = BlockConfig::default();
+    block_config.advance_block();
+
+    let previous = &block_config.previous_block_info;
+    let current = &block_config.current_block_info;
+    assert_eq!(previous.block_nonce, 0);
+    assert_eq!(current.block_nonce, 1);
+    assert_eq!(current.block_round, 1);
+    assert_eq!(current.block_epoch, 0);
+    assert_eq!(current.block_timestamp_millis, TimestampMillis::new(6000));
+    assert_ne!(current.block_random_seed, previous.block_random_seed);
+}
+
+#[test]
+fn advance_blocks_is_deterministic() {
+    let mut first = BlockConfig::default();
+    let mut second = BlockConfig::default();
+    first.advance_blocks(10);
+    for _ in 0..10 {
+        second.advance_block();
+    }
+
+    assert_eq!(first.current_block_info.block_nonce, 10);
+    assert_eq!(
+        first.current_block_info.block_random_seed,
+        second.current_block_info.block_random_seed
+    );
+    assert_eq!(
+        first.previous_block_info.block_random_seed,
+        second.previous_block_info.block_random_seed
+    );
+}
+
+#[test]
+fn advance_blocks_changes_epoch() {
+    assert_eq!(
+        BlockConfig::default().epoch_length_rounds,
+        DEFAULT_EPOCH_LENGTH_ROUNDS
+    );
+    let mut block_config = BlockConfig {
+        epoch_length_rounds: 10,
+        ..Default::default()
+    };
+
+    block_config.advance_blocks(9);
+    assert_eq!(block_config.current_block_info.block_epoch, 0);
+    assert_eq!(block_config.epoch_start_block_info.block_round, 0);
+
+    block_config.advance_block();
+    assert_eq!(block_config.current_block_info.block_epoch, 1);
+    assert_eq!(block_config.epoch_start_block_info.block_round, 10);
+    assert_eq!(block_config.epoch_start_block_info.block_nonce, 10);
+
+    block_config.advance_blocks(25);
+    assert_eq!(block_config.current_block_info.block_round, 35);
+    assert_eq!(block_config.current_block_info.block_epoch, 3);
+    assert_eq!(block_config.epoch_start_block_info.block_round, 30);
+}
+
+#[test]
+fn advance_time_rounds_up() {
+    let mut block_config = BlockConfig {
+        block_round_time_ms: 600,
+        ..Default::default()
+    };
+
+    assert_eq!(block_config.rounds_for_duration(DurationMillis::new(0)), 0);
+    assert_eq!(
+        block_config.rounds_for_duration(DurationMillis::new(600)),
+        1
+    );
+    assert_eq!(
+        block_config.rounds_for_duration(DurationMillis::new(601)),
+        2
+    );
+
+    block_config.advance_time(DurationMillis::new(60_000));
+    assert_eq!(block_config.current_block_info.block_nonce, 100);
+    assert_eq!(
+        block_config.current_block_info.block_timestamp_millis,
+        TimestampMillis::new(60_000)
+    );
+}
+
+#[test]
+fn advance_blocks_keeps_block_hashes() {
+    let mut block_config = BlockConfig::default();
+    assert_eq!(block_config.block_hash(0), None);
+
+    block_config.advance_blocks(3);
+    let hash_1 = block_config.block_hash(1).unwrap().to_vec();
+    let hash_3 = block_config.block_hash(3).unwrap().to_vec();
+    assert_eq!(hash_3.len(), 32);
+    assert_ne!(hash_1, hash_3);
+
+    // the genesis block was not produced, so its hash is unknown
+    assert_eq!(block_config.block_hash(0), None);
+    assert_eq!(block_config.block_hash(4), None);
+
+    // same history, same hashes
+    let mut other = BlockConfig::default();
+    other.advance_blocks(5);
+    assert_eq!(other.block_hash(1), Some(hash_1.as_slice()));
+    assert_eq!(other.block_hash(3), Some(hash_3.as_slice()));
+}
@@ -0,0 +1,59 @@
+use multiversx_chain_vm::{
+    blockchain::state::{AccountData, BlockchainState},
+    types::Address,
+};
+use num_bigint::BigUint;
+
+fn account(byte: u8) -> AccountData {
+    AccountData::new_empty(Address::from([byte; 32]))
+}
+
+#[test]
+fn state_root_hash_ignores_insertion_order() {
+    let mut first = BlockchainState::default();
+    first.add_account(account(1));
+    first.add_account(account(2));
+
+    let mut second = BlockchainState::default();
+    second.add_account(account(2));
+    second.add_account(account(1));
+
+    assert_eq!(first.state_root_hash(), second.state_root_hash());
+    assert_ne!(
+        first.state_root_hash(),
+        BlockchainState::default().state_root_hash()
+    );
+}
+
+#[test]
+fn state_root_hash_covers_balance_and_storage() {
+    let mut state = BlockchainState::default();
+    state.add_account(account(1));
+    let initial = state.state_root_hash();
+
+    let mut with_balance = account(1);
+    with_balance.egld_balance = BigUint::from(100u32);
+    state.add_account(with_balance);
+    let after_balance = state.state_root_hash();
+    assert_ne!(initial, after_balance);
+
+    let mut with_storage = account(1);
+    with_storage.egld_balance = BigUint::from(100u32);
+    with_storage
+        .storage
+        .insert(b"key".to_vec(), b"value".to_vec());
+    state.add_account(with_storage);
+    assert_ne!(after_balance, state.state_root_hash());
+}
+
+#[test]
+fn state_root_hash_ignores_cleared_storage() {
+    let mut state = BlockchainState::default();
+    state.add_account(account(1));
+    let initial = state.state_root_hash();
+
+    let mut with_cleared_key = account(1);
+    with_cleared_key.storage.insert(b"key".to_vec(), Vec::new());
+    state.add_account(with_cleared_key);
+    assert_eq!(initial, state.state_root_hash());
+}
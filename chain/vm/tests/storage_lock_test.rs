@@ -0,0 +1,49 @@
+use multiversx_chain_vm::{
+    blockchain::state::{AccountData, BlockchainState, storage_lock_key},
+    chain_core::types::TimestampMillis,
+    types::Address,
+};
+
+const KEY: &[u8] = b"locked-key";
+
+fn contract() -> Address {
+    Address::from([5u8; 32])
+}
+
+fn set_block_timestamp_seconds(state: &mut BlockchainState, seconds: u64) {
+    state.block_config.current_block_info.block_timestamp_millis =
+        TimestampMillis::new(seconds * 1000);
+}
+
+#[test]
+fn storage_lock_expires_with_block_timestamp() {
+    let mut state = BlockchainState::default();
+    state.add_account(AccountData::new_empty(contract()));
+    assert_eq!(state.get_storage_lock(&contract(), KEY), 0);
+    assert!(!state.is_storage_locked(&contract(), KEY));
+
+    state.set_storage_lock(&contract(), KEY, 1000);
+    assert_eq!(state.get_storage_lock(&contract(), KEY), 1000);
+
+    set_block_timestamp_seconds(&mut state, 999);
+    assert!(state.is_storage_locked(&contract(), KEY));
+
+    set_block_timestamp_seconds(&mut state, 1000);
+    assert!(!state.is_storage_locked(&contract(), KEY));
+}
+
+#[test]
+fn storage_lock_kept_under_protected_key() {
+    let mut state = BlockchainState::default();
+    state.add_account(AccountData::new_empty(contract()));
+    state.set_storage_lock(&contract(), KEY, 0x0102);
+
+    let storage = &state.accounts[&contract()].storage;
+    assert_eq!(storage_lock_key(KEY), b"ELRONDtimelocklocked-key".to_vec());
+    assert_eq!(storage.get(&storage_lock_key(KEY)), Some(&vec![0x01, 0x02]));
+    assert!(!storage.contains_key(KEY));
+
+    // clearing the lock
+    state.set_storage_lock(&contract(), KEY, 0);
+    assert_eq!(state.get_storage_lock(&contract(), KEY), 0);
+}
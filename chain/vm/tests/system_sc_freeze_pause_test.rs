@@ -0,0 +1,287 @@
+use std::sync::Arc;
+
+use multiversx_chain_vm::{
+    blockchain::state::{
+        AccountData, BlockchainState, EsdtInstanceMetadata, EsdtTokenConfig, EsdtTokenProperties,
+    },
+    host::context::{TxCache, TxInput, TxResult},
+    system_sc::{ESDT_SYSTEM_SC_ADDRESS, execute_system_sc},
+    types::Address,
+};
+use num_bigint::BigUint;
+
+const TOKEN_ID: &[u8] = b"FREEZE-123456";
+const NFT_ID: &[u8] = b"FRZNFT-123456";
+
+fn owner() -> Address {
+    Address::from([1u8; 32])
+}
+
+fn holder() -> Address {
+    Address::from([2u8; 32])
+}
+
+fn setup_state() -> BlockchainState {
+    let mut state = BlockchainState::default();
+    state.add_account(AccountData::new_empty(owner()));
+    let mut holder_account = AccountData::new_empty(holder());
+    holder_account.esdt.set_esdt_balance(
+        TOKEN_ID.to_vec(),
+        0,
+        &BigUint::from(1000u32),
+        EsdtInstanceMetadata::default(),
+    );
+    holder_account.esdt.set_esdt_balance(
+        NFT_ID.to_vec(),
+        1,
+        &BigUint::from(1u32),
+        EsdtInstanceMetadata::default(),
+    );
+    holder_account.esdt.set_esdt_balance(
+        NFT_ID.to_vec(),
+        2,
+        &BigUint::from(1u32),
+        EsdtInstanceMetadata::default(),
+    );
+    state.add_account(holder_account);
+    state
+}
+
+fn system_sc_call(state: &mut BlockchainState, func_name: &str, args: Vec<Vec<u8>>) -> TxResult {
+    let tx_input = TxInput {
+        from: owner(),
+        to: ESDT_SYSTEM_SC_ADDRESS,
+        func_name: func_name.into(),
+        args,
+        ..Default::default()
+    };
+    let tx_cache = TxCache::new(Arc::new(state.clone()));
+    let (tx_result, blockchain_updates) = execute_system_sc(tx_input, tx_cache);
+    if tx_result.result_status.is_success() {
+        state.commit_updates(blockchain_updates);
+    }
+    tx_result
+}
+
+fn check_transfer(
+    state: &BlockchainState,
+    token_identifier: &[u8],
+    nonce: u64,
+) -> Result<(), String> {
+    let tx_cache = TxCache::new(Arc::new(state.clone()));
+    tx_cache
+        .check_esdt_transfer_allowed(&holder(), &owner(), token_identifier, nonce)
+        .map_err(|err| err.message)
+}
+
+#[test]
+fn system_sc_freeze_unfreeze_test() {
+    let mut state = setup_state();
+    assert!(check_transfer(&state, TOKEN_ID, 0).is_ok());
+
+    let result = system_sc_call(
+        &mut state,
+        "freeze",
+        vec![TOKEN_ID.to_vec(), holder().to_vec()],
+    );
+    assert!(result.result_status.is_success());
+    assert_eq!(result.result_logs[0].endpoint.as_str(), "ESDTFreeze");
+    assert!(state.accounts[&holder()].esdt.is_frozen(TOKEN_ID, 0));
+    assert_eq!(
+        check_transfer(&state, TOKEN_ID, 0),
+        Err("ESDT is frozen for this account".to_string())
+    );
+
+    let result = system_sc_call(
+        &mut state,
+        "unFreeze",
+        vec![TOKEN_ID.to_vec(), holder().to_vec()],
+    );
+    assert!(result.result_status.is_success());
+    assert!(!state.accounts[&holder()].esdt.is_frozen(TOKEN_ID, 0));
+    assert!(check_transfer(&state, TOKEN_ID, 0).is_ok());
+}
+
+#[test]
+fn system_sc_wipe_test() {
+    let mut state = setup_state();
+
+    let result = system_sc_call(
+        &mut state,
+        "wipe",
+        vec![TOKEN_ID.to_vec(), holder().to_vec()],
+    );
+    assert_eq!(
+        result.result_message,
+        "cannot wipe because the account is not frozen for this esdt token"
+    );
+
+    let _ = system_sc_call(
+        &mut state,
+        "freeze",
+        vec![TOKEN_ID.to_vec(), holder().to_vec()],
+    );
+    let result = system_sc_call(
+        &mut state,
+        "wipe",
+        vec![TOKEN_ID.to_vec(), holder().to_vec()],
+    );
+    assert!(result.result_status.is_success());
+    assert_eq!(
+        state.accounts[&holder()].esdt.get_esdt_balance(TOKEN_ID, 0),
+        BigUint::from(0u32)
+    );
+}
+
+#[test]
+fn system_sc_freeze_single_nft_test() {
+    let mut state = setup_state();
+
+    let result = system_sc_call(
+        &mut state,
+        "freezeSingleNFT",
+        vec![NFT_ID.to_vec(), vec![1], holder().to_vec()],
+    );
+    assert!(result.result_status.is_success());
+    assert!(check_transfer(&state, NFT_ID, 1).is_err());
+    assert!(check_transfer(&state, NFT_ID, 2).is_ok());
+
+    let result = system_sc_call(
+        &mut state,
+        "wipeSingleNFT",
+        vec![NFT_ID.to_vec(), vec![2], holder().to_vec()],
+    );
+    assert!(!result.result_status.is_success());
+
+    let result = system_sc_call(
+        &mut state,
+        "wipeSingleNFT",
+        vec![NFT_ID.to_vec(), vec![1], holder().to_vec()],
+    );
+    assert!(result.result_status.is_success());
+    assert_eq!(
+        state.accounts[&holder()].esdt.get_esdt_balance(NFT_ID, 1),
+        BigUint::from(0u32)
+    );
+
+    let result = system_sc_call(
+        &mut state,
+        "unFreezeSingleNFT",
+        vec![NFT_ID.to_vec(), vec![1], holder().to_vec()],
+    );
+    assert!(result.result_status.is_success());
+    assert!(check_transfer(&state, NFT_ID, 1).is_ok());
+}
+
+#[test]
+fn system_sc_pause_unpause_test() {
+    let mut state = setup_state();
+
+    let result = system_sc_call(&mut state, "unPause", vec![TOKEN_ID.to_vec()]);
+    assert_eq!(
+        result.result_message,
+        "cannot unPause an already un-paused contract"
+    );
+
+    let result = system_sc_call(&mut state, "pause", vec![TOKEN_ID.to_vec()]);
+    assert!(result.result_status.is_success());
+    assert!(state.esdt_tokens[TOKEN_ID].paused);
+    assert_eq!(
+        check_transfer(&state, TOKEN_ID, 0),
+        Err("esdt token is paused".to_string())
+    );
+
+    let result = system_sc_call(&mut state, "pause", vec![TOKEN_ID.to_vec()]);
+    assert_eq!(
+        result.result_message,
+        "cannot pause an already paused contract"
+    );
+
+    let result = system_sc_call(&mut state, "unPause", vec![TOKEN_ID.to_vec()]);
+    assert!(result.result_status.is_success());
+    assert!(check_transfer(&state, TOKEN_ID, 0).is_ok());
+}
+
+/// As if the token had been issued via the system SC.
+fn set_issued_token(state: &mut BlockchainState, owner: Address, properties: EsdtTokenProperties) {
+    state.esdt_tokens.insert(
+        TOKEN_ID.to_vec(),
+        EsdtTokenConfig {
+            owner: Some(owner),
+            properties,
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn system_sc_freeze_owner_only_test() {
+    let mut state = setup_state();
+    set_issued_token(&mut state, holder(), EsdtTokenProperties::all());
+
+    for (func_name, args) in [
+        ("freeze", vec![TOKEN_ID.to_vec(), holder().to_vec()]),
+        ("wipe", vec![TOKEN_ID.to_vec(), holder().to_vec()]),
+        ("pause", vec![TOKEN_ID.to_vec()]),
+    ] {
+        let result = system_sc_call(&mut state, func_name, args);
+        assert_eq!(result.result_message, "can be called by owner only");
+    }
+}
+
+#[test]
+fn system_sc_freeze_properties_test() {
+    let mut state = setup_state();
+    set_issued_token(&mut state, owner(), EsdtTokenProperties::default());
+
+    let result = system_sc_call(
+        &mut state,
+        "freeze",
+        vec![TOKEN_ID.to_vec(), holder().to_vec()],
+    );
+    assert_eq!(result.result_message, "cannot freeze");
+
+    let result = system_sc_call(
+        &mut state,
+        "wipe",
+        vec![TOKEN_ID.to_vec(), holder().to_vec()],
+    );
+    assert_eq!(result.result_message, "cannot wipe");
+
+    let result = system_sc_call(&mut state, "pause", vec![TOKEN_ID.to_vec()]);
+    assert_eq!(result.result_message, "cannot pause/un-pause");
+
+    set_issued_token(&mut state, owner(), EsdtTokenProperties::all());
+    let result = system_sc_call(
+        &mut state,
+        "freeze",
+        vec![TOKEN_ID.to_vec(), holder().to_vec()],
+    );
+    assert!(result.result_status.is_success());
+}
+
+#[test]
+fn system_sc_freeze_invalid_address_test() {
+    let mut state = setup_state();
+
+    let result = system_sc_call(
+        &mut state,
+        "freeze",
+        vec![TOKEN_ID.to_vec(), b"holder".to_vec()],
+    );
+    assert_eq!(result.result_message, "invalid address to freeze/unfreeze");
+
+    let result = system_sc_call(
+        &mut state,
+        "freezeSingleNFT",
+        vec![NFT_ID.to_vec(), vec![1], Vec::new()],
+    );
+    assert_eq!(result.result_message, "invalid address to freeze/unfreeze");
+
+    let result = system_sc_call(
+        &mut state,
+        "wipe",
+        vec![TOKEN_ID.to_vec(), b"holder".to_vec()],
+    );
+    assert_eq!(result.result_message, "invalid address to wipe");
+}
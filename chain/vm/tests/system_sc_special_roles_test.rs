@@ -0,0 +1,259 @@
+use std::sync::Arc;
+
+use multiversx_chain_vm::{
+    blockchain::state::{AccountData, BlockchainState},
+    host::context::{TxCache, TxInput, TxResult},
+    system_sc::{ESDT_SYSTEM_SC_ADDRESS, execute_system_sc},
+    types::{Address, EsdtLocalRole},
+};
+
+const NFT_CREATE: &[u8] = b"ESDTRoleNFTCreate";
+const LOCAL_BURN: &[u8] = b"ESDTRoleLocalBurn";
+
+fn owner() -> Address {
+    Address::from([1u8; 32])
+}
+
+fn minter() -> Address {
+    Address::from([2u8; 32])
+}
+
+fn setup_state() -> BlockchainState {
+    let mut state = BlockchainState::default();
+    state.add_account(AccountData::new_empty(owner()));
+    state.add_account(AccountData::new_empty(minter()));
+    state
+}
+
+fn system_sc_call(state: &mut BlockchainState, func_name: &str, args: Vec<Vec<u8>>) -> TxResult {
+    let tx_input = TxInput {
+        from: owner(),
+        to: ESDT_SYSTEM_SC_ADDRESS,
+        func_name: func_name.into(),
+        args,
+        ..Default::default()
+    };
+    let tx_cache = TxCache::new(Arc::new(state.clone()));
+    let (tx_result, blockchain_updates) = execute_system_sc(tx_input, tx_cache);
+    if tx_result.result_status.is_success() {
+        state.commit_updates(blockchain_updates);
+    }
+    tx_result
+}
+
+fn issue_nft(state: &mut BlockchainState) -> Vec<u8> {
+    let result = system_sc_call(
+        state,
+        "issueNonFungible",
+        vec![
+            b"TestNFT".to_vec(),
+            b"TNFT".to_vec(),
+            b"canTransferNFTCreateRole".to_vec(),
+            b"true".to_vec(),
+        ],
+    );
+    assert!(result.result_status.is_success());
+    result.result_values[0].clone()
+}
+
+fn has_role(state: &BlockchainState, address: &Address, token: &[u8], role: &[u8]) -> bool {
+    state.accounts[address].esdt.has_special_role(token, role)
+}
+
+#[test]
+fn system_sc_single_nft_create_role_test() {
+    let mut state = setup_state();
+    let token = issue_nft(&mut state);
+    assert!(has_role(&state, &owner(), &token, NFT_CREATE));
+
+    let result = system_sc_call(
+        &mut state,
+        "setSpecialRole",
+        vec![token.clone(), minter().to_vec(), NFT_CREATE.to_vec()],
+    );
+    assert_eq!(result.result_message, "NFT create role already exists");
+
+    let result = system_sc_call(&mut state, "changeToMultiShardCreate", vec![token.clone()]);
+    assert!(result.result_status.is_success());
+
+    let result = system_sc_call(
+        &mut state,
+        "setSpecialRole",
+        vec![token.clone(), minter().to_vec(), NFT_CREATE.to_vec()],
+    );
+    assert!(result.result_status.is_success());
+    assert!(has_role(&state, &minter(), &token, NFT_CREATE));
+}
+
+#[test]
+fn system_sc_transfer_nft_create_role_test() {
+    let mut state = setup_state();
+    let token = issue_nft(&mut state);
+    state
+        .accounts
+        .get_mut(&owner())
+        .unwrap()
+        .esdt
+        .get_mut_by_identifier(&token)
+        .unwrap()
+        .last_nonce = 5;
+
+    let result = system_sc_call(
+        &mut state,
+        "transferNFTCreateRole",
+        vec![token.clone(), minter().to_vec(), owner().to_vec()],
+    );
+    assert_eq!(
+        result.result_message,
+        "old address does not have NFT create role"
+    );
+
+    let result = system_sc_call(
+        &mut state,
+        "transferNFTCreateRole",
+        vec![token.clone(), owner().to_vec(), minter().to_vec()],
+    );
+    assert!(result.result_status.is_success());
+    assert!(!has_role(&state, &owner(), &token, NFT_CREATE));
+    assert!(has_role(&state, &minter(), &token, NFT_CREATE));
+    assert_eq!(
+        state.accounts[&minter()]
+            .esdt
+            .get_by_identifier(&token)
+            .unwrap()
+            .last_nonce,
+        5
+    );
+    assert_eq!(
+        state.esdt_tokens[&token].addresses_with_role(NFT_CREATE),
+        vec![minter()]
+    );
+}
+
+#[test]
+fn system_sc_unset_special_role_test() {
+    let mut state = setup_state();
+    let token = issue_nft(&mut state);
+
+    let result = system_sc_call(
+        &mut state,
+        "unSetSpecialRole",
+        vec![token.clone(), minter().to_vec(), NFT_CREATE.to_vec()],
+    );
+    assert_eq!(result.result_message, "special role not set for address");
+
+    let result = system_sc_call(
+        &mut state,
+        "unSetSpecialRole",
+        vec![token.clone(), owner().to_vec(), NFT_CREATE.to_vec()],
+    );
+    assert!(result.result_status.is_success());
+    assert!(!has_role(&state, &owner(), &token, NFT_CREATE));
+
+    // the role is free again
+    let result = system_sc_call(
+        &mut state,
+        "setSpecialRole",
+        vec![token.clone(), minter().to_vec(), NFT_CREATE.to_vec()],
+    );
+    assert!(result.result_status.is_success());
+    assert!(has_role(&state, &minter(), &token, NFT_CREATE));
+}
+
+#[test]
+fn system_sc_roles_invalid_address_test() {
+    let mut state = setup_state();
+    let token = issue_nft(&mut state);
+
+    for func_name in ["setSpecialRole", "unSetSpecialRole"] {
+        let result = system_sc_call(
+            &mut state,
+            func_name,
+            vec![token.clone(), b"short".to_vec(), NFT_CREATE.to_vec()],
+        );
+        assert_eq!(
+            result.result_message,
+            "invalid address to set/unset special role"
+        );
+    }
+
+    let result = system_sc_call(
+        &mut state,
+        "transferNFTCreateRole",
+        vec![token.clone(), owner().to_vec(), b"short".to_vec()],
+    );
+    assert_eq!(
+        result.result_message,
+        "invalid address to transfer NFT create role"
+    );
+    assert!(has_role(&state, &owner(), &token, NFT_CREATE));
+}
+
+#[test]
+fn system_sc_stop_nft_create_test() {
+    let mut state = setup_state();
+    let token = issue_nft(&mut state);
+
+    let result = system_sc_call(&mut state, "stopNFTCreate", vec![token.clone()]);
+    assert!(result.result_status.is_success());
+    assert!(!has_role(&state, &owner(), &token, NFT_CREATE));
+    assert!(state.esdt_tokens[&token].nft_create_stopped);
+
+    let result = system_sc_call(
+        &mut state,
+        "setSpecialRole",
+        vec![token.clone(), owner().to_vec(), NFT_CREATE.to_vec()],
+    );
+    assert_eq!(
+        result.result_message,
+        "cannot add NFT create role as NFT creation was stopped"
+    );
+
+    let result = system_sc_call(&mut state, "stopNFTCreate", vec![token]);
+    assert_eq!(result.result_message, "NFT create was already stopped");
+}
+
+#[test]
+fn system_sc_burn_role_globally_test() {
+    let mut state = setup_state();
+    let token = issue_nft(&mut state);
+
+    let check_burn = |state: &BlockchainState| {
+        TxCache::new(Arc::new(state.clone()))
+            .check_esdt_role(&minter(), &token, EsdtLocalRole::Burn)
+            .is_ok()
+    };
+    assert!(!has_role(&state, &minter(), &token, LOCAL_BURN));
+    assert!(!check_burn(&state));
+
+    let result = system_sc_call(&mut state, "setBurnRoleGlobally", vec![token.clone()]);
+    assert!(result.result_status.is_success());
+    assert!(check_burn(&state));
+
+    let result = system_sc_call(&mut state, "setBurnRoleGlobally", vec![token.clone()]);
+    assert_eq!(
+        result.result_message,
+        "cannot set burn role globally as it was already set"
+    );
+
+    let result = system_sc_call(&mut state, "unsetBurnRoleGlobally", vec![token.clone()]);
+    assert!(result.result_status.is_success());
+    assert!(!check_burn(&state));
+}
+
+#[test]
+fn system_sc_roles_owner_only_test() {
+    let mut state = setup_state();
+    let token = issue_nft(&mut state);
+
+    let tx_input = TxInput {
+        from: minter(),
+        to: ESDT_SYSTEM_SC_ADDRESS,
+        func_name: "stopNFTCreate".into(),
+        args: vec![token],
+        ..Default::default()
+    };
+    let tx_cache = TxCache::new(Arc::new(state.clone()));
+    let (tx_result, _) = execute_system_sc(tx_input, tx_cache);
+    assert_eq!(tx_result.result_message, "can be called by owner only");
+}
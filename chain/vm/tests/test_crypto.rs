@@ -42,3 +42,108 @@ fn test_verify_ed25519_invalid_args() {
     let success = crypto_functions::verify_ed25519(&pub_bytes, &msg_bytes, &sig_bytes);
     assert!(!success);
 }
+
+#[test]
+fn test_ripemd160() {
+    assert_eq!(
+        crypto_functions::ripemd160(&[1, 2, 3]).to_vec(),
+        hex::decode("79f901da2609f020adadbf2e5f68a16c8c3f7d57").unwrap()
+    );
+}
+
+#[test]
+fn test_verify_secp256k1() {
+    let compressed_key =
+        hex::decode("02a673638cb9587cb68ea08dbef685c6f2d2a751a8b3c6f2a7e9a4999e6e4bfaf5").unwrap();
+    let uncompressed_key = hex::decode("04a673638cb9587cb68ea08dbef685c6f2d2a751a8b3c6f2a7e9a4999e6e4bfaf5ca1d22fe57c6103dbaac10cf15d15c0791cab8bb9a04f800e4d215276cb3e008").unwrap();
+    let message = b"message to sign";
+    let signature = hex::decode("3045022100fab29dbcb623e7e96f44665f312b89ffdb9e4d2e2f53353236fbbcf57b2f49bf02203d6f9c63df8be43121042970a549a2d3baeca093fd37b726ddcc0924638409f5").unwrap();
+
+    assert!(crypto_functions::verify_secp256k1(
+        &compressed_key,
+        message,
+        &signature,
+        crypto_functions::ECDSA_DOUBLE_SHA256
+    ));
+    assert!(crypto_functions::verify_secp256k1(
+        &uncompressed_key,
+        message,
+        &signature,
+        crypto_functions::ECDSA_DOUBLE_SHA256
+    ));
+    assert!(!crypto_functions::verify_secp256k1(
+        &compressed_key,
+        message,
+        &signature,
+        crypto_functions::ECDSA_SHA256
+    ));
+    assert!(!crypto_functions::verify_secp256k1(
+        &compressed_key,
+        b"other message",
+        &signature,
+        crypto_functions::ECDSA_DOUBLE_SHA256
+    ));
+}
+
+#[test]
+fn test_verify_secp256k1_plain_msg() {
+    let key = hex::decode("04e32df42865e97135acfb65f3bae71bdc86f4d49150ad6a440b6f15878109880a0a2b2667f7e725ceea70c673093bf67663e0312623c8e091b13cf2c0f11ef652").unwrap();
+    let message_hash =
+        hex::decode("ce0677bb30baa8cf067c88db9811f4333d131bf8bcf12fe7065d211dce971008").unwrap();
+    let r =
+        hex::decode("90f27b8b488db00b00606796d2987f6a5f59ae62ea05effe84fef5b8b0e54998").unwrap();
+    let s =
+        hex::decode("4a691139ad57a3f0b906637673aa2f63d1f55cb1a69199d4009eea23ceaddc93").unwrap();
+
+    let signature = crypto_functions::encode_secp256k1_der_signature(&r, &s).unwrap();
+    assert_eq!(
+        signature,
+        hex::decode("304502210090f27b8b488db00b00606796d2987f6a5f59ae62ea05effe84fef5b8b0e5499802204a691139ad57a3f0b906637673aa2f63d1f55cb1a69199d4009eea23ceaddc93").unwrap()
+    );
+
+    assert!(crypto_functions::verify_secp256k1(
+        &key,
+        &message_hash,
+        &signature,
+        crypto_functions::ECDSA_PLAIN_MSG
+    ));
+}
+
+#[test]
+fn test_encode_secp256k1_der_signature_trims_leading_zeros() {
+    assert_eq!(
+        crypto_functions::encode_secp256k1_der_signature(&[0, 0, 0x12], &[0, 0x80]),
+        Some(vec![0x30, 0x07, 0x02, 0x01, 0x12, 0x02, 0x02, 0x00, 0x80])
+    );
+}
+
+#[test]
+fn test_encode_secp256k1_der_signature_too_long() {
+    // a 127 byte integer, with the sign padding, no longer fits a short form length
+    assert_eq!(
+        crypto_functions::encode_secp256k1_der_signature(&[0xff; 127], &[1]),
+        None
+    );
+    // both integers fit, but not the whole sequence
+    assert_eq!(
+        crypto_functions::encode_secp256k1_der_signature(&[1; 100], &[1; 100]),
+        None
+    );
+}
+
+#[test]
+fn test_verify_secp256r1() {
+    let key =
+        hex::decode("02bc52274edebbef8878eacc4d1e0ed4fb213e5b0737389701ae8d59c403325720").unwrap();
+    let message = hex::decode("bf9facf48b2219db73b50c7ff59ceef2ada56632c71afc555d6bb4072d7634d1d9353acd53517ffb9a06935a89a6454fcaa40c69becf9f8029a271fd252ea55307d00d6e97a30719d48d6b7f993af24e9c54381cba02a113238eaee9d741cababeb21aaf").unwrap();
+    let mut signature = hex::decode("c7877497444274267a4ea6f42deefde23a12e44f1ec1b437018e5c0e2834ce376dec1b81ebeacf5fbc6882e69af7cafad47bbb96cfb09e8d77d12afff7543052").unwrap();
+
+    assert!(crypto_functions::verify_secp256r1(
+        &key, &message, &signature
+    ));
+
+    signature[0] = 0;
+    assert!(!crypto_functions::verify_secp256r1(
+        &key, &message, &signature
+    ));
+}
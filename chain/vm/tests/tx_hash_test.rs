@@ -0,0 +1,51 @@
+use multiversx_chain_vm::{
+    builtin_functions::BuiltinFunctionContainer,
+    host::context::{
+        AsyncCallTxData, CallType, TxFunctionName, TxInput, TxResult, async_call_tx_input,
+        async_callback_tx_input,
+    },
+    types::{Address, H256},
+};
+
+fn async_call_data() -> AsyncCallTxData {
+    AsyncCallTxData {
+        from: Address::from([1u8; 32]),
+        to: Address::from([2u8; 32]),
+        call_value: 0u32.into(),
+        endpoint_name: TxFunctionName::from("endpoint"),
+        arguments: Vec::new(),
+        gas_limit: 1_000_000,
+        tx_hash: H256::from([3u8; 32]),
+        original_tx_hash: H256::from([4u8; 32]),
+        prev_tx_hash: H256::from([5u8; 32]),
+        original_caller: Address::from([1u8; 32]),
+        relayer: None,
+    }
+}
+
+#[test]
+fn tx_hashes_default_to_current_tx_hash() {
+    let tx_input = TxInput {
+        tx_hash: H256::from([3u8; 32]),
+        ..Default::default()
+    };
+
+    assert_eq!(tx_input.original_tx_hash(), &tx_input.tx_hash);
+    assert_eq!(tx_input.prev_tx_hash(), &tx_input.tx_hash);
+}
+
+#[test]
+fn async_call_propagates_tx_hashes() {
+    let async_data = async_call_data();
+
+    let call_input = async_call_tx_input(&async_data, CallType::AsyncCall);
+    assert_eq!(call_input.tx_hash, H256::from([3u8; 32]));
+    assert_eq!(call_input.original_tx_hash(), &H256::from([4u8; 32]));
+    assert_eq!(call_input.prev_tx_hash(), &H256::from([5u8; 32]));
+
+    let callback_input =
+        async_callback_tx_input(&async_data, &TxResult::empty(), &BuiltinFunctionContainer);
+    assert_eq!(callback_input.tx_hash, H256::from([3u8; 32]));
+    assert_eq!(callback_input.original_tx_hash(), &H256::from([4u8; 32]));
+    assert_eq!(callback_input.prev_tx_hash(), &H256::from([5u8; 32]));
+}
@@ -0,0 +1,168 @@
+use multiversx_sc_scenario::{imports::*, scenario::run_gas_profile::GasProfileReport};
+
+use adder::*;
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const OTHER_ADDRESS: TestAddress = TestAddress::new("other");
+const ADDER_ADDRESS: TestSCAddress = TestSCAddress::new("adder");
+const CODE_PATH: MxscPath = MxscPath::new("output/adder.mxsc.json");
+const TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("TOKEN-123456");
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new()
+        .gas_schedule(GasScheduleVersion::V9)
+        .enable_gas_metering();
+
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+    blockchain.register_contract(CODE_PATH, adder::ContractBuilder);
+    blockchain
+}
+
+fn deploy(world: &mut ScenarioWorld) {
+    world
+        .account(OWNER_ADDRESS)
+        .nonce(1)
+        .esdt_balance(TOKEN_ID, 1000);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(adder_proxy::AdderProxy)
+        .init(5u32)
+        .code(CODE_PATH)
+        .new_address(ADDER_ADDRESS)
+        .run();
+}
+
+#[test]
+fn adder_gas_metering_gas_used() {
+    let mut world = world();
+    deploy(&mut world);
+
+    let gas_used = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ADDER_ADDRESS)
+        .gas(5_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .add(1u32)
+        .returns(ReturnsGasUsed)
+        .run();
+
+    assert_eq!(gas_used, 139_000);
+}
+
+#[test]
+fn adder_gas_metering_out_of_gas() {
+    let mut world = world();
+    deploy(&mut world);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ADDER_ADDRESS)
+        .gas(100)
+        .typed(adder_proxy::AdderProxy)
+        .add(1u32)
+        .returns(ExpectError(5, "not enough gas"))
+        .run();
+
+    world
+        .query()
+        .to(ADDER_ADDRESS)
+        .typed(adder_proxy::AdderProxy)
+        .sum()
+        .returns(ExpectValue(5u32))
+        .run();
+}
+
+#[test]
+fn adder_gas_metering_builtin_function() {
+    let mut world = world();
+    deploy(&mut world);
+    world.account(OTHER_ADDRESS).nonce(1);
+
+    let gas_used = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(OTHER_ADDRESS)
+        .gas(5_000_000)
+        .raw_call("ESDTTransfer")
+        .argument(&TOKEN_ID)
+        .argument(&100u32)
+        .returns(ReturnsGasUsed)
+        .run();
+
+    let gas_schedule = GasScheduleVersion::V9.load_gas_schedule();
+    assert_eq!(gas_used, gas_schedule.builtin_cost.esdt_transfer);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(OTHER_ADDRESS)
+        .gas(1000)
+        .raw_call("ESDTTransfer")
+        .argument(&TOKEN_ID)
+        .argument(&100u32)
+        .returns(ExpectError(5, "not enough gas"))
+        .run();
+
+    world
+        .check_account(OTHER_ADDRESS)
+        .esdt_balance(TOKEN_ID, 100);
+}
+
+#[test]
+fn adder_gas_metering_disabled() {
+    let mut world = ScenarioWorld::new().gas_schedule(GasScheduleVersion::V9);
+    world.set_current_dir_from_workspace("contracts/examples/adder");
+    world.register_contract(CODE_PATH, adder::ContractBuilder);
+    deploy(&mut world);
+
+    let gas_used = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ADDER_ADDRESS)
+        .gas(5_000_000)
+        .typed(adder_proxy::AdderProxy)
+        .add(1u32)
+        .returns(ReturnsGasUsed)
+        .run();
+
+    assert_eq!(gas_used, 0);
+}
+
+#[test]
+fn adder_gas_profile() {
+    let mut world = ScenarioWorld::new().gas_schedule(GasScheduleVersion::V9);
+    world.set_current_dir_from_workspace("contracts/examples/adder");
+    world.register_contract(CODE_PATH, adder::ContractBuilder);
+    world.start_gas_profile();
+    deploy(&mut world);
+
+    for _ in 0..2 {
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ADDER_ADDRESS)
+            .gas(5_000_000)
+            .typed(adder_proxy::AdderProxy)
+            .add(1u32)
+            .run();
+    }
+
+    let report_path = std::env::temp_dir().join("adder-gas-profile-test.json");
+    let _ = std::fs::remove_file(&report_path);
+    world.write_gas_profile(&report_path);
+
+    let report = GasProfileReport::load_from_file(&report_path);
+    let add_profile = report.find_endpoint("sc:adder", "add").unwrap();
+    assert_eq!(add_profile.calls, 2);
+    assert!(add_profile.min_gas > 0);
+    assert_eq!(add_profile.total_gas, 2 * add_profile.min_gas);
+    assert!(add_profile.categories.contains_key("BigIntAPICost"));
+    assert!(report.find_endpoint("sc:adder", "init").is_some());
+
+    let markdown = std::fs::read_to_string(report_path.with_extension("md")).unwrap();
+    assert!(markdown.contains("## sc:adder"));
+}
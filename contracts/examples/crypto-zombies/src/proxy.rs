@@ -303,3 +303,38 @@ where
     pub win_count: usize,
     pub loss_count: usize,
 }
+
+pub mod events {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct NewZombieEvent<Api>
+    where
+        Api: ManagedTypeApi,
+    {
+        pub zombie_id: usize,
+        pub name: ManagedBuffer<Api>,
+        pub dna: u64,
+    }
+
+    impl<Api> TxProxyEvent for NewZombieEvent<Api>
+    where
+        Api: ManagedTypeApi,
+    {
+        const IDENTIFIER: &'static str = "newZombieEvent";
+
+        fn decode_event(input: &mut EventLogInput) -> Result<Self, DecodeError> {
+            Ok(NewZombieEvent {
+                zombie_id: input.next_topic()?,
+                name: input.data()?,
+                dna: input.next_topic()?,
+            })
+        }
+
+        fn visit_fields<V: EventFieldVisitor>(&self, visitor: &mut V) {
+            visitor.topic("zombie_id", &self.zombie_id);
+            visitor.data("name", &self.name);
+            visitor.topic("dna", &self.dna);
+        }
+    }
+}
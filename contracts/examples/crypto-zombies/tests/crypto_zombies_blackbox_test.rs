@@ -616,3 +616,232 @@ fn test_multiple_users_each_create_one_zombie() {
     assert_eq!(z0.name, ManagedBuffer::from("Zombie1"));
     assert_eq!(z1.name, ManagedBuffer::from("Zombie2"));
 }
+
+#[test]
+fn test_create_zombie_scripted_dna() {
+    let mut state = CryptoZombiesState::new();
+    state.deploy();
+
+    state.world.script_random_u64s(&[1234567890]);
+    state.create_zombie(USER1_ADDRESS, "Alpha");
+
+    let zombie = state.query_zombie(0);
+    assert_eq!(zombie.dna, 1234567890u64);
+}
+
+#[test]
+fn test_create_zombie_event() {
+    let mut state = CryptoZombiesState::new();
+    state.deploy();
+
+    state.world.script_random_u64s(&[1234567890]);
+    let events: Vec<proxy::events::NewZombieEvent<StaticApi>> = state
+        .world
+        .tx()
+        .from(USER1_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(proxy::CryptoZombiesProxy)
+        .create_random_zombie(ManagedBuffer::from("Alpha"))
+        .returns(ReturnsEvents::new())
+        .run();
+
+    assert_eq!(
+        events,
+        vec![proxy::events::NewZombieEvent {
+            zombie_id: 0,
+            name: ManagedBuffer::from("Alpha"),
+            dna: 1234567890,
+        }]
+    );
+}
+
+#[test]
+fn test_expect_zombie_events() {
+    let mut state = CryptoZombiesState::new();
+    state.deploy();
+
+    state.world.script_random_u64s(&[1234567890]);
+    state
+        .world
+        .tx()
+        .from(USER1_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(proxy::CryptoZombiesProxy)
+        .create_random_zombie(ManagedBuffer::from("Alpha"))
+        .returns(ExpectEvent::new(
+            proxy::events::NewZombieEvent::<StaticApi> {
+                zombie_id: 0,
+                name: ManagedBuffer::from("Alpha"),
+                dna: 1234567890,
+            },
+        ))
+        .run();
+
+    state.world.script_random_u64s(&[42]);
+    state
+        .world
+        .tx()
+        .from(USER2_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(proxy::CryptoZombiesProxy)
+        .create_random_zombie(ManagedBuffer::from("Beta"))
+        .returns(ExpectEvents(vec![proxy::events::NewZombieEvent::<
+            StaticApi,
+        > {
+            zombie_id: 1,
+            name: ManagedBuffer::from("Beta"),
+            dna: 42,
+        }]))
+        .run();
+}
+
+#[test]
+fn test_expect_zombie_event_partial() {
+    let mut state = CryptoZombiesState::new();
+    state.deploy();
+
+    state
+        .world
+        .tx()
+        .from(USER1_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(proxy::CryptoZombiesProxy)
+        .create_random_zombie(ManagedBuffer::from("Alpha"))
+        .returns(
+            ExpectEvent::new(proxy::events::NewZombieEvent::<StaticApi> {
+                zombie_id: 0,
+                name: ManagedBuffer::from("Alpha"),
+                dna: 0,
+            })
+            .ignore("dna"),
+        )
+        .run();
+}
+
+#[test]
+#[should_panic(
+    expected = "event newZombieEvent was not emitted, the emitted ones differ in:\n#0:\n    dna: expected `1`, actual `1234567890`"
+)]
+fn test_expect_zombie_event_mismatch() {
+    let mut state = CryptoZombiesState::new();
+    state.deploy();
+
+    state.world.script_random_u64s(&[1234567890]);
+    state
+        .world
+        .tx()
+        .from(USER1_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(proxy::CryptoZombiesProxy)
+        .create_random_zombie(ManagedBuffer::from("Alpha"))
+        .returns(ExpectEvent::new(
+            proxy::events::NewZombieEvent::<StaticApi> {
+                zombie_id: 0,
+                name: ManagedBuffer::from("Alpha"),
+                dna: 1,
+            },
+        ))
+        .run();
+}
+
+#[test]
+#[should_panic(
+    expected = "newZombieEvent events mismatch:\nexpected 2 events, 1 emitted\n#0:\n    name: expected `ManagedBuffer"
+)]
+fn test_expect_zombie_events_mismatch() {
+    let mut state = CryptoZombiesState::new();
+    state.deploy();
+
+    state.world.script_random_u64s(&[42]);
+    state
+        .world
+        .tx()
+        .from(USER1_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(proxy::CryptoZombiesProxy)
+        .create_random_zombie(ManagedBuffer::from("Alpha"))
+        .returns(ExpectEvents(vec![
+            proxy::events::NewZombieEvent::<StaticApi> {
+                zombie_id: 0,
+                name: ManagedBuffer::from("Beta"),
+                dna: 42,
+            },
+            proxy::events::NewZombieEvent::<StaticApi> {
+                zombie_id: 1,
+                name: ManagedBuffer::from("Gamma"),
+                dna: 42,
+            },
+        ]))
+        .run();
+}
+
+#[test]
+fn test_attack_scripted_win() {
+    let mut state = CryptoZombiesState::new();
+    state.deploy();
+    state.create_zombie(USER1_ADDRESS, "Alpha");
+    state.create_zombie(USER2_ADDRESS, "Beta");
+
+    // 69 % 100 is below the victory probability of 70
+    state.world.script_random_bytes(&[69]);
+    state.attack(USER1_ADDRESS, 0, 1);
+
+    let attacker = state.query_zombie(0);
+    assert_eq!(attacker.win_count, 1usize);
+    assert_eq!(attacker.level, 2u16);
+    assert_eq!(state.query_zombie_last_index(), 3usize);
+}
+
+#[test]
+fn test_attack_scripted_loss() {
+    let mut state = CryptoZombiesState::new();
+    state.deploy();
+    state.create_zombie(USER1_ADDRESS, "Alpha");
+    state.create_zombie(USER2_ADDRESS, "Beta");
+
+    // 171 % 100 = 71, just above the victory probability
+    state.world.script_random_bytes(&[171]);
+    state.attack(USER1_ADDRESS, 0, 1);
+
+    let attacker = state.query_zombie(0);
+    assert_eq!(attacker.loss_count, 1usize);
+    assert_eq!(state.query_zombie(1).win_count, 1usize);
+    assert_eq!(state.query_zombie_last_index(), 2usize);
+}
+
+#[test]
+fn test_pinned_seed_is_reproducible() {
+    let mut first = CryptoZombiesState::new();
+    first.world.pin_random_seed(b"seed");
+    first.deploy();
+    first.create_zombie(USER1_ADDRESS, "Alpha");
+
+    let mut second = CryptoZombiesState::new();
+    second.world.pin_random_seed(b"seed");
+    second.deploy();
+    second.create_zombie(USER2_ADDRESS, "Beta");
+
+    assert_eq!(first.query_zombie(0).dna, second.query_zombie(0).dna);
+}
+
+#[test]
+fn test_replay_consumed_random_bytes() {
+    let mut first = CryptoZombiesState::new();
+    first.deploy();
+    assert!(first.world.take_consumed_random_bytes().is_empty());
+
+    first.world.record_random_bytes();
+    first.create_zombie(USER1_ADDRESS, "Alpha");
+    let consumed = first.world.take_consumed_random_bytes();
+    assert_eq!(consumed.len(), 1);
+    assert!(first.world.take_consumed_random_bytes().is_empty());
+
+    // new block random seeds, so only the replay makes the outcome identical
+    let mut second = CryptoZombiesState::new();
+    second.deploy();
+    second.world.advance_blocks(1);
+    second.world.script_random_bytes(&consumed.concat());
+    second.create_zombie(USER2_ADDRESS, "Beta");
+
+    assert_eq!(first.query_zombie(0).dna, second.query_zombie(0).dna);
+}
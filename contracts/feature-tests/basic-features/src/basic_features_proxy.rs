@@ -114,6 +114,24 @@ where
             .original_result()
     }
 
+    pub fn get_original_caller(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_original_caller")
+            .original_result()
+    }
+
+    pub fn get_relayer(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_relayer")
+            .original_result()
+    }
+
     pub fn verify_secp256r1_signature<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -8,6 +8,16 @@ pub trait BlockchainApiFeatures {
         self.blockchain().get_caller()
     }
 
+    #[endpoint]
+    fn get_original_caller(&self) -> ManagedAddress {
+        self.blockchain().get_original_caller()
+    }
+
+    #[endpoint]
+    fn get_relayer(&self) -> OptionalValue<ManagedAddress> {
+        self.blockchain().get_relayer().into()
+    }
+
     #[endpoint]
     fn get_owner_address(&self) -> ManagedAddress {
         self.blockchain().get_owner_address()
@@ -280,6 +280,12 @@ fn get_cumulated_validator_rewards_go() {
     world().run("scenarios/get_cumulated_validator_rewards.scen.json");
 }
 
+#[test]
+#[ignore = "TODO: missing support from scenario-go"]
+fn get_relayer_go() {
+    world().run("scenarios/get_relayer.scen.json");
+}
+
 #[test]
 #[ignore = "TODO: missing support from scenario-go"]
 fn get_shard_of_address_go() {
@@ -118,7 +118,6 @@ fn count_ones_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_elliptic_curves_rs() {
     world().run("scenarios/crypto_elliptic_curves.scen.json");
 }
@@ -129,7 +128,6 @@ fn crypto_keccak_256_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_ripemd_160_rs() {
     world().run("scenarios/crypto_ripemd160.scen.json");
 }
@@ -163,13 +161,11 @@ fn crypto_verify_ed_25519_rs() {
 }
 
 #[test]
-#[ignore]
 fn crypto_verify_secp_256_k_1_rs() {
     world().run("scenarios/crypto_verify_secp256k1.scen.json");
 }
 
 #[test]
-#[ignore]
 fn crypto_verify_secp_256_r_1_rs() {
     world().run("scenarios/crypto_verify_secp256r1.scen.json");
 }
@@ -304,6 +300,11 @@ fn get_cumulated_validator_rewards_rs() {
     world().run("scenarios/get_cumulated_validator_rewards.scen.json");
 }
 
+#[test]
+fn get_relayer_rs() {
+    world().run("scenarios/get_relayer.scen.json");
+}
+
 #[test]
 fn get_shard_of_address_rs() {
     world().run("scenarios/get_shard_of_address.scen.json");
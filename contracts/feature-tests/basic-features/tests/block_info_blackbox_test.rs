@@ -83,3 +83,68 @@ fn block_info_blackbox() {
     assert_eq!(epoch_start_block_nonce, 15_000);
     assert_eq!(epoch_start_block_round, 17_000);
 }
+
+fn query_u64(world: &mut ScenarioWorld, endpoint: &str) -> u64 {
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .raw_call(endpoint)
+        .original_result::<u64>()
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn block_progression_blackbox() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(basic_features_proxy::BasicFeaturesProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world.block_round_time_ms(600);
+    world.epoch_length_rounds(100);
+
+    world.advance_blocks(5);
+
+    assert_eq!(query_u64(&mut world, "get_block_nonce"), 5);
+    assert_eq!(query_u64(&mut world, "get_block_round"), 5);
+    assert_eq!(query_u64(&mut world, "get_block_epoch"), 0);
+
+    let (prev_timestamp_ms, _, timestamp_ms, _) = world
+        .query()
+        .to(SC_ADDRESS)
+        .typed(basic_features_proxy::BasicFeaturesProxy)
+        .get_block_timestamps()
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    assert_eq!(prev_timestamp_ms, TimestampMillis::new(2_400));
+    assert_eq!(timestamp_ms, TimestampMillis::new(3_000));
+
+    // 1 minute = 100 rounds of 600 ms, crossing into the next epoch
+    world.advance_time(DurationMillis::new(60_000));
+
+    assert_eq!(query_u64(&mut world, "get_block_nonce"), 105);
+    assert_eq!(query_u64(&mut world, "get_block_epoch"), 1);
+
+    let (_, epoch_start_block_timestamp_ms, epoch_start_block_nonce, epoch_start_block_round) =
+        world
+            .query()
+            .to(SC_ADDRESS)
+            .typed(basic_features_proxy::BasicFeaturesProxy)
+            .epoch_info()
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple();
+    assert_eq!(epoch_start_block_timestamp_ms, TimestampMillis::new(60_000));
+    assert_eq!(epoch_start_block_nonce, 100);
+    assert_eq!(epoch_start_block_round, 100);
+}
@@ -0,0 +1,83 @@
+use multiversx_sc_scenario::imports::*;
+
+use basic_features::basic_features_proxy;
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const USER_ADDRESS: TestAddress = TestAddress::new("user");
+const RELAYER_ADDRESS: TestAddress = TestAddress::new("relayer");
+const SC_ADDRESS: TestSCAddress = TestSCAddress::new("basic-features");
+const CODE_PATH: MxscPath = MxscPath::new("output/basic-features.mxsc.json");
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+    blockchain.register_contract(CODE_PATH, basic_features::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn relayed_tx_blackbox() {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1);
+    world.account(USER_ADDRESS).nonce(1).balance(1_000);
+    world.account(RELAYER_ADDRESS).nonce(1).balance(10_000_000);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(basic_features_proxy::BasicFeaturesProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    let relayer = world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(basic_features_proxy::BasicFeaturesProxy)
+        .get_relayer()
+        .returns(ReturnsResult)
+        .run();
+    assert!(relayer.into_option().is_none());
+
+    let relayer = world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .relayer(RELAYER_ADDRESS)
+        .typed(basic_features_proxy::BasicFeaturesProxy)
+        .get_relayer()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(
+        relayer.into_option(),
+        Some(RELAYER_ADDRESS.to_managed_address())
+    );
+
+    let original_caller = world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(SC_ADDRESS)
+        .relayer(RELAYER_ADDRESS)
+        .typed(basic_features_proxy::BasicFeaturesProxy)
+        .get_original_caller()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(original_caller, USER_ADDRESS.to_managed_address());
+
+    // the sender still pays the value
+    world.transfer_step(
+        TransferStep::new()
+            .from(USER_ADDRESS)
+            .to(OWNER_ADDRESS)
+            .relayer(RELAYER_ADDRESS)
+            .egld_value(100u64),
+    );
+
+    world.check_account(USER_ADDRESS).balance(900);
+    world.check_account(OWNER_ADDRESS).balance(100);
+    world.check_account(RELAYER_ADDRESS).balance(10_000_000);
+}
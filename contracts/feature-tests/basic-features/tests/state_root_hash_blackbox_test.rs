@@ -0,0 +1,81 @@
+use multiversx_sc_scenario::imports::*;
+
+use basic_features::basic_features_proxy;
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const SC_ADDRESS: TestSCAddress = TestSCAddress::new("basic-features");
+const CODE_PATH: MxscPath = MxscPath::new("output/basic-features.mxsc.json");
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+    blockchain.register_contract(CODE_PATH, basic_features::ContractBuilder);
+    blockchain
+}
+
+fn query_state_root_hash(world: &mut ScenarioWorld) -> [u8; 32] {
+    world
+        .query()
+        .to(SC_ADDRESS)
+        .raw_call("get_state_root_hash")
+        .original_result::<ManagedByteArray<StaticApi, 32>>()
+        .returns(ReturnsResult)
+        .run()
+        .to_byte_array()
+}
+
+fn deployed_world() -> ScenarioWorld {
+    let mut world = world();
+
+    world.account(OWNER_ADDRESS).nonce(1);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(basic_features_proxy::BasicFeaturesProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(SC_ADDRESS)
+        .run();
+
+    world
+}
+
+#[test]
+fn state_root_hash_blackbox() {
+    let mut world = deployed_world();
+
+    let initial = world.state_root_hash();
+    assert_eq!(query_state_root_hash(&mut world), initial);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SC_ADDRESS)
+        .typed(basic_features_proxy::BasicFeaturesProxy)
+        .store_bytes(b"abc")
+        .run();
+
+    let after_store = world.state_root_hash();
+    assert_ne!(after_store, initial);
+    assert_eq!(query_state_root_hash(&mut world), after_store);
+}
+
+#[test]
+fn state_root_hash_snapshot_equality() {
+    let mut first = deployed_world();
+    let mut second = deployed_world();
+    assert_eq!(first.state_root_hash(), second.state_root_hash());
+
+    for world in [&mut first, &mut second] {
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(SC_ADDRESS)
+            .typed(basic_features_proxy::BasicFeaturesProxy)
+            .store_bytes(b"abc")
+            .run();
+    }
+    assert_eq!(first.state_root_hash(), second.state_root_hash());
+}
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                          676
+// Endpoints:                          678
 // Async Callback:                       1
-// Total number of exported functions: 678
+// Total number of exported functions: 680
 
 #![no_std]
 
@@ -361,6 +361,8 @@ multiversx_sc_wasm_adapter::endpoints! {
         get_block_timestamp_ms => get_block_timestamp_ms
         get_prev_block_timestamp_ms => get_prev_block_timestamp_ms
         get_caller => get_caller
+        get_original_caller => get_original_caller
+        get_relayer => get_relayer
         get_owner_address => get_owner_address
         get_shard_of_address => get_shard_of_address
         is_smart_contract => is_smart_contract
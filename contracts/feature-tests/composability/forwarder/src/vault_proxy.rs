@@ -101,6 +101,15 @@ where
             .original_result()
     }
 
+    pub fn echo_tx_hash(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedByteArray<Env::Api, 32>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("echo_tx_hash")
+            .original_result()
+    }
+
     pub fn accept_funds(
         self,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
@@ -230,6 +230,11 @@ fn forw_raw_sync_readonly_go() {
     world().run("scenarios/forw_raw_sync_readonly.scen.json");
 }
 
+#[test]
+fn forw_raw_sync_readonly_transfer_go() {
+    world().run("scenarios/forw_raw_sync_readonly_transfer.scen.json");
+}
+
 #[test]
 fn forw_raw_sync_reject_go() {
     world().run("scenarios/forw_raw_sync_reject.scen.json");
@@ -287,6 +287,11 @@ fn forw_raw_sync_readonly_rs() {
     world().run("scenarios/forw_raw_sync_readonly.scen.json");
 }
 
+#[test]
+fn forw_raw_sync_readonly_transfer_rs() {
+    world().run("scenarios/forw_raw_sync_readonly_transfer.scen.json");
+}
+
 #[test]
 #[ignore = "different error code/message in Rust VM"]
 fn forw_raw_sync_reject_rs() {
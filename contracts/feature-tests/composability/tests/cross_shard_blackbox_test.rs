@@ -0,0 +1,222 @@
+use multiversx_sc_scenario::imports::*;
+
+use forwarder::forwarder_proxy;
+
+const USER_ADDRESS: TestAddress = TestAddress::new("user");
+const VAULT_ADDRESS: TestSCAddress = TestSCAddress::new("vault");
+const VAULT_PATH: MxscPath = MxscPath::new("../vault/output/vault.mxsc.json");
+const FORWARDER_ADDRESS: TestSCAddress = TestSCAddress::new("forwarder");
+const FORWARDER_PATH: MxscPath = MxscPath::new("../forwarder/output/forwarder.mxsc.json");
+
+const TOKEN_ID_EXPR: TestTokenIdentifier = TestTokenIdentifier::new("TOKEN-123456");
+
+/// The test addresses all end in `_`, which places them in shard 1, out of 3.
+/// This one is in shard 0.
+fn vault_address_other_shard() -> Address {
+    let mut address = VAULT_ADDRESS.eval_to_array();
+    address[31] = 0;
+    Address::from(address)
+}
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new().enable_cross_shard(ShardConfig::THREE_SHARDS);
+
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+    blockchain.register_contract(VAULT_PATH, vault::ContractBuilder);
+    blockchain.register_contract(FORWARDER_PATH, forwarder::ContractBuilder);
+
+    blockchain
+        .account(USER_ADDRESS)
+        .nonce(1)
+        .esdt_balance(TOKEN_ID_EXPR, 1000);
+    blockchain
+        .account(vault_address_other_shard())
+        .nonce(1)
+        .code(VAULT_PATH)
+        .esdt_balance(TOKEN_ID_EXPR, 1000);
+    blockchain.account(VAULT_ADDRESS).nonce(1).code(VAULT_PATH);
+    blockchain
+        .account(FORWARDER_ADDRESS)
+        .nonce(1)
+        .code(FORWARDER_PATH);
+
+    blockchain
+}
+
+fn echo_args_async(world: &mut ScenarioWorld, vault_address: &Address) {
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(FORWARDER_ADDRESS)
+        .typed(forwarder_proxy::ForwarderProxy)
+        .echo_args_async(
+            vault_address,
+            MultiValueVec::from(vec![b"a".to_vec(), b"b".to_vec()]),
+        )
+        .run();
+}
+
+#[test]
+fn cross_shard_same_shard_async_test() {
+    let mut world = world();
+
+    echo_args_async(&mut world, &VAULT_ADDRESS.to_address());
+
+    assert!(world.cross_shard_messages().is_empty());
+}
+
+#[test]
+fn cross_shard_legacy_async_delivered_block_by_block_test() {
+    let mut world = world();
+
+    echo_args_async(&mut world, &vault_address_other_shard());
+
+    let messages = world.cross_shard_messages();
+    assert_eq!(messages.len(), 1);
+    assert!(!messages[0].payload.is_callback());
+    assert_eq!(messages[0].source_shard, ShardId::from(1));
+    assert_eq!(messages[0].destination_shard, ShardId::from(0));
+
+    // the call
+    let responses = world.deliver_cross_shard_block();
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0].out, vec![b"a".to_vec(), b"b".to_vec()]);
+
+    let messages = world.cross_shard_messages();
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].payload.is_callback());
+    assert_eq!(messages[0].source_shard, ShardId::from(0));
+    assert_eq!(messages[0].destination_shard, ShardId::from(1));
+
+    // the callback
+    let responses = world.deliver_cross_shard_block();
+    assert_eq!(
+        responses[0].out,
+        vec![b"success".to_vec(), b"a".to_vec(), b"b".to_vec()]
+    );
+    assert!(world.cross_shard_messages().is_empty());
+}
+
+#[test]
+fn cross_shard_legacy_async_fail_test() {
+    let mut world = world();
+
+    echo_args_async(&mut world, &vault_address_other_shard());
+
+    let call_id = world.cross_shard_messages()[0].id;
+    let call_response = world.fail_cross_shard_message(call_id, "simulated failure");
+    assert_eq!(call_response.tx_error.status, ReturnCode::UserError);
+
+    let callback_id = world.cross_shard_messages()[0].id;
+    let callback_response = world.deliver_cross_shard_message(callback_id);
+    assert_eq!(
+        callback_response.out,
+        vec![
+            b"error".to_vec(),
+            4u32.to_be_bytes().to_vec(),
+            b"simulated failure".to_vec()
+        ]
+    );
+}
+
+#[test]
+fn cross_shard_promise_intermediate_state_test() {
+    let mut world = world();
+    let vault_address = vault_address_other_shard();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(FORWARDER_ADDRESS)
+        .gas(100_000_000)
+        .typed(forwarder_proxy::ForwarderProxy)
+        .forward_promise_retrieve_funds(
+            &vault_address,
+            TOKEN_ID_EXPR,
+            0u64,
+            NonZeroBigUint::try_from(500u64).unwrap(),
+        )
+        .run();
+
+    // nothing reached the vault yet
+    world
+        .check_account(&vault_address)
+        .esdt_balance(TOKEN_ID_EXPR, 1000);
+
+    world.deliver_cross_shard_block();
+
+    // the funds left the vault, but they travel with the callback, which did not arrive yet
+    world
+        .check_account(&vault_address)
+        .esdt_balance(TOKEN_ID_EXPR, 500);
+    world
+        .check_account(FORWARDER_ADDRESS)
+        .esdt_balance(TOKEN_ID_EXPR, 0)
+        .check_storage("str:callback_data.len", "");
+
+    world.deliver_all_cross_shard_messages();
+
+    world
+        .check_account(FORWARDER_ADDRESS)
+        .esdt_balance(TOKEN_ID_EXPR, 500)
+        .check_storage("str:callback_data.len", "1");
+}
+
+fn forward_async_accept_funds(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(FORWARDER_ADDRESS)
+        .typed(forwarder_proxy::ForwarderProxy)
+        .forward_async_accept_funds(vault_address_other_shard())
+        .single_esdt(&TOKEN_ID_EXPR.into(), 0, &BigUint::from(100u64))
+        .run();
+}
+
+#[test]
+fn cross_shard_payment_escrow_test() {
+    let mut world = world();
+
+    forward_async_accept_funds(&mut world);
+
+    // the payment left the forwarder, but did not reach the vault yet
+    world
+        .check_account(FORWARDER_ADDRESS)
+        .esdt_balance(TOKEN_ID_EXPR, 0);
+    world
+        .check_account(vault_address_other_shard())
+        .esdt_balance(TOKEN_ID_EXPR, 1000);
+
+    world.deliver_all_cross_shard_messages();
+
+    world
+        .check_account(FORWARDER_ADDRESS)
+        .esdt_balance(TOKEN_ID_EXPR, 0);
+    world
+        .check_account(vault_address_other_shard())
+        .esdt_balance(TOKEN_ID_EXPR, 1100);
+}
+
+#[test]
+fn cross_shard_payment_refund_test() {
+    let mut world = world();
+
+    forward_async_accept_funds(&mut world);
+
+    let call_id = world.cross_shard_messages()[0].id;
+    world.fail_cross_shard_message(call_id, "simulated failure");
+
+    // the refund travels with the callback
+    world
+        .check_account(FORWARDER_ADDRESS)
+        .esdt_balance(TOKEN_ID_EXPR, 0);
+
+    world.deliver_all_cross_shard_messages();
+
+    world
+        .check_account(FORWARDER_ADDRESS)
+        .esdt_balance(TOKEN_ID_EXPR, 100);
+    world
+        .check_account(vault_address_other_shard())
+        .esdt_balance(TOKEN_ID_EXPR, 1000);
+}
@@ -0,0 +1,178 @@
+use multiversx_sc_scenario::imports::*;
+
+use forwarder::vault_proxy;
+
+const USER_ADDRESS: TestAddress = TestAddress::new("user");
+const VAULT_ADDRESS: TestSCAddress = TestSCAddress::new("vault");
+const VAULT_PATH: MxscPath = MxscPath::new("../vault/output/vault.mxsc.json");
+const FORWARDER_RAW_ADDRESS: TestSCAddress = TestSCAddress::new("forwarder-raw");
+const FORWARDER_RAW_PATH: MxscPath =
+    MxscPath::new("../forwarder-raw/output/forwarder-raw.mxsc.json");
+
+/// The test addresses all end in `_`, which places them in shard 1, out of 3.
+/// This one is in shard 0.
+fn vault_address_other_shard() -> Address {
+    let mut address = VAULT_ADDRESS.eval_to_array();
+    address[31] = 0;
+    Address::from(address)
+}
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new().enable_canonical_tx_hash();
+
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+    blockchain.register_contract(VAULT_PATH, vault::ContractBuilder);
+    blockchain.register_contract(FORWARDER_RAW_PATH, forwarder_raw::ContractBuilder);
+
+    blockchain.account(USER_ADDRESS).nonce(1);
+    blockchain.account(VAULT_ADDRESS).nonce(1).code(VAULT_PATH);
+    blockchain
+        .account(vault_address_other_shard())
+        .nonce(1)
+        .code(VAULT_PATH);
+    blockchain
+        .account(FORWARDER_RAW_ADDRESS)
+        .nonce(1)
+        .code(FORWARDER_RAW_PATH);
+
+    blockchain
+}
+
+fn echo_tx_hash(world: &mut ScenarioWorld) -> (ManagedByteArray<StaticApi, 32>, H256) {
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(VAULT_ADDRESS)
+        .typed(vault_proxy::VaultProxy)
+        .echo_tx_hash()
+        .returns(ReturnsResult)
+        .returns(ReturnsTxHash)
+        .run()
+}
+
+fn forward_async_echo_tx_hash(world: &mut ScenarioWorld, vault_address: &Address) -> H256 {
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(FORWARDER_RAW_ADDRESS)
+        .raw_call("forward_async_call")
+        .argument(vault_address)
+        .argument(&"echo_tx_hash")
+        .returns(ReturnsTxHash)
+        .run()
+}
+
+/// The last callback argument is the result of the async call.
+fn last_callback_arg(world: &mut ScenarioWorld) -> Vec<u8> {
+    let callback_args = world
+        .query()
+        .to(FORWARDER_RAW_ADDRESS)
+        .raw_call("callback_args_at_index")
+        .argument(&1usize)
+        .returns(ReturnsRawResult)
+        .run();
+    callback_args
+        .iter()
+        .next_back()
+        .expect("missing callback args")
+        .to_vec()
+}
+
+#[test]
+fn tx_hash_direct_call_test() {
+    let mut world = world();
+
+    let (echoed, tx_hash) = echo_tx_hash(&mut world);
+    assert_eq!(&echoed.to_byte_array(), tx_hash.as_array());
+    assert_ne!(tx_hash.as_array(), &[b'.'; 32]);
+
+    // the sender nonce is part of the hash
+    let (_, second_tx_hash) = echo_tx_hash(&mut world);
+    assert_ne!(tx_hash, second_tx_hash);
+}
+
+#[test]
+fn tx_hash_canonical_disabled_test() {
+    let mut world = ScenarioWorld::new();
+    world.set_current_dir_from_workspace("contracts/feature-tests/composability");
+    world.register_contract(VAULT_PATH, vault::ContractBuilder);
+    world.account(USER_ADDRESS).nonce(1);
+    world.account(VAULT_ADDRESS).nonce(1).code(VAULT_PATH);
+
+    // without tx id, the hash is derived from the empty tx id
+    let (echoed, tx_hash) = echo_tx_hash(&mut world);
+    assert_eq!(tx_hash.as_array(), &[b'.'; 32]);
+    assert_eq!(&echoed.to_byte_array(), tx_hash.as_array());
+}
+
+#[test]
+fn tx_hash_deterministic_test() {
+    let (_, first) = echo_tx_hash(&mut world());
+    let (_, second) = echo_tx_hash(&mut world());
+    assert_eq!(first, second);
+}
+
+#[test]
+fn tx_hash_from_tx_id_test() {
+    let mut world = world();
+
+    let (echoed, tx_hash) = world
+        .tx()
+        .id("echo-tx-hash")
+        .from(USER_ADDRESS)
+        .to(VAULT_ADDRESS)
+        .typed(vault_proxy::VaultProxy)
+        .echo_tx_hash()
+        .returns(ReturnsResult)
+        .returns(ReturnsTxHash)
+        .run();
+
+    assert_eq!(tx_hash.as_array(), b"echo-tx-hash....................");
+    assert_eq!(&echoed.to_byte_array(), tx_hash.as_array());
+}
+
+#[test]
+fn tx_hash_sync_call_test() {
+    let mut world = world();
+
+    let (logs, tx_hash) = world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(FORWARDER_RAW_ADDRESS)
+        .raw_call("call_execute_on_dest_context")
+        .argument(&VAULT_ADDRESS)
+        .argument(&"echo_tx_hash")
+        .returns(ReturnsLogs)
+        .returns(ReturnsTxHash)
+        .run();
+
+    let sync_result_log = logs
+        .iter()
+        .find(|log| log.topics[0] == b"execute_on_dest_context_result")
+        .expect("missing sync call result log");
+
+    // the result is a nested-encoded list of buffers
+    let mut expected_data = 32u32.to_be_bytes().to_vec();
+    expected_data.extend_from_slice(tx_hash.as_bytes());
+    assert_eq!(sync_result_log.data, vec![expected_data]);
+}
+
+#[test]
+fn tx_hash_async_call_test() {
+    let mut world = world();
+
+    let tx_hash = forward_async_echo_tx_hash(&mut world, &VAULT_ADDRESS.to_address());
+    assert_eq!(last_callback_arg(&mut world), tx_hash.to_vec());
+}
+
+#[test]
+fn tx_hash_cross_shard_async_call_test() {
+    let mut world = world().enable_cross_shard(ShardConfig::THREE_SHARDS);
+
+    let tx_hash = forward_async_echo_tx_hash(&mut world, &vault_address_other_shard());
+    assert_eq!(world.cross_shard_messages().len(), 1);
+    world.deliver_all_cross_shard_messages();
+
+    // the call gets a new hash on the other shard, but the original tx hash is preserved
+    assert_eq!(last_callback_arg(&mut world), tx_hash.to_vec());
+}
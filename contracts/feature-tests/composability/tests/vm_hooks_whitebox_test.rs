@@ -0,0 +1,177 @@
+use multiversx_sc_scenario::{
+    imports::*,
+    multiversx_chain_vm::executor::{MemLength, MemPtr},
+};
+
+const USER_ADDRESS: TestAddress = TestAddress::new("user");
+const VAULT_ADDRESS: TestSCAddress = TestSCAddress::new("vault");
+const VAULT_PATH: MxscPath = MxscPath::new("../vault/output/vault.mxsc.json");
+const FORWARDER_RAW_ADDRESS: TestSCAddress = TestSCAddress::new("forwarder-raw");
+const FORWARDER_RAW_PATH: MxscPath =
+    MxscPath::new("../forwarder-raw/output/forwarder-raw.mxsc.json");
+
+const LOCKED_KEY: &[u8] = b"locked-key";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+    blockchain.register_contract(VAULT_PATH, vault::ContractBuilder);
+    blockchain.register_contract(FORWARDER_RAW_PATH, forwarder_raw::ContractBuilder);
+
+    blockchain.account(USER_ADDRESS).nonce(1);
+    blockchain.account(VAULT_ADDRESS).nonce(1).code(VAULT_PATH);
+    blockchain
+        .account(FORWARDER_RAW_ADDRESS)
+        .nonce(1)
+        .code(FORWARDER_RAW_PATH);
+
+    blockchain
+}
+
+fn set_storage_lock(lock_timestamp: i64) -> i32 {
+    DebugApi::api_impl().with_vm_hooks(|vh| {
+        vh.set_storage_lock(
+            LOCKED_KEY.as_ptr() as MemPtr,
+            LOCKED_KEY.len() as MemLength,
+            lock_timestamp,
+        )
+    })
+}
+
+fn get_storage_lock() -> i64 {
+    DebugApi::api_impl().with_vm_hooks(|vh| {
+        vh.get_storage_lock(LOCKED_KEY.as_ptr() as MemPtr, LOCKED_KEY.len() as MemLength)
+    })
+}
+
+fn is_storage_locked() -> bool {
+    DebugApi::api_impl().with_vm_hooks(|vh| {
+        vh.is_storage_locked(LOCKED_KEY.as_ptr() as MemPtr, LOCKED_KEY.len() as MemLength)
+    }) == 1
+}
+
+fn clear_storage_lock() -> i32 {
+    DebugApi::api_impl().with_vm_hooks(|vh| {
+        vh.clear_storage_lock(LOCKED_KEY.as_ptr() as MemPtr, LOCKED_KEY.len() as MemLength)
+    })
+}
+
+/// Calls the vault via the legacy `executeReadOnly` hook, with the argument lengths given explicitly.
+fn execute_read_only(function: &[u8], arguments: &[&[u8]], lengths: &[i32]) -> i32 {
+    let address = VAULT_ADDRESS.eval_to_array();
+    let lengths_bytes: Vec<u8> = lengths
+        .iter()
+        .flat_map(|length| length.to_le_bytes())
+        .collect();
+    let data = arguments.concat();
+    DebugApi::api_impl().with_vm_hooks(|vh| {
+        vh.execute_read_only(
+            5_000_000,
+            address.as_ptr() as MemPtr,
+            function.as_ptr() as MemPtr,
+            function.len() as MemLength,
+            lengths.len() as i32,
+            lengths_bytes.as_ptr() as MemPtr,
+            data.as_ptr() as MemPtr,
+        )
+    })
+}
+
+#[test]
+fn storage_lock_hooks_test() {
+    let mut world = world();
+    world.current_block().block_timestamp_seconds(100);
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(FORWARDER_RAW_ADDRESS)
+        .whitebox(forwarder_raw::contract_obj, |_| {
+            assert_eq!(get_storage_lock(), 0);
+            assert!(!is_storage_locked());
+
+            set_storage_lock(200);
+            assert_eq!(get_storage_lock(), 200);
+            assert!(is_storage_locked());
+        });
+
+    // the lock was saved under the protected key, not the key itself
+    world
+        .check_account(FORWARDER_RAW_ADDRESS)
+        .check_storage("str:ELRONDtimelocklocked-key", "200")
+        .check_storage("str:locked-key", "");
+
+    // still locked in a later block, until the timestamp is reached
+    world.current_block().block_timestamp_seconds(199);
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(FORWARDER_RAW_ADDRESS)
+        .whitebox(forwarder_raw::contract_obj, |_| {
+            assert!(is_storage_locked());
+        });
+
+    world.current_block().block_timestamp_seconds(200);
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(FORWARDER_RAW_ADDRESS)
+        .whitebox(forwarder_raw::contract_obj, |_| {
+            assert!(!is_storage_locked());
+            assert_eq!(get_storage_lock(), 200);
+
+            clear_storage_lock();
+            assert_eq!(get_storage_lock(), 0);
+        });
+}
+
+#[test]
+fn execute_read_only_hook_test() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(FORWARDER_RAW_ADDRESS)
+        .whitebox(forwarder_raw::contract_obj, |_| {
+            let result = execute_read_only(
+                b"echo_arguments_without_storage",
+                &[b"arg1", b"arg2"],
+                &[4, 4],
+            );
+            assert_eq!(result, 0);
+        });
+}
+
+#[test]
+fn execute_read_only_hook_storage_write_test() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(FORWARDER_RAW_ADDRESS)
+        .returns(ExpectError(10, "cannot write on read only mode"))
+        .whitebox(forwarder_raw::contract_obj, |_| {
+            execute_read_only(b"echo_arguments", &[b"arg1"], &[4]);
+        });
+
+    world
+        .check_account(VAULT_ADDRESS)
+        .check_storage("str:call_counts|nested:str:echo_arguments", "");
+}
+
+#[test]
+fn execute_read_only_hook_negative_length_test() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(FORWARDER_RAW_ADDRESS)
+        .returns(ExpectError(10, "argument out of range"))
+        .whitebox(forwarder_raw::contract_obj, |_| {
+            execute_read_only(b"echo_arguments_without_storage", &[b"arg1"], &[-1]);
+        });
+}
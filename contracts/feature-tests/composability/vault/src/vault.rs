@@ -50,6 +50,11 @@ pub trait Vault {
         self.blockchain().get_caller()
     }
 
+    #[endpoint]
+    fn echo_tx_hash(&self) -> ManagedByteArray<Self::Api, 32> {
+        self.blockchain().get_tx_hash()
+    }
+
     fn all_transfers_multi(&self) -> MultiValueEncoded<PaymentMultiValue> {
         self.call_value().all().clone().into_multi_value()
     }
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           16
+// Endpoints:                           17
 // Async Callback (empty):               1
-// Total number of exported functions:  19
+// Total number of exported functions:  20
 
 #![no_std]
 
@@ -23,6 +23,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         echo_arguments => echo_arguments
         echo_arguments_without_storage => echo_arguments_without_storage
         echo_caller => echo_caller
+        echo_tx_hash => echo_tx_hash
         accept_funds => accept_funds
         accept_funds_echo_payment => accept_funds_echo_payment
         accept_funds_single_esdt_transfer => accept_funds_single_esdt_transfer
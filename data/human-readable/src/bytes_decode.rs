@@ -0,0 +1,395 @@
+use std::error::Error;
+
+use multiversx_sc_scenario::{
+    multiversx_sc::abi::{
+        ContractAbi, EnumVariantDescription, StructFieldDescription, TypeContents,
+    },
+    num_bigint::BigInt,
+};
+
+use crate::{
+    AnyValue, DecodeError, EnumVariant, SingleValue, StructField, StructValue, UnknownTypeError,
+    ValuePathError,
+    bytes_encode::{decimal_number_type, fixed_bytes_len},
+    number::NumberType,
+    type_name::split_type_name,
+};
+
+/// Deserializes a top-encoded value, such as an endpoint argument or result, based on its ABI type.
+pub fn top_decode_any_value(
+    input: &[u8],
+    type_name: &str,
+    contract_abi: &ContractAbi,
+) -> Result<AnyValue, Box<dyn Error>> {
+    let (base_name, type_args) = split_type_name(type_name);
+    match (base_name, type_args.as_slice()) {
+        ("Option", [item_type]) => {
+            return match input.split_first() {
+                None => Ok(AnyValue::Option(None)),
+                Some((1, rest)) => {
+                    let value = nested_decode_exact(rest, item_type, contract_abi)?;
+                    Ok(AnyValue::Option(Some(Box::new(value))))
+                }
+                Some(_) => Err(Box::new(DecodeError("invalid option"))),
+            };
+        }
+        ("List", [item_type]) => {
+            let mut remaining = input;
+            let mut items = Vec::new();
+            while !remaining.is_empty() {
+                let item = nested_decode_any_value(&mut remaining, item_type, contract_abi)
+                    .map_err(|err| ValuePathError::at_index(items.len(), err))?;
+                items.push(item);
+            }
+            return Ok(AnyValue::List(items));
+        }
+        ("ManagedDecimal" | "ManagedDecimalSigned", [decimals]) if *decimals != "usize" => {
+            let value = top_decode_number(input, decimal_number_type(base_name))?;
+            return Ok(AnyValue::SingleValue(SingleValue::Decimal {
+                value,
+                decimals: decimals.parse()?,
+            }));
+        }
+        _ => {}
+    }
+
+    if let Some(number_type) = NumberType::from_type_name(type_name) {
+        let value = top_decode_number(input, number_type)?;
+        return Ok(number_type.to_any_value(value));
+    }
+
+    match type_name {
+        "bool" => {
+            return match input {
+                [] => Ok(AnyValue::SingleValue(SingleValue::Bool(false))),
+                [1] => Ok(AnyValue::SingleValue(SingleValue::Bool(true))),
+                _ => Err(Box::new(DecodeError("invalid bool"))),
+            };
+        }
+        "ManagedBuffer"
+        | "bytes"
+        | "string"
+        | "utf-8 string"
+        | "TokenIdentifier"
+        | "EgldOrEsdtTokenIdentifier"
+        | "TokenId" => {
+            return bytes_value(input, type_name);
+        }
+        _ => {}
+    }
+
+    let type_description = contract_abi.type_descriptions.find_or_default(type_name);
+    match &type_description.contents {
+        // the first variant is top-encoded as nothing, if empty
+        TypeContents::Enum(variants) if input.is_empty() => {
+            let variant = find_variant(variants, 0)?;
+            if !variant.is_empty_variant() {
+                return Err(Box::new(DecodeError("input too short")));
+            }
+            return Ok(AnyValue::Enum(Box::new(EnumVariant {
+                discriminant: 0,
+                value: AnyValue::None,
+            })));
+        }
+        // explicit enums are encoded as the variant name
+        TypeContents::ExplicitEnum(variants) => {
+            let discriminant = variants
+                .iter()
+                .position(|variant| variant.name.as_bytes() == input)
+                .ok_or_else(|| Box::new(DecodeError("enum variant not found")))?;
+            return Ok(AnyValue::Enum(Box::new(EnumVariant {
+                discriminant,
+                value: AnyValue::None,
+            })));
+        }
+        _ => {}
+    }
+
+    nested_decode_exact(input, type_name, contract_abi)
+}
+
+fn nested_decode_exact(
+    input: &[u8],
+    type_name: &str,
+    contract_abi: &ContractAbi,
+) -> Result<AnyValue, Box<dyn Error>> {
+    let mut remaining = input;
+    let value = nested_decode_any_value(&mut remaining, type_name, contract_abi)?;
+    if !remaining.is_empty() {
+        return Err(Box::new(DecodeError("input too long")));
+    }
+    Ok(value)
+}
+
+/// Deserializes a value that is part of a larger structure, consuming only the bytes it needs.
+pub fn nested_decode_any_value(
+    input: &mut &[u8],
+    type_name: &str,
+    contract_abi: &ContractAbi,
+) -> Result<AnyValue, Box<dyn Error>> {
+    let (base_name, type_args) = split_type_name(type_name);
+    match (base_name, type_args.as_slice()) {
+        ("Option", [item_type]) => match take(input, 1)? {
+            [0] => Ok(AnyValue::Option(None)),
+            [1] => {
+                let value = nested_decode_any_value(input, item_type, contract_abi)?;
+                Ok(AnyValue::Option(Some(Box::new(value))))
+            }
+            _ => Err(Box::new(DecodeError("invalid option"))),
+        },
+        ("List", [item_type]) => {
+            // the length comes from the input, so no preallocation
+            let len = take_u32(input)?;
+            let mut items = Vec::new();
+            for index in 0..len {
+                let item = nested_decode_any_value(input, item_type, contract_abi)
+                    .map_err(|err| ValuePathError::at_index(index, err))?;
+                items.push(item);
+            }
+            Ok(AnyValue::List(items))
+        }
+        ("array", [size, item_type]) => {
+            let size = size.parse::<usize>()?;
+            nested_decode_items(input, &vec![*item_type; size], contract_abi)
+        }
+        ("tuple", item_types) => nested_decode_items(input, item_types, contract_abi),
+        ("ManagedDecimal" | "ManagedDecimalSigned", [decimals]) => {
+            let value = nested_decode_number(input, decimal_number_type(base_name))?;
+            let decimals = if *decimals == "usize" {
+                take_u32(input)?
+            } else {
+                decimals.parse()?
+            };
+            Ok(AnyValue::SingleValue(SingleValue::Decimal {
+                value,
+                decimals,
+            }))
+        }
+        _ => nested_decode_single_or_described(input, type_name, contract_abi),
+    }
+}
+
+fn nested_decode_single_or_described(
+    input: &mut &[u8],
+    type_name: &str,
+    contract_abi: &ContractAbi,
+) -> Result<AnyValue, Box<dyn Error>> {
+    if let Some(number_type) = NumberType::from_type_name(type_name) {
+        let value = nested_decode_number(input, number_type)?;
+        return Ok(number_type.to_any_value(value));
+    }
+
+    match type_name {
+        "bool" => {
+            return match take(input, 1)? {
+                [0] => Ok(AnyValue::SingleValue(SingleValue::Bool(false))),
+                [1] => Ok(AnyValue::SingleValue(SingleValue::Bool(true))),
+                _ => Err(Box::new(DecodeError("invalid bool"))),
+            };
+        }
+        "ManagedBuffer"
+        | "bytes"
+        | "string"
+        | "utf-8 string"
+        | "TokenIdentifier"
+        | "EgldOrEsdtTokenIdentifier"
+        | "TokenId" => {
+            let len = take_u32(input)?;
+            return bytes_value(take(input, len)?, type_name);
+        }
+        "Address" | "H256" | "CodeMetadata" => {
+            let bytes = take(input, fixed_bytes_len(type_name))?;
+            return Ok(AnyValue::SingleValue(SingleValue::Bytes(bytes.into())));
+        }
+        _ => {}
+    }
+
+    let type_description = contract_abi.type_descriptions.find_or_default(type_name);
+    match &type_description.contents {
+        TypeContents::Struct(fields) => nested_decode_fields(input, fields, contract_abi),
+        TypeContents::Enum(variants) => {
+            let discriminant = take(input, 1)?[0] as usize;
+            let variant = find_variant(variants, discriminant)?;
+            let value = nested_decode_variant_value(input, variant, contract_abi)
+                .map_err(|err| ValuePathError::at_field(&variant.name, err))?;
+            Ok(AnyValue::Enum(Box::new(EnumVariant {
+                discriminant,
+                value,
+            })))
+        }
+        TypeContents::ExplicitEnum(_) => Err(Box::new(DecodeError(
+            "explicit enums can only be top-decoded",
+        ))),
+        TypeContents::NotSpecified => Err(Box::new(UnknownTypeError(type_name.to_owned()))),
+    }
+}
+
+fn nested_decode_variant_value(
+    input: &mut &[u8],
+    variant: &EnumVariantDescription,
+    contract_abi: &ContractAbi,
+) -> Result<AnyValue, Box<dyn Error>> {
+    if variant.is_empty_variant() {
+        return Ok(AnyValue::None);
+    }
+
+    // tuple variants with only one field hold the value directly
+    if variant.is_tuple_variant() && variant.fields.len() == 1 {
+        return nested_decode_any_value(input, &variant.fields[0].field_type.abi, contract_abi);
+    }
+
+    nested_decode_fields(input, &variant.fields, contract_abi)
+}
+
+fn nested_decode_fields(
+    input: &mut &[u8],
+    fields: &[StructFieldDescription],
+    contract_abi: &ContractAbi,
+) -> Result<AnyValue, Box<dyn Error>> {
+    let mut field_values = Vec::new();
+    for field in fields {
+        let value = nested_decode_any_value(input, &field.field_type.abi, contract_abi)
+            .map_err(|err| ValuePathError::at_field(&field.name, err))?;
+        field_values.push(StructField {
+            name: field.name.clone(),
+            value,
+        });
+    }
+    Ok(AnyValue::Struct(StructValue(field_values)))
+}
+
+fn nested_decode_items(
+    input: &mut &[u8],
+    item_types: &[&str],
+    contract_abi: &ContractAbi,
+) -> Result<AnyValue, Box<dyn Error>> {
+    let items = item_types
+        .iter()
+        .enumerate()
+        .map(|(index, item_type)| {
+            nested_decode_any_value(input, item_type, contract_abi)
+                .map_err(|err| ValuePathError::at_index(index, err))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(AnyValue::List(items))
+}
+
+/// Deserializes a value from a list of arguments, such as the results of an endpoint.
+///
+/// The multi-values (`multi`, `variadic`, `counted-variadic`, `optional`) consume as many arguments as they need,
+/// `variadic` taking all remaining ones.
+pub fn top_decode_multi_value(
+    input: &mut &[Vec<u8>],
+    type_name: &str,
+    contract_abi: &ContractAbi,
+) -> Result<AnyValue, Box<dyn Error>> {
+    let (base_name, type_args) = split_type_name(type_name);
+    match (base_name, type_args.as_slice()) {
+        ("multi", item_types) => top_decode_multi_items(input, item_types, contract_abi),
+        ("variadic", [item_type]) => {
+            let mut items = Vec::new();
+            while !input.is_empty() {
+                let item = top_decode_multi_value(input, item_type, contract_abi)
+                    .map_err(|err| ValuePathError::at_index(items.len(), err))?;
+                items.push(item);
+            }
+            Ok(AnyValue::List(items))
+        }
+        ("counted-variadic", [item_type]) => {
+            let count = next_arg(input)?;
+            let count = top_decode_number(count, NumberType::from_type_name("usize").unwrap())?;
+            let count = usize::try_from(&count)?;
+            if count > input.len() {
+                return Err(Box::new(DecodeError("not enough arguments")));
+            }
+            top_decode_multi_items(input, &vec![*item_type; count], contract_abi)
+        }
+        ("optional", [item_type]) => {
+            if input.is_empty() {
+                return Ok(AnyValue::Option(None));
+            }
+            let value = top_decode_multi_value(input, item_type, contract_abi)?;
+            Ok(AnyValue::Option(Some(Box::new(value))))
+        }
+        ("ignore", []) => Ok(AnyValue::None),
+        _ => top_decode_any_value(next_arg(input)?, type_name, contract_abi),
+    }
+}
+
+fn top_decode_multi_items(
+    input: &mut &[Vec<u8>],
+    item_types: &[&str],
+    contract_abi: &ContractAbi,
+) -> Result<AnyValue, Box<dyn Error>> {
+    let items = item_types
+        .iter()
+        .enumerate()
+        .map(|(index, item_type)| {
+            top_decode_multi_value(input, item_type, contract_abi)
+                .map_err(|err| ValuePathError::at_index(index, err))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(AnyValue::List(items))
+}
+
+fn next_arg<'a>(input: &mut &'a [Vec<u8>]) -> Result<&'a [u8], Box<dyn Error>> {
+    let (arg, rest) = input
+        .split_first()
+        .ok_or_else(|| Box::new(DecodeError("not enough arguments")))?;
+    *input = rest;
+    Ok(arg)
+}
+
+fn top_decode_number(input: &[u8], number_type: NumberType) -> Result<BigInt, Box<dyn Error>> {
+    let value = number_type
+        .top_decode(input)
+        .ok_or_else(|| Box::new(DecodeError("input too long")))?;
+    if !number_type.contains(&value) {
+        return Err(Box::new(DecodeError("number out of range")));
+    }
+    Ok(value)
+}
+
+fn nested_decode_number(
+    input: &mut &[u8],
+    number_type: NumberType,
+) -> Result<BigInt, Box<dyn Error>> {
+    let len = match number_type.size {
+        Some(size) => size,
+        None => take_u32(input)?,
+    };
+    top_decode_number(take(input, len)?, number_type)
+}
+
+fn bytes_value(input: &[u8], type_name: &str) -> Result<AnyValue, Box<dyn Error>> {
+    if matches!(type_name, "ManagedBuffer" | "bytes") {
+        return Ok(AnyValue::SingleValue(SingleValue::Bytes(input.into())));
+    }
+    let value = String::from_utf8(input.to_vec())
+        .map_err(|_| Box::new(DecodeError("invalid utf-8 string")))?;
+    Ok(AnyValue::SingleValue(SingleValue::String(value)))
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], Box<dyn Error>> {
+    if input.len() < len {
+        return Err(Box::new(DecodeError("input too short")));
+    }
+    let (taken, rest) = input.split_at(len);
+    *input = rest;
+    Ok(taken)
+}
+
+fn take_u32(input: &mut &[u8]) -> Result<usize, Box<dyn Error>> {
+    let bytes: [u8; 4] = take(input, 4)?.try_into()?;
+    Ok(u32::from_be_bytes(bytes) as usize)
+}
+
+fn find_variant(
+    variants: &[EnumVariantDescription],
+    discriminant: usize,
+) -> Result<&EnumVariantDescription, Box<dyn Error>> {
+    variants
+        .iter()
+        .find(|v| v.discriminant == discriminant)
+        .ok_or_else(|| Box::new(DecodeError("unknown enum discriminant")).into())
+}
@@ -0,0 +1,409 @@
+use std::error::Error;
+
+use multiversx_sc_scenario::{
+    multiversx_sc::abi::{
+        ContractAbi, EnumVariantDescription, StructFieldDescription, TypeContents,
+    },
+    num_bigint::BigInt,
+};
+
+use crate::{
+    AnyValue, EncodeError, SingleValue, StructValue, UnknownTypeError, ValuePathError,
+    number::{NumberType, number_from_any_value},
+    type_name::split_type_name,
+};
+
+/// Serializes a value to its top-encoded bytes, as a contract would, based on its ABI type.
+pub fn top_encode_any_value(
+    input: &AnyValue,
+    type_name: &str,
+    contract_abi: &ContractAbi,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    let (base_name, type_args) = split_type_name(type_name);
+    match (base_name, type_args.as_slice()) {
+        ("Option", [_]) if matches!(input, AnyValue::Option(None)) => return Ok(Vec::new()),
+        ("List", [item_type]) => {
+            let mut dest = Vec::new();
+            for (index, item) in expect_list(input)?.iter().enumerate() {
+                nested_encode_any_value(item, item_type, contract_abi, &mut dest)
+                    .map_err(|err| ValuePathError::at_index(index, err))?;
+            }
+            return Ok(dest);
+        }
+        ("ManagedDecimal" | "ManagedDecimalSigned", [decimals]) if *decimals != "usize" => {
+            let (value, _) = expect_decimal(input, base_name, decimals)?;
+            return Ok(decimal_number_type(base_name).top_encode(value));
+        }
+        _ => {}
+    }
+
+    if let Some(number_type) = NumberType::from_type_name(type_name) {
+        let value = expect_number(input, number_type)?;
+        return Ok(number_type.top_encode(&value));
+    }
+
+    match (type_name, input) {
+        ("bool", AnyValue::SingleValue(SingleValue::Bool(value))) => {
+            return Ok(if *value { vec![1] } else { Vec::new() });
+        }
+        (
+            "ManagedBuffer"
+            | "bytes"
+            | "string"
+            | "utf-8 string"
+            | "TokenIdentifier"
+            | "EgldOrEsdtTokenIdentifier"
+            | "TokenId",
+            AnyValue::SingleValue(SingleValue::Bytes(bytes)),
+        ) => return Ok(bytes.to_vec()),
+        (
+            "ManagedBuffer"
+            | "bytes"
+            | "string"
+            | "utf-8 string"
+            | "TokenIdentifier"
+            | "EgldOrEsdtTokenIdentifier"
+            | "TokenId",
+            AnyValue::SingleValue(SingleValue::String(value)),
+        ) => return Ok(value.as_bytes().to_vec()),
+        _ => {}
+    }
+
+    let type_description = contract_abi.type_descriptions.find_or_default(type_name);
+    match &type_description.contents {
+        // empty variants are top-encoded as their discriminant alone, i.e. nothing for the first one
+        TypeContents::Enum(variants) => {
+            let AnyValue::Enum(enum_value) = input else {
+                return Err(Box::new(EncodeError("expected enum value")));
+            };
+            let variant = find_variant(variants, enum_value.discriminant)?;
+            if variant.is_empty_variant() {
+                return Ok(NumberType::from_type_name("u8")
+                    .unwrap()
+                    .top_encode(&variant.discriminant.into()));
+            }
+        }
+        // explicit enums are encoded as the variant name
+        TypeContents::ExplicitEnum(variants) => {
+            let AnyValue::Enum(enum_value) = input else {
+                return Err(Box::new(EncodeError("expected enum value")));
+            };
+            let variant = variants
+                .get(enum_value.discriminant)
+                .ok_or_else(|| Box::new(EncodeError("missing variant")))?;
+            return Ok(variant.name.as_bytes().to_vec());
+        }
+        _ => {}
+    }
+
+    let mut dest = Vec::new();
+    nested_encode_any_value(input, type_name, contract_abi, &mut dest)?;
+    Ok(dest)
+}
+
+/// Serializes a value as part of a larger structure, e.g. as a struct field or a list item.
+pub fn nested_encode_any_value(
+    input: &AnyValue,
+    type_name: &str,
+    contract_abi: &ContractAbi,
+    dest: &mut Vec<u8>,
+) -> Result<(), Box<dyn Error>> {
+    let (base_name, type_args) = split_type_name(type_name);
+    match (base_name, type_args.as_slice()) {
+        ("Option", [item_type]) => {
+            let AnyValue::Option(value) = input else {
+                return Err(Box::new(EncodeError("expected option value")));
+            };
+            match value {
+                Some(value) => {
+                    dest.push(1);
+                    nested_encode_any_value(value, item_type, contract_abi, dest)
+                }
+                None => {
+                    dest.push(0);
+                    Ok(())
+                }
+            }
+        }
+        ("List", [item_type]) => {
+            let items = expect_list(input)?;
+            dest.extend_from_slice(&(items.len() as u32).to_be_bytes());
+            nested_encode_items(items, &vec![*item_type; items.len()], contract_abi, dest)
+        }
+        ("array", [size, item_type]) => {
+            let items = expect_list(input)?;
+            if size.parse::<usize>()? != items.len() {
+                return Err(Box::new(EncodeError("wrong number of array items")));
+            }
+            nested_encode_items(items, &vec![*item_type; items.len()], contract_abi, dest)
+        }
+        ("tuple", item_types) => {
+            let items = expect_list(input)?;
+            if item_types.len() != items.len() {
+                return Err(Box::new(EncodeError("wrong number of tuple items")));
+            }
+            nested_encode_items(items, item_types, contract_abi, dest)
+        }
+        ("ManagedDecimal" | "ManagedDecimalSigned", [decimals]) => {
+            let (value, value_decimals) = expect_decimal(input, base_name, decimals)?;
+            decimal_number_type(base_name).nested_encode(value, dest);
+            if *decimals == "usize" {
+                dest.extend_from_slice(&(value_decimals as u32).to_be_bytes());
+            }
+            Ok(())
+        }
+        _ => nested_encode_single_or_described(input, type_name, contract_abi, dest),
+    }
+}
+
+fn nested_encode_single_or_described(
+    input: &AnyValue,
+    type_name: &str,
+    contract_abi: &ContractAbi,
+    dest: &mut Vec<u8>,
+) -> Result<(), Box<dyn Error>> {
+    if let Some(number_type) = NumberType::from_type_name(type_name) {
+        let value = expect_number(input, number_type)?;
+        number_type.nested_encode(&value, dest);
+        return Ok(());
+    }
+
+    match type_name {
+        "bool" => {
+            let AnyValue::SingleValue(SingleValue::Bool(value)) = input else {
+                return Err(Box::new(EncodeError("expected bool value")));
+            };
+            dest.push(*value as u8);
+            return Ok(());
+        }
+        "ManagedBuffer"
+        | "bytes"
+        | "string"
+        | "utf-8 string"
+        | "TokenIdentifier"
+        | "EgldOrEsdtTokenIdentifier"
+        | "TokenId" => {
+            let bytes: &[u8] = match input {
+                AnyValue::SingleValue(SingleValue::Bytes(bytes)) => bytes,
+                AnyValue::SingleValue(SingleValue::String(value)) => value.as_bytes(),
+                _ => return Err(Box::new(EncodeError("expected bytes value"))),
+            };
+            dest.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+            dest.extend_from_slice(bytes);
+            return Ok(());
+        }
+        "Address" | "H256" | "CodeMetadata" => {
+            let AnyValue::SingleValue(SingleValue::Bytes(bytes)) = input else {
+                return Err(Box::new(EncodeError("expected bytes value")));
+            };
+            if bytes.len() != fixed_bytes_len(type_name) {
+                return Err(Box::new(EncodeError("wrong number of bytes")));
+            }
+            dest.extend_from_slice(bytes);
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    let type_description = contract_abi.type_descriptions.find_or_default(type_name);
+    match &type_description.contents {
+        TypeContents::Struct(fields) => {
+            let AnyValue::Struct(struct_value) = input else {
+                return Err(Box::new(EncodeError("expected struct value")));
+            };
+            nested_encode_fields(struct_value, fields, contract_abi, dest)
+        }
+        TypeContents::Enum(variants) => {
+            let AnyValue::Enum(enum_value) = input else {
+                return Err(Box::new(EncodeError("expected enum value")));
+            };
+            let variant = find_variant(variants, enum_value.discriminant)?;
+            dest.push(variant.discriminant as u8);
+            nested_encode_variant_value(&enum_value.value, variant, contract_abi, dest)
+                .map_err(|err| ValuePathError::at_field(&variant.name, err))
+        }
+        TypeContents::ExplicitEnum(_) => Err(Box::new(EncodeError(
+            "explicit enums can only be top-encoded",
+        ))),
+        TypeContents::NotSpecified => Err(Box::new(UnknownTypeError(type_name.to_owned()))),
+    }
+}
+
+fn nested_encode_variant_value(
+    value: &AnyValue,
+    variant: &EnumVariantDescription,
+    contract_abi: &ContractAbi,
+    dest: &mut Vec<u8>,
+) -> Result<(), Box<dyn Error>> {
+    if variant.is_empty_variant() {
+        return Ok(());
+    }
+
+    // tuple variants with only one field hold the value directly
+    if variant.is_tuple_variant() && variant.fields.len() == 1 {
+        return nested_encode_any_value(
+            value,
+            &variant.fields[0].field_type.abi,
+            contract_abi,
+            dest,
+        );
+    }
+
+    let AnyValue::Struct(struct_value) = value else {
+        return Err(Box::new(EncodeError("expected struct value")));
+    };
+    nested_encode_fields(struct_value, &variant.fields, contract_abi, dest)
+}
+
+fn nested_encode_fields(
+    struct_value: &StructValue,
+    fields: &[StructFieldDescription],
+    contract_abi: &ContractAbi,
+    dest: &mut Vec<u8>,
+) -> Result<(), Box<dyn Error>> {
+    for field in fields {
+        let value = struct_value
+            .0
+            .iter()
+            .find(|f| f.name == field.name)
+            .ok_or_else(|| {
+                ValuePathError::at_field(&field.name, Box::new(EncodeError("missing field")))
+            })?;
+        nested_encode_any_value(&value.value, &field.field_type.abi, contract_abi, dest)
+            .map_err(|err| ValuePathError::at_field(&field.name, err))?;
+    }
+    Ok(())
+}
+
+fn nested_encode_items(
+    items: &[AnyValue],
+    item_types: &[&str],
+    contract_abi: &ContractAbi,
+    dest: &mut Vec<u8>,
+) -> Result<(), Box<dyn Error>> {
+    for (index, (item, item_type)) in items.iter().zip(item_types).enumerate() {
+        nested_encode_any_value(item, item_type, contract_abi, dest)
+            .map_err(|err| ValuePathError::at_index(index, err))?;
+    }
+    Ok(())
+}
+
+/// Serializes a value to a list of arguments, flattening the multi-values
+/// (`multi`, `variadic`, `counted-variadic`, `optional`) the same way endpoints expect them.
+pub fn top_encode_multi_value(
+    input: &AnyValue,
+    type_name: &str,
+    contract_abi: &ContractAbi,
+) -> Result<Vec<Vec<u8>>, Box<dyn Error>> {
+    let mut args = Vec::new();
+    top_encode_multi_value_to(input, type_name, contract_abi, &mut args)?;
+    Ok(args)
+}
+
+fn top_encode_multi_value_to(
+    input: &AnyValue,
+    type_name: &str,
+    contract_abi: &ContractAbi,
+    args: &mut Vec<Vec<u8>>,
+) -> Result<(), Box<dyn Error>> {
+    let (base_name, type_args) = split_type_name(type_name);
+    let (items, item_types) = match (base_name, type_args.as_slice()) {
+        ("multi", item_types) => {
+            let items = expect_list(input)?;
+            if item_types.len() != items.len() {
+                return Err(Box::new(EncodeError("wrong number of multi-value items")));
+            }
+            (items, item_types.to_vec())
+        }
+        ("variadic", [item_type]) => {
+            let items = expect_list(input)?;
+            (items, vec![*item_type; items.len()])
+        }
+        ("counted-variadic", [item_type]) => {
+            let items = expect_list(input)?;
+            args.push(
+                NumberType::from_type_name("usize")
+                    .unwrap()
+                    .top_encode(&items.len().into()),
+            );
+            (items, vec![*item_type; items.len()])
+        }
+        ("optional", [item_type]) => {
+            let AnyValue::Option(value) = input else {
+                return Err(Box::new(EncodeError("expected option value")));
+            };
+            if let Some(value) = value {
+                top_encode_multi_value_to(value, item_type, contract_abi, args)?;
+            }
+            return Ok(());
+        }
+        ("ignore", []) => return Ok(()),
+        _ => {
+            args.push(top_encode_any_value(input, type_name, contract_abi)?);
+            return Ok(());
+        }
+    };
+
+    for (index, (item, item_type)) in items.iter().zip(item_types).enumerate() {
+        top_encode_multi_value_to(item, item_type, contract_abi, args)
+            .map_err(|err| ValuePathError::at_index(index, err))?;
+    }
+    Ok(())
+}
+
+fn expect_list(input: &AnyValue) -> Result<&Vec<AnyValue>, Box<dyn Error>> {
+    let AnyValue::List(items) = input else {
+        return Err(Box::new(EncodeError("expected list value")));
+    };
+    Ok(items)
+}
+
+fn expect_number(input: &AnyValue, number_type: NumberType) -> Result<BigInt, Box<dyn Error>> {
+    let value = number_from_any_value(input)
+        .ok_or_else(|| Box::new(EncodeError("expected number value")))?;
+    if !number_type.contains(&value) {
+        return Err(Box::new(EncodeError("number out of range")));
+    }
+    Ok(value)
+}
+
+/// Checks the decimal value against the ABI type, which either has a fixed number of decimals, or `usize`.
+fn expect_decimal<'a>(
+    input: &'a AnyValue,
+    base_name: &str,
+    decimals_type: &str,
+) -> Result<(&'a BigInt, usize), Box<dyn Error>> {
+    let AnyValue::SingleValue(SingleValue::Decimal { value, decimals }) = input else {
+        return Err(Box::new(EncodeError("expected decimal value")));
+    };
+    if decimals_type != "usize" && decimals_type.parse::<usize>()? != *decimals {
+        return Err(Box::new(EncodeError("wrong number of decimals")));
+    }
+    if !decimal_number_type(base_name).contains(value) {
+        return Err(Box::new(EncodeError("expected unsigned decimal value")));
+    }
+    Ok((value, *decimals))
+}
+
+pub(crate) fn decimal_number_type(base_name: &str) -> NumberType {
+    let number_type_name = if base_name == "ManagedDecimalSigned" {
+        "BigInt"
+    } else {
+        "BigUint"
+    };
+    NumberType::from_type_name(number_type_name).unwrap()
+}
+
+pub(crate) fn fixed_bytes_len(type_name: &str) -> usize {
+    if type_name == "CodeMetadata" { 2 } else { 32 }
+}
+
+fn find_variant(
+    variants: &[EnumVariantDescription],
+    discriminant: usize,
+) -> Result<&EnumVariantDescription, Box<dyn Error>> {
+    variants
+        .iter()
+        .find(|v| v.discriminant == discriminant)
+        .ok_or_else(|| Box::new(EncodeError("missing variant")).into())
+}
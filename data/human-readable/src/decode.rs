@@ -1,14 +1,16 @@
 use std::{error::Error, fmt::Display};
 
 use crate::{
-    SingleValue, StructField, StructValue,
-    format::HumanReadableValue,
-    multiversx_sc::abi::{TypeContents, TypeDescription},
+    SingleValue, StructField, StructValue, UnknownTypeError, ValuePathError,
+    format::{HumanReadableValue, JsonValue},
+    multiversx_sc::abi::{ExplicitEnumVariantDescription, TypeContents, TypeDescription},
+    number::{NumberType, parse_decimal, rescale_decimal},
+    type_name::split_type_name,
 };
 use multiversx_sc_scenario::{
     imports::Bech32Address,
     multiversx_sc::abi::{ContractAbi, EnumVariantDescription, StructFieldDescription},
-    num_bigint::{BigInt, BigUint},
+    num_bigint::BigInt,
 };
 
 use crate::AnyValue;
@@ -29,38 +31,93 @@ pub fn decode_any_value(
 ) -> Result<AnyValue, Box<dyn Error>> {
     match &type_description.contents {
         TypeContents::NotSpecified => {
-            decode_single_value(input, type_description.names.abi.as_str())
+            decode_unspecified(input, type_description.names.abi.as_str(), contract_abi)
         }
         TypeContents::Enum(variants) => decode_enum(input, variants, contract_abi),
         TypeContents::Struct(fields) => decode_struct(input, fields, contract_abi),
-        TypeContents::ExplicitEnum(_) => panic!("not supported"),
+        TypeContents::ExplicitEnum(variants) => decode_explicit_enum(input, variants),
     }
 }
 
-fn decode_single_value(
+/// Types not described in the ABI: the generic types and the single values.
+fn decode_unspecified(
     input: &HumanReadableValue,
     type_name: &str,
+    contract_abi: &ContractAbi,
 ) -> Result<AnyValue, Box<dyn Error>> {
-    match type_name {
-        "BigUint" | "u64" | "u32" | "u16" | "usize" | "u8" => {
-            let number_value = input
-                .get_value()
-                .as_number()
-                .ok_or_else(|| Box::new(DecodeError("expected unsigned number value")))?;
-
-            let value = number_value.to_string().parse::<BigUint>()?;
-            Ok(AnyValue::SingleValue(SingleValue::UnsignedNumber(value)))
+    let (base_name, type_args) = split_type_name(type_name);
+    match (base_name, type_args.as_slice()) {
+        ("Option" | "optional", [item_type]) => {
+            if input.get_value().is_null() {
+                return Ok(AnyValue::Option(None));
+            }
+            let value = decode_human_readable_value(input, item_type, contract_abi)?;
+            Ok(AnyValue::Option(Some(Box::new(value))))
         }
-        "BigInt" | "i64" | "i32" | "i16" | "isize" | "i8" => {
-            let number_value = input
-                .get_value()
-                .as_number()
-                .ok_or_else(|| Box::new(DecodeError("expected number value")))?;
+        ("List" | "variadic" | "counted-variadic", [item_type]) => {
+            let items = expect_array(input)?;
+            decode_items(items, &vec![*item_type; items.len()], contract_abi)
+        }
+        ("array", [size, item_type]) => {
+            let items = expect_array(input)?;
+            if size.parse::<usize>()? != items.len() {
+                return Err(Box::new(DecodeError("wrong number of array items")));
+            }
+            decode_items(items, &vec![*item_type; items.len()], contract_abi)
+        }
+        ("tuple" | "multi", item_types) => {
+            let items = expect_array(input)?;
+            if item_types.len() != items.len() {
+                return Err(Box::new(DecodeError("wrong number of tuple items")));
+            }
+            decode_items(items, item_types, contract_abi)
+        }
+        ("ManagedDecimal" | "ManagedDecimalSigned", [decimals]) => {
+            decode_decimal(input, base_name == "ManagedDecimalSigned", decimals)
+        }
+        ("ignore", []) => Ok(AnyValue::None),
+        _ => decode_single_value(input, type_name),
+    }
+}
+
+fn expect_array(input: &HumanReadableValue) -> Result<&Vec<JsonValue>, Box<dyn Error>> {
+    input
+        .get_value()
+        .as_array()
+        .ok_or_else(|| Box::new(DecodeError("expected array value")).into())
+}
+
+fn decode_items(
+    items: &[JsonValue],
+    item_types: &[&str],
+    contract_abi: &ContractAbi,
+) -> Result<AnyValue, Box<dyn Error>> {
+    let values = items
+        .iter()
+        .zip(item_types)
+        .enumerate()
+        .map(|(index, (item, item_type))| {
+            decode_human_readable_value(&item.clone().into(), item_type, contract_abi)
+                .map_err(|err| ValuePathError::at_index(index, err))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(AnyValue::List(values))
+}
 
-            let value = number_value.to_string().parse::<BigInt>()?;
-            Ok(AnyValue::SingleValue(SingleValue::SignedNumber(value)))
+fn decode_single_value(
+    input: &HumanReadableValue,
+    type_name: &str,
+) -> Result<AnyValue, Box<dyn Error>> {
+    if let Some(number_type) = NumberType::from_type_name(type_name) {
+        let value = decode_number(input)?;
+        if !number_type.contains(&value) {
+            return Err(Box::new(DecodeError("number out of range")));
         }
-        "ManagedBuffer" => {
+        return Ok(number_type.to_any_value(value));
+    }
+
+    match type_name {
+        "ManagedBuffer" | "bytes" => {
             let array_value = input
                 .get_value()
                 .as_array()
@@ -79,7 +136,7 @@ fn decode_single_value(
 
             Ok(AnyValue::SingleValue(SingleValue::Bytes(bytes.into())))
         }
-        "string" | "utf-8 string" => {
+        "string" | "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" | "TokenId" => {
             let str_value = input
                 .get_value()
                 .as_str()
@@ -95,12 +152,28 @@ fn decode_single_value(
                 .as_str()
                 .ok_or_else(|| Box::new(DecodeError("expected string value")))?;
 
-            let address = Bech32Address::from_bech32_string(str_value.to_owned());
+            let address = Bech32Address::try_from_bech32_string(str_value.to_owned())
+                .map_err(|_| Box::new(DecodeError("invalid bech32 address")))?;
 
             Ok(AnyValue::SingleValue(SingleValue::Bytes(
                 address.address.as_bytes().into(),
             )))
         }
+        "H256" | "CodeMetadata" => {
+            let str_value = input
+                .get_value()
+                .as_str()
+                .ok_or_else(|| Box::new(DecodeError("expected hex string value")))?;
+
+            let bytes = hex::decode(str_value.trim_start_matches("0x"))
+                .map_err(|_| Box::new(DecodeError("invalid hex string")))?;
+            let expected_len = if type_name == "H256" { 32 } else { 2 };
+            if bytes.len() != expected_len {
+                return Err(Box::new(DecodeError("wrong number of bytes")));
+            }
+
+            Ok(AnyValue::SingleValue(SingleValue::Bytes(bytes.into())))
+        }
         "bool" => {
             let bool_value = input
                 .get_value()
@@ -109,8 +182,52 @@ fn decode_single_value(
 
             Ok(AnyValue::SingleValue(SingleValue::Bool(bool_value)))
         }
-        _ => Err(Box::new(DecodeError("unknown type"))),
+        _ => Err(Box::new(UnknownTypeError(type_name.to_owned()))),
+    }
+}
+
+/// Numbers are accepted both as JSON numbers and as strings, since large values don't fit in a JSON number.
+fn decode_number(input: &HumanReadableValue) -> Result<BigInt, Box<dyn Error>> {
+    let number_str = match input.get_value() {
+        JsonValue::Number(number) => number.to_string(),
+        JsonValue::String(number) => number.clone(),
+        _ => return Err(Box::new(DecodeError("expected number value"))),
+    };
+    number_str
+        .parse::<BigInt>()
+        .map_err(|_| Box::new(DecodeError("invalid number")).into())
+}
+
+/// Decimals are given as numbers or strings, e.g. `"12.345"`.
+///
+/// With a fixed number of decimals, the value gets scaled to it; otherwise the number of decimals is the one in the input.
+fn decode_decimal(
+    input: &HumanReadableValue,
+    signed: bool,
+    decimals: &str,
+) -> Result<AnyValue, Box<dyn Error>> {
+    let decimal_str = match input.get_value() {
+        JsonValue::Number(number) => number.to_string(),
+        JsonValue::String(number) => number.clone(),
+        _ => return Err(Box::new(DecodeError("expected decimal value"))),
+    };
+    let (mut value, mut value_decimals) =
+        parse_decimal(&decimal_str).ok_or_else(|| Box::new(DecodeError("invalid decimal")))?;
+
+    if decimals != "usize" {
+        let fixed_decimals = decimals.parse::<usize>()?;
+        value = rescale_decimal(value, value_decimals, fixed_decimals)
+            .ok_or_else(|| Box::new(DecodeError("too many decimals")))?;
+        value_decimals = fixed_decimals;
+    }
+    if !signed && value < BigInt::default() {
+        return Err(Box::new(DecodeError("expected unsigned decimal value")));
     }
+
+    Ok(AnyValue::SingleValue(SingleValue::Decimal {
+        value,
+        decimals: value_decimals,
+    }))
 }
 
 pub fn decode_struct(
@@ -121,10 +238,11 @@ pub fn decode_struct(
     let mut field_values: Vec<StructField> = vec![];
 
     for field in fields.iter() {
-        let value = input
-            .child(&field.name)
-            .ok_or_else(|| Box::new(DecodeError("missing field")))?;
-        let value = decode_human_readable_value(&value, &field.field_type.abi, contract_abi)?;
+        let value = input.child(&field.name).ok_or_else(|| {
+            ValuePathError::at_field(&field.name, Box::new(DecodeError("missing field")))
+        })?;
+        let value = decode_human_readable_value(&value, &field.field_type.abi, contract_abi)
+            .map_err(|err| ValuePathError::at_field(&field.name, err))?;
         field_values.push(StructField {
             name: field.name.clone(),
             value,
@@ -175,17 +293,30 @@ pub fn decode_enum(
         .find(|el| el.name == discriminant_name)
         .ok_or_else(|| Box::new(DecodeError("enum variant not found")))?;
 
+    let value = decode_enum_variant_value(input, variant, contract_abi)
+        .map_err(|err| ValuePathError::at_field(&variant.name, err))?;
+
+    Ok(AnyValue::Enum(Box::new(crate::EnumVariant {
+        discriminant: variant.discriminant,
+        value,
+    })))
+}
+
+fn decode_enum_variant_value(
+    input: &HumanReadableValue,
+    variant: &EnumVariantDescription,
+    contract_abi: &ContractAbi,
+) -> Result<AnyValue, Box<dyn Error>> {
+    let value = input.child(variant.name.as_str()).unwrap();
+
     // handle tuple with only one field as a special case (we don't need a wrapper array)
     if variant.is_tuple_variant() && variant.fields.len() == 1 {
-        let value = input.child(variant.name.as_str()).unwrap();
-        let value =
-            decode_human_readable_value(&value, &variant.fields[0].field_type.abi, contract_abi)?;
-        return Ok(AnyValue::Enum(Box::new(crate::EnumVariant {
-            discriminant: variant.discriminant,
-            value,
-        })));
+        return decode_human_readable_value(
+            &value,
+            &variant.fields[0].field_type.abi,
+            contract_abi,
+        );
     } else if variant.is_tuple_variant() {
-        let value = input.child(variant.name.as_str()).unwrap();
         let value = value
             .get_value()
             .as_array()
@@ -204,31 +335,43 @@ pub fn decode_enum(
                 &(value.to_owned().into()),
                 &field.field_type.abi,
                 contract_abi,
-            )?;
+            )
+            .map_err(|err| ValuePathError::at_index(i, err))?;
             field_values.push(StructField {
                 name: field.name.clone(),
                 value,
             });
         }
 
-        return Ok(AnyValue::Enum(Box::new(crate::EnumVariant {
-            discriminant: variant.discriminant,
-            value: AnyValue::Struct(StructValue(field_values)),
-        })));
+        return Ok(AnyValue::Struct(StructValue(field_values)));
     }
 
     // is not empty and is not a tuple so just try to parse a struct from the fields
-    let value = input.child(variant.name.as_str()).unwrap();
-    let value = decode_struct(&value, &variant.fields, contract_abi)?;
+    decode_struct(&value, &variant.fields, contract_abi)
+}
+
+/// Explicit enums are represented by the variant name.
+pub fn decode_explicit_enum(
+    input: &HumanReadableValue,
+    variants: &[ExplicitEnumVariantDescription],
+) -> Result<AnyValue, Box<dyn Error>> {
+    let variant_name = input
+        .get_value()
+        .as_str()
+        .ok_or_else(|| Box::new(DecodeError("expected string value for enum")))?;
+    let discriminant = variants
+        .iter()
+        .position(|variant| variant.name == variant_name)
+        .ok_or_else(|| Box::new(DecodeError("enum variant not found")))?;
 
     Ok(AnyValue::Enum(Box::new(crate::EnumVariant {
-        discriminant: variant.discriminant,
-        value,
+        discriminant,
+        value: AnyValue::None,
     })))
 }
 
 #[derive(Debug)]
-pub struct DecodeError(&'static str);
+pub struct DecodeError(pub(crate) &'static str);
 
 impl Display for DecodeError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -7,7 +7,9 @@ use multiversx_sc_scenario::{
     num_bigint::{BigInt, BigUint},
 };
 
-use crate::{AnyValue, SingleValue, StructField, StructValue};
+use crate::{
+    AnyValue, SingleValue, StructField, StructValue, UnknownTypeError, type_name::split_type_name,
+};
 
 pub fn default_value_for_abi_type(
     type_name: &str,
@@ -23,11 +25,52 @@ pub fn default_value_for_any_value(
 ) -> Result<AnyValue, Box<dyn Error>> {
     match &type_description.contents {
         TypeContents::NotSpecified => {
-            default_value_for_single_value(type_description.names.abi.as_str())
+            default_value_for_unspecified(type_description.names.abi.as_str(), contract_abi)
         }
         TypeContents::Enum(variants) => default_value_for_enum(variants, contract_abi),
         TypeContents::Struct(fields) => default_value_for_struct(fields, contract_abi),
-        TypeContents::ExplicitEnum(_) => panic!("not supported"),
+        TypeContents::ExplicitEnum(variants) => {
+            if variants.is_empty() {
+                return Err(Box::new(DefaultValueError("enum variant not found")));
+            }
+            Ok(AnyValue::Enum(Box::new(crate::EnumVariant {
+                discriminant: 0,
+                value: AnyValue::None,
+            })))
+        }
+    }
+}
+
+/// Types not described in the ABI: the generic types and the single values.
+fn default_value_for_unspecified(
+    type_name: &str,
+    contract_abi: &ContractAbi,
+) -> Result<AnyValue, Box<dyn Error>> {
+    let (base_name, type_args) = split_type_name(type_name);
+    match (base_name, type_args.as_slice()) {
+        ("Option" | "optional", [_]) => Ok(AnyValue::Option(None)),
+        ("List" | "variadic" | "counted-variadic", [_]) => Ok(AnyValue::List(Vec::new())),
+        ("array", [size, item_type]) => {
+            let items = (0..size.parse::<usize>()?)
+                .map(|_| default_value_for_abi_type(item_type, contract_abi))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(AnyValue::List(items))
+        }
+        ("tuple" | "multi", item_types) => {
+            let items = item_types
+                .iter()
+                .map(|item_type| default_value_for_abi_type(item_type, contract_abi))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(AnyValue::List(items))
+        }
+        ("ManagedDecimal" | "ManagedDecimalSigned", [decimals]) => {
+            Ok(AnyValue::SingleValue(SingleValue::Decimal {
+                value: BigInt::default(),
+                decimals: decimals.parse().unwrap_or_default(),
+            }))
+        }
+        ("ignore", []) => Ok(AnyValue::None),
+        _ => default_value_for_single_value(type_name),
     }
 }
 
@@ -36,16 +79,27 @@ pub fn default_value_for_single_value(type_name: &str) -> Result<AnyValue, Box<d
         "BigUint" | "u64" | "u32" | "u16" | "usize" | "u8" => Ok(AnyValue::SingleValue(
             SingleValue::UnsignedNumber(BigUint::default()),
         )),
+        // zero is not a valid value
+        "NonZeroBigUint" => Ok(AnyValue::SingleValue(SingleValue::UnsignedNumber(
+            BigUint::from(1u8),
+        ))),
         "BigInt" | "i64" | "i32" | "i16" | "isize" | "i8" => Ok(AnyValue::SingleValue(
             SingleValue::SignedNumber(BigInt::default()),
         )),
-        "ManagedBuffer" => Ok(AnyValue::SingleValue(SingleValue::Bytes(Vec::new().into()))),
-        "string" | "utf-8 string" => Ok(AnyValue::SingleValue(SingleValue::String("".to_owned()))),
-        "Address" => Ok(AnyValue::SingleValue(SingleValue::Bytes(
+        "ManagedBuffer" | "bytes" => {
+            Ok(AnyValue::SingleValue(SingleValue::Bytes(Vec::new().into())))
+        }
+        "string" | "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" | "TokenId" => {
+            Ok(AnyValue::SingleValue(SingleValue::String("".to_owned())))
+        }
+        "Address" | "H256" => Ok(AnyValue::SingleValue(SingleValue::Bytes(
             vec![0u8; 32].into(),
         ))),
+        "CodeMetadata" => Ok(AnyValue::SingleValue(SingleValue::Bytes(
+            vec![0u8; 2].into(),
+        ))),
         "bool" => Ok(AnyValue::SingleValue(SingleValue::Bool(false))),
-        _ => Err(Box::new(DefaultValueError("unknown type"))),
+        _ => Err(Box::new(UnknownTypeError(type_name.to_owned()))),
     }
 }
 
@@ -3,12 +3,19 @@ use std::{error::Error, fmt::Display};
 use multiversx_sc_scenario::{
     imports::{Address, Bech32Address},
     multiversx_sc::abi::{
-        ContractAbi, EnumVariantDescription, StructFieldDescription, TypeContents, TypeDescription,
+        ContractAbi, EnumVariantDescription, ExplicitEnumVariantDescription,
+        StructFieldDescription, TypeContents, TypeDescription,
     },
+    num_bigint::BigInt,
 };
 use serde_json::{Map, Value as JsonValue};
 
-use crate::{AnyValue, SingleValue, format::HumanReadableValue};
+use crate::{
+    AnyValue, SingleValue, UnknownTypeError, ValuePathError,
+    format::HumanReadableValue,
+    number::{NumberType, format_decimal, number_from_any_value},
+    type_name::split_type_name,
+};
 
 pub fn encode_human_readable_value(
     input: &AnyValue,
@@ -26,48 +33,100 @@ pub fn encode_any_value(
 ) -> Result<HumanReadableValue, Box<dyn Error>> {
     match &type_description.contents {
         TypeContents::NotSpecified => {
-            encode_single_value(input, type_description.names.abi.as_str())
+            encode_unspecified(input, type_description.names.abi.as_str(), contract_abi)
         }
         TypeContents::Enum(variants) => encode_enum(input, variants, contract_abi),
         TypeContents::Struct(fields) => encode_struct(input, fields, contract_abi),
-        TypeContents::ExplicitEnum(_) => panic!("not supported"),
+        TypeContents::ExplicitEnum(variants) => encode_explicit_enum(input, variants),
     }
 }
 
-fn encode_single_value(
+/// Types not described in the ABI: the generic types and the single values.
+fn encode_unspecified(
     input: &AnyValue,
     type_name: &str,
+    contract_abi: &ContractAbi,
 ) -> Result<HumanReadableValue, Box<dyn Error>> {
-    match type_name {
-        "BigUint" | "u64" | "u32" | "u16" | "usize" | "u8" => {
-            let AnyValue::SingleValue(value) = input else {
-                return Err(Box::new(EncodeError("expected single value")));
-            };
-            let SingleValue::UnsignedNumber(value) = value else {
-                return Err(Box::new(EncodeError("expected unsigned number value")));
+    let (base_name, type_args) = split_type_name(type_name);
+    match (base_name, type_args.as_slice()) {
+        ("Option" | "optional", [item_type]) => {
+            let AnyValue::Option(value) = input else {
+                return Err(Box::new(EncodeError("expected option value")));
             };
-
-            // could be biguint, so we convert to string first
-            let json_value: JsonValue = serde_json::from_str(&value.to_string())
-                .map_err(|_| Box::new(EncodeError("expected number value")))?;
-
-            Ok(json_value.into())
+            match value {
+                Some(value) => encode_human_readable_value(value, item_type, contract_abi),
+                None => Ok(JsonValue::Null.into()),
+            }
         }
-        "BigInt" | "i64" | "i32" | "i16" | "isize" | "i8" => {
-            let AnyValue::SingleValue(value) = input else {
-                return Err(Box::new(EncodeError("expected single value")));
-            };
-            let SingleValue::SignedNumber(value) = value else {
-                return Err(Box::new(EncodeError("expected signed number value")));
+        ("List" | "variadic" | "counted-variadic", [item_type]) => {
+            let items = expect_list(input)?;
+            encode_items(items, &vec![*item_type; items.len()], contract_abi)
+        }
+        ("array", [size, item_type]) => {
+            let items = expect_list(input)?;
+            if size.parse::<usize>()? != items.len() {
+                return Err(Box::new(EncodeError("wrong number of array items")));
+            }
+            encode_items(items, &vec![*item_type; items.len()], contract_abi)
+        }
+        ("tuple" | "multi", item_types) => {
+            let items = expect_list(input)?;
+            if item_types.len() != items.len() {
+                return Err(Box::new(EncodeError("wrong number of tuple items")));
+            }
+            encode_items(items, item_types, contract_abi)
+        }
+        ("ManagedDecimal" | "ManagedDecimalSigned", [_]) => {
+            let AnyValue::SingleValue(SingleValue::Decimal { value, decimals }) = input else {
+                return Err(Box::new(EncodeError("expected decimal value")));
             };
+            Ok(JsonValue::String(format_decimal(value, *decimals)).into())
+        }
+        ("ignore", []) => Ok(JsonValue::Null.into()),
+        _ => encode_single_value(input, type_name),
+    }
+}
 
-            // could be bigint, so we convert to string first
-            let json_value: JsonValue = serde_json::from_str(&value.to_string())
-                .map_err(|_| Box::new(EncodeError("expected number value")))?;
+fn expect_list(input: &AnyValue) -> Result<&Vec<AnyValue>, Box<dyn Error>> {
+    let AnyValue::List(items) = input else {
+        return Err(Box::new(EncodeError("expected list value")));
+    };
+    Ok(items)
+}
 
-            Ok(json_value.into())
+fn encode_items(
+    items: &[AnyValue],
+    item_types: &[&str],
+    contract_abi: &ContractAbi,
+) -> Result<HumanReadableValue, Box<dyn Error>> {
+    let values = items
+        .iter()
+        .zip(item_types)
+        .enumerate()
+        .map(|(index, (item, item_type))| {
+            encode_human_readable_value(item, item_type, contract_abi)
+                .map(|value| value.get_value().to_owned())
+                .map_err(|err| ValuePathError::at_index(index, err))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(JsonValue::Array(values).into())
+}
+
+fn encode_single_value(
+    input: &AnyValue,
+    type_name: &str,
+) -> Result<HumanReadableValue, Box<dyn Error>> {
+    if let Some(number_type) = NumberType::from_type_name(type_name) {
+        let value = number_from_any_value(input)
+            .ok_or_else(|| Box::new(EncodeError("expected number value")))?;
+        if !number_type.contains(&value) {
+            return Err(Box::new(EncodeError("number out of range")));
         }
-        "ManagedBuffer" => {
+        return Ok(encode_number(&value).into());
+    }
+
+    match type_name {
+        "ManagedBuffer" | "bytes" => {
             let AnyValue::SingleValue(value) = input else {
                 return Err(Box::new(EncodeError("expected single value")));
             };
@@ -83,7 +142,7 @@ fn encode_single_value(
             )
             .into())
         }
-        "string" | "utf-8 string" => {
+        "string" | "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" | "TokenId" => {
             let AnyValue::SingleValue(value) = input else {
                 return Err(Box::new(EncodeError("expected single value")));
             };
@@ -100,11 +159,24 @@ fn encode_single_value(
             let SingleValue::Bytes(value) = value else {
                 return Err(Box::new(EncodeError("expected bytes value")));
             };
+            if value.len() != 32 {
+                return Err(Box::new(EncodeError("wrong number of bytes")));
+            }
 
             let bech32_address =
                 Bech32Address::encode_address_default_hrp(Address::from_slice(value));
             Ok(JsonValue::String(bech32_address.bech32).into())
         }
+        "H256" | "CodeMetadata" => {
+            let AnyValue::SingleValue(value) = input else {
+                return Err(Box::new(EncodeError("expected single value")));
+            };
+            let SingleValue::Bytes(value) = value else {
+                return Err(Box::new(EncodeError("expected bytes value")));
+            };
+
+            Ok(JsonValue::String(hex::encode(value)).into())
+        }
         "bool" => {
             let AnyValue::SingleValue(value) = input else {
                 return Err(Box::new(EncodeError("expected single value")));
@@ -115,13 +187,21 @@ fn encode_single_value(
 
             Ok(JsonValue::Bool(value.to_owned()).into())
         }
-        _ => {
-            println!("unknown type: {}", type_name);
-            Err(Box::new(EncodeError("unknown type")))
-        }
+        _ => Err(Box::new(UnknownTypeError(type_name.to_owned()))),
     }
 }
 
+/// Numbers that don't fit in 64 bits are rendered as strings.
+fn encode_number(value: &BigInt) -> JsonValue {
+    if let Ok(small) = i64::try_from(value) {
+        return small.into();
+    }
+    if let Ok(small) = u64::try_from(value) {
+        return small.into();
+    }
+    JsonValue::String(value.to_string())
+}
+
 pub fn encode_struct(
     input: &AnyValue,
     fields: &[StructFieldDescription],
@@ -137,9 +217,12 @@ pub fn encode_struct(
     for field in fields.iter() {
         let value = struct_fields
             .find(|f| f.name == field.name)
-            .ok_or_else(|| Box::new(EncodeError("missing field")))?;
+            .ok_or_else(|| {
+                ValuePathError::at_field(&field.name, Box::new(EncodeError("missing field")))
+            })?;
 
-        let value = encode_human_readable_value(&value.value, &field.field_type.abi, contract_abi)?;
+        let value = encode_human_readable_value(&value.value, &field.field_type.abi, contract_abi)
+            .map_err(|err| ValuePathError::at_field(&field.name, err))?;
         field_values.insert(field.name.to_owned(), value.get_value().to_owned());
     }
 
@@ -163,65 +246,73 @@ pub fn encode_enum(
         return Ok(JsonValue::String(variant.name.to_owned()).into());
     }
 
+    let value = encode_enum_variant_value(&enum_value.value, variant, contract_abi)
+        .map_err(|err| ValuePathError::at_field(&variant.name, err))?;
+
+    Ok(JsonValue::Object(vec![(variant.name.to_owned(), value)].into_iter().collect()).into())
+}
+
+fn encode_enum_variant_value(
+    value: &AnyValue,
+    variant: &EnumVariantDescription,
+    contract_abi: &ContractAbi,
+) -> Result<JsonValue, Box<dyn Error>> {
     if variant.is_tuple_variant() && variant.fields.len() == 1 {
-        let value = encode_human_readable_value(
-            &enum_value.value,
-            &variant.fields[0].field_type.abi,
-            contract_abi,
-        )?;
-        return Ok(JsonValue::Object(
-            vec![(variant.name.to_owned(), value.get_value().to_owned())]
-                .into_iter()
-                .collect(),
-        )
-        .into());
+        let value =
+            encode_human_readable_value(value, &variant.fields[0].field_type.abi, contract_abi)?;
+        return Ok(value.get_value().to_owned());
     }
 
-    if variant.is_tuple_variant() {
-        let AnyValue::Struct(variant_fields) = &enum_value.value else {
-            return Err(Box::new(EncodeError("expected struct value")));
-        };
+    let AnyValue::Struct(variant_fields) = value else {
+        return Err(Box::new(EncodeError("expected struct value")));
+    };
 
+    if variant.is_tuple_variant() {
         let mut field_values: Vec<JsonValue> = vec![];
 
-        for (field, field_type) in variant.fields.iter().zip(variant_fields.0.iter()) {
-            let value = encode_human_readable_value(
-                &field_type.value,
-                &field.field_type.abi,
-                contract_abi,
-            )?;
+        for (i, (field, field_type)) in variant
+            .fields
+            .iter()
+            .zip(variant_fields.0.iter())
+            .enumerate()
+        {
+            let value =
+                encode_human_readable_value(&field_type.value, &field.field_type.abi, contract_abi)
+                    .map_err(|err| ValuePathError::at_index(i, err))?;
             field_values.push(value.get_value().to_owned());
         }
 
-        return Ok(JsonValue::Object(
-            vec![(variant.name.to_owned(), JsonValue::Array(field_values))]
-                .into_iter()
-                .collect(),
-        )
-        .into());
+        return Ok(JsonValue::Array(field_values));
     }
 
-    let AnyValue::Struct(variant_fields) = &enum_value.value else {
-        return Err(Box::new(EncodeError("expected struct value")));
-    };
-
     let mut field_values: Map<String, JsonValue> = Map::new();
     for (field, field_type) in variant.fields.iter().zip(variant_fields.0.iter()) {
         let value =
-            encode_human_readable_value(&field_type.value, &field.field_type.abi, contract_abi)?;
+            encode_human_readable_value(&field_type.value, &field.field_type.abi, contract_abi)
+                .map_err(|err| ValuePathError::at_field(&field.name, err))?;
         field_values.insert(field.name.to_owned(), value.get_value().to_owned());
     }
 
-    Ok(JsonValue::Object(
-        vec![(variant.name.to_owned(), JsonValue::Object(field_values))]
-            .into_iter()
-            .collect(),
-    )
-    .into())
+    Ok(JsonValue::Object(field_values))
+}
+
+/// Explicit enums are represented by the variant name.
+pub fn encode_explicit_enum(
+    input: &AnyValue,
+    variants: &[ExplicitEnumVariantDescription],
+) -> Result<HumanReadableValue, Box<dyn Error>> {
+    let AnyValue::Enum(enum_value) = input else {
+        return Err(Box::new(EncodeError("expected enum value")));
+    };
+    let variant = variants
+        .get(enum_value.discriminant)
+        .ok_or_else(|| Box::new(EncodeError("missing variant")))?;
+
+    Ok(JsonValue::String(variant.name.to_owned()).into())
 }
 
 #[derive(Debug)]
-pub struct EncodeError(&'static str);
+pub struct EncodeError(pub(crate) &'static str);
 
 impl Display for EncodeError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -0,0 +1,70 @@
+use std::{error::Error, fmt::Display};
+
+/// Locates an error inside a nested value, e.g. `owner.tokens[2]: expected string value`.
+#[derive(Debug)]
+pub struct ValuePathError {
+    pub path: String,
+    pub source: Box<dyn Error>,
+}
+
+impl ValuePathError {
+    pub(crate) fn at_field(name: &str, err: Box<dyn Error>) -> Box<dyn Error> {
+        Self::prepend(name.to_owned(), err)
+    }
+
+    pub(crate) fn at_index(index: usize, err: Box<dyn Error>) -> Box<dyn Error> {
+        Self::prepend(format!("[{index}]"), err)
+    }
+
+    fn prepend(segment: String, err: Box<dyn Error>) -> Box<dyn Error> {
+        match err.downcast::<ValuePathError>() {
+            Ok(mut path_err) => {
+                if !path_err.path.starts_with('[') {
+                    path_err.path.insert(0, '.');
+                }
+                path_err.path.insert_str(0, &segment);
+                path_err
+            }
+            Err(source) => Box::new(ValuePathError {
+                path: segment,
+                source,
+            }),
+        }
+    }
+}
+
+impl Display for ValuePathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.source)
+    }
+}
+
+impl Error for ValuePathError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+/// The ABI type name is neither described in the ABI, nor a known built-in type.
+#[derive(Debug)]
+pub struct UnknownTypeError(pub String);
+
+impl Display for UnknownTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown type: {}", self.0)
+    }
+}
+
+impl Error for UnknownTypeError {}
+
+/// The event identifier does not match any of the events in the ABI.
+#[derive(Debug)]
+pub struct UnknownEventError(pub String);
+
+impl Display for UnknownEventError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown event: {}", self.0)
+    }
+}
+
+impl Error for UnknownEventError {}
@@ -0,0 +1,70 @@
+use std::error::Error;
+
+use multiversx_sc_scenario::multiversx_sc::abi::{ContractAbi, EventInputAbi};
+use serde_json::{Map, Value as JsonValue};
+
+use crate::{
+    DecodeError, UnknownEventError, ValuePathError, encode_human_readable_value,
+    top_decode_any_value,
+};
+
+/// An event log, decoded based on the event descriptions in the contract ABI.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedEvent {
+    pub identifier: String,
+
+    /// The event arguments in human-readable form, by argument name.
+    pub fields: Map<String, JsonValue>,
+}
+
+/// Decodes the topics and data of an event log.
+///
+/// The first topic is the event identifier, followed by the indexed arguments, in order.
+/// The non-indexed argument, if the event has one, is in the log data.
+pub fn decode_event(
+    topics: &[Vec<u8>],
+    data: &[Vec<u8>],
+    contract_abi: &ContractAbi,
+) -> Result<DecodedEvent, Box<dyn Error>> {
+    let (identifier, mut indexed_topics) = topics
+        .split_first()
+        .ok_or_else(|| Box::new(DecodeError("missing event identifier")))?;
+    let identifier = String::from_utf8(identifier.clone())?;
+    let event_abi = contract_abi
+        .events
+        .iter()
+        .find(|event| event.identifier == identifier)
+        .ok_or_else(|| Box::new(UnknownEventError(identifier.clone())))?;
+
+    let mut fields = Map::new();
+    for input in &event_abi.inputs {
+        let raw = if input.indexed {
+            let (topic, rest) = indexed_topics
+                .split_first()
+                .ok_or_else(|| Box::new(DecodeError("not enough topics")))?;
+            indexed_topics = rest;
+            topic.as_slice()
+        } else {
+            data.first().map(Vec::as_slice).unwrap_or_default()
+        };
+        let value = decode_event_input(raw, input, contract_abi)
+            .map_err(|err| ValuePathError::at_field(&input.arg_name, err))?;
+        fields.insert(input.arg_name.clone(), value);
+    }
+
+    if !indexed_topics.is_empty() {
+        return Err(Box::new(DecodeError("too many topics")));
+    }
+
+    Ok(DecodedEvent { identifier, fields })
+}
+
+fn decode_event_input(
+    raw: &[u8],
+    input: &EventInputAbi,
+    contract_abi: &ContractAbi,
+) -> Result<JsonValue, Box<dyn Error>> {
+    let value = top_decode_any_value(raw, &input.type_name, contract_abi)?;
+    let value = encode_human_readable_value(&value, &input.type_name, contract_abi)?;
+    Ok(value.get_value().to_owned())
+}
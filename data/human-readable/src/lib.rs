@@ -1,12 +1,22 @@
+mod bytes_decode;
+mod bytes_encode;
 mod decode;
 mod defaults;
 mod encode;
+mod error;
+mod event;
 pub mod format;
+mod number;
 pub mod schema;
+mod type_name;
 mod value;
 
+pub use bytes_decode::*;
+pub use bytes_encode::*;
 pub use decode::*;
 pub use defaults::*;
 pub use encode::*;
+pub use error::*;
+pub use event::*;
 use multiversx_sc_scenario::multiversx_sc;
 pub use value::*;
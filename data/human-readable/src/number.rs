@@ -0,0 +1,191 @@
+use multiversx_sc_scenario::num_bigint::{BigInt, BigUint, Sign};
+
+use crate::{AnyValue, SingleValue};
+
+/// Describes how a numeric ABI type is represented and encoded.
+#[derive(Clone, Copy)]
+pub(crate) struct NumberType {
+    pub signed: bool,
+    /// Nested-encoded size, in bytes. `None` for arbitrary-size numbers, which are length-prefixed.
+    pub size: Option<usize>,
+    pub non_zero: bool,
+}
+
+impl NumberType {
+    pub fn from_type_name(type_name: &str) -> Option<Self> {
+        let (signed, size) = match type_name {
+            "u8" => (false, Some(1)),
+            "u16" => (false, Some(2)),
+            "u32" | "usize" => (false, Some(4)),
+            "u64" => (false, Some(8)),
+            "BigUint" | "NonZeroBigUint" => (false, None),
+            "i8" => (true, Some(1)),
+            "i16" => (true, Some(2)),
+            "i32" | "isize" => (true, Some(4)),
+            "i64" => (true, Some(8)),
+            "BigInt" => (true, None),
+            _ => return None,
+        };
+        Some(NumberType {
+            signed,
+            size,
+            non_zero: type_name == "NonZeroBigUint",
+        })
+    }
+
+    pub fn contains(&self, value: &BigInt) -> bool {
+        if self.non_zero && value.sign() == Sign::NoSign {
+            return false;
+        }
+        if !self.signed && value.sign() == Sign::Minus {
+            return false;
+        }
+        let Some(size) = self.size else {
+            return true;
+        };
+
+        let bits = size * 8;
+        if self.signed {
+            let limit = BigInt::from(1u8) << (bits - 1);
+            value >= &-limit.clone() && value < &limit
+        } else {
+            value < &(BigInt::from(1u8) << bits)
+        }
+    }
+
+    pub fn to_any_value(self, value: BigInt) -> AnyValue {
+        if self.signed {
+            AnyValue::SingleValue(SingleValue::SignedNumber(value))
+        } else {
+            AnyValue::SingleValue(SingleValue::UnsignedNumber(value.magnitude().clone()))
+        }
+    }
+
+    /// Minimal big endian representation, empty for zero.
+    pub fn top_encode(self, value: &BigInt) -> Vec<u8> {
+        match value.sign() {
+            Sign::NoSign => Vec::new(),
+            _ if self.signed => value.to_signed_bytes_be(),
+            _ => value.magnitude().to_bytes_be(),
+        }
+    }
+
+    pub fn nested_encode(self, value: &BigInt, dest: &mut Vec<u8>) {
+        let bytes = self.top_encode(value);
+        match self.size {
+            Some(size) => {
+                let padding = if value.sign() == Sign::Minus { 0xff } else { 0 };
+                dest.resize(dest.len() + size - bytes.len(), padding);
+                dest.extend_from_slice(&bytes);
+            }
+            None => {
+                dest.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+                dest.extend_from_slice(&bytes);
+            }
+        }
+    }
+
+    pub fn top_decode(self, bytes: &[u8]) -> Option<BigInt> {
+        if let Some(size) = self.size
+            && bytes.len() > size
+        {
+            return None;
+        }
+        let value = if self.signed {
+            BigInt::from_signed_bytes_be(bytes)
+        } else {
+            BigInt::from_bytes_be(Sign::Plus, bytes)
+        };
+        Some(value)
+    }
+}
+
+/// Extracts the number from any of the numeric single values.
+pub(crate) fn number_from_any_value(value: &AnyValue) -> Option<BigInt> {
+    match value {
+        AnyValue::SingleValue(SingleValue::UnsignedNumber(value)) => Some(value.clone().into()),
+        AnyValue::SingleValue(SingleValue::SignedNumber(value)) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+/// Parses numbers such as `-12.345` into their raw value and number of decimals.
+pub(crate) fn parse_decimal(input: &str) -> Option<(BigInt, usize)> {
+    let (negative, unsigned) = match input.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, input),
+    };
+    let (integer_part, fractional_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if integer_part.is_empty()
+        || !integer_part.bytes().all(|b| b.is_ascii_digit())
+        || !fractional_part.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let digits = format!("{integer_part}{fractional_part}");
+    let magnitude = digits.parse::<BigUint>().ok()?;
+    let sign = if negative { Sign::Minus } else { Sign::Plus };
+    Some((BigInt::from_biguint(sign, magnitude), fractional_part.len()))
+}
+
+/// Inverse of [`parse_decimal`], always outputs exactly `decimals` fractional digits.
+pub(crate) fn format_decimal(value: &BigInt, decimals: usize) -> String {
+    let mut digits = value.magnitude().to_string();
+    if digits.len() <= decimals {
+        digits.insert_str(0, &"0".repeat(decimals + 1 - digits.len()));
+    }
+    if decimals > 0 {
+        digits.insert(digits.len() - decimals, '.');
+    }
+    if value.sign() == Sign::Minus {
+        digits.insert(0, '-');
+    }
+    digits
+}
+
+/// Changes the number of decimals of a raw decimal value, without losing precision.
+pub(crate) fn rescale_decimal(value: BigInt, from: usize, to: usize) -> Option<BigInt> {
+    if to >= from {
+        return Some(value * BigInt::from(10u8).pow((to - from) as u32));
+    }
+    let divisor = BigInt::from(10u8).pow((from - to) as u32);
+    if &value % &divisor != BigInt::default() {
+        return None;
+    }
+    Some(value / divisor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_ranges() {
+        let u8_type = NumberType::from_type_name("u8").unwrap();
+        assert!(u8_type.contains(&255.into()));
+        assert!(!u8_type.contains(&256.into()));
+        assert!(!u8_type.contains(&(-1).into()));
+
+        let i8_type = NumberType::from_type_name("i8").unwrap();
+        assert!(i8_type.contains(&(-128).into()));
+        assert!(!i8_type.contains(&(-129).into()));
+        assert!(!i8_type.contains(&128.into()));
+
+        let non_zero_type = NumberType::from_type_name("NonZeroBigUint").unwrap();
+        assert!(!non_zero_type.contains(&0.into()));
+    }
+
+    #[test]
+    fn test_decimal_roundtrip() {
+        let (value, decimals) = parse_decimal("-0.05").unwrap();
+        assert_eq!(value, (-5).into());
+        assert_eq!(decimals, 2);
+        assert_eq!(format_decimal(&value, decimals), "-0.05");
+
+        assert_eq!(format_decimal(&1500.into(), 3), "1.500");
+        assert_eq!(format_decimal(&15.into(), 0), "15");
+        assert!(parse_decimal("1.2.3").is_none());
+        assert!(parse_decimal(".5").is_none());
+    }
+}
@@ -5,6 +5,8 @@ use multiversx_sc_scenario::multiversx_sc::abi::{
 };
 use serde_json::Value as JsonValue;
 
+use crate::type_name::split_type_name;
+
 pub fn build_schema_for_type(
     type_name: &str,
     contract_abi: &ContractAbi,
@@ -18,26 +20,123 @@ pub fn build_schema_for_type_description(
     contract_abi: &ContractAbi,
 ) -> Result<JsonValue, SchemaError> {
     match &type_description.contents {
-        TypeContents::NotSpecified => build_schema_for_single_value(&type_description.names.abi),
+        TypeContents::NotSpecified => {
+            build_schema_for_unspecified(&type_description.names.abi, contract_abi)
+        }
         TypeContents::Enum(variants) => build_schema_for_enum(variants, contract_abi),
         TypeContents::Struct(fields) => build_schema_for_struct(fields, contract_abi),
-        TypeContents::ExplicitEnum(_) => panic!("not supported"),
+        TypeContents::ExplicitEnum(variants) => Ok(JsonValue::Object(
+            vec![(
+                "enum".to_owned(),
+                JsonValue::Array(variants.iter().map(|x| x.name.clone().into()).collect()),
+            )]
+            .into_iter()
+            .collect(),
+        )),
+    }
+}
+
+/// Types not described in the ABI: the generic types and the single values.
+fn build_schema_for_unspecified(
+    type_name: &str,
+    contract_abi: &ContractAbi,
+) -> Result<JsonValue, SchemaError> {
+    let (base_name, type_args) = split_type_name(type_name);
+    match (base_name, type_args.as_slice()) {
+        ("Option" | "optional", [item_type]) => Ok(JsonValue::Object(
+            vec![(
+                "oneOf".to_owned(),
+                JsonValue::Array(vec![
+                    JsonValue::Object(
+                        vec![("type".to_owned(), "null".into())]
+                            .into_iter()
+                            .collect(),
+                    ),
+                    build_schema_for_type(item_type, contract_abi)?,
+                ]),
+            )]
+            .into_iter()
+            .collect(),
+        )),
+        ("List" | "variadic" | "counted-variadic", [item_type]) => Ok(JsonValue::Object(
+            vec![
+                ("type".to_owned(), "array".into()),
+                (
+                    "items".to_owned(),
+                    build_schema_for_type(item_type, contract_abi)?,
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        )),
+        ("array", [size, item_type]) => {
+            let size = size
+                .parse::<u64>()
+                .map_err(|_| SchemaError("invalid array size"))?;
+            Ok(JsonValue::Object(
+                vec![
+                    ("type".to_owned(), "array".into()),
+                    (
+                        "items".to_owned(),
+                        build_schema_for_type(item_type, contract_abi)?,
+                    ),
+                    ("minItems".to_owned(), size.into()),
+                    ("maxItems".to_owned(), size.into()),
+                ]
+                .into_iter()
+                .collect(),
+            ))
+        }
+        ("tuple" | "multi", item_types) => {
+            let mut values = Vec::new();
+            for item_type in item_types {
+                values.push(build_schema_for_type(item_type, contract_abi)?);
+            }
+
+            Ok(JsonValue::Object(
+                vec![
+                    ("type".to_owned(), "array".into()),
+                    ("additionalItems".to_owned(), false.into()),
+                    ("items".to_owned(), JsonValue::Array(values)),
+                ]
+                .into_iter()
+                .collect(),
+            ))
+        }
+        // decimals such as "12.345"
+        ("ManagedDecimal" | "ManagedDecimalSigned", [_]) => Ok(JsonValue::Object(
+            vec![("type".to_owned(), vec!["number", "string"].into())]
+                .into_iter()
+                .collect(),
+        )),
+        ("ignore", []) => Ok(JsonValue::Object(
+            vec![("type".to_owned(), "null".into())]
+                .into_iter()
+                .collect(),
+        )),
+        _ => build_schema_for_single_value(type_name),
     }
 }
 
 pub fn build_schema_for_single_value(type_name: &str) -> Result<JsonValue, SchemaError> {
     match type_name {
-        "BigUint" | "u64" | "u32" | "u16" | "usize" | "u8" => Ok(JsonValue::Object(
+        "u64" | "u32" | "u16" | "usize" | "u8" => Ok(JsonValue::Object(
             vec![("type".to_owned(), "integer".into())]
                 .into_iter()
                 .collect(),
         )),
-        "BigInt" | "i64" | "i32" | "i16" | "isize" | "i8" => Ok(JsonValue::Object(
+        "i64" | "i32" | "i16" | "isize" | "i8" => Ok(JsonValue::Object(
             vec![("type".to_owned(), "integer".into())]
                 .into_iter()
                 .collect(),
         )),
-        "ManagedBuffer" => Ok(JsonValue::Object(
+        // large values are given as strings
+        "BigUint" | "NonZeroBigUint" | "BigInt" => Ok(JsonValue::Object(
+            vec![("type".to_owned(), vec!["integer", "string"].into())]
+                .into_iter()
+                .collect(),
+        )),
+        "ManagedBuffer" | "bytes" => Ok(JsonValue::Object(
             vec![
                 ("type".to_owned(), "array".into()),
                 ("items".to_owned(), build_schema_for_single_value("u8")?),
@@ -45,7 +144,14 @@ pub fn build_schema_for_single_value(type_name: &str) -> Result<JsonValue, Schem
             .into_iter()
             .collect(),
         )),
-        "string" | "utf-8 string" | "Address" => Ok(JsonValue::Object(
+        "string"
+        | "utf-8 string"
+        | "TokenIdentifier"
+        | "EgldOrEsdtTokenIdentifier"
+        | "TokenId"
+        | "Address"
+        | "H256"
+        | "CodeMetadata" => Ok(JsonValue::Object(
             vec![("type".to_owned(), "string".into())]
                 .into_iter()
                 .collect(),
@@ -276,6 +382,31 @@ mod tests {
         assert!(err.is_err());
     }
 
+    #[test]
+    fn test_build_schema_for_generic_types() {
+        let abi = ContractAbi::default();
+        assert_eq!(
+            build_schema_for_type("Option<u32>", &abi).unwrap(),
+            json!({"oneOf": [{"type": "null"}, {"type": "integer"}]})
+        );
+        assert_eq!(
+            build_schema_for_type("List<bool>", &abi).unwrap(),
+            json!({"type": "array", "items": {"type": "boolean"}})
+        );
+        assert_eq!(
+            build_schema_for_type("array2<u8>", &abi).unwrap(),
+            json!({"type": "array", "items": {"type": "integer"}, "minItems": 2, "maxItems": 2})
+        );
+        assert_eq!(
+            build_schema_for_type("multi<TokenIdentifier,BigUint>", &abi).unwrap(),
+            json!({
+                "type": "array",
+                "additionalItems": false,
+                "items": [{"type": "string"}, {"type": ["integer", "string"]}]
+            })
+        );
+    }
+
     #[test]
     fn test_build_schema_for_struct() {
         let fields = vec![
@@ -0,0 +1,68 @@
+/// Splits an ABI type name into its base name and its generic arguments,
+/// e.g. `multi<u8,List<u32>>` becomes `multi` and `[u8, List<u32>]`.
+///
+/// Fixed-size arrays such as `array32<u8>` are returned as `array`, with the size as first argument.
+pub(crate) fn split_type_name(type_name: &str) -> (&str, Vec<&str>) {
+    let type_name = type_name.trim();
+    let Some(open) = type_name.find('<') else {
+        return (type_name, Vec::new());
+    };
+    if !type_name.ends_with('>') {
+        return (type_name, Vec::new());
+    }
+
+    let base = &type_name[..open];
+    let mut args = split_type_args(&type_name[open + 1..type_name.len() - 1]);
+    if let Some(size) = base.strip_prefix("array")
+        && !size.is_empty()
+        && size.bytes().all(|b| b.is_ascii_digit())
+    {
+        args.insert(0, size);
+        return ("array", args);
+    }
+
+    (base, args)
+}
+
+fn split_type_args(args: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in args.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                result.push(args[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    let last = args[start..].trim();
+    if !last.is_empty() {
+        result.push(last);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_type_name() {
+        assert_eq!(split_type_name("u32"), ("u32", vec![]));
+        assert_eq!(split_type_name("Option<u32>"), ("Option", vec!["u32"]));
+        assert_eq!(
+            split_type_name("multi<u8,tuple<u16, List<u32>>>"),
+            ("multi", vec!["u8", "tuple<u16, List<u32>>"])
+        );
+        assert_eq!(split_type_name("array32<u8>"), ("array", vec!["32", "u8"]));
+        assert_eq!(
+            split_type_name("ManagedDecimal<usize>"),
+            ("ManagedDecimal", vec!["usize"])
+        );
+    }
+}
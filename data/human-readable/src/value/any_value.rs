@@ -1,14 +1,21 @@
 use multiversx_sc_scenario::multiversx_sc::codec::{
-    EncodeErrorHandler, NestedEncode, NestedEncodeOutput, TopEncode, TopEncodeOutput,
+    EncodeError, EncodeErrorHandler, NestedEncode, NestedEncodeOutput, TopEncode, TopEncodeOutput,
 };
 
 use crate::{EnumVariant, SingleValue, StructValue};
 
+/// The bytes of options, lists and decimals depend on their ABI type, which the value alone does not carry.
+pub(crate) const TYPE_REQUIRED_ENCODE_ERROR: &str = "options, lists and decimals can only be encoded based on their ABI type, use top_encode_any_value";
+
 pub enum AnyValue {
     None,
     SingleValue(SingleValue),
     Struct(StructValue),
     Enum(Box<EnumVariant>),
+    /// `Option<T>` and `optional<T>`.
+    Option(Option<Box<AnyValue>>),
+    /// Lists, tuples and arrays, as well as the multi-values: `multi`, `variadic`, `counted-variadic`.
+    List(Vec<AnyValue>),
 }
 
 impl NestedEncode for AnyValue {
@@ -22,6 +29,9 @@ impl NestedEncode for AnyValue {
             AnyValue::SingleValue(sv) => sv.dep_encode_or_handle_err(dest, h),
             AnyValue::Struct(s) => s.dep_encode_or_handle_err(dest, h),
             AnyValue::Enum(e) => e.dep_encode_or_handle_err(dest, h),
+            AnyValue::Option(_) | AnyValue::List(_) => {
+                Err(h.handle_error(EncodeError::from(TYPE_REQUIRED_ENCODE_ERROR)))
+            }
         }
     }
 }
@@ -37,6 +47,9 @@ impl TopEncode for AnyValue {
             AnyValue::SingleValue(sv) => sv.top_encode_or_handle_err(output, h),
             AnyValue::Struct(s) => s.top_encode_or_handle_err(output, h),
             AnyValue::Enum(e) => e.top_encode_or_handle_err(output, h),
+            AnyValue::Option(_) | AnyValue::List(_) => {
+                Err(h.handle_error(EncodeError::from(TYPE_REQUIRED_ENCODE_ERROR)))
+            }
         }
     }
 }
@@ -1,14 +1,21 @@
 use multiversx_sc_scenario::multiversx_sc::codec::{
-    EncodeErrorHandler, NestedEncode, NestedEncodeOutput, TopEncode, TopEncodeOutput,
+    EncodeError, EncodeErrorHandler, NestedEncode, NestedEncodeOutput, TopEncode, TopEncodeOutput,
     num_bigint::{BigInt, BigUint},
 };
 
+use super::any_value::TYPE_REQUIRED_ENCODE_ERROR;
+
 pub enum SingleValue {
     UnsignedNumber(BigUint),
     SignedNumber(BigInt),
     Bytes(Box<[u8]>),
     String(String),
     Bool(bool),
+    /// `ManagedDecimal` and `ManagedDecimalSigned`, `value` being scaled by `10^decimals`.
+    Decimal {
+        value: BigInt,
+        decimals: usize,
+    },
 }
 
 impl NestedEncode for SingleValue {
@@ -23,6 +30,9 @@ impl NestedEncode for SingleValue {
             SingleValue::Bytes(bytes) => bytes.dep_encode_or_handle_err(dest, h),
             SingleValue::String(s) => s.as_bytes().dep_encode_or_handle_err(dest, h),
             SingleValue::Bool(b) => b.dep_encode_or_handle_err(dest, h),
+            SingleValue::Decimal { .. } => {
+                Err(h.handle_error(EncodeError::from(TYPE_REQUIRED_ENCODE_ERROR)))
+            }
         }
     }
 }
@@ -39,6 +49,9 @@ impl TopEncode for SingleValue {
             SingleValue::Bytes(bytes) => bytes.top_encode_or_handle_err(output, h),
             SingleValue::String(s) => s.as_bytes().top_encode_or_handle_err(output, h),
             SingleValue::Bool(b) => b.top_encode_or_handle_err(output, h),
+            SingleValue::Decimal { .. } => {
+                Err(h.handle_error(EncodeError::from(TYPE_REQUIRED_ENCODE_ERROR)))
+            }
         }
     }
 }
@@ -0,0 +1,391 @@
+use multiversx_sc_codec_human_readable::{
+    AnyValue, SingleValue, ValuePathError, decode_event, decode_human_readable_value,
+    default_value_for_abi_type, encode_human_readable_value, format::HumanReadableValue,
+    nested_decode_any_value, nested_encode_any_value, top_decode_any_value, top_decode_multi_value,
+    top_encode_any_value, top_encode_multi_value,
+};
+use multiversx_sc_scenario::{
+    meta::abi_json::deserialize_abi_from_json,
+    multiversx_sc::{
+        abi::ContractAbi,
+        codec::{top_encode_to_vec_u8, top_encode_to_vec_u8_or_panic},
+    },
+    num_bigint::BigUint,
+};
+
+const ABI_JSON: &str = r#"{
+    "name": "Test",
+    "endpoints": [],
+    "events": [
+        {
+            "identifier": "payment",
+            "inputs": [
+                {
+                    "name": "caller",
+                    "type": "u32",
+                    "indexed": true
+                },
+                {
+                    "name": "payment",
+                    "type": "Payment"
+                }
+            ]
+        }
+    ],
+    "esdtAttributes": [],
+    "hasCallback": false,
+    "types": {
+        "Payment": {
+            "type": "struct",
+            "fields": [
+                {
+                    "name": "token",
+                    "type": "TokenIdentifier"
+                },
+                {
+                    "name": "amounts",
+                    "type": "List<BigUint>"
+                },
+                {
+                    "name": "memo",
+                    "type": "Option<utf-8 string>"
+                }
+            ]
+        },
+        "Status": {
+            "type": "explicit-enum",
+            "variants": [
+                {
+                    "name": "completed"
+                },
+                {
+                    "name": "interrupted"
+                }
+            ]
+        },
+        "Simple": {
+            "type": "enum",
+            "variants": [
+                {
+                    "name": "First",
+                    "discriminant": 0
+                },
+                {
+                    "name": "Second",
+                    "discriminant": 1
+                }
+            ]
+        }
+    }
+}"#;
+
+fn abi() -> ContractAbi {
+    deserialize_abi_from_json(ABI_JSON).unwrap().into()
+}
+
+/// JSON -> value -> bytes, checks the bytes, then goes all the way back to JSON.
+fn check_roundtrip(json: &str, type_name: &str, expected_bytes: &[u8]) {
+    let abi = abi();
+    let input = json.parse::<HumanReadableValue>().unwrap();
+
+    let value = decode_human_readable_value(&input, type_name, &abi).unwrap();
+    let bytes = top_encode_any_value(&value, type_name, &abi).unwrap();
+    assert_eq!(bytes, expected_bytes);
+
+    let decoded = top_decode_any_value(&bytes, type_name, &abi).unwrap();
+    let output = encode_human_readable_value(&decoded, type_name, &abi).unwrap();
+    assert_eq!(output.get_value(), input.get_value());
+}
+
+fn decode_error(json: &str, type_name: &str) -> String {
+    let input = json.parse::<HumanReadableValue>().unwrap();
+    decode_human_readable_value(&input, type_name, &abi())
+        .err()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn numbers_test() {
+    check_roundtrip("0", "u64", &[]);
+    check_roundtrip("-1", "i16", &top_encode_to_vec_u8_or_panic(&-1i16));
+    check_roundtrip(
+        "18446744073709551615",
+        "u64",
+        &top_encode_to_vec_u8_or_panic(&u64::MAX),
+    );
+
+    // large numbers are strings
+    let big = BigUint::from(u64::MAX) * 1000u32;
+    check_roundtrip(
+        &format!("\"{big}\""),
+        "BigUint",
+        &top_encode_to_vec_u8_or_panic(&big),
+    );
+
+    assert_eq!(decode_error("256", "u8"), "number out of range");
+    assert_eq!(decode_error("-1", "BigUint"), "number out of range");
+    assert_eq!(decode_error("0", "NonZeroBigUint"), "number out of range");
+    assert_eq!(decode_error("1.5", "u32"), "invalid number");
+}
+
+#[test]
+fn single_values_test() {
+    check_roundtrip(
+        r#""EGLD-000000""#,
+        "EgldOrEsdtTokenIdentifier",
+        b"EGLD-000000",
+    );
+    check_roundtrip("[1, 2]", "bytes", &[1, 2]);
+    check_roundtrip(r#""0500""#, "CodeMetadata", &[5, 0]);
+    check_roundtrip(&format!("\"{}\"", "ab".repeat(32)), "H256", &[0xab; 32]);
+
+    assert_eq!(
+        decode_error(r#""erd1invalid""#, "Address"),
+        "invalid bech32 address"
+    );
+    assert_eq!(decode_error("1", "Unknown"), "unknown type: Unknown");
+}
+
+#[test]
+fn generic_types_test() {
+    check_roundtrip("null", "Option<u32>", &[]);
+    check_roundtrip(
+        "7",
+        "Option<u32>",
+        &top_encode_to_vec_u8_or_panic(&Some(7u32)),
+    );
+    check_roundtrip(
+        "[1, 2, 3]",
+        "List<u16>",
+        &top_encode_to_vec_u8_or_panic(&vec![1u16, 2, 3]),
+    );
+    check_roundtrip(
+        r#"[5, "abc"]"#,
+        "tuple<u8,utf-8 string>",
+        &top_encode_to_vec_u8_or_panic(&(5u8, b"abc".to_vec())),
+    );
+    check_roundtrip(
+        "[[1, 2], [3, 4]]",
+        "List<array2<u8>>",
+        &top_encode_to_vec_u8_or_panic(&vec![[1u8, 2], [3, 4]]),
+    );
+    check_roundtrip("[null, 1]", "List<Option<u8>>", &[0, 1, 1]);
+
+    assert_eq!(
+        decode_error("[1, 2, 3]", "array2<u8>"),
+        "wrong number of array items"
+    );
+}
+
+#[test]
+fn decimal_test() {
+    // fixed number of decimals, only the value is encoded
+    check_roundtrip(
+        r#""1.50""#,
+        "ManagedDecimal<2>",
+        &top_encode_to_vec_u8_or_panic(&BigUint::from(150u32)),
+    );
+    // variable number of decimals, also encoded
+    check_roundtrip(
+        r#""-0.005""#,
+        "ManagedDecimalSigned<usize>",
+        &[0, 0, 0, 1, 0xfb, 0, 0, 0, 3],
+    );
+
+    // the number of decimals gets adjusted, without losing precision
+    let abi = abi();
+    let input = "1.5".parse::<HumanReadableValue>().unwrap();
+    let value = decode_human_readable_value(&input, "ManagedDecimal<3>", &abi).unwrap();
+    let output = encode_human_readable_value(&value, "ManagedDecimal<3>", &abi).unwrap();
+    assert_eq!(output.to_string(), r#""1.500""#);
+
+    assert_eq!(
+        decode_error("1.555", "ManagedDecimal<2>"),
+        "too many decimals"
+    );
+    assert_eq!(
+        decode_error("-1", "ManagedDecimal<usize>"),
+        "expected unsigned decimal value"
+    );
+}
+
+#[test]
+fn struct_test() {
+    let expected_bytes = top_encode_to_vec_u8_or_panic(&(
+        b"TOKEN-123456".to_vec(),
+        vec![BigUint::from(5u32), BigUint::from(1000u32)],
+        Some(b"hi".to_vec()),
+    ));
+    check_roundtrip(
+        r#"{"token": "TOKEN-123456", "amounts": [5, 1000], "memo": "hi"}"#,
+        "Payment",
+        &expected_bytes,
+    );
+}
+
+#[test]
+fn enums_test() {
+    // explicit enums are represented and encoded as the variant name
+    check_roundtrip(r#""interrupted""#, "Status", b"interrupted");
+    assert_eq!(
+        decode_error(r#""other""#, "Status"),
+        "enum variant not found"
+    );
+
+    // the first empty variant is top-encoded as nothing
+    check_roundtrip(r#""First""#, "Simple", &[]);
+    check_roundtrip(r#""Second""#, "Simple", &[1]);
+    check_roundtrip(r#"["First", "Second"]"#, "List<Simple>", &[0, 1]);
+}
+
+#[test]
+fn nested_encoding_test() {
+    let abi = abi();
+    let input = r#"[7, "x"]"#.parse::<HumanReadableValue>().unwrap();
+    let type_name = "tuple<u32,bytes>";
+    let value = decode_human_readable_value(
+        &r#"[7, [120]]"#.parse::<HumanReadableValue>().unwrap(),
+        type_name,
+        &abi,
+    )
+    .unwrap();
+
+    let mut dest = Vec::new();
+    nested_encode_any_value(&value, type_name, &abi, &mut dest).unwrap();
+    assert_eq!(dest, vec![0, 0, 0, 7, 0, 0, 0, 1, b'x']);
+
+    // the input is only consumed as needed
+    dest.push(0xff);
+    let mut remaining = &dest[..];
+    let decoded = nested_decode_any_value(&mut remaining, type_name, &abi).unwrap();
+    assert_eq!(remaining, &[0xff]);
+
+    let output = encode_human_readable_value(&decoded, type_name, &abi).unwrap();
+    assert_eq!(output.to_string(), "[7,[120]]");
+
+    // strings and bytes are not interchangeable in JSON
+    assert!(decode_human_readable_value(&input, type_name, &abi).is_err());
+}
+
+#[test]
+fn multi_values_test() {
+    let abi = abi();
+    let type_name = "multi<u8,optional<counted-variadic<utf-8 string>>>";
+
+    let input = r#"[3, ["a", "bc"]]"#.parse::<HumanReadableValue>().unwrap();
+    let value = decode_human_readable_value(&input, type_name, &abi).unwrap();
+    let args = top_encode_multi_value(&value, type_name, &abi).unwrap();
+    assert_eq!(args, vec![vec![3], vec![2], b"a".to_vec(), b"bc".to_vec()]);
+
+    let mut remaining = &args[..];
+    let decoded = top_decode_multi_value(&mut remaining, type_name, &abi).unwrap();
+    assert!(remaining.is_empty());
+    let output = encode_human_readable_value(&decoded, type_name, &abi).unwrap();
+    assert_eq!(output.get_value(), input.get_value());
+
+    // missing optional arguments
+    let args = [vec![3u8]];
+    let mut remaining = &args[..];
+    let decoded = top_decode_multi_value(&mut remaining, type_name, &abi).unwrap();
+    let output = encode_human_readable_value(&decoded, type_name, &abi).unwrap();
+    assert_eq!(output.to_string(), "[3,null]");
+
+    // variadic arguments take everything left
+    let args = [vec![1u8], vec![], vec![2]];
+    let mut remaining = &args[..];
+    let decoded = top_decode_multi_value(&mut remaining, "variadic<u8>", &abi).unwrap();
+    let output = encode_human_readable_value(&decoded, "variadic<u8>", &abi).unwrap();
+    assert_eq!(output.to_string(), "[1,0,2]");
+}
+
+#[test]
+fn error_path_test() {
+    let abi = abi();
+
+    let input = r#"{"token": "TOKEN-123456", "amounts": [5, -1], "memo": null}"#
+        .parse::<HumanReadableValue>()
+        .unwrap();
+    let err = decode_human_readable_value(&input, "Payment", &abi)
+        .err()
+        .unwrap();
+    assert_eq!(err.to_string(), "amounts[1]: number out of range");
+    let path_err = err.downcast::<ValuePathError>().unwrap();
+    assert_eq!(path_err.path, "amounts[1]");
+
+    assert_eq!(
+        decode_error(r#"{"token": "TOKEN-123456", "amounts": []}"#, "Payment"),
+        "memo: missing field"
+    );
+
+    // the same paths when decoding bytes
+    let bytes = [0, 0, 0, 1, b'T', 0, 0, 0, 1, 0, 0, 0, 9];
+    let err = top_decode_any_value(&bytes, "Payment", &abi).err().unwrap();
+    assert_eq!(err.to_string(), "amounts[0]: input too short");
+}
+
+#[test]
+fn defaults_test() {
+    let abi = abi();
+    for type_name in [
+        "Payment",
+        "Status",
+        "Option<u8>",
+        "array3<u16>",
+        "tuple<Address,H256,CodeMetadata>",
+        "ManagedDecimal<18>",
+    ] {
+        let value = default_value_for_abi_type(type_name, &abi).unwrap();
+        let bytes = top_encode_any_value(&value, type_name, &abi).unwrap();
+        let decoded = top_decode_any_value(&bytes, type_name, &abi).unwrap();
+        let json = encode_human_readable_value(&decoded, type_name, &abi).unwrap();
+        assert_eq!(
+            json.get_value(),
+            encode_human_readable_value(&value, type_name, &abi)
+                .unwrap()
+                .get_value()
+        );
+    }
+
+    let AnyValue::SingleValue(SingleValue::UnsignedNumber(non_zero)) =
+        default_value_for_abi_type("NonZeroBigUint", &abi).unwrap()
+    else {
+        panic!("Expected default value to be a SingleValue::UnsignedNumber")
+    };
+    assert_eq!(non_zero, 1u32.into());
+
+    // without the ABI type, lists, options and decimals cannot be encoded
+    for type_name in [
+        "tuple<Option<u8>,List<u8>>",
+        "Option<u8>",
+        "ManagedDecimal<18>",
+    ] {
+        let value = default_value_for_abi_type(type_name, &abi).unwrap();
+        assert!(top_encode_to_vec_u8(&value).is_err());
+        assert!(top_encode_any_value(&value, type_name, &abi).is_ok());
+    }
+}
+
+#[test]
+fn events_test() {
+    let abi = abi();
+    let payment = r#"{"token":"EGLD","amounts":[5],"memo":null}"#;
+    let payment_value =
+        decode_human_readable_value(&payment.parse().unwrap(), "Payment", &abi).unwrap();
+    let data = vec![top_encode_any_value(&payment_value, "Payment", &abi).unwrap()];
+
+    let event = decode_event(&[b"payment".to_vec(), vec![7]], &data, &abi).unwrap();
+    assert_eq!(event.identifier, "payment");
+    let expected_fields: serde_json::Value =
+        serde_json::from_str(&format!(r#"{{"caller":7,"payment":{payment}}}"#)).unwrap();
+    assert_eq!(serde_json::Value::Object(event.fields), expected_fields);
+
+    let err = decode_event(&[b"other".to_vec()], &data, &abi).unwrap_err();
+    assert_eq!(err.to_string(), "unknown event: other");
+
+    let err = decode_event(&[b"payment".to_vec()], &data, &abi).unwrap_err();
+    assert_eq!(err.to_string(), "not enough topics");
+
+    let err = decode_event(&[b"payment".to_vec(), vec![7]], &[vec![0]], &abi).unwrap_err();
+    assert_eq!(err.to_string(), "payment.token: input too short");
+}
@@ -8,6 +8,8 @@ use alloc::{
 pub struct EventInputAbi {
     pub arg_name: String,
     pub type_name: TypeName,
+    /// The Rust type, as written in the generated proxies. Empty if the ABI was loaded from JSON.
+    pub rust_type_name: TypeName,
     pub indexed: bool,
 }
 
@@ -33,6 +35,7 @@ impl EventAbi {
         self.inputs.push(EventInputAbi {
             arg_name: arg_name.to_string(),
             type_name: T::type_name(),
+            rust_type_name: T::type_name_rust(),
             indexed,
         });
     }
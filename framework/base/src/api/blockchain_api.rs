@@ -29,6 +29,11 @@ pub trait BlockchainApiImpl: ManagedTypeApiImpl {
         self.mb_overwrite(dest, self.get_caller_legacy().as_bytes());
     }
 
+    fn load_original_caller_managed(&self, dest: Self::ManagedBufferHandle);
+
+    /// Loads an empty buffer if the transaction is not relayed.
+    fn load_relayer_managed(&self, dest: Self::ManagedBufferHandle);
+
     fn get_sc_address_legacy(&self) -> Address;
 
     fn load_sc_address_managed(&self, dest: Self::ManagedBufferHandle) {
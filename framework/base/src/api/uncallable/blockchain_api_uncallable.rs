@@ -16,6 +16,14 @@ impl BlockchainApi for UncallableApi {
 }
 
 impl BlockchainApiImpl for UncallableApi {
+    fn load_original_caller_managed(&self, _dest: Self::ManagedBufferHandle) {
+        unreachable!()
+    }
+
+    fn load_relayer_managed(&self, _dest: Self::ManagedBufferHandle) {
+        unreachable!()
+    }
+
     fn get_sc_address_legacy(&self) -> Address {
         unreachable!()
     }
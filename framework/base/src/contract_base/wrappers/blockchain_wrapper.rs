@@ -83,6 +83,32 @@ where
         }
     }
 
+    /// The sender of the original transaction, preserved across nested calls.
+    ///
+    /// For relayed transactions, this is the sender, not the relayer.
+    #[inline]
+    pub fn get_original_caller(&self) -> ManagedAddress<A> {
+        unsafe {
+            let result = ManagedAddress::new_uninit();
+            A::blockchain_api_impl().load_original_caller_managed(result.get_handle());
+            result
+        }
+    }
+
+    /// The relayer of the current transaction, if it is a relayed transaction (relayed v3).
+    pub fn get_relayer(&self) -> Option<ManagedAddress<A>> {
+        let relayer_buffer = unsafe {
+            let result = ManagedBuffer::new_uninit();
+            A::blockchain_api_impl().load_relayer_managed(result.get_handle());
+            result
+        };
+        if relayer_buffer.is_empty() {
+            None
+        } else {
+            ManagedAddress::try_from(relayer_buffer).ok()
+        }
+    }
+
     #[inline]
     pub fn get_owner_address(&self) -> ManagedAddress<A> {
         unsafe {
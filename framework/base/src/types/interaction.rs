@@ -15,6 +15,7 @@ mod tx_from;
 mod tx_gas;
 mod tx_payment;
 mod tx_proxy;
+mod tx_proxy_event;
 mod tx_result_handler;
 mod tx_result_handler_list;
 mod tx_to;
@@ -37,6 +38,7 @@ pub use tx_from::*;
 pub use tx_gas::*;
 pub use tx_payment::*;
 pub use tx_proxy::*;
+pub use tx_proxy_event::*;
 pub use tx_result_handler::{TxEmptyResultHandler, TxResultHandler};
 pub use tx_result_handler_list::*;
 pub use tx_to::*;
@@ -12,9 +12,9 @@ use super::{
     AnnotatedValue, Code, DeployCall, Egld, EgldPayment, ExplicitGas, FromSource, FunctionCall,
     ManagedArgBuffer, OriginalResultMarker, RHList, RHListAppendNoRet, RHListAppendRet, RHListItem,
     TxCodeSource, TxCodeValue, TxData, TxDataFunctionCall, TxEgldValue, TxEnv,
-    TxEnvMockDeployAddress, TxEnvWithTxHash, TxFrom, TxFromSourceValue, TxFromSpecified, TxGas,
-    TxGasValue, TxPayment, TxPaymentEgldOnly, TxProxyTrait, TxResultHandler, TxTo, TxToSpecified,
-    UpgradeCall,
+    TxEnvMockDeployAddress, TxEnvWithRelayer, TxEnvWithTxHash, TxFrom, TxFromSourceValue,
+    TxFromSpecified, TxGas, TxGasValue, TxPayment, TxPaymentEgldOnly, TxProxyTrait,
+    TxResultHandler, TxTo, TxToSpecified, UpgradeCall,
 };
 
 /// Universal representation of a blockchain transaction.
@@ -993,3 +993,27 @@ where
         self
     }
 }
+
+impl<Env, From, To, Payment, Gas, Data, RH> Tx<Env, From, To, Payment, Gas, Data, RH>
+where
+    Env: TxEnvWithRelayer,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Payment: TxPayment<Env>,
+    Gas: TxGas<Env>,
+    Data: TxDataFunctionCall<Env>,
+    RH: TxResultHandler<Env>,
+{
+    /// Turns the transaction into a relayed transaction (relayed v3).
+    ///
+    /// The relayer pays the gas, the sender remains the caller.
+    /// When sending to a real blockchain, the relayer wallet also needs to co-sign the transaction.
+    pub fn relayer<R>(mut self, relayer: R) -> Self
+    where
+        R: TxFromSpecified<Env>,
+    {
+        let relayer_address = relayer.resolve_address(&self.env).to_address();
+        self.env.set_relayer(relayer_address);
+        self
+    }
+}
@@ -1,6 +1,9 @@
 use crate::{
     api::CallTypeApi,
-    types::{ManagedAddress, ManagedBuffer, heap::H256},
+    types::{
+        ManagedAddress, ManagedBuffer,
+        heap::{Address, H256},
+    },
 };
 
 use super::{AnnotatedValue, TxFromSpecified};
@@ -38,3 +41,11 @@ pub trait TxEnvWithTxHash: TxEnv {
     /// Retrieves current tx hash, while resetting it in self.
     fn take_tx_hash(&mut self) -> Option<H256>;
 }
+
+/// Environments that can send relayed transactions (relayed v3), where a relayer pays the gas.
+pub trait TxEnvWithRelayer: TxEnv {
+    fn set_relayer(&mut self, relayer: Address);
+
+    /// Retrieves current relayer, while resetting it in self.
+    fn take_relayer(&mut self) -> Option<Address>;
+}
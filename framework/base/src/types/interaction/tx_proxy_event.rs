@@ -0,0 +1,72 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crate::codec::{DecodeError, TopDecode, TopDecodeMulti, TopEncode, TopEncodeMulti};
+
+/// Implemented by the event structs in generated proxies.
+///
+/// Decodes the logs produced by the `#[event]` with the same identifier.
+pub trait TxProxyEvent: Sized {
+    /// The event identifier, i.e. the first topic of the log.
+    const IDENTIFIER: &'static str;
+
+    /// Decodes the event fields: indexed fields from the topics, in order, the rest from the log data.
+    fn decode_event(input: &mut EventLogInput) -> Result<Self, DecodeError>;
+
+    /// Passes the event fields to the visitor, in order, as they are encoded in the log.
+    fn visit_fields<V: EventFieldVisitor>(&self, visitor: &mut V);
+
+    /// Decodes a log, given its raw topics (identifier included) and data.
+    ///
+    /// Returns `None` if the log was produced by a different event.
+    fn decode_log(topics: &[Vec<u8>], data: &[Vec<u8>]) -> Option<Result<Self, DecodeError>> {
+        let (identifier, topics) = topics.split_first()?;
+        if identifier.as_slice() != Self::IDENTIFIER.as_bytes() {
+            return None;
+        }
+
+        let mut input =
+            EventLogInput::new(topics.to_vec(), data.first().cloned().unwrap_or_default());
+        Some(Self::decode_event(&mut input).and_then(|event| input.finish().map(|()| event)))
+    }
+}
+
+/// The topics (without the identifier) and the data of an event log, being decoded.
+pub struct EventLogInput {
+    topics: Vec<Vec<u8>>,
+    data: Vec<u8>,
+}
+
+impl EventLogInput {
+    pub fn new(topics: Vec<Vec<u8>>, data: Vec<u8>) -> Self {
+        EventLogInput { topics, data }
+    }
+
+    /// Decodes the next indexed field. Multi-values can span several topics.
+    pub fn next_topic<T: TopDecodeMulti>(&mut self) -> Result<T, DecodeError> {
+        T::multi_decode(&mut self.topics)
+    }
+
+    /// Decodes the non-indexed field. Events have at most one.
+    pub fn data<T: TopDecode>(&mut self) -> Result<T, DecodeError> {
+        T::top_decode(core::mem::take(&mut self.data).into_boxed_slice())
+    }
+
+    /// Checks that all topics were consumed.
+    pub fn finish(self) -> Result<(), DecodeError> {
+        if self.topics.is_empty() {
+            Ok(())
+        } else {
+            Err(DecodeError::MULTI_TOO_MANY_ARGS)
+        }
+    }
+}
+
+/// Receives the fields of an event, see [`TxProxyEvent::visit_fields`].
+pub trait EventFieldVisitor {
+    /// An indexed field, encoded in the topics.
+    fn topic<T: TopEncodeMulti + Debug>(&mut self, name: &'static str, value: &T);
+
+    /// The non-indexed field, encoded in the log data.
+    fn data<T: TopEncode + Debug>(&mut self, name: &'static str, value: &T);
+}
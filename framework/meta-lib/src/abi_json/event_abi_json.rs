@@ -30,6 +30,7 @@ impl From<&EventInputAbiJson> for EventInputAbi {
         EventInputAbi {
             arg_name: abi.arg_name.to_string(),
             type_name: abi.type_name.clone(),
+            rust_type_name: String::new(),
             indexed: abi.indexed.unwrap_or(false),
         }
     }
@@ -34,7 +34,12 @@ impl From<&TypeDescriptionJson> for TypeDescription {
                     .map(EnumVariantDescriptionJson::to_enum_variant_description)
                     .collect(),
             ),
-            TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM => TypeContents::ExplicitEnum(vec![]), // TODO: @Laur implement explicit enum
+            TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM => TypeContents::ExplicitEnum(
+                abi.variants
+                    .iter()
+                    .map(EnumVariantDescriptionJson::to_explicit_enum_variant_description)
+                    .collect(),
+            ),
             TYPE_DESCRIPTION_JSON_TYPE_STRUCT => TypeContents::Struct(
                 abi.fields
                     .iter()
@@ -113,6 +118,12 @@ impl TypeDescriptionJson {
                         .map(EnumVariantDescriptionJson::to_enum_variant_description)
                         .collect(),
                 ),
+                TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM => TypeContents::ExplicitEnum(
+                    self.variants
+                        .iter()
+                        .map(EnumVariantDescriptionJson::to_explicit_enum_variant_description)
+                        .collect(),
+                ),
                 _ => TypeContents::NotSpecified,
             },
             macro_attributes: Vec::new(),
@@ -212,4 +223,11 @@ impl EnumVariantDescriptionJson {
                 .collect(),
         }
     }
+
+    pub fn to_explicit_enum_variant_description(&self) -> ExplicitEnumVariantDescription {
+        ExplicitEnumVariantDescription {
+            docs: self.docs.clone(),
+            name: self.name.clone(),
+        }
+    }
 }
@@ -1,8 +1,8 @@
 use std::fmt::Display;
 
 use multiversx_sc::abi::{
-    EndpointAbi, EnumVariantDescription, InputAbi, OutputAbi, StructFieldDescription, TypeContents,
-    TypeDescription,
+    EndpointAbi, EnumVariantDescription, EventAbi, InputAbi, OutputAbi, StructFieldDescription,
+    TypeContents, TypeDescription,
 };
 
 use crate::contract::{
@@ -12,8 +12,8 @@ use crate::contract::{
 };
 
 use super::proxy_process_type_name::{
-    extract_paths, extract_struct_crate, process_rust_type, proxy_methods_type_name,
-    proxy_type_name,
+    extract_paths, extract_struct_crate, process_rust_type, proxy_event_type_name,
+    proxy_methods_type_name, proxy_type_name,
 };
 
 const SEMICOLON: char = ';';
@@ -87,6 +87,7 @@ impl<'a> ProxyGenerator<'a> {
         self.write_struct_tx_proxy_methods();
         self.write_content();
         self.write_types();
+        self.write_events();
     }
 
     fn write_header(&mut self) {
@@ -251,6 +252,130 @@ where
         }
     }
 
+    fn write_events(&mut self) {
+        if !self.proxy_config.add_events || self.proxy_config.abi.events.is_empty() {
+            return;
+        }
+
+        self.writeln(
+            "
+pub mod events {
+    use super::*;",
+        );
+
+        let mut written_events: Vec<(String, EventAbi)> = Vec::new();
+        for event in self.proxy_config.abi.events.clone() {
+            if written_events
+                .iter()
+                .any(|(_, written)| same_event_signature(written, &event))
+            {
+                continue;
+            }
+
+            // events sharing an identifier, or a type name, get numbered: `DepositEvent2`, `DepositEvent3`...
+            let base_type_name = proxy_event_type_name(&event.identifier);
+            let mut type_name = base_type_name.clone();
+            let mut index = 1;
+            while written_events.iter().any(|(name, _)| name == &type_name) {
+                index += 1;
+                type_name = format!("{base_type_name}{index}");
+            }
+
+            self.write_event(&event, &type_name);
+            written_events.push((type_name, event));
+        }
+
+        self.writeln("}");
+    }
+
+    fn write_event(&mut self, event: &EventAbi, type_name: &str) {
+        let uses_api = event.inputs.iter().any(|input| {
+            input.rust_type_name.contains("$API") || input.rust_type_name.contains("UncallableApi")
+        });
+        let fields: Vec<(String, String)> = event
+            .inputs
+            .iter()
+            .map(|input| {
+                (
+                    input.arg_name.clone(),
+                    self.adjust_type_name_with_api(&input.rust_type_name),
+                )
+            })
+            .collect();
+        let generics = if uses_api { "<Api>" } else { "" };
+        let where_clause = if generics.is_empty() {
+            " "
+        } else {
+            "
+    where
+        Api: ManagedTypeApi,
+    "
+        };
+
+        self.writeln("");
+        for doc in &event.docs {
+            self.writeln(format!("    /// {doc}"));
+        }
+        self.writeln("    #[derive(Debug, Clone, PartialEq)]");
+        self.write(format!(
+            "    pub struct {type_name}{generics}{where_clause}"
+        ));
+        if fields.is_empty() {
+            self.writeln("{}");
+        } else {
+            self.writeln("{");
+            for (field_name, field_type) in &fields {
+                self.writeln(format!("        pub {field_name}: {field_type},"));
+            }
+            self.writeln("    }");
+        }
+
+        let identifier = &event.identifier;
+        let input_name = if fields.is_empty() { "_input" } else { "input" };
+        self.write(format!(
+            r#"
+    impl{generics} TxProxyEvent for {type_name}{generics}{where_clause}{{
+        const IDENTIFIER: &'static str = "{identifier}";
+
+        fn decode_event({input_name}: &mut EventLogInput) -> Result<Self, DecodeError> {{
+            Ok({type_name} {{"#
+        ));
+        if fields.is_empty() {
+            self.writeln("})");
+        } else {
+            self.writeln("");
+            for (input, (field_name, _)) in event.inputs.iter().zip(&fields) {
+                let method = if input.indexed { "next_topic" } else { "data" };
+                self.writeln(format!("                {field_name}: input.{method}()?,"));
+            }
+            self.writeln("            })");
+        }
+        self.writeln("        }");
+
+        let visitor_name = if fields.is_empty() {
+            "_visitor"
+        } else {
+            "visitor"
+        };
+        self.write(format!(
+            "
+        fn visit_fields<V: EventFieldVisitor>(&self, {visitor_name}: &mut V) {{"
+        ));
+        if fields.is_empty() {
+            self.writeln("}");
+        } else {
+            self.writeln("");
+            for (input, (field_name, _)) in event.inputs.iter().zip(&fields) {
+                let method = if input.indexed { "topic" } else { "data" };
+                self.writeln(format!(
+                    "            visitor.{method}(\"{field_name}\", &self.{field_name});"
+                ));
+            }
+            self.writeln("        }");
+        }
+        self.writeln("    }");
+    }
+
     /// Checks if a path-rename rule applies to this type's crate prefix,
     /// meaning the type should not be generated in the proxy (it's accessible via the renamed path).
     fn has_path_rename_for_crate(&self, rust_name: &str) -> bool {
@@ -761,9 +886,23 @@ where
     }
 }
 
+/// The same event, declared more than once, only gets generated once.
+fn same_event_signature(first: &EventAbi, second: &EventAbi) -> bool {
+    first.identifier == second.identifier
+        && first.inputs.len() == second.inputs.len()
+        && first.inputs.iter().zip(&second.inputs).all(|(a, b)| {
+            a.arg_name == b.arg_name
+                && a.rust_type_name == b.rust_type_name
+                && a.indexed == b.indexed
+        })
+}
+
 #[cfg(test)]
 pub mod tests {
-    use multiversx_sc::abi::{BuildInfoAbi, ContractAbi, ContractCrateBuildAbi, FrameworkBuildAbi};
+    use multiversx_sc::abi::{
+        BuildInfoAbi, ContractAbi, ContractCrateBuildAbi, EventAbi, EventInputAbi,
+        FrameworkBuildAbi,
+    };
 
     use crate::contract::{meta_config::MetaConfig, sc_config::proxy_config::ProxyConfig};
 
@@ -830,4 +969,138 @@ pub mod tests {
 
         assert_eq!(expected_result_sanitized, cleaned_path_sanitized.as_str());
     }
+
+    fn event_input(arg_name: &str, rust_type_name: &str, indexed: bool) -> EventInputAbi {
+        EventInputAbi {
+            arg_name: arg_name.to_owned(),
+            type_name: String::new(),
+            rust_type_name: rust_type_name.to_owned(),
+            indexed,
+        }
+    }
+
+    #[test]
+    fn write_events_test() {
+        let build_info = BuildInfoAbi {
+            rustc: None,
+            contract_crate: ContractCrateBuildAbi {
+                name: "contract-crate".to_owned(),
+                version: "0.0.0".to_owned(),
+                git_version: "0.0.0".to_owned(),
+            },
+            framework: FrameworkBuildAbi::create(),
+        };
+
+        let mut contract_abi = ContractAbi::new(build_info, &[""], "contract-crate", false);
+        let mut deposit_event = EventAbi::new(&["Funds received."], "deposit");
+        deposit_event.inputs = vec![
+            event_input("caller", "ManagedAddress<$API>", true),
+            event_input("amount", "BigUint<$API>", false),
+            event_input("nonce", "u64", true),
+        ];
+        contract_abi.events.push(deposit_event);
+        contract_abi.events.push(EventAbi::new(&[], "paused-event"));
+
+        let meta_config = MetaConfig::create(contract_abi.clone(), false);
+        let mut proxy_config = ProxyConfig::output_dir_proxy_config(contract_abi);
+        proxy_config.add_events = true;
+
+        let mut output = Vec::new();
+        let mut proxy_generator =
+            ProxyGenerator::new(&meta_config, &mut output, &proxy_config, false);
+        proxy_generator.write_events();
+
+        let expected = r#"
+pub mod events {
+    use super::*;
+
+    /// Funds received.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DepositEvent<Api>
+    where
+        Api: ManagedTypeApi,
+    {
+        pub caller: ManagedAddress<Api>,
+        pub amount: BigUint<Api>,
+        pub nonce: u64,
+    }
+
+    impl<Api> TxProxyEvent for DepositEvent<Api>
+    where
+        Api: ManagedTypeApi,
+    {
+        const IDENTIFIER: &'static str = "deposit";
+
+        fn decode_event(input: &mut EventLogInput) -> Result<Self, DecodeError> {
+            Ok(DepositEvent {
+                caller: input.next_topic()?,
+                amount: input.data()?,
+                nonce: input.next_topic()?,
+            })
+        }
+
+        fn visit_fields<V: EventFieldVisitor>(&self, visitor: &mut V) {
+            visitor.topic("caller", &self.caller);
+            visitor.data("amount", &self.amount);
+            visitor.topic("nonce", &self.nonce);
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PausedEvent {}
+
+    impl TxProxyEvent for PausedEvent {
+        const IDENTIFIER: &'static str = "paused-event";
+
+        fn decode_event(_input: &mut EventLogInput) -> Result<Self, DecodeError> {
+            Ok(PausedEvent {})
+        }
+
+        fn visit_fields<V: EventFieldVisitor>(&self, _visitor: &mut V) {}
+    }
+}
+"#;
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+
+    #[test]
+    fn write_events_shared_identifier_test() {
+        let build_info = BuildInfoAbi {
+            rustc: None,
+            contract_crate: ContractCrateBuildAbi {
+                name: "contract-crate".to_owned(),
+                version: "0.0.0".to_owned(),
+                git_version: "0.0.0".to_owned(),
+            },
+            framework: FrameworkBuildAbi::create(),
+        };
+
+        let mut contract_abi = ContractAbi::new(build_info, &[""], "contract-crate", false);
+        contract_abi.events.push(EventAbi::new(&[], "paused"));
+        // the same event, declared twice
+        contract_abi.events.push(EventAbi::new(&[], "paused"));
+        // a different event, with the same identifier
+        let mut paused_with_reason = EventAbi::new(&[], "paused");
+        paused_with_reason.inputs = vec![event_input("reason", "u64", true)];
+        contract_abi.events.push(paused_with_reason);
+
+        let meta_config = MetaConfig::create(contract_abi.clone(), false);
+        let mut proxy_config = ProxyConfig::output_dir_proxy_config(contract_abi);
+        proxy_config.add_events = true;
+
+        let mut output = Vec::new();
+        let mut proxy_generator =
+            ProxyGenerator::new(&meta_config, &mut output, &proxy_config, false);
+        proxy_generator.write_events();
+
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.matches("pub struct PausedEvent {}").count(), 1);
+        assert_eq!(output.matches("pub struct PausedEvent2 {").count(), 1);
+        assert_eq!(
+            output
+                .matches("const IDENTIFIER: &'static str = \"paused\";")
+                .count(),
+            2
+        );
+    }
 }
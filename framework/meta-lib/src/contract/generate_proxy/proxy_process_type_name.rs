@@ -8,6 +8,26 @@ pub(super) fn proxy_methods_type_name(contract_trait_name: &str) -> String {
     format!("{contract_trait_name}ProxyMethods")
 }
 
+/// Converts an event identifier (e.g. `"swap-tokens"`, `"tokensSwapped"`) into a struct name.
+pub(super) fn proxy_event_type_name(event_identifier: &str) -> String {
+    let mut name = String::new();
+    for segment in event_identifier
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|segment| !segment.is_empty())
+    {
+        let mut chars = segment.chars();
+        if let Some(first) = chars.next() {
+            name.push(first.to_ascii_uppercase());
+            name.extend(chars);
+        }
+    }
+
+    if !name.ends_with("Event") {
+        name.push_str("Event");
+    }
+    name
+}
+
 /// Extracts the crate name from a fully qualified Rust type path.
 ///
 /// Returns the first segment before `::`. If the path contains no `::`,
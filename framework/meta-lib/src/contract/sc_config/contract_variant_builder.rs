@@ -316,6 +316,7 @@ fn process_proxy_contracts(config: &ScConfigSerde, original_abi: &ContractAbi) -
                 PathBuf::from(&proxy_config.path),
                 proxy_config.override_import.to_owned(),
                 proxy_config.path_rename.to_owned(),
+                proxy_config.add_events,
                 contract.abi,
             ));
         }
@@ -9,6 +9,7 @@ pub struct ProxyConfig {
     pub path: PathBuf,
     pub override_import: String,
     pub path_rename: Vec<PathRename>,
+    pub add_events: bool,
     pub abi: ContractAbi,
 }
 
@@ -17,12 +18,14 @@ impl ProxyConfig {
         path: PathBuf,
         override_imports: Option<String>,
         path_rename: Option<Vec<PathRename>>,
+        add_events: Option<bool>,
         abi: ContractAbi,
     ) -> Self {
         ProxyConfig {
             path,
             override_import: override_imports.unwrap_or_default(),
             path_rename: path_rename.unwrap_or_default(),
+            add_events: add_events.unwrap_or_default(),
             abi,
         }
     }
@@ -33,6 +36,7 @@ impl ProxyConfig {
             path: Path::new("output").join(proxy_output),
             override_import: String::new(),
             path_rename: Vec::new(),
+            add_events: false,
             abi,
         }
     }
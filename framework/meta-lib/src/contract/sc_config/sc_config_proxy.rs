@@ -29,6 +29,10 @@ pub struct ProxyConfigSerde {
     #[serde(default)]
     #[serde(rename = "add-endpoints")]
     pub add_endpoints: Vec<String>,
+
+    #[serde(default)]
+    #[serde(rename = "add-events")]
+    pub add_events: Option<bool>,
 }
 
 #[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq, Hash)]
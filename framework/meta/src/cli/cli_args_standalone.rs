@@ -77,6 +77,12 @@ pub enum StandaloneCliAction {
     #[command(name = "report", about = "Generate code report")]
     CodeReportGen(CodeReportArgs),
 
+    #[command(
+        name = "gas-report",
+        about = "Compares or converts gas profiles produced by scenario tests."
+    )]
+    GasReport(GasReportArgs),
+
     #[command(
         about = "Generates a scenario test initialized with real data fetched from the blockchain."
     )]
@@ -337,6 +343,49 @@ pub struct ConvertArgs {
     pub output: PathBuf,
 }
 
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct GasReportArgs {
+    #[command(subcommand)]
+    pub command: GasReportAction,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum GasReportAction {
+    #[command(name = "compare", about = "Compare two gas profiles.")]
+    Compare(GasReportCompareArgs),
+
+    #[command(name = "convert", about = "Converts a gas profile to a Markdown file.")]
+    Convert(GasReportConvertArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct GasReportCompareArgs {
+    /// Path to the previous version of the gas profile JSON file
+    /// that will be used for comparison.
+    #[arg(short, long, verbatim_doc_comment)]
+    pub baseline: PathBuf,
+
+    /// Path to the current version of the gas profile JSON file
+    /// that will be compared.
+    #[arg(short, long, verbatim_doc_comment)]
+    pub new: PathBuf,
+
+    /// Path to the Markdown file where the comparison results will be written.
+    #[arg(short, long, verbatim_doc_comment)]
+    pub output: PathBuf,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct GasReportConvertArgs {
+    /// Path to the gas profile JSON file that needs to be converted to Markdown format.
+    #[arg(short, long, verbatim_doc_comment)]
+    pub input: PathBuf,
+
+    /// Path to the Markdown file where the report will be written.
+    #[arg(short, long, verbatim_doc_comment)]
+    pub output: PathBuf,
+}
+
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
 pub struct MetaLibArgs {
     #[arg(
@@ -1,5 +1,5 @@
 use clap::{Args, Subcommand};
-use std::path::PathBuf;
+use std::{fmt, path::PathBuf, str::FromStr};
 
 pub use super::cli_args_sender::SenderArgs;
 
@@ -50,8 +50,13 @@ pub struct GatewayArgs {
 #[derive(Clone, PartialEq, Eq, Debug, Args)]
 pub struct TxArgs {
     /// Gas limit for the transaction.
+    /// If omitted, the gas limit is estimated via the proxy's transaction cost endpoint.
     #[arg(long)]
-    pub gas_limit: u64,
+    pub gas_limit: Option<u64>,
+
+    /// Factor applied to the estimated gas limit. Only used when --gas-limit is omitted.
+    #[arg(long, default_value = "1.0", conflicts_with = "gas_limit")]
+    pub gas_limit_multiplier: GasLimitMultiplier,
 
     /// Gas price override in smallest EGLD denomination.
     /// If omitted, the minimum gas price is taken from the network config automatically.
@@ -67,15 +72,93 @@ pub struct TxArgs {
     #[arg(long, default_value = "false")]
     pub send: bool,
 
+    /// If set, the transaction is executed against the current network state, without being broadcast.
+    /// The simulation results are added to the tx JSON.
+    #[arg(long, default_value = "false", conflicts_with = "send")]
+    pub simulate: bool,
+
     /// Wait for the transaction result. Requires --send.
     #[arg(long, default_value = "false", requires = "send")]
     pub wait_result: bool,
 
+    /// Maximum number of seconds to wait for the transaction result.
+    /// If omitted, --wait-result waits until the transaction reaches a final status.
+    #[arg(long, requires = "wait_result", value_name = "SECONDS")]
+    pub timeout: Option<u64>,
+
     /// Path to write the signed tx JSON to. Defaults to stdout when --send is not set.
     #[arg(long)]
     pub outfile: Option<PathBuf>,
 }
 
+/// Factor applied to the estimated gas limit.
+///
+/// Kept as an exact decimal fraction, `numerator / 10^decimals`, without trailing zeros.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GasLimitMultiplier {
+    numerator: u64,
+    decimals: u32,
+}
+
+impl GasLimitMultiplier {
+    pub fn apply(self, gas_units: u64) -> u64 {
+        let denominator = 10u128.pow(self.decimals);
+        let gas_limit = (gas_units as u128 * self.numerator as u128).div_ceil(denominator);
+        gas_limit.try_into().unwrap_or(u64::MAX)
+    }
+}
+
+impl Default for GasLimitMultiplier {
+    fn default() -> Self {
+        GasLimitMultiplier {
+            numerator: 1,
+            decimals: 0,
+        }
+    }
+}
+
+impl FromStr for GasLimitMultiplier {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid gas limit multiplier: {s}");
+        let (integer_part, fractional_part) = s.split_once('.').unwrap_or((s, ""));
+        let fractional_part = fractional_part.trim_end_matches('0');
+        let digits = format!("{integer_part}{fractional_part}");
+        if digits.is_empty()
+            || !digits.bytes().all(|b| b.is_ascii_digit())
+            || fractional_part.len() > 18
+        {
+            return Err(invalid());
+        }
+
+        let numerator: u64 = digits.parse().map_err(|_| invalid())?;
+        if numerator == 0 {
+            return Err(format!("gas limit multiplier must be positive, got {s}"));
+        }
+        Ok(GasLimitMultiplier {
+            numerator,
+            decimals: fractional_part.len() as u32,
+        })
+    }
+}
+
+impl fmt::Display for GasLimitMultiplier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let denominator = 10u64.pow(self.decimals);
+        let integer_part = self.numerator / denominator;
+        if self.decimals == 0 {
+            return write!(f, "{integer_part}");
+        }
+        let fractional_part = self.numerator % denominator;
+        write!(
+            f,
+            "{integer_part}.{fractional_part:0width$}",
+            width = self.decimals as usize
+        )
+    }
+}
+
 /// Payment arguments: EGLD value and optional ESDT token transfers.
 #[derive(Clone, PartialEq, Eq, Debug, Args)]
 pub struct PaymentArgs {
@@ -124,6 +207,10 @@ pub struct DeployArgs {
     #[arg(long, num_args = 0..)]
     pub arguments: Vec<String>,
 
+    /// Path to the contract ABI JSON file, used to decode the results of --simulate.
+    #[arg(long)]
+    pub abi: Option<PathBuf>,
+
     #[command(flatten)]
     pub gateway: GatewayArgs,
 
@@ -150,9 +237,15 @@ pub struct CallArgs {
     pub function: String,
 
     /// Endpoint arguments in mandos expression format.
+    /// With --abi, human-readable values typed by the endpoint inputs (e.g. erd1..., 1.5, '{"token":"EGLD"}').
     #[arg(long, num_args = 0..)]
     pub arguments: Vec<String>,
 
+    /// Path to the contract ABI JSON file.
+    /// Used to check and encode the arguments, and to decode the results of --simulate.
+    #[arg(long)]
+    pub abi: Option<PathBuf>,
+
     #[command(flatten)]
     pub gateway: GatewayArgs,
 
@@ -183,6 +276,10 @@ pub struct UpgradeArgs {
     #[arg(long, num_args = 0..)]
     pub arguments: Vec<String>,
 
+    /// Path to the contract ABI JSON file, used to decode the results of --simulate.
+    #[arg(long)]
+    pub abi: Option<PathBuf>,
+
     #[command(flatten)]
     pub gateway: GatewayArgs,
 
@@ -209,9 +306,14 @@ pub struct QueryArgs {
     pub function: String,
 
     /// Query arguments in mandos expression format.
+    /// With --abi, human-readable values typed by the endpoint inputs (e.g. erd1..., 1.5, '{"token":"EGLD"}').
     #[arg(long, num_args = 0..)]
     pub arguments: Vec<String>,
 
+    /// Path to the contract ABI JSON file, used to check and encode the arguments, and to decode the results.
+    #[arg(long)]
+    pub abi: Option<PathBuf>,
+
     #[command(flatten)]
     pub gateway: GatewayArgs,
 }
@@ -260,6 +362,11 @@ pub struct SendArgs {
     /// Wait for the transaction result after broadcasting.
     #[arg(long, default_value = "false")]
     pub wait_result: bool,
+
+    /// Maximum number of seconds to wait for the transaction result.
+    /// If omitted, --wait-result waits until the transaction reaches a final status.
+    #[arg(long, requires = "wait_result", value_name = "SECONDS")]
+    pub timeout: Option<u64>,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Args)]
@@ -280,9 +387,62 @@ pub struct SignArgs {
     #[arg(long, default_value = "false", requires = "send")]
     pub wait_result: bool,
 
+    /// Maximum number of seconds to wait for the transaction result.
+    /// If omitted, --wait-result waits until the transaction reaches a final status.
+    #[arg(long, requires = "wait_result", value_name = "SECONDS")]
+    pub timeout: Option<u64>,
+
     #[command(flatten)]
     pub sender: SenderArgs,
 
     #[command(flatten)]
     pub gateway: GatewayArgs,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gas_limit_multiplier_parse() {
+        let multiplier = |numerator, decimals| GasLimitMultiplier {
+            numerator,
+            decimals,
+        };
+        assert_eq!("1.5".parse(), Ok(multiplier(15, 1)));
+        assert_eq!("1.50".parse(), Ok(multiplier(15, 1)));
+        assert_eq!("2".parse(), Ok(multiplier(2, 0)));
+        assert_eq!("2.0".parse(), Ok(multiplier(2, 0)));
+        assert_eq!(".25".parse(), Ok(multiplier(25, 2)));
+        assert!("0".parse::<GasLimitMultiplier>().is_err());
+        assert!("0.00".parse::<GasLimitMultiplier>().is_err());
+        assert!(".".parse::<GasLimitMultiplier>().is_err());
+        assert!("1e3".parse::<GasLimitMultiplier>().is_err());
+        assert!("-1.2".parse::<GasLimitMultiplier>().is_err());
+        assert!("NaN".parse::<GasLimitMultiplier>().is_err());
+        assert!("inf".parse::<GasLimitMultiplier>().is_err());
+        assert!("abc".parse::<GasLimitMultiplier>().is_err());
+    }
+
+    #[test]
+    fn gas_limit_multiplier_apply_rounds_up() {
+        assert_eq!(GasLimitMultiplier::default().apply(50_000), 50_000);
+        let multiplier = |s: &str| s.parse::<GasLimitMultiplier>().unwrap();
+        assert_eq!(multiplier("1.5").apply(50_000), 75_000);
+        assert_eq!(multiplier("1.1").apply(3), 4);
+        assert_eq!(multiplier("0.05").apply(1_000), 50);
+    }
+
+    #[test]
+    fn gas_limit_multiplier_display() {
+        assert_eq!(GasLimitMultiplier::default().to_string(), "1");
+        assert_eq!(
+            "1.50".parse::<GasLimitMultiplier>().unwrap().to_string(),
+            "1.5"
+        );
+        assert_eq!(
+            "0.05".parse::<GasLimitMultiplier>().unwrap().to_string(),
+            "0.05"
+        );
+    }
+}
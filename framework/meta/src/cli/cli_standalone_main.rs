@@ -9,6 +9,7 @@ use clap::Parser;
 
 use crate::cmd::all::call_all_meta;
 use crate::cmd::code_report::report;
+use crate::cmd::gas_report::gas_report;
 use crate::cmd::info::call_info;
 use crate::cmd::install::install;
 use crate::cmd::reproducible_builds::{
@@ -59,6 +60,9 @@ pub async fn cli_main_standalone() {
             args.validate_args();
             report(args);
         }
+        Some(StandaloneCliAction::GasReport(args)) => {
+            gas_report(args);
+        }
         Some(StandaloneCliAction::Account(args)) => {
             args.validate_args();
             retrieve_address(args).await;
@@ -3,6 +3,7 @@ pub mod chain_simulator;
 mod check_wasmer_dependencies;
 pub mod code_report;
 pub mod data;
+pub mod gas_report;
 pub mod info;
 pub mod install;
 pub mod print_util;
@@ -0,0 +1,39 @@
+pub mod compare;
+pub mod render_gas_report;
+
+use std::{fs::File, path::Path};
+
+use multiversx_sc_scenario::scenario::run_gas_profile::GasProfileReport;
+use render_gas_report::GasReportRender;
+
+use crate::cli::{GasReportAction, GasReportArgs, GasReportCompareArgs, GasReportConvertArgs};
+
+pub fn gas_report(args: &GasReportArgs) {
+    match &args.command {
+        GasReportAction::Compare(compare_args) => compare_gas_report(compare_args),
+        GasReportAction::Convert(convert_args) => convert_gas_report(convert_args),
+    }
+}
+
+pub fn compare_gas_report(compare_args: &GasReportCompareArgs) {
+    let mut output_file = create_file(&compare_args.output);
+
+    let baseline = if compare_args.baseline.exists() {
+        GasProfileReport::load_from_file(&compare_args.baseline)
+    } else {
+        GasProfileReport::default()
+    };
+    let new = GasProfileReport::load_from_file(&compare_args.new);
+
+    GasReportRender::new(&mut output_file, &baseline, &new).compare_reports();
+}
+
+pub fn convert_gas_report(convert_args: &GasReportConvertArgs) {
+    let report = GasProfileReport::load_from_file(&convert_args.input);
+    std::fs::write(&convert_args.output, report.to_markdown())
+        .expect("could not write gas report file");
+}
+
+fn create_file(file_path: &Path) -> File {
+    File::create(file_path).expect("could not write gas report file")
+}
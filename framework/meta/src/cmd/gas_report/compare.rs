@@ -0,0 +1,33 @@
+pub(crate) fn gas_status_after_comparing(gas: u64, compared_gas: u64) -> String {
+    match gas.cmp(&compared_gas) {
+        std::cmp::Ordering::Greater => {
+            format!(
+                "{} :arrow_right: {} :red_circle: (+{}{})",
+                compared_gas,
+                gas,
+                gas - compared_gas,
+                percentage_suffix(gas - compared_gas, compared_gas)
+            )
+        }
+        std::cmp::Ordering::Less => {
+            format!(
+                "{} :arrow_right: {} :green_circle: (-{}{})",
+                compared_gas,
+                gas,
+                compared_gas - gas,
+                percentage_suffix(compared_gas - gas, compared_gas)
+            )
+        }
+        std::cmp::Ordering::Equal => {
+            format!("{}", gas)
+        }
+    }
+}
+
+fn percentage_suffix(diff: u64, compared_gas: u64) -> String {
+    if compared_gas == 0 {
+        return String::new();
+    }
+
+    format!(", {:.2}%", diff as f64 * 100.0 / compared_gas as f64)
+}
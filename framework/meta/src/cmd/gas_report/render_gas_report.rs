@@ -0,0 +1,144 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use multiversx_sc_scenario::scenario::run_gas_profile::{
+    ContractGasProfile, EndpointGasProfile, GasProfileReport,
+};
+
+use super::compare::gas_status_after_comparing;
+
+pub struct GasReportRender<'a> {
+    pub file: Option<&'a mut dyn std::io::Write>,
+    pub compared_report: &'a GasProfileReport,
+    pub report: &'a GasProfileReport,
+}
+
+impl<'a> GasReportRender<'a> {
+    pub fn new(
+        file: &'a mut dyn std::io::Write,
+        compared_report: &'a GasProfileReport,
+        report: &'a GasProfileReport,
+    ) -> Self {
+        Self {
+            file: Some(file),
+            compared_report,
+            report,
+        }
+    }
+
+    pub fn compare_reports(&mut self) {
+        self.writeln("# Gas profile");
+
+        let empty_contract = ContractGasProfile::default();
+        for (contract_name, contract) in &self.report.contracts {
+            let compared_contract = self
+                .compared_report
+                .contracts
+                .get(contract_name)
+                .unwrap_or(&empty_contract);
+            self.render_contract(contract_name, contract, compared_contract);
+        }
+
+        for contract_name in self.compared_report.contracts.keys() {
+            if !self.report.contracts.contains_key(contract_name) {
+                self.writeln("");
+                self.writeln(format!("## {contract_name}"));
+                self.writeln("");
+                self.writeln(":x: Contract no longer profiled.");
+            }
+        }
+
+        if self.compared_report.is_empty() {
+            self.writeln("\n:warning: Could not find the gas report for the base branch. Displaying only the report for the current branch. :warning:");
+        }
+    }
+
+    fn writeln(&mut self, s: impl Display) {
+        let file = self.file.as_mut().unwrap();
+        file.write_all(s.to_string().as_bytes()).unwrap();
+        file.write_all(b"\n").unwrap();
+    }
+
+    fn render_contract(
+        &mut self,
+        contract_name: &str,
+        contract: &ContractGasProfile,
+        compared_contract: &ContractGasProfile,
+    ) {
+        self.writeln("");
+        self.writeln(format!("## {contract_name}"));
+        self.writeln("");
+        self.writeln("| Endpoint | Calls | Avg | Max |");
+        self.writeln("| :-- | --: | --: | --: |");
+
+        for (endpoint_name, endpoint) in &contract.endpoints {
+            match compared_contract.endpoints.get(endpoint_name) {
+                Some(compared_endpoint) => {
+                    self.render_compared_endpoint(endpoint_name, endpoint, compared_endpoint)
+                }
+                None => self.writeln(format!(
+                    "| {endpoint_name} :new: | {} | {} | {} |",
+                    endpoint.calls,
+                    endpoint.avg_gas(),
+                    endpoint.max_gas
+                )),
+            }
+        }
+
+        for endpoint_name in compared_contract.endpoints.keys() {
+            if !contract.endpoints.contains_key(endpoint_name) {
+                self.writeln(format!("| {endpoint_name} :x: | - | - | - |"));
+            }
+        }
+
+        self.render_categories(&contract.categories(), &compared_contract.categories());
+    }
+
+    fn render_compared_endpoint(
+        &mut self,
+        endpoint_name: &str,
+        endpoint: &EndpointGasProfile,
+        compared_endpoint: &EndpointGasProfile,
+    ) {
+        self.writeln(format!(
+            "| {endpoint_name} | {} | {} | {} |",
+            endpoint.calls,
+            gas_status_after_comparing(endpoint.avg_gas(), compared_endpoint.avg_gas()),
+            gas_status_after_comparing(endpoint.max_gas, compared_endpoint.max_gas),
+        ));
+    }
+
+    /// Category totals depend on the number of calls, so they are only relevant when the test suite did not change.
+    fn render_categories(
+        &mut self,
+        categories: &BTreeMap<String, u64>,
+        compared_categories: &BTreeMap<String, u64>,
+    ) {
+        if categories.is_empty() && compared_categories.is_empty() {
+            return;
+        }
+
+        self.writeln("");
+        self.writeln("| Category | Total |");
+        self.writeln("| :-- | --: |");
+
+        let mut category_names: Vec<&String> = categories.keys().collect();
+        for category_name in compared_categories.keys() {
+            if !categories.contains_key(category_name) {
+                category_names.push(category_name);
+            }
+        }
+        category_names.sort();
+
+        for category_name in category_names {
+            let gas = categories.get(category_name).copied().unwrap_or_default();
+            let compared_gas = compared_categories
+                .get(category_name)
+                .copied()
+                .unwrap_or_default();
+            self.writeln(format!(
+                "| {category_name} | {} |",
+                gas_status_after_comparing(gas, compared_gas)
+            ));
+        }
+    }
+}
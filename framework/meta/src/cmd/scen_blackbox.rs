@@ -3,7 +3,7 @@ mod const_state;
 mod contract_scenarios;
 mod format_values;
 mod num_format;
-mod parse_abi;
+pub(crate) mod parse_abi;
 mod scenario_loader;
 mod set_state_gen;
 mod step_code_gen;
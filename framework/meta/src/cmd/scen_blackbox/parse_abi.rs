@@ -13,7 +13,7 @@ use multiversx_sc::abi::TypeName;
 /// For `arrayN<T>` types the numeric size is extracted as the first type argument.
 /// Nested angle brackets are handled correctly, e.g.
 /// `variadic<multi<u64, Address>>` → `("variadic", ["multi<u64, Address>"])`.
-pub(super) fn parse_abi_type(abi_type: &str) -> (TypeName, Vec<TypeName>) {
+pub(crate) fn parse_abi_type(abi_type: &str) -> (TypeName, Vec<TypeName>) {
     // Special case: arrayN<T> — e.g. "array32<u8>"
     if let Some(rest) = abi_type.strip_prefix("array") {
         if let Some(open) = rest.find('<') {
@@ -51,7 +51,7 @@ pub(super) fn parse_abi_type(abi_type: &str) -> (TypeName, Vec<TypeName>) {
 /// Parses a comma-separated list of type names, respecting nested angle brackets.
 ///
 /// Used to split `multi<A,B,...>` or the contents of any parameterized ABI type.
-pub(super) fn parse_multi_fields(s: &str) -> Vec<TypeName> {
+pub(crate) fn parse_multi_fields(s: &str) -> Vec<TypeName> {
     let mut fields = Vec::new();
     let mut depth = 0;
     let mut current = String::new();
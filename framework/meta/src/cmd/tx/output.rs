@@ -1,5 +1,6 @@
-use multiversx_sc_snippets::sdk::data::transaction::{ApiTransactionResult, Transaction};
+use multiversx_sc_snippets::sdk::data::transaction::{ApiLogs, ApiTransactionResult, Transaction};
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
 
 /// mxpy-compatible output format for a signed transaction.
 ///
@@ -28,4 +29,32 @@ pub struct TxOutputFile {
         skip_serializing_if = "Option::is_none"
     )]
     pub transaction_on_network: Option<ApiTransactionResult>,
+
+    /// Populated when the transaction was simulated instead of broadcast.
+    #[serde(rename = "simulation", skip_serializing_if = "Option::is_none")]
+    pub simulation: Option<TxSimulationOutput>,
+}
+
+/// Outcome of a `--simulate` run, nothing of which was committed on-chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxSimulationOutput {
+    pub status: String,
+
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub fail_reason: String,
+
+    /// The gas limit, minus the gas refunded to the sender in the simulation.
+    #[serde(default)]
+    pub gas_used: u64,
+
+    /// Hex-encoded raw results.
+    pub return_data: Vec<String>,
+
+    /// Results decoded according to the contract ABI, when one was provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decoded_return_data: Option<Vec<JsonValue>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logs: Option<ApiLogs>,
 }
@@ -1,8 +1,14 @@
 use anyhow::Result;
-use multiversx_sc_snippets::imports::{Bech32Address, Interactor, InteractorIntoSdkTransaction};
+use multiversx_sc_snippets::{
+    DynamicContract,
+    imports::{Bech32Address, Interactor, InteractorIntoSdkTransaction},
+};
 
 use super::parse_payments::parse_all_payment_args;
-use super::tx_cli_common::{build_arg_buffer, load_wallet, sign_and_dispatch};
+use super::tx_cli_common::{
+    AbiEndpointKind, AbiResultDecoder, build_abi_arg_buffer, build_arg_buffer, load_wallet,
+    sign_and_dispatch,
+};
 use crate::cli::cli_args_tx::CallArgs;
 
 pub async fn tx_call(args: &CallArgs) {
@@ -28,20 +34,44 @@ async fn tx_call_inner(args: &CallArgs) -> Result<()> {
     };
 
     let contract = Bech32Address::try_from_bech32_string(args.contract.clone())?;
+    let abi_contract = args
+        .abi
+        .as_deref()
+        .map(|abi| DynamicContract::from_abi_file(abi, contract.clone()))
+        .transpose()?;
 
     // Build call transaction.
-    let arg_buffer = build_arg_buffer(&args.arguments)?;
+    let arg_buffer = match &abi_contract {
+        Some(abi_contract) => build_abi_arg_buffer(abi_contract, &args.function, &args.arguments)?,
+        None => build_arg_buffer(&args.arguments)?,
+    };
     let payments = parse_all_payment_args(&args.payment)?;
 
     let tx = interactor
         .tx()
         .from(&sender_bech32)
         .to(&contract)
-        .gas(args.tx.gas_limit)
+        .gas(args.tx.gas_limit.unwrap_or_default())
         .payment(payments)
         .raw_call(args.function.as_str())
         .arguments_raw(arg_buffer)
         .into_sdk_transaction();
 
-    sign_and_dispatch(wallet, tx, nonce, &args.tx, &args.gateway, None).await
+    let result_decoder = abi_contract.map(|abi_contract| {
+        AbiResultDecoder::new(
+            abi_contract,
+            AbiEndpointKind::Endpoint(args.function.clone()),
+        )
+    });
+
+    sign_and_dispatch(
+        wallet,
+        tx,
+        nonce,
+        &args.tx,
+        &args.gateway,
+        None,
+        result_decoder,
+    )
+    .await
 }
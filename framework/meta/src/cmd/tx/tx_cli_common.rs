@@ -1,15 +1,19 @@
-use std::fs;
+use std::{fs, time::Duration};
 
 use anyhow::{Context, Result, anyhow};
 use multiversx_sc_snippets::ExplorerUrl;
 use multiversx_sc_snippets::{
-    hex,
+    DynamicContract, hex,
     imports::{
         BytesValue, GatewayHttpProxy, InterpretableFrom, InterpreterContext, ManagedArgBuffer,
         ManagedBuffer, StaticApi,
     },
     sdk::{
-        data::transaction::{ApiTransactionResult, Transaction},
+        RetrievePolicy,
+        data::transaction::{
+            ApiLogs, ApiTransactionResult, Transaction, TransactionSimulationResults,
+        },
+        retrieve_tx_on_network::parse_reason,
         utils::base64_decode,
         wallet::Wallet,
     },
@@ -17,10 +21,11 @@ use multiversx_sc_snippets::{
 use serde::Serialize;
 
 use multiversx_sc_scenario::imports::ReturnCode;
+use multiversx_sc_scenario::scenario_model::{TxResponse, TxResponseStatus};
 use multiversx_sc_snippets::network_response;
 use serde_json::Value;
 
-use super::output::TxOutputFile;
+use super::output::{TxOutputFile, TxSimulationOutput};
 pub use crate::cli::cli_args_sender::load_wallet;
 use crate::cli::cli_args_tx::{GatewayArgs, TxArgs};
 
@@ -46,13 +51,59 @@ pub(super) fn load_transaction_from_file(path: &std::path::Path) -> Result<Trans
         .with_context(|| format!("failed to deserialize transaction from {}", path.display()))
 }
 
+/// The polling policy for `--wait-result`, bounded by `--timeout`, if given.
+/// `None` if the result is not awaited.
+pub(super) fn wait_result_policy(
+    wait_result: bool,
+    timeout_seconds: Option<u64>,
+) -> Option<RetrievePolicy> {
+    wait_result.then(|| match timeout_seconds {
+        Some(timeout_seconds) => {
+            RetrievePolicy::default().with_timeout(Duration::from_secs(timeout_seconds))
+        }
+        None => RetrievePolicy::default().without_timeout(),
+    })
+}
+
 /// Wait for a transaction result on the network.
 pub(super) async fn fetch_tx_on_network(
     gateway: &str,
     tx_hash: &str,
+    policy: &RetrievePolicy,
 ) -> Result<(ApiTransactionResult, ReturnCode)> {
     let proxy = GatewayHttpProxy::new(gateway.to_string());
-    multiversx_sdk::retrieve_tx_on_network(&proxy, tx_hash.to_string()).await
+    multiversx_sdk::retrieve_tx_on_network_with_policy(&proxy, tx_hash.to_string(), policy).await
+}
+
+/// Fill in the gas limit from the proxy's cost estimation endpoint,
+/// unless it was explicitly given via `--gas-limit`.
+///
+/// Must be called after all other fields of the transaction have been set, and before signing.
+pub(super) async fn apply_gas_limit(
+    tx: &mut Transaction,
+    tx_args: &TxArgs,
+    proxy_url: &str,
+) -> Result<()> {
+    if let Some(gas_limit) = tx_args.gas_limit {
+        tx.gas_limit = gas_limit;
+        return Ok(());
+    }
+
+    let proxy = GatewayHttpProxy::new(proxy_url.to_string());
+    let cost = proxy
+        .request_transaction_cost(tx)
+        .await
+        .context("failed to estimate gas limit")?;
+    if !cost.return_message.is_empty() {
+        return Err(anyhow!("gas estimation failed: {}", cost.return_message));
+    }
+
+    tx.gas_limit = tx_args.gas_limit_multiplier.apply(cost.tx_gas_units);
+    println!(
+        "estimated gas limit: {} (x{})",
+        tx.gas_limit, tx_args.gas_limit_multiplier
+    );
+    Ok(())
 }
 
 /// Write `output` to `outfile`, or print to stdout when no outfile is given.
@@ -69,11 +120,13 @@ pub(super) fn save_output(output: &TxOutputFile, outfile: Option<&std::path::Pat
 
 /// Broadcast the transaction inside `output`, update the hash (and optionally
 /// the on-network result), then write/print the updated output.
+///
+/// The on-network result is only awaited if a `wait_result` policy is given.
 pub(super) async fn broadcast_and_save(
     output: TxOutputFile,
     proxy_url: &str,
     outfile: Option<&std::path::Path>,
-    wait_result: bool,
+    wait_result: Option<RetrievePolicy>,
 ) -> Result<()> {
     if output.emitted_transaction.signature.is_none() {
         return Err(anyhow!(
@@ -97,9 +150,10 @@ pub(super) async fn broadcast_and_save(
         ..output
     };
 
-    if wait_result {
+    if let Some(policy) = wait_result {
         println!("Waiting for transaction result...");
-        let (tx_on_network, return_code) = fetch_tx_on_network(proxy_url, &tx_hash).await?;
+        let (tx_on_network, return_code) =
+            fetch_tx_on_network(proxy_url, &tx_hash, &policy).await?;
         let tx_response = network_response::parse_tx_response(tx_on_network.clone(), return_code);
         print_tx_results(&tx_response);
         output_with_hash.transaction_on_network = Some(tx_on_network);
@@ -114,6 +168,119 @@ pub(super) async fn broadcast_and_save(
     Ok(())
 }
 
+/// Simulate the transaction inside `output` against the current network state,
+/// attach the simulation results, then write/print the updated output.
+/// Nothing is broadcast.
+pub(super) async fn simulate_and_save(
+    output: TxOutputFile,
+    proxy_url: &str,
+    outfile: Option<&std::path::Path>,
+    result_decoder: Option<&AbiResultDecoder>,
+) -> Result<()> {
+    if output.emitted_transaction.signature.is_none() {
+        return Err(anyhow!(
+            "transaction is not signed; sign it before simulating"
+        ));
+    }
+
+    let proxy = GatewayHttpProxy::new(proxy_url.to_string());
+    let results = proxy
+        .simulate_transaction(&output.emitted_transaction)
+        .await
+        .context("failed to simulate transaction")?;
+    let gas_used = simulation_gas_used(
+        output.emitted_transaction.gas_limit,
+        output.emitted_transaction.gas_price,
+        &results,
+    );
+
+    let tx_response = simulation_tx_response(&output.emitted_transaction, &results, gas_used);
+    println!("Simulation results:");
+    print_tx_results(&tx_response);
+    println!("Gas used: {gas_used}");
+    if let Some(logs) = &results.logs {
+        print_logs(logs);
+    }
+
+    let decoded_return_data =
+        result_decoder.and_then(|decoder| match decoder.decode_results(&tx_response.out) {
+            Ok(decoded) => Some(decoded),
+            Err(err) => {
+                println!("Cannot decode results: {err:#}");
+                None
+            }
+        });
+    if let Some(decoded) = &decoded_return_data {
+        for (i, value) in decoded.iter().enumerate() {
+            println!("Decoded[{i}]: {value}");
+        }
+    }
+
+    let simulation = TxSimulationOutput {
+        status: results.status,
+        fail_reason: results.fail_reason,
+        gas_used,
+        return_data: tx_response.out.iter().map(hex::encode).collect(),
+        decoded_return_data,
+        logs: results.logs,
+    };
+    let output_with_simulation = TxOutputFile {
+        simulation: Some(simulation),
+        ..output
+    };
+    save_output(&output_with_simulation, outfile)
+}
+
+/// The simulation reports no gas used, only the gas refunded to the sender, in the refund results.
+fn simulation_gas_used(
+    gas_limit: u64,
+    gas_price: u64,
+    results: &TransactionSimulationResults,
+) -> u64 {
+    if gas_price == 0 {
+        return gas_limit;
+    }
+    let refund: u128 = results
+        .sc_results
+        .values()
+        .filter(|sc_result| sc_result.is_refund)
+        .map(|sc_result| sc_result.value)
+        .sum();
+    let refunded_gas = refund / gas_price as u128;
+    gas_limit.saturating_sub(refunded_gas.try_into().unwrap_or(u64::MAX))
+}
+
+/// Interpret the simulation results the same way as the results of an executed transaction.
+fn simulation_tx_response(
+    tx: &Transaction,
+    results: &TransactionSimulationResults,
+    gas_used: u64,
+) -> TxResponse {
+    if results.status == "fail" || !results.fail_reason.is_empty() {
+        let (return_code, message) = parse_reason(&results.fail_reason);
+        return TxResponse {
+            tx_error: TxResponseStatus::new(return_code, &message),
+            ..Default::default()
+        };
+    }
+
+    let tx_on_network = ApiTransactionResult {
+        nonce: tx.nonce,
+        value: tx.value.clone(),
+        receiver: tx.receiver.clone(),
+        sender: tx.sender.clone(),
+        gas_price: tx.gas_price,
+        gas_limit: tx.gas_limit,
+        gas_used,
+        data: tx.data.clone(),
+        status: results.status.clone(),
+        smart_contract_results: results.sc_results.values().cloned().collect(),
+        logs: results.logs.clone(),
+        ..Default::default()
+    };
+    network_response::parse_tx_response(tx_on_network, ReturnCode::Success)
+}
+
 /// Serialize a value to a JSON string with 4-space indentation (matches mxpy output).
 pub(super) fn to_json_pretty<T: Serialize>(value: &T) -> Result<String> {
     let mut buf = Vec::new();
@@ -138,9 +305,150 @@ pub fn build_arg_buffer(arguments: &[String]) -> Result<ManagedArgBuffer<StaticA
     Ok(arg_buffer)
 }
 
-/// Apply nonce / gas-price / chain-id overrides, sign the transaction, then
-/// write / print / broadcast it according to the `TxArgs` flags.
+/// Interpret the arguments as human-readable values, typed by the endpoint inputs in the contract ABI
+/// (e.g. `erd1...`, `1.5`, `{"token":"EGLD","amount":"1000"}`).
+///
+/// Each argument is converted according to the type of its input, see `abi_arg_to_json`.
+pub fn build_abi_arg_buffer(
+    contract: &DynamicContract,
+    endpoint_name: &str,
+    arguments: &[String],
+) -> Result<ManagedArgBuffer<StaticApi>> {
+    let inputs = &contract.endpoint_abi(endpoint_name)?.inputs;
+    let json_args: Vec<Value> = arguments
+        .iter()
+        .enumerate()
+        .map(|(index, arg)| match inputs.get(index) {
+            Some(input) => abi_arg_to_json(arg, &input.type_names.abi),
+            None => parse_json_or_string(arg),
+        })
+        .collect();
+    let function_call = contract.function_call(endpoint_name, &json_args)?;
+    Ok(function_call.arg_buffer)
+}
+
+/// Converts a command line argument to JSON, based on the ABI type of its input.
+///
+/// - Numbers and decimals are passed on as strings, JSON numbers would lose precision
+///   beyond `u64` and drop the trailing zeros of decimals.
+/// - Strings, addresses and the other text types are taken as they are, they need no quotes.
+/// - Byte buffers accept a JSON array of bytes, anything else is taken as UTF-8 text.
+/// - Everything else is parsed as JSON, falling back to a string, e.g. for enum variant names.
+///
+/// In all cases, `null` and quoted JSON strings keep their JSON meaning.
+fn abi_arg_to_json(arg: &str, type_name: &str) -> Value {
+    let parsed = serde_json::from_str::<Value>(arg).ok();
+    if matches!(parsed, Some(Value::Null)) {
+        return Value::Null;
+    }
+
+    let type_name = strip_option_type(type_name);
+    if matches!(type_name, "ManagedBuffer" | "bytes") {
+        return match parsed {
+            Some(Value::Array(items)) => Value::Array(items),
+            Some(Value::String(text)) => bytes_to_json(text.as_bytes()),
+            _ => bytes_to_json(arg.as_bytes()),
+        };
+    }
+
+    if is_number_type(type_name) || is_text_type(type_name) {
+        return match parsed {
+            Some(Value::String(text)) => Value::String(text),
+            _ => Value::String(arg.to_owned()),
+        };
+    }
+
+    parsed.unwrap_or_else(|| Value::String(arg.to_owned()))
+}
+
+fn parse_json_or_string(arg: &str) -> Value {
+    serde_json::from_str(arg).unwrap_or_else(|_| Value::String(arg.to_owned()))
+}
+
+/// `Option<T>` and `optional<T>` arguments are given as their item, or `null`.
+fn strip_option_type(type_name: &str) -> &str {
+    ["Option<", "optional<"]
+        .iter()
+        .find_map(|prefix| type_name.strip_prefix(prefix)?.strip_suffix('>'))
+        .unwrap_or(type_name)
+}
+
+fn is_number_type(type_name: &str) -> bool {
+    matches!(
+        type_name,
+        "u8" | "u16"
+            | "u32"
+            | "u64"
+            | "usize"
+            | "i8"
+            | "i16"
+            | "i32"
+            | "i64"
+            | "isize"
+            | "BigUint"
+            | "NonZeroBigUint"
+            | "BigInt"
+    ) || type_name.starts_with("ManagedDecimal<")
+        || type_name.starts_with("ManagedDecimalSigned<")
+}
+
+fn is_text_type(type_name: &str) -> bool {
+    matches!(
+        type_name,
+        "string"
+            | "utf-8 string"
+            | "TokenIdentifier"
+            | "EgldOrEsdtTokenIdentifier"
+            | "TokenId"
+            | "Address"
+            | "H256"
+            | "CodeMetadata"
+    )
+}
+
+fn bytes_to_json(bytes: &[u8]) -> Value {
+    Value::Array(bytes.iter().map(|byte| Value::from(*byte)).collect())
+}
+
+/// Which ABI entry point produced the results that need decoding.
+pub(super) enum AbiEndpointKind {
+    Constructor,
+    UpgradeConstructor,
+    Endpoint(String),
+}
+
+/// Decodes raw transaction results into JSON, based on the output types declared in the contract ABI.
+///
+/// Goes through the same codec as the ABI-typed arguments, so both directions support the same types.
+pub(super) struct AbiResultDecoder {
+    contract: DynamicContract,
+    endpoint_kind: AbiEndpointKind,
+}
+
+impl AbiResultDecoder {
+    pub fn new(contract: DynamicContract, endpoint_kind: AbiEndpointKind) -> Self {
+        AbiResultDecoder {
+            contract,
+            endpoint_kind,
+        }
+    }
+
+    /// Decodes all results, one JSON value per ABI output.
+    pub fn decode_results(&self, raw_results: &[Vec<u8>]) -> Result<Vec<Value>> {
+        match &self.endpoint_kind {
+            AbiEndpointKind::Constructor => self.contract.decode_constructor_results(raw_results),
+            AbiEndpointKind::UpgradeConstructor => {
+                self.contract.decode_upgrade_results(raw_results)
+            }
+            AbiEndpointKind::Endpoint(name) => self.contract.decode_results(name, raw_results),
+        }
+    }
+}
+
+/// Apply nonce / gas-price / chain-id overrides, estimate the gas limit if needed, sign the
+/// transaction, then write / print / broadcast / simulate it according to the `TxArgs` flags.
 /// `contract_address` should be `Some(bech32)` for deploy transactions.
+/// `result_decoder` is only used when simulating.
 pub async fn sign_and_dispatch(
     wallet: Wallet,
     mut tx: Transaction,
@@ -148,6 +456,7 @@ pub async fn sign_and_dispatch(
     tx_args: &TxArgs,
     gateway_args: &GatewayArgs,
     contract_address: Option<String>,
+    result_decoder: Option<AbiResultDecoder>,
 ) -> Result<()> {
     tx.nonce = nonce;
     if let Some(gas_price) = tx_args.gas_price {
@@ -156,6 +465,7 @@ pub async fn sign_and_dispatch(
     if let Some(chain_id) = &gateway_args.chain {
         tx.chain_id = chain_id.clone();
     }
+    apply_gas_limit(&mut tx, tx_args, &gateway_args.proxy).await?;
 
     let decoded_data = tx
         .data
@@ -172,6 +482,7 @@ pub async fn sign_and_dispatch(
         emitted_transaction_hash: String::new(),
         contract_address,
         transaction_on_network: None,
+        simulation: None,
     };
 
     if tx_args.send {
@@ -179,7 +490,15 @@ pub async fn sign_and_dispatch(
             output,
             &gateway_args.proxy,
             tx_args.outfile.as_deref(),
-            tx_args.wait_result,
+            wait_result_policy(tx_args.wait_result, tx_args.timeout),
+        )
+        .await?;
+    } else if tx_args.simulate {
+        simulate_and_save(
+            output,
+            &gateway_args.proxy,
+            tx_args.outfile.as_deref(),
+            result_decoder.as_ref(),
         )
         .await?;
     } else {
@@ -199,3 +518,290 @@ pub(super) fn print_tx_results(tx_response: &multiversx_sc_scenario::scenario_mo
         println!("Result[{i}]: 0x{}", hex::encode(result));
     }
 }
+
+fn print_logs(logs: &ApiLogs) {
+    for (i, event) in logs.events.iter().enumerate() {
+        println!("Log[{i}]: {} ({})", event.identifier, event.address);
+        for topic in &event.topics {
+            println!("    topic: 0x{}", hex::encode(base64_decode(topic)));
+        }
+        event
+            .data
+            .for_each(|data| println!("    data: 0x{}", hex::encode(base64_decode(data))));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use multiversx_sc_meta_lib::abi_json::deserialize_abi_from_json;
+    use multiversx_sc_snippets::imports::Bech32Address;
+
+    use super::*;
+
+    const ABI_JSON: &str = r#"{
+        "name": "Test",
+        "constructor": { "inputs": [], "outputs": [] },
+        "endpoints": [
+            {
+                "name": "stake",
+                "mutability": "mutable",
+                "inputs": [
+                    { "name": "owner", "type": "Address" },
+                    { "name": "rate", "type": "ManagedDecimal<2>" },
+                    { "name": "kind", "type": "StakeKind" }
+                ],
+                "outputs": []
+            },
+            {
+                "name": "deposit",
+                "mutability": "mutable",
+                "inputs": [
+                    { "name": "amount", "type": "BigUint" },
+                    { "name": "rate", "type": "ManagedDecimal<usize>" },
+                    { "name": "label", "type": "string" },
+                    { "name": "memo", "type": "ManagedBuffer" },
+                    { "name": "limit", "type": "optional<u64>", "multi_arg": true }
+                ],
+                "outputs": []
+            }
+        ],
+        "types": {
+            "StakeKind": {
+                "type": "enum",
+                "variants": [
+                    { "name": "Flexible", "discriminant": 0 },
+                    {
+                        "name": "Locked",
+                        "discriminant": 1,
+                        "fields": [{ "name": "epochs", "type": "u64" }]
+                    }
+                ]
+            }
+        }
+    }"#;
+
+    fn build_endpoint_args(endpoint_name: &str, arguments: &[&str]) -> Result<Vec<Vec<u8>>> {
+        let abi = deserialize_abi_from_json(ABI_JSON).unwrap().into();
+        let contract = DynamicContract::new(abi, Bech32Address::zero_default_hrp());
+        let arguments: Vec<String> = arguments.iter().map(|arg| arg.to_string()).collect();
+        let arg_buffer = build_abi_arg_buffer(&contract, endpoint_name, &arguments)?;
+        Ok(arg_buffer.raw_arg_iter().map(|arg| arg.to_vec()).collect())
+    }
+
+    fn build_args(arguments: &[&str]) -> Result<Vec<Vec<u8>>> {
+        build_endpoint_args("stake", arguments)
+    }
+
+    #[test]
+    fn abi_arguments_are_human_readable() {
+        let owner = Bech32Address::zero_default_hrp().to_bech32_string();
+        assert_eq!(
+            build_args(&[&owner, "1.5", r#"{"Locked":{"epochs":10}}"#]).unwrap(),
+            vec![vec![0u8; 32], vec![0x96], vec![1, 0, 0, 0, 0, 0, 0, 0, 10]]
+        );
+        assert_eq!(
+            build_args(&[&owner, "\"2\"", "Flexible"]).unwrap(),
+            vec![vec![0u8; 32], vec![0xc8], vec![]]
+        );
+    }
+
+    #[test]
+    fn abi_arguments_converted_by_input_type() {
+        let amount = 100_000_000_000_000_000_000u128.to_be_bytes();
+        let amount = amount[amount.iter().position(|&b| b != 0).unwrap()..].to_vec();
+        assert_eq!(
+            build_endpoint_args(
+                "deposit",
+                &["100000000000000000000", "1.50", "42", "42", "7"]
+            )
+            .unwrap(),
+            vec![
+                amount.clone(),
+                vec![0, 0, 0, 1, 150, 0, 0, 0, 2],
+                b"42".to_vec(),
+                b"42".to_vec(),
+                vec![7],
+            ]
+        );
+
+        // quoted strings and byte arrays, optional argument left out
+        assert_eq!(
+            build_endpoint_args(
+                "deposit",
+                &["\"100000000000000000000\"", "\"0.5\"", "\"42\"", "[1,2]"]
+            )
+            .unwrap(),
+            vec![
+                amount,
+                vec![0, 0, 0, 1, 5, 0, 0, 0, 1],
+                b"42".to_vec(),
+                vec![1, 2]
+            ]
+        );
+    }
+
+    #[test]
+    fn abi_arguments_are_checked() {
+        let owner = Bech32Address::zero_default_hrp().to_bech32_string();
+        assert_eq!(
+            build_args(&[&owner]).unwrap_err().to_string(),
+            "stake expects 3 arguments, got 1"
+        );
+        assert_eq!(
+            build_args(&[&owner, "1.555", "Flexible"])
+                .unwrap_err()
+                .to_string(),
+            "invalid argument rate: too many decimals"
+        );
+        assert_eq!(
+            build_args(&["erd1invalid", "1", "Flexible"])
+                .unwrap_err()
+                .to_string(),
+            "invalid argument owner: invalid bech32 address"
+        );
+    }
+
+    const RESULTS_ABI_JSON: &str = r#"{
+        "name": "Test",
+        "constructor": { "inputs": [], "outputs": [{ "type": "u32" }] },
+        "endpoints": [
+            {
+                "name": "getInfo",
+                "mutability": "readonly",
+                "inputs": [],
+                "outputs": [
+                    { "type": "Info" },
+                    { "type": "Status" },
+                    { "type": "variadic<multi<TokenIdentifier,BigUint>>", "multi_result": true }
+                ]
+            },
+            {
+                "name": "getRate",
+                "mutability": "readonly",
+                "inputs": [],
+                "outputs": [{ "type": "ManagedDecimal<2>" }]
+            }
+        ],
+        "types": {
+            "Info": {
+                "type": "struct",
+                "fields": [
+                    { "name": "owner", "type": "Address" },
+                    { "name": "amount", "type": "BigUint" },
+                    { "name": "nonce", "type": "u64" },
+                    { "name": "flags", "type": "List<bool>" }
+                ]
+            },
+            "Status": {
+                "type": "enum",
+                "variants": [
+                    { "name": "Inactive", "discriminant": 0 },
+                    {
+                        "name": "Active",
+                        "discriminant": 1,
+                        "fields": [{ "name": "0", "type": "u32" }]
+                    }
+                ]
+            }
+        }
+    }"#;
+
+    fn result_decoder(endpoint_kind: AbiEndpointKind) -> AbiResultDecoder {
+        let abi = deserialize_abi_from_json(RESULTS_ABI_JSON).unwrap().into();
+        let contract = DynamicContract::new(abi, Bech32Address::zero_default_hrp());
+        AbiResultDecoder::new(contract, endpoint_kind)
+    }
+
+    fn info_bytes() -> Vec<u8> {
+        let mut raw = vec![0u8; 32];
+        raw.extend_from_slice(&[0, 0, 0, 2, 0x03, 0xe8]); // amount: 1000
+        raw.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 5]); // nonce: 5
+        raw.extend_from_slice(&[0, 0, 0, 2, 1, 0]); // flags: [true, false]
+        raw
+    }
+
+    #[test]
+    fn decode_struct_enum_and_variadic() {
+        let decoded = result_decoder(AbiEndpointKind::Endpoint("getInfo".to_owned()))
+            .decode_results(&[
+                info_bytes(),
+                vec![1, 0, 0, 0, 7],
+                b"TOKEN-123456".to_vec(),
+                vec![0x01, 0x00],
+            ])
+            .unwrap();
+
+        assert_eq!(decoded.len(), 3);
+        assert_eq!(decoded[0]["nonce"], serde_json::json!(5));
+        assert_eq!(decoded[0]["flags"], serde_json::json!([true, false]));
+    }
+
+    #[test]
+    fn decode_managed_decimal() {
+        let decoded = result_decoder(AbiEndpointKind::Endpoint("getRate".to_owned()))
+            .decode_results(&[vec![0x96]])
+            .unwrap();
+        assert_eq!(decoded, vec![serde_json::json!("1.50")]);
+    }
+
+    #[test]
+    fn decode_constructor_results() {
+        let decoded = result_decoder(AbiEndpointKind::Constructor)
+            .decode_results(&[vec![0x2a]])
+            .unwrap();
+        assert_eq!(decoded, vec![serde_json::json!(42)]);
+    }
+
+    #[test]
+    fn undecodable_results_are_errors() {
+        assert!(
+            result_decoder(AbiEndpointKind::Endpoint("getRate".to_owned()))
+                .decode_results(&[vec![0x96], vec![0x01]])
+                .is_err()
+        );
+        assert!(
+            result_decoder(AbiEndpointKind::Endpoint("missing".to_owned()))
+                .decode_results(&[])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn wait_result_policy_timeout() {
+        assert_eq!(wait_result_policy(false, Some(10)), None);
+        assert_eq!(wait_result_policy(true, None).unwrap().timeout, None);
+        assert_eq!(
+            wait_result_policy(true, Some(10)).unwrap().timeout,
+            Some(Duration::from_secs(10))
+        );
+    }
+
+    #[test]
+    fn simulation_gas_used_subtracts_refund() {
+        let sc_result = |hash: &str, value: u64, is_refund: bool| {
+            format!(
+                r#""{hash}": {{
+                    "nonce": 1,
+                    "value": {value},
+                    "receiver": "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th",
+                    "sender": "erd1qqqqqqqqqqqqqpgqfzydqmdw7m2vazsp6u5p95yxz76t2p9rd8ss0zp9ts",
+                    "prevTxHash": "",
+                    "originalTxHash": "",
+                    "gasLimit": 0,
+                    "gasPrice": 1000000000,
+                    "callType": 0,
+                    "isRefund": {is_refund}
+                }}"#
+            )
+        };
+        let results_json = format!(
+            r#"{{ "status": "success", "scResults": {{ {}, {} }} }}"#,
+            sc_result("01", 2_000_000_000_000, true),
+            sc_result("02", 5_000_000_000_000, false),
+        );
+        let results: TransactionSimulationResults = serde_json::from_str(&results_json).unwrap();
+
+        assert_eq!(simulation_gas_used(10_000, 1_000_000_000, &results), 8_000);
+        assert_eq!(simulation_gas_used(10_000, 0, &results), 10_000);
+    }
+}
@@ -2,11 +2,13 @@ use std::fs;
 
 use anyhow::{Context, Result};
 use multiversx_sc::chain_core::std::new_address::compute_new_address_bech32;
-use multiversx_sc_snippets::ExplorerUrl;
 use multiversx_sc_snippets::imports::{BytesValue, Interactor, InteractorIntoSdkTransaction};
+use multiversx_sc_snippets::{DynamicContract, ExplorerUrl};
 
 use super::parse_code_metadata::parse_code_metadata;
-use super::tx_cli_common::{build_arg_buffer, load_wallet, sign_and_dispatch};
+use super::tx_cli_common::{
+    AbiEndpointKind, AbiResultDecoder, build_arg_buffer, load_wallet, sign_and_dispatch,
+};
 use crate::cli::cli_args_tx::DeployArgs;
 
 pub async fn tx_deploy(args: &DeployArgs) {
@@ -44,7 +46,7 @@ async fn tx_deploy_inner(args: &DeployArgs) -> Result<()> {
     let tx_builder = interactor
         .tx()
         .from(&sender_bech32)
-        .gas(args.tx.gas_limit)
+        .gas(args.tx.gas_limit.unwrap_or_default())
         .egld(args.payment.value)
         .raw_deploy()
         .code(code)
@@ -64,6 +66,13 @@ async fn tx_deploy_inner(args: &DeployArgs) -> Result<()> {
         println!("new contract address: {contract_address}");
     }
 
+    let result_decoder = args
+        .abi
+        .as_deref()
+        .map(|abi| DynamicContract::from_abi_file(abi, contract_address.clone()))
+        .transpose()?
+        .map(|contract| AbiResultDecoder::new(contract, AbiEndpointKind::Constructor));
+
     sign_and_dispatch(
         wallet,
         tx,
@@ -71,6 +80,7 @@ async fn tx_deploy_inner(args: &DeployArgs) -> Result<()> {
         &args.tx,
         &args.gateway,
         Some(contract_address.to_bech32_string()),
+        result_decoder,
     )
     .await
 }
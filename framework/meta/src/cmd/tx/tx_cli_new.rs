@@ -13,7 +13,9 @@ use crate::cmd::tx::tx_cli_common::load_wallet;
 use super::{
     output::TxOutputFile,
     parse_payments::parse_all_payment_args,
-    tx_cli_common::{broadcast_and_save, save_output},
+    tx_cli_common::{
+        apply_gas_limit, broadcast_and_save, save_output, simulate_and_save, wait_result_policy,
+    },
 };
 
 pub async fn tx_new(args: &NewArgs) {
@@ -45,7 +47,7 @@ async fn tx_new_inner(args: &NewArgs) -> Result<()> {
         .tx()
         .from(&sender)
         .to(&receiver)
-        .gas(args.tx.gas_limit)
+        .gas(args.tx.gas_limit.unwrap_or_default())
         .payment(payments)
         .into_sdk_transaction();
 
@@ -68,6 +70,7 @@ async fn tx_new_inner(args: &NewArgs) -> Result<()> {
     if let Some(chain_id) = &args.gateway.chain {
         tx.chain_id = chain_id.clone();
     }
+    apply_gas_limit(&mut tx, &args.tx, &args.gateway.proxy).await?;
 
     let sig = wallet.sign_tx(&tx);
     tx.signature = Some(hex::encode(sig));
@@ -78,6 +81,7 @@ async fn tx_new_inner(args: &NewArgs) -> Result<()> {
         emitted_transaction_hash: String::new(),
         contract_address: None,
         transaction_on_network: None,
+        simulation: None,
     };
 
     if args.tx.send {
@@ -85,7 +89,15 @@ async fn tx_new_inner(args: &NewArgs) -> Result<()> {
             output,
             &args.gateway.proxy,
             args.tx.outfile.as_deref(),
-            args.tx.wait_result,
+            wait_result_policy(args.tx.wait_result, args.tx.timeout),
+        )
+        .await?;
+    } else if args.tx.simulate {
+        simulate_and_save(
+            output,
+            &args.gateway.proxy,
+            args.tx.outfile.as_deref(),
+            None,
         )
         .await?;
     } else {
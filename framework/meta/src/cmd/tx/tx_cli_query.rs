@@ -1,11 +1,13 @@
 use anyhow::{Context, Result, anyhow};
 use multiversx_sc_snippets::{
-    hex,
+    DynamicContract, hex,
     imports::{Bech32Address, GatewayHttpProxy},
     sdk::data::vm::VMQueryInput,
 };
 
-use super::tx_cli_common::{build_arg_buffer, to_json_pretty};
+use super::tx_cli_common::{
+    AbiEndpointKind, AbiResultDecoder, build_abi_arg_buffer, build_arg_buffer, to_json_pretty,
+};
 use crate::cli::cli_args_tx::QueryArgs;
 
 pub async fn tx_query(args: &QueryArgs) {
@@ -17,9 +19,17 @@ pub async fn tx_query(args: &QueryArgs) {
 
 async fn tx_query_inner(args: &QueryArgs) -> Result<()> {
     let contract = Bech32Address::try_from_bech32_string(args.contract.clone())?;
+    let abi_contract = args
+        .abi
+        .as_deref()
+        .map(|abi| DynamicContract::from_abi_file(abi, contract.clone()))
+        .transpose()?;
 
     // Encode arguments as hex strings (the gateway expects hex, no "0x" prefix).
-    let arg_buffer = build_arg_buffer(&args.arguments)?;
+    let arg_buffer = match &abi_contract {
+        Some(abi_contract) => build_abi_arg_buffer(abi_contract, &args.function, &args.arguments)?,
+        None => build_arg_buffer(&args.arguments)?,
+    };
     let hex_args: Vec<String> = arg_buffer
         .raw_arg_iter()
         .map(|buf| hex::encode(buf.to_boxed_bytes().as_slice()))
@@ -45,15 +55,20 @@ async fn tx_query_inner(args: &QueryArgs) -> Result<()> {
         ));
     }
 
-    // Decode base64 return values and display as hex strings, matching mxpy output.
-    let return_data: Vec<String> = result
-        .data
-        .return_data_base64_decode()
-        .into_iter()
-        .map(|bytes| hex::encode(&bytes))
-        .collect();
+    let raw_results = result.data.return_data_base64_decode();
 
-    let json = to_json_pretty(&return_data)?;
+    // Decode by the endpoint outputs if the ABI is known,
+    // otherwise display as hex strings, matching mxpy output.
+    let json = if let Some(abi_contract) = abi_contract {
+        let decoder = AbiResultDecoder::new(
+            abi_contract,
+            AbiEndpointKind::Endpoint(args.function.clone()),
+        );
+        to_json_pretty(&decoder.decode_results(&raw_results)?)?
+    } else {
+        let return_data: Vec<String> = raw_results.iter().map(hex::encode).collect();
+        to_json_pretty(&return_data)?
+    };
     println!("{json}");
     Ok(())
 }
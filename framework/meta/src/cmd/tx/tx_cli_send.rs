@@ -3,7 +3,7 @@ use multiversx_sc_snippets::sdk::utils::base64_decode;
 
 use super::{
     output::TxOutputFile,
-    tx_cli_common::{broadcast_and_save, load_transaction_from_file},
+    tx_cli_common::{broadcast_and_save, load_transaction_from_file, wait_result_policy},
 };
 use crate::cli::cli_args_tx::SendArgs;
 
@@ -29,13 +29,14 @@ async fn tx_send_inner(args: &SendArgs) -> Result<()> {
         emitted_transaction_hash: String::new(),
         contract_address: None,
         transaction_on_network: None,
+        simulation: None,
     };
 
     broadcast_and_save(
         output,
         &args.proxy,
         args.outfile.as_deref(),
-        args.wait_result,
+        wait_result_policy(args.wait_result, args.timeout),
     )
     .await
 }
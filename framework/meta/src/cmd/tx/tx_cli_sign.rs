@@ -3,7 +3,10 @@ use multiversx_sc_snippets::{hex, sdk::utils::base64_decode};
 
 use super::{
     output::TxOutputFile,
-    tx_cli_common::{broadcast_and_save, load_transaction_from_file, load_wallet, save_output},
+    tx_cli_common::{
+        broadcast_and_save, load_transaction_from_file, load_wallet, save_output,
+        wait_result_policy,
+    },
 };
 use crate::cli::cli_args_tx::SignArgs;
 
@@ -47,6 +50,7 @@ async fn tx_sign_inner(args: &SignArgs) -> Result<()> {
         emitted_transaction_hash: String::new(),
         contract_address: None,
         transaction_on_network: None,
+        simulation: None,
     };
 
     if args.send {
@@ -54,7 +58,7 @@ async fn tx_sign_inner(args: &SignArgs) -> Result<()> {
             output,
             &args.gateway.proxy,
             args.outfile.as_deref(),
-            args.wait_result,
+            wait_result_policy(args.wait_result, args.timeout),
         )
         .await?;
     } else {
@@ -2,12 +2,15 @@ use std::fs;
 
 use anyhow::{Context, Result};
 use multiversx_sc_scenario::imports::NotPayable;
-use multiversx_sc_snippets::imports::{
-    Bech32Address, BytesValue, Interactor, InteractorIntoSdkTransaction,
+use multiversx_sc_snippets::{
+    DynamicContract,
+    imports::{Bech32Address, BytesValue, Interactor, InteractorIntoSdkTransaction},
 };
 
 use super::parse_code_metadata::parse_code_metadata;
-use super::tx_cli_common::{build_arg_buffer, load_wallet, sign_and_dispatch};
+use super::tx_cli_common::{
+    AbiEndpointKind, AbiResultDecoder, build_arg_buffer, load_wallet, sign_and_dispatch,
+};
 use crate::cli::cli_args_tx::UpgradeArgs;
 
 pub async fn tx_upgrade(args: &UpgradeArgs) {
@@ -47,7 +50,7 @@ async fn tx_upgrade_inner(args: &UpgradeArgs) -> Result<()> {
         .tx()
         .from(&sender_bech32)
         .to(&contract)
-        .gas(args.tx.gas_limit)
+        .gas(args.tx.gas_limit.unwrap_or_default())
         .payment(NotPayable)
         .raw_upgrade()
         .code(code)
@@ -56,5 +59,21 @@ async fn tx_upgrade_inner(args: &UpgradeArgs) -> Result<()> {
 
     let tx = tx_builder.into_sdk_transaction();
 
-    sign_and_dispatch(wallet, tx, nonce, &args.tx, &args.gateway, None).await
+    let result_decoder = args
+        .abi
+        .as_deref()
+        .map(|abi| DynamicContract::from_abi_file(abi, contract.clone()))
+        .transpose()?
+        .map(|contract| AbiResultDecoder::new(contract, AbiEndpointKind::UpgradeConstructor));
+
+    sign_and_dispatch(
+        wallet,
+        tx,
+        nonce,
+        &args.tx,
+        &args.gateway,
+        None,
+        result_decoder,
+    )
+    .await
 }
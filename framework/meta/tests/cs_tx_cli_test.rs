@@ -29,6 +29,7 @@ async fn test_adder_deploy_add_get_sum() {
     let outfile_deploy = outfiles_dir.join("adder-deploy-cs.interaction.json");
     let outfile_call = outfiles_dir.join("adder-call-cs.interaction.json");
     let outfile_upgrade = outfiles_dir.join("adder-upgrade-cs.interaction.json");
+    let outfile_simulate = outfiles_dir.join("adder-simulate-cs.interaction.json");
 
     let sc_meta_bin = env!("CARGO_BIN_EXE_sc-meta");
 
@@ -113,6 +114,48 @@ async fn test_adder_deploy_add_get_sum() {
 
     println!("Deployed adder at: {contract_address}");
 
+    // ── simulate add(100) ─────────────────────────────────────────────────────
+    let status = Command::new(sc_meta_bin)
+        .args([
+            "tx",
+            "call",
+            contract_address,
+            "--pem",
+            wallet_pem_path.to_str().unwrap(),
+            "--proxy",
+            CHAIN_SIMULATOR_URL,
+            "--chain",
+            CHAIN_SIMULATOR_CHAIN_ID,
+            "--gas-limit",
+            "5000000",
+            "--function",
+            "add",
+            "--arguments",
+            "100",
+            "--simulate",
+            "--outfile",
+            outfile_simulate.to_str().unwrap(),
+        ])
+        .status()
+        .expect("failed to execute sc-meta tx call --simulate");
+
+    assert!(status.success(), "simulated add call failed");
+
+    let simulate_content =
+        std::fs::read_to_string(&outfile_simulate).expect("failed to read simulate outfile");
+    let simulate_json: serde_json::Value =
+        serde_json::from_str(&simulate_content).expect("failed to parse simulate outfile JSON");
+    assert_eq!(
+        simulate_json["simulation"]["status"].as_str().unwrap(),
+        "success",
+        "simulation status mismatch"
+    );
+    assert_eq!(
+        simulate_json["emittedTransactionHash"].as_str().unwrap(),
+        "",
+        "simulated transaction must not be broadcast"
+    );
+
     // ── add(5) ────────────────────────────────────────────────────────────────
     let status = Command::new(sc_meta_bin)
         .args([
@@ -185,7 +228,7 @@ async fn test_adder_deploy_add_get_sum() {
     println!("getSum result: {stdout}");
 
     // The result is a JSON array of hex-encoded values, e.g. ["05"].
-    // 5 decimal = 0x05.
+    // 5 decimal = 0x05. The simulated add(100) must not have been committed.
     let result: Vec<String> =
         serde_json::from_str(stdout.trim()).expect("failed to parse query output as JSON");
     assert_eq!(result, vec!["05"], "getSum returned unexpected value");
@@ -390,3 +433,68 @@ async fn test_egld_transfer_alice_to_bob() {
         "Alice's balance did not decrease by at least the transfer amount"
     );
 }
+
+/// Sends EGLD via `sc-meta tx new` without `--gas-limit`,
+/// and checks that the estimated gas limit has the multiplier applied.
+#[tokio::test]
+#[serial]
+#[cfg_attr(not(feature = "chain-simulator-tests"), ignore)]
+async fn test_egld_transfer_estimated_gas_limit() {
+    let workspace = find_current_workspace().unwrap();
+    let test_artefacts_dir = workspace.join("framework/meta/tests/cs_tx_cli_test");
+    let wallet_pem_path = test_artefacts_dir.join("cs_tx_test_owner.pem");
+    let outfiles_dir = test_artefacts_dir.join("outfiles");
+    std::fs::create_dir_all(&outfiles_dir).unwrap();
+    let outfile = outfiles_dir.join("transfer-estimated-gas-cs.interaction.json");
+
+    let sc_meta_bin = env!("CARGO_BIN_EXE_sc-meta");
+
+    let interactor = Interactor::new(CHAIN_SIMULATOR_URL)
+        .await
+        .use_chain_simulator(true);
+
+    let wallet = Wallet::from_pem_file(&wallet_pem_path).unwrap();
+    interactor
+        .send_user_funds(&wallet.to_address().to_bech32_default())
+        .await
+        .unwrap();
+    interactor.generate_blocks(10).await.unwrap();
+
+    let bob_bech32 = test_wallets::bob().to_address().to_bech32_default();
+
+    let status = Command::new(sc_meta_bin)
+        .args([
+            "tx",
+            "new",
+            "--proxy",
+            CHAIN_SIMULATOR_URL,
+            "--chain",
+            CHAIN_SIMULATOR_CHAIN_ID,
+            "--receiver",
+            bob_bech32.to_bech32_str(),
+            "--pem",
+            wallet_pem_path.to_str().unwrap(),
+            "--gas-limit-multiplier",
+            "1.5",
+            "--value",
+            &TRANSFER_AMOUNT.to_string(),
+            "--send",
+            "--outfile",
+            outfile.to_str().unwrap(),
+        ])
+        .status()
+        .expect("failed to execute sc-meta tx new");
+
+    assert!(status.success(), "sc-meta tx new command failed");
+
+    let content = std::fs::read_to_string(&outfile).expect("failed to read outfile");
+    let json: serde_json::Value =
+        serde_json::from_str(&content).expect("failed to parse outfile JSON");
+
+    // A plain EGLD transfer costs exactly the minimum gas limit.
+    assert_eq!(
+        json["emittedTransaction"]["gasLimit"].as_u64().unwrap(),
+        GAS_LIMIT * 3 / 2,
+        "estimated gas limit mismatch"
+    );
+}
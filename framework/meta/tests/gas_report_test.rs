@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+
+use multiversx_sc_meta::cmd::gas_report::render_gas_report::GasReportRender;
+use multiversx_sc_scenario::scenario::run_gas_profile::GasProfileReport;
+
+fn categories(entries: &[(&str, u64)]) -> BTreeMap<String, u64> {
+    entries
+        .iter()
+        .map(|(name, gas)| (name.to_string(), *gas))
+        .collect()
+}
+
+fn render_comparison(baseline: &GasProfileReport, new: &GasProfileReport) -> String {
+    let mut output = Vec::new();
+    GasReportRender::new(&mut output, baseline, new).compare_reports();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn gas_report_compare_test() {
+    let mut baseline = GasProfileReport::default();
+    baseline.record(
+        "sc:adder",
+        "add",
+        1000,
+        &categories(&[("BigIntAPICost", 1000)]),
+    );
+    baseline.record("sc:adder", "sub", 500, &BTreeMap::new());
+
+    let mut new = GasProfileReport::default();
+    new.record(
+        "sc:adder",
+        "add",
+        1200,
+        &categories(&[("BigIntAPICost", 1200)]),
+    );
+    new.record(
+        "sc:adder",
+        "add",
+        1000,
+        &categories(&[("BigIntAPICost", 1000)]),
+    );
+    new.record("sc:adder", "init", 300, &BTreeMap::new());
+
+    let markdown = render_comparison(&baseline, &new);
+    assert!(markdown.contains("## sc:adder"));
+    assert!(markdown.contains("| add | 2 | 1000 :arrow_right: 1100 :red_circle: (+100, 10.00%) | 1000 :arrow_right: 1200 :red_circle: (+200, 20.00%) |"));
+    assert!(markdown.contains("| init :new: | 1 | 300 | 300 |"));
+    assert!(markdown.contains("| sub :x: | - | - | - |"));
+    assert!(
+        markdown
+            .contains("| BigIntAPICost | 1000 :arrow_right: 2200 :red_circle: (+1200, 120.00%) |")
+    );
+    assert!(!markdown.contains(":warning:"));
+}
+
+#[test]
+fn gas_report_compare_missing_baseline_test() {
+    let mut new = GasProfileReport::default();
+    new.record("sc:adder", "add", 1000, &BTreeMap::new());
+
+    let markdown = render_comparison(&GasProfileReport::default(), &new);
+    assert!(markdown.contains("| add :new: | 1 | 1000 | 1000 |"));
+    assert!(markdown.contains(":warning:"));
+}
+
+#[test]
+fn gas_report_merge_test() {
+    let mut report = GasProfileReport::default();
+    report.record(
+        "sc:adder",
+        "add",
+        1000,
+        &categories(&[("BigIntAPICost", 10)]),
+    );
+
+    let mut other = GasProfileReport::default();
+    other.record("sc:adder", "add", 400, &categories(&[("BigIntAPICost", 4)]));
+    other.record("sc:other", "init", 50, &BTreeMap::new());
+
+    report.merge(&other);
+    let add = report.find_endpoint("sc:adder", "add").unwrap();
+    assert_eq!(add.calls, 2);
+    assert_eq!(add.min_gas, 400);
+    assert_eq!(add.max_gas, 1000);
+    assert_eq!(add.avg_gas(), 700);
+    assert_eq!(add.categories, categories(&[("BigIntAPICost", 14)]));
+    assert!(report.find_endpoint("sc:other", "init").is_some());
+}
@@ -23,6 +23,18 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
         self.with_vm_hooks(|vh| vh.managed_caller(dest.get_raw_handle_unchecked()));
     }
 
+    fn load_original_caller_managed(&self, dest: Self::ManagedBufferHandle) {
+        self.assert_live_handle(&dest);
+        self.with_vm_hooks(|vh| {
+            vh.managed_get_original_caller_addr(dest.get_raw_handle_unchecked())
+        });
+    }
+
+    fn load_relayer_managed(&self, dest: Self::ManagedBufferHandle) {
+        self.assert_live_handle(&dest);
+        self.with_vm_hooks(|vh| vh.managed_get_relayer_addr(dest.get_raw_handle_unchecked()));
+    }
+
     fn get_sc_address_legacy(&self) -> Address {
         panic!("legacy BlockchainApi functionality no longer supported")
     }
@@ -73,8 +85,9 @@ impl<VHB: VMHooksApiBackend> BlockchainApiImpl for VMHooksApi<VHB> {
         });
     }
 
-    fn load_state_root_hash_managed(&self, _dest: Self::ManagedBufferHandle) {
-        panic!("state root hash not implemented")
+    fn load_state_root_hash_managed(&self, dest: Self::ManagedBufferHandle) {
+        self.assert_live_handle(&dest);
+        self.with_vm_hooks(|vh| vh.managed_get_state_root_hash(dest.get_raw_handle_unchecked()));
     }
 
     fn get_tx_hash_legacy(&self) -> H256 {
@@ -1,6 +1,9 @@
 use multiversx_chain_vm::{
     executor::VMHooksEarlyExit,
-    host::vm_hooks::{TxVMHooksContext, VMHooksDispatcher},
+    host::{
+        context::TxContextRef,
+        vm_hooks::{TxVMHooksContext, VMHooksDispatcher},
+    },
 };
 use multiversx_sc::{api::HandleConstraints, chain_core::types::ReturnCode, err_msg};
 
@@ -23,7 +26,7 @@ impl VMHooksApiBackend for DebugApiBackend {
     {
         let instance = ContractDebugStack::static_peek();
         let tx_context_ref = instance.tx_context_ref.clone();
-        let vh_context = TxVMHooksContext::new(tx_context_ref, ContractDebugInstanceState);
+        let vh_context = TxVMHooksContext::new(tx_context_ref, instance.instance_state());
         let mut dispatcher = VMHooksDispatcher::new(vh_context);
         let result = f(&mut dispatcher);
         std::mem::drop(dispatcher);
@@ -42,7 +45,8 @@ This can sometimes happen during whitebox testing if the objects are mixed betwe
                 handle.get_raw_handle()
             )
         });
-        let vh_context = TxVMHooksContext::new(tx_context_ref, ContractDebugInstanceState);
+        let instance_state = handle_instance_state(&tx_context_ref);
+        let vh_context = TxVMHooksContext::new(tx_context_ref, instance_state);
         let mut dispatcher = VMHooksDispatcher::new(vh_context);
         let result = f(&mut dispatcher);
         std::mem::drop(dispatcher);
@@ -79,7 +83,8 @@ This can sometimes happen during whitebox testing if the objects are mixed betwe
             // context is not live, skip the call
             return;
         };
-        let vh_context = TxVMHooksContext::new(tx_context_ref, ContractDebugInstanceState);
+        let instance_state = handle_instance_state(&tx_context_ref);
+        let vh_context = TxVMHooksContext::new(tx_context_ref, instance_state);
         let mut dispatcher = VMHooksDispatcher::new(vh_context);
         f(&mut dispatcher);
         std::mem::drop(dispatcher);
@@ -122,6 +127,17 @@ impl std::fmt::Debug for DebugApi {
     }
 }
 
+/// Handles can outlive the instance that created them, in which case their gas is no longer tracked.
+fn handle_instance_state(tx_context_ref: &TxContextRef) -> ContractDebugInstanceState {
+    if !tx_context_ref.runtime_ref.vm_ref.gas_metering {
+        return ContractDebugInstanceState::default();
+    }
+
+    ContractDebugStack::try_find_by_tx_context(tx_context_ref)
+        .map(|instance| instance.instance_state())
+        .unwrap_or_default()
+}
+
 fn assert_handles_on_same_context(handle1: &DebugHandle, handle2: &DebugHandle) {
     if !handle1.is_on_same_context(handle2) {
         ContractDebugInstanceState::early_exit_panic(
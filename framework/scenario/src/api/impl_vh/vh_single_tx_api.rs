@@ -6,12 +6,12 @@ use std::{
 use multiversx_chain_vm_executor::{MemLength, MemPtr, VMHooksEarlyExit};
 
 use multiversx_chain_vm::{
-    blockchain::state::{AccountData, BlockConfig},
+    blockchain::state::{AccountData, BlockConfig, EsdtTokenConfig, compute_state_root_hash},
     host::{
         context::{BackTransfers, ManagedTypeContainer, TxFunctionName, TxInput, TxResult},
         vm_hooks::VMHooksContext,
     },
-    schedule::GasSchedule,
+    schedule::{GasCostCategory, GasSchedule},
     types::{Address, VMCodeMetadata},
 };
 
@@ -75,7 +75,7 @@ impl VMHooksContext for SingleTxApiVMHooksContext {
         &ZERO_GAS_SCHEDULE
     }
 
-    fn use_gas(&mut self, _gas: u64) -> Result<(), VMHooksEarlyExit> {
+    fn use_gas(&mut self, _category: GasCostCategory, _gas: u64) -> Result<(), VMHooksEarlyExit> {
         Ok(())
     }
 
@@ -98,6 +98,14 @@ impl VMHooksContext for SingleTxApiVMHooksContext {
     }
 
     fn storage_write(&mut self, key: &[u8], value: &[u8]) -> Result<(), VMHooksEarlyExit> {
+        self.storage_write_protected(key, value)
+    }
+
+    fn storage_write_protected(
+        &mut self,
+        key: &[u8],
+        value: &[u8],
+    ) -> Result<(), VMHooksEarlyExit> {
         self.0.with_account_mut(&self.0.tx_input_box.to, |account| {
             account.storage.insert(key.to_vec(), value.to_vec());
         });
@@ -108,6 +116,10 @@ impl VMHooksContext for SingleTxApiVMHooksContext {
         &self.0.block_config
     }
 
+    fn state_root_hash(&self) -> [u8; 32] {
+        compute_state_root_hash(self.0.accounts.lock().unwrap().values())
+    }
+
     fn back_transfers_lock(&self) -> MutexGuard<'_, BackTransfers> {
         panic!("cannot access back transfers in the SingleTxApi")
     }
@@ -120,6 +132,10 @@ impl VMHooksContext for SingleTxApiVMHooksContext {
         vec![]
     }
 
+    fn esdt_token_config(&self, _token_identifier: &[u8]) -> Option<EsdtTokenConfig> {
+        None
+    }
+
     fn perform_async_call(
         &mut self,
         _to: Address,
@@ -3,12 +3,12 @@ use std::sync::{Mutex, MutexGuard};
 use multiversx_chain_vm_executor::{MemLength, MemPtr, VMHooksEarlyExit};
 
 use multiversx_chain_vm::{
-    blockchain::state::{AccountData, BlockConfig},
+    blockchain::state::{AccountData, BlockConfig, EsdtTokenConfig},
     host::{
         context::{BackTransfers, ManagedTypeContainer, TxFunctionName, TxInput, TxLog, TxResult},
         vm_hooks::VMHooksContext,
     },
-    schedule::GasSchedule,
+    schedule::{GasCostCategory, GasSchedule},
     types::{Address, VMCodeMetadata},
 };
 
@@ -47,7 +47,7 @@ impl VMHooksContext for StaticApiVMHooksContext {
         &ZERO_GAS_SCHEDULE
     }
 
-    fn use_gas(&mut self, _gas: u64) -> Result<(), VMHooksEarlyExit> {
+    fn use_gas(&mut self, _category: GasCostCategory, _gas: u64) -> Result<(), VMHooksEarlyExit> {
         Ok(())
     }
 
@@ -83,10 +83,22 @@ impl VMHooksContext for StaticApiVMHooksContext {
         panic!("cannot access the storage in the StaticApi")
     }
 
+    fn storage_write_protected(
+        &mut self,
+        _key: &[u8],
+        _value: &[u8],
+    ) -> Result<(), VMHooksEarlyExit> {
+        panic!("cannot access the storage in the StaticApi")
+    }
+
     fn get_block_config(&self) -> &BlockConfig {
         panic!("cannot access the block info in the StaticApi")
     }
 
+    fn state_root_hash(&self) -> [u8; 32] {
+        panic!("cannot access the state root hash in the StaticApi")
+    }
+
     fn back_transfers_lock(&self) -> MutexGuard<'_, BackTransfers> {
         panic!("cannot access the back transfers in the StaticApi")
     }
@@ -99,6 +111,10 @@ impl VMHooksContext for StaticApiVMHooksContext {
         panic!("cannot access account data in the StaticApi")
     }
 
+    fn esdt_token_config(&self, _token_identifier: &[u8]) -> Option<EsdtTokenConfig> {
+        panic!("cannot access token data in the StaticApi")
+    }
+
     fn perform_async_call(
         &mut self,
         _to: Address,
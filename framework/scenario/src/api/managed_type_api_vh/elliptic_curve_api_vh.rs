@@ -1,210 +1,388 @@
-use multiversx_sc::{api::EllipticCurveApiImpl, types::BoxedBytes};
+use multiversx_chain_vm::executor::MemPtr;
+use multiversx_sc::{
+    api::{EllipticCurveApiImpl, HandleConstraints, use_raw_handle},
+    types::BoxedBytes,
+};
 
-use crate::api::{VMHooksApi, VMHooksApiBackend};
+use crate::{
+    api::{VMHooksApi, VMHooksApiBackend, i32_to_bool},
+    executor::debug::ContractDebugInstanceState,
+};
 
 impl<VHB: VMHooksApiBackend> EllipticCurveApiImpl for VMHooksApi<VHB> {
-    fn ec_create_from_name_bytes(&self, _name: &[u8]) -> Self::EllipticCurveHandle {
-        panic!("ec_create not implemented")
+    fn ec_create_from_name_bytes(&self, name: &[u8]) -> Self::EllipticCurveHandle {
+        let (offset, length) = ContractDebugInstanceState::main_memory_ptr(name);
+        let raw_handle = self.with_vm_hooks(|vh| vh.create_ec(offset, length));
+        use_raw_handle(raw_handle)
     }
 
     fn ec_create_from_name_mb(
         &self,
-        _name_handle: Self::ManagedBufferHandle,
+        name_handle: Self::ManagedBufferHandle,
     ) -> Self::EllipticCurveHandle {
-        panic!("ec_create not implemented")
+        let raw_handle = self.with_vm_hooks_ctx_1(&name_handle, |vh| {
+            vh.managed_create_ec(name_handle.get_raw_handle_unchecked())
+        });
+        use_raw_handle(raw_handle)
     }
 
     fn ec_get_values(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _field_order_handle: Self::BigIntHandle,
-        _base_point_order_handle: Self::BigIntHandle,
-        _eq_constant_handle: Self::BigIntHandle,
-        _x_base_point_handle: Self::BigIntHandle,
-        _y_base_point_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        field_order_handle: Self::BigIntHandle,
+        base_point_order_handle: Self::BigIntHandle,
+        eq_constant_handle: Self::BigIntHandle,
+        x_base_point_handle: Self::BigIntHandle,
+        y_base_point_handle: Self::BigIntHandle,
     ) {
-        panic!("ec_get_values not implemented")
+        self.with_vm_hooks_ctx_3(
+            &ec_handle,
+            &field_order_handle,
+            &base_point_order_handle,
+            |vh| {
+                vh.elliptic_curve_get_values(
+                    ec_handle.get_raw_handle_unchecked(),
+                    field_order_handle.get_raw_handle_unchecked(),
+                    base_point_order_handle.get_raw_handle_unchecked(),
+                    eq_constant_handle.get_raw_handle_unchecked(),
+                    x_base_point_handle.get_raw_handle_unchecked(),
+                    y_base_point_handle.get_raw_handle_unchecked(),
+                )
+            },
+        );
     }
 
-    fn ec_curve_length(&self, _ec_handle: Self::EllipticCurveHandle) -> u32 {
-        panic!("ec_curve_length not implemented")
+    fn ec_curve_length(&self, ec_handle: Self::EllipticCurveHandle) -> u32 {
+        self.with_vm_hooks_ctx_1(&ec_handle, |vh| {
+            vh.get_curve_length_ec(ec_handle.get_raw_handle_unchecked())
+        }) as u32
     }
 
-    fn ec_private_key_byte_length(&self, _ec_handle: Self::EllipticCurveHandle) -> u32 {
-        panic!("ec_private_key_byte_length not implemented")
+    fn ec_private_key_byte_length(&self, ec_handle: Self::EllipticCurveHandle) -> u32 {
+        self.with_vm_hooks_ctx_1(&ec_handle, |vh| {
+            vh.get_priv_key_byte_length_ec(ec_handle.get_raw_handle_unchecked())
+        }) as u32
     }
 
     fn ec_add(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_first_point: Self::BigIntHandle,
-        _y_first_point: Self::BigIntHandle,
-        _x_second_point: Self::BigIntHandle,
-        _y_second_point: Self::BigIntHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_first_point: Self::BigIntHandle,
+        y_first_point: Self::BigIntHandle,
+        x_second_point: Self::BigIntHandle,
+        y_second_point: Self::BigIntHandle,
     ) {
-        panic!("ec_add not implemented")
+        self.with_vm_hooks_ctx_3(&x_result_handle, &y_result_handle, &ec_handle, |vh| {
+            vh.add_ec(
+                x_result_handle.get_raw_handle_unchecked(),
+                y_result_handle.get_raw_handle_unchecked(),
+                ec_handle.get_raw_handle_unchecked(),
+                x_first_point.get_raw_handle_unchecked(),
+                y_first_point.get_raw_handle_unchecked(),
+                x_second_point.get_raw_handle_unchecked(),
+                y_second_point.get_raw_handle_unchecked(),
+            )
+        });
     }
 
     fn ec_double(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_point_handle: Self::BigIntHandle,
-        _y_point_handle: Self::BigIntHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_point_handle: Self::BigIntHandle,
+        y_point_handle: Self::BigIntHandle,
     ) {
-        panic!("ec_double not implemented")
+        self.with_vm_hooks_ctx_3(&x_result_handle, &y_result_handle, &ec_handle, |vh| {
+            vh.double_ec(
+                x_result_handle.get_raw_handle_unchecked(),
+                y_result_handle.get_raw_handle_unchecked(),
+                ec_handle.get_raw_handle_unchecked(),
+                x_point_handle.get_raw_handle_unchecked(),
+                y_point_handle.get_raw_handle_unchecked(),
+            )
+        });
     }
 
     fn ec_is_on_curve(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_point_handle: Self::BigIntHandle,
-        _y_point_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_point_handle: Self::BigIntHandle,
+        y_point_handle: Self::BigIntHandle,
     ) -> bool {
-        panic!("ec_is_on_curve not implemented")
+        i32_to_bool(
+            self.with_vm_hooks_ctx_3(&ec_handle, &x_point_handle, &y_point_handle, |vh| {
+                vh.is_on_curve_ec(
+                    ec_handle.get_raw_handle_unchecked(),
+                    x_point_handle.get_raw_handle_unchecked(),
+                    y_point_handle.get_raw_handle_unchecked(),
+                )
+            }),
+        )
     }
 
     fn ec_scalar_mult_legacy(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_point_handle: Self::BigIntHandle,
-        _y_point_handle: Self::BigIntHandle,
-        _data: &[u8],
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_point_handle: Self::BigIntHandle,
+        y_point_handle: Self::BigIntHandle,
+        data: &[u8],
     ) {
-        panic!("ec_scalar_mult not implemented")
+        let (offset, length) = ContractDebugInstanceState::main_memory_ptr(data);
+        self.with_vm_hooks_ctx_3(&x_result_handle, &y_result_handle, &ec_handle, |vh| {
+            vh.scalar_mult_ec(
+                x_result_handle.get_raw_handle_unchecked(),
+                y_result_handle.get_raw_handle_unchecked(),
+                ec_handle.get_raw_handle_unchecked(),
+                x_point_handle.get_raw_handle_unchecked(),
+                y_point_handle.get_raw_handle_unchecked(),
+                offset,
+                length,
+            )
+        });
     }
 
     fn ec_scalar_mult(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_point_handle: Self::BigIntHandle,
-        _y_point_handle: Self::BigIntHandle,
-        _data_handle: Self::ManagedBufferHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_point_handle: Self::BigIntHandle,
+        y_point_handle: Self::BigIntHandle,
+        data_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_scalar_mult not implemented")
+        self.with_vm_hooks_ctx_3(&x_result_handle, &y_result_handle, &ec_handle, |vh| {
+            vh.managed_scalar_mult_ec(
+                x_result_handle.get_raw_handle_unchecked(),
+                y_result_handle.get_raw_handle_unchecked(),
+                ec_handle.get_raw_handle_unchecked(),
+                x_point_handle.get_raw_handle_unchecked(),
+                y_point_handle.get_raw_handle_unchecked(),
+                data_handle.get_raw_handle_unchecked(),
+            )
+        });
     }
 
     fn ec_scalar_base_mult_legacy(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data: &[u8],
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data: &[u8],
     ) {
-        panic!("ec_scalar_base_mult not implemented")
+        let (offset, length) = ContractDebugInstanceState::main_memory_ptr(data);
+        self.with_vm_hooks_ctx_3(&x_result_handle, &y_result_handle, &ec_handle, |vh| {
+            vh.scalar_base_mult_ec(
+                x_result_handle.get_raw_handle_unchecked(),
+                y_result_handle.get_raw_handle_unchecked(),
+                ec_handle.get_raw_handle_unchecked(),
+                offset,
+                length,
+            )
+        });
     }
 
     fn ec_scalar_base_mult(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data_handle: Self::ManagedBufferHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_scalar_base_mult not implemented")
+        self.with_vm_hooks_ctx_3(&x_result_handle, &y_result_handle, &ec_handle, |vh| {
+            vh.managed_scalar_base_mult_ec(
+                x_result_handle.get_raw_handle_unchecked(),
+                y_result_handle.get_raw_handle_unchecked(),
+                ec_handle.get_raw_handle_unchecked(),
+                data_handle.get_raw_handle_unchecked(),
+            )
+        });
     }
 
     fn ec_marshal_legacy(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_pair_handle: Self::BigIntHandle,
-        _y_pair_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_pair_handle: Self::BigIntHandle,
+        y_pair_handle: Self::BigIntHandle,
     ) -> BoxedBytes {
-        panic!("ec_marshal not implemented")
+        self.with_vm_hooks_ctx_3(&ec_handle, &x_pair_handle, &y_pair_handle, |vh| {
+            let curve_length = vh.get_curve_length_ec(ec_handle.get_raw_handle_unchecked())?;
+            let byte_length = (curve_length as usize).div_ceil(8);
+            let mut result = BoxedBytes::zeros(1 + 2 * byte_length);
+            vh.marshal_ec(
+                x_pair_handle.get_raw_handle_unchecked(),
+                y_pair_handle.get_raw_handle_unchecked(),
+                ec_handle.get_raw_handle_unchecked(),
+                result.as_mut_ptr() as MemPtr,
+            )?;
+            Ok(result)
+        })
     }
 
     fn ec_marshal(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_pair_handle: Self::BigIntHandle,
-        _y_pair_handle: Self::BigIntHandle,
-        _result_handle: Self::ManagedBufferHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_pair_handle: Self::BigIntHandle,
+        y_pair_handle: Self::BigIntHandle,
+        result_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_marshal not implemented")
+        self.with_vm_hooks_ctx_3(&ec_handle, &x_pair_handle, &y_pair_handle, |vh| {
+            vh.managed_marshal_ec(
+                x_pair_handle.get_raw_handle_unchecked(),
+                y_pair_handle.get_raw_handle_unchecked(),
+                ec_handle.get_raw_handle_unchecked(),
+                result_handle.get_raw_handle_unchecked(),
+            )
+        });
     }
 
     fn ec_marshal_compressed_legacy(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_pair_handle: Self::BigIntHandle,
-        _y_pair_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_pair_handle: Self::BigIntHandle,
+        y_pair_handle: Self::BigIntHandle,
     ) -> BoxedBytes {
-        panic!("ec_marshal_compressed not implemented")
+        self.with_vm_hooks_ctx_3(&ec_handle, &x_pair_handle, &y_pair_handle, |vh| {
+            let curve_length = vh.get_curve_length_ec(ec_handle.get_raw_handle_unchecked())?;
+            let byte_length = (curve_length as usize).div_ceil(8);
+            let mut result = BoxedBytes::zeros(1 + byte_length);
+            vh.marshal_compressed_ec(
+                x_pair_handle.get_raw_handle_unchecked(),
+                y_pair_handle.get_raw_handle_unchecked(),
+                ec_handle.get_raw_handle_unchecked(),
+                result.as_mut_ptr() as MemPtr,
+            )?;
+            Ok(result)
+        })
     }
 
     fn ec_marshal_compressed(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_pair_handle: Self::BigIntHandle,
-        _y_pair_handle: Self::BigIntHandle,
-        _result_handle: Self::ManagedBufferHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_pair_handle: Self::BigIntHandle,
+        y_pair_handle: Self::BigIntHandle,
+        result_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_marshal_compressed not implemented")
+        self.with_vm_hooks_ctx_3(&ec_handle, &x_pair_handle, &y_pair_handle, |vh| {
+            vh.managed_marshal_compressed_ec(
+                x_pair_handle.get_raw_handle_unchecked(),
+                y_pair_handle.get_raw_handle_unchecked(),
+                ec_handle.get_raw_handle_unchecked(),
+                result_handle.get_raw_handle_unchecked(),
+            )
+        });
     }
 
     fn ec_unmarshal_legacy(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data: &[u8],
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data: &[u8],
     ) {
-        panic!("ec_unmarshal not implemented")
+        let (offset, length) = ContractDebugInstanceState::main_memory_ptr(data);
+        self.with_vm_hooks_ctx_3(&x_result_handle, &y_result_handle, &ec_handle, |vh| {
+            vh.unmarshal_ec(
+                x_result_handle.get_raw_handle_unchecked(),
+                y_result_handle.get_raw_handle_unchecked(),
+                ec_handle.get_raw_handle_unchecked(),
+                offset,
+                length,
+            )
+        });
     }
 
     fn ec_unmarshal(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data_handle: Self::ManagedBufferHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_unmarshal not implemented")
+        self.with_vm_hooks_ctx_3(&x_result_handle, &y_result_handle, &ec_handle, |vh| {
+            vh.managed_unmarshal_ec(
+                x_result_handle.get_raw_handle_unchecked(),
+                y_result_handle.get_raw_handle_unchecked(),
+                ec_handle.get_raw_handle_unchecked(),
+                data_handle.get_raw_handle_unchecked(),
+            )
+        });
     }
 
     fn ec_unmarshal_compressed_legacy(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data: &[u8],
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data: &[u8],
     ) {
-        panic!("ec_unmarshal_compressed not implemented")
+        let (offset, length) = ContractDebugInstanceState::main_memory_ptr(data);
+        self.with_vm_hooks_ctx_3(&x_result_handle, &y_result_handle, &ec_handle, |vh| {
+            vh.unmarshal_compressed_ec(
+                x_result_handle.get_raw_handle_unchecked(),
+                y_result_handle.get_raw_handle_unchecked(),
+                ec_handle.get_raw_handle_unchecked(),
+                offset,
+                length,
+            )
+        });
     }
 
     fn ec_unmarshal_compressed(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data_handle: Self::ManagedBufferHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_unmarshal_compressed not implemented")
+        self.with_vm_hooks_ctx_3(&x_result_handle, &y_result_handle, &ec_handle, |vh| {
+            vh.managed_unmarshal_compressed_ec(
+                x_result_handle.get_raw_handle_unchecked(),
+                y_result_handle.get_raw_handle_unchecked(),
+                ec_handle.get_raw_handle_unchecked(),
+                data_handle.get_raw_handle_unchecked(),
+            )
+        });
     }
 
     fn ec_generate_key_legacy(
         &self,
-        _x_pub_key_handle: Self::BigIntHandle,
-        _y_pub_key_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
+        x_pub_key_handle: Self::BigIntHandle,
+        y_pub_key_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
     ) -> BoxedBytes {
-        panic!("ec_generate_key not implemented")
+        self.with_vm_hooks_ctx_3(&x_pub_key_handle, &y_pub_key_handle, &ec_handle, |vh| {
+            let key_length =
+                vh.get_priv_key_byte_length_ec(ec_handle.get_raw_handle_unchecked())?;
+            let mut private_key = BoxedBytes::zeros(key_length as usize);
+            vh.generate_key_ec(
+                x_pub_key_handle.get_raw_handle_unchecked(),
+                y_pub_key_handle.get_raw_handle_unchecked(),
+                ec_handle.get_raw_handle_unchecked(),
+                private_key.as_mut_ptr() as MemPtr,
+            )?;
+            Ok(private_key)
+        })
     }
 
     fn ec_generate_key(
         &self,
-        _x_pub_key_handle: Self::BigIntHandle,
-        _y_pub_key_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _result_handle: Self::ManagedBufferHandle,
+        x_pub_key_handle: Self::BigIntHandle,
+        y_pub_key_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        result_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_generate_key not implemented")
+        self.with_vm_hooks_ctx_3(&x_pub_key_handle, &y_pub_key_handle, &ec_handle, |vh| {
+            vh.managed_generate_key_ec(
+                x_pub_key_handle.get_raw_handle_unchecked(),
+                y_pub_key_handle.get_raw_handle_unchecked(),
+                ec_handle.get_raw_handle_unchecked(),
+                result_handle.get_raw_handle_unchecked(),
+            )
+        });
     }
 }
@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::{cell::Cell, rc::Rc};
 
 use multiversx_chain_vm::host::{
     context::{TxContextRef, TxFunctionName, TxPanic},
@@ -8,7 +8,8 @@ use multiversx_chain_vm_executor::{ExecutorError, Instance, InstanceCallResult};
 use multiversx_sc::chain_core::types::ReturnCode;
 
 use super::{
-    ContractContainer, ContractContainerRef, ContractDebugStack, StaticVarData, catch_tx_panic,
+    ContractContainer, ContractContainerRef, ContractDebugInstanceState, ContractDebugStack,
+    StaticVarData, catch_tx_panic,
 };
 
 /// Used as a flag to check the instance under lambda calls.
@@ -22,14 +23,23 @@ pub struct ContractDebugInstance {
     pub tx_context_ref: TxContextRef,
     pub contract_container_ref: ContractContainerRef,
     pub static_var_ref: Rc<StaticVarData>,
+
+    /// Gas consumed by VM hooks so far, only tracked if gas metering is enabled in the VM.
+    pub points_used: Option<Rc<Cell<u64>>>,
 }
 
 impl ContractDebugInstance {
     pub fn new(tx_context_ref: TxContextRef, contract_container: ContractContainerRef) -> Self {
+        let points_used = tx_context_ref
+            .runtime_ref
+            .vm_ref
+            .gas_metering
+            .then(Rc::default);
         ContractDebugInstance {
             tx_context_ref,
             contract_container_ref: contract_container,
             static_var_ref: Default::default(),
+            points_used,
         }
     }
 
@@ -39,9 +49,15 @@ impl ContractDebugInstance {
             tx_context_ref: TxContextRef::dummy(),
             contract_container_ref: ContractContainerRef::new(ContractContainer::dummy()),
             static_var_ref: Default::default(),
+            points_used: None,
         }
     }
 
+    /// The instance state handed to the VM hooks, shares the gas counter with the instance.
+    pub fn instance_state(&self) -> ContractDebugInstanceState {
+        ContractDebugInstanceState::new(self.points_used.clone())
+    }
+
     pub(super) fn wrap_lambda_call<F>(
         panic_message_flag: bool,
         instance_call: RuntimeInstanceCall,
@@ -132,7 +148,7 @@ impl Instance for ContractDebugInstance {
     }
 
     fn get_points_used(&mut self) -> Result<u64, ExecutorError> {
-        Ok(0)
+        Ok(self.points_used.as_ref().map_or(0, |points| points.get()))
     }
 
     fn reset(&self) -> Result<(), ExecutorError> {
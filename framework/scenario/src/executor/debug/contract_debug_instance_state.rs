@@ -1,11 +1,22 @@
+use std::{cell::Cell, rc::Rc};
+
 use multiversx_chain_vm_executor::{
     BreakpointValue, ExecutorError, InstanceState, MemLength, MemPtr, VMHooksEarlyExit,
 };
 
-#[derive(Clone, Debug)]
-pub struct ContractDebugInstanceState;
+/// Contract instances in the debugger do not meter instructions,
+/// so only the gas consumed by VM hooks can be tracked here, and only if gas metering is enabled.
+#[derive(Clone, Debug, Default)]
+pub struct ContractDebugInstanceState {
+    /// Shared with the instance, `None` if gas is not metered.
+    points_used: Option<Rc<Cell<u64>>>,
+}
 
 impl ContractDebugInstanceState {
+    pub fn new(points_used: Option<Rc<Cell<u64>>>) -> Self {
+        ContractDebugInstanceState { points_used }
+    }
+
     /// Interprets the input as a regular pointer.
     ///
     /// ## Safety
@@ -49,10 +60,13 @@ impl ContractDebugInstanceState {
 
 impl InstanceState for ContractDebugInstanceState {
     fn get_points_used(&mut self) -> Result<u64, ExecutorError> {
-        Ok(0)
+        Ok(self.points_used.as_ref().map_or(0, |points| points.get()))
     }
 
-    fn set_points_used(&mut self, _points: u64) -> Result<(), ExecutorError> {
+    fn set_points_used(&mut self, points: u64) -> Result<(), ExecutorError> {
+        if let Some(points_used) = &self.points_used {
+            points_used.set(points);
+        }
         Ok(())
     }
 
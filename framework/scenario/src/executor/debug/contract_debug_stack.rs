@@ -23,14 +23,19 @@ impl ContractDebugStack {
     ///
     /// Used for resolving `DebugHandle`s.
     pub fn find_by_tx_context(tx_context_ref: &TxContextRef) -> ContractDebugInstance {
+        Self::try_find_by_tx_context(tx_context_ref)
+            .expect("invalid TxContext: not found on ContractDebugStack")
+    }
+
+    /// Same as `find_by_tx_context`, but does not crash if the context is not on the stack.
+    pub fn try_find_by_tx_context(tx_context_ref: &TxContextRef) -> Option<ContractDebugInstance> {
         API_STACK.with(|cell| {
             let stack = cell.lock().unwrap();
             stack
                 .0
                 .iter()
                 .find(|instance| TxContextRef::ptr_eq(tx_context_ref, &instance.tx_context_ref))
-                .expect("invalid TxContext: not found on ContractDebugStack")
-                .clone()
+                .cloned()
         })
     }
 
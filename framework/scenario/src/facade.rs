@@ -3,6 +3,8 @@ mod debugger_backend;
 pub mod expr;
 pub mod result_handlers;
 mod scenario_world;
+mod scenario_world_cross_shard;
+mod scenario_world_randomness;
 mod scenario_world_register;
 mod scenario_world_runner;
 mod scenario_world_steps;
@@ -1,5 +1,9 @@
 use crate::scenario::{
-    self, ScenarioRunner, model::*, run_trace::ScenarioTrace, run_vm::ScenarioVMRunner,
+    self, ScenarioRunner,
+    model::*,
+    run_gas_profile::ScenarioGasProfile,
+    run_trace::ScenarioTrace,
+    run_vm::{ScenarioVMRunner, pin_tx_id_hash},
 };
 use std::path::Path;
 
@@ -8,6 +12,7 @@ use std::path::Path;
 pub(crate) struct DebuggerBackend {
     pub vm_runner: ScenarioVMRunner,
     pub trace: Option<ScenarioTrace>,
+    pub gas_profile: Option<ScenarioGasProfile>,
 }
 
 impl DebuggerBackend {
@@ -16,6 +21,9 @@ impl DebuggerBackend {
         if let Some(trace) = &mut self.trace {
             f(trace);
         }
+        if let Some(gas_profile) = &mut self.gas_profile {
+            f(gas_profile);
+        }
     }
 }
 
@@ -40,6 +48,9 @@ impl ScenarioRunner for DebuggerBackend {
         if let Some(trace) = &mut self.trace {
             trace.run_sc_call_step(step);
         }
+        if let Some(gas_profile) = &mut self.gas_profile {
+            gas_profile.run_sc_call_step(step);
+        }
     }
 
     fn run_multi_sc_call_step(&mut self, steps: &mut [ScCallStep]) {
@@ -50,6 +61,9 @@ impl ScenarioRunner for DebuggerBackend {
         if let Some(trace) = &mut self.trace {
             trace.run_multi_sc_call_step(steps);
         }
+        if let Some(gas_profile) = &mut self.gas_profile {
+            gas_profile.run_multi_sc_call_step(steps);
+        }
     }
 
     fn run_sc_query_step(&mut self, step: &mut ScQueryStep) {
@@ -58,6 +72,9 @@ impl ScenarioRunner for DebuggerBackend {
         if let Some(trace) = &mut self.trace {
             trace.run_sc_query_step(step);
         }
+        if let Some(gas_profile) = &mut self.gas_profile {
+            gas_profile.run_sc_query_step(step);
+        }
     }
 
     fn run_sc_deploy_step(&mut self, step: &mut ScDeployStep) {
@@ -66,6 +83,9 @@ impl ScenarioRunner for DebuggerBackend {
         if let Some(trace) = &mut self.trace {
             trace.run_sc_deploy_step(step);
         }
+        if let Some(gas_profile) = &mut self.gas_profile {
+            gas_profile.run_sc_deploy_step(step);
+        }
     }
 
     fn run_multi_sc_deploy_step(&mut self, steps: &mut [ScDeployStep]) {
@@ -76,6 +96,9 @@ impl ScenarioRunner for DebuggerBackend {
         if let Some(trace) = &mut self.trace {
             trace.run_multi_sc_deploy_step(steps);
         }
+        if let Some(gas_profile) = &mut self.gas_profile {
+            gas_profile.run_multi_sc_deploy_step(steps);
+        }
     }
 
     fn run_transfer_step(&mut self, step: &TransferStep) {
@@ -110,12 +133,16 @@ impl DebuggerBackend {
                     self.run_set_state_step(set_state_step);
                 }
                 Step::ScCall(sc_call_step) => {
+                    let tx_id = sc_call_step.get_tx_id().to_owned();
+                    pin_tx_id_hash(&tx_id, &mut sc_call_step.explicit_tx_hash);
                     self.run_sc_call_step(sc_call_step);
                 }
                 Step::ScQuery(sc_query_step) => {
                     self.run_sc_query_step(sc_query_step);
                 }
                 Step::ScDeploy(sc_deploy_step) => {
+                    let tx_id = sc_deploy_step.get_tx_id().to_owned();
+                    pin_tx_id_hash(&tx_id, &mut sc_deploy_step.explicit_tx_hash);
                     self.run_sc_deploy_step(sc_deploy_step);
                 }
                 Step::Transfer(transfer_step) => {
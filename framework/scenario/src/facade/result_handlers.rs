@@ -1,7 +1,9 @@
 mod expect_error;
+mod expect_events;
 mod expect_message;
 mod expect_status;
 mod expect_value;
+mod returns_events;
 mod returns_gas_used;
 mod returns_logs;
 mod returns_message;
@@ -12,9 +14,11 @@ mod returns_tx_hash;
 mod with_tx_raw_response;
 
 pub use expect_error::ExpectError;
+pub use expect_events::{ExpectEvent, ExpectEvents};
 pub use expect_message::ExpectMessage;
 pub use expect_status::ExpectStatus;
 pub use expect_value::ExpectValue;
+pub use returns_events::ReturnsEvents;
 pub use returns_gas_used::ReturnsGasUsed;
 pub use returns_logs::ReturnsLogs;
 pub use returns_message::ReturnsMessage;
@@ -0,0 +1,174 @@
+use core::fmt::Debug;
+
+use multiversx_sc::{
+    codec::{TopEncode, TopEncodeMulti},
+    types::{EventFieldVisitor, RHListItem, RHListItemExec, TxEnv, TxProxyEvent},
+};
+
+use crate::scenario_model::TxResponse;
+
+use super::returns_events::decode_events;
+
+/// Verifies that the transaction emitted the given event, among any others.
+///
+/// The event type is one of the event structs generated in proxies.
+/// Fields are compared by their encoded topics and data,
+/// fields marked with `ignore` are left out of the comparison.
+///
+/// Can only be used in tests, not available in contracts.
+pub struct ExpectEvent<E> {
+    event: E,
+    ignored_fields: Vec<&'static str>,
+}
+
+impl<E> ExpectEvent<E> {
+    pub fn new(event: E) -> Self {
+        ExpectEvent {
+            event,
+            ignored_fields: Vec::new(),
+        }
+    }
+
+    /// Leaves a field out of the comparison, for partial matching.
+    pub fn ignore(mut self, field_name: &'static str) -> Self {
+        self.ignored_fields.push(field_name);
+        self
+    }
+}
+
+/// Verifies that the transaction emitted exactly these events of type `E`, in this order.
+///
+/// Logs of other events are ignored.
+///
+/// Can only be used in tests, not available in contracts.
+pub struct ExpectEvents<E>(pub Vec<E>);
+
+impl<Env, Original, E> RHListItem<Env, Original> for ExpectEvent<E>
+where
+    Env: TxEnv,
+{
+    type Returns = ();
+}
+
+impl<Env, Original, E> RHListItemExec<TxResponse, Env, Original> for ExpectEvent<E>
+where
+    Env: TxEnv,
+    E: TxProxyEvent,
+{
+    fn item_process_result(self, raw_result: &TxResponse) -> Self::Returns {
+        let expected = EventFields::of(&self.event);
+        for field_name in &self.ignored_fields {
+            assert!(
+                expected.0.iter().any(|field| field.name == *field_name),
+                "event {} has no field {field_name}",
+                E::IDENTIFIER
+            );
+        }
+
+        let events = decode_events::<E>(raw_result);
+        if events.is_empty() {
+            panic!("event {} was not emitted", E::IDENTIFIER);
+        }
+
+        let mut message = format!(
+            "event {} was not emitted, the emitted ones differ in:",
+            E::IDENTIFIER
+        );
+        for (index, event) in events.iter().enumerate() {
+            let mismatches = expected.mismatches(&EventFields::of(event), &self.ignored_fields);
+            if mismatches.is_empty() {
+                return;
+            }
+            message.push_str(&format!("\n#{index}:{mismatches}"));
+        }
+        panic!("{message}");
+    }
+}
+
+impl<Env, Original, E> RHListItem<Env, Original> for ExpectEvents<E>
+where
+    Env: TxEnv,
+{
+    type Returns = ();
+}
+
+impl<Env, Original, E> RHListItemExec<TxResponse, Env, Original> for ExpectEvents<E>
+where
+    Env: TxEnv,
+    E: TxProxyEvent,
+{
+    fn item_process_result(self, raw_result: &TxResponse) -> Self::Returns {
+        let events = decode_events::<E>(raw_result);
+        let mut message = String::new();
+        if events.len() != self.0.len() {
+            message.push_str(&format!(
+                "\nexpected {} events, {} emitted",
+                self.0.len(),
+                events.len()
+            ));
+        }
+        for (index, (expected, event)) in self.0.iter().zip(&events).enumerate() {
+            let mismatches = EventFields::of(expected).mismatches(&EventFields::of(event), &[]);
+            if !mismatches.is_empty() {
+                message.push_str(&format!("\n#{index}:{mismatches}"));
+            }
+        }
+
+        if !message.is_empty() {
+            panic!("{} events mismatch:{message}", E::IDENTIFIER);
+        }
+    }
+}
+
+/// An event field, encoded as in the log, along with its debug representation, for messages.
+struct EventField {
+    name: &'static str,
+    encoded: Vec<Vec<u8>>,
+    debug: String,
+}
+
+struct EventFields(Vec<EventField>);
+
+impl EventFields {
+    fn of<E: TxProxyEvent>(event: &E) -> Self {
+        let mut fields = EventFields(Vec::new());
+        event.visit_fields(&mut fields);
+        fields
+    }
+
+    /// One line per field that differs, empty if all compared fields match.
+    fn mismatches(&self, actual: &EventFields, ignored_fields: &[&'static str]) -> String {
+        let mut mismatches = String::new();
+        for (expected, actual) in self.0.iter().zip(&actual.0) {
+            if !ignored_fields.contains(&expected.name) && expected.encoded != actual.encoded {
+                mismatches.push_str(&format!(
+                    "\n    {}: expected `{}`, actual `{}`",
+                    expected.name, expected.debug, actual.debug
+                ));
+            }
+        }
+        mismatches
+    }
+}
+
+impl EventFieldVisitor for EventFields {
+    fn topic<T: TopEncodeMulti + Debug>(&mut self, name: &'static str, value: &T) {
+        let mut encoded = Vec::<Vec<u8>>::new();
+        value.multi_encode(&mut encoded).expect("encoding error");
+        self.0.push(EventField {
+            name,
+            encoded,
+            debug: format!("{value:?}"),
+        });
+    }
+
+    fn data<T: TopEncode + Debug>(&mut self, name: &'static str, value: &T) {
+        let mut encoded = Vec::<u8>::new();
+        value.top_encode(&mut encoded).expect("encoding error");
+        self.0.push(EventField {
+            name,
+            encoded: vec![encoded],
+            debug: format!("{value:?}"),
+        });
+    }
+}
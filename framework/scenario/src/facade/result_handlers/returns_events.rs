@@ -0,0 +1,72 @@
+use core::marker::PhantomData;
+
+use multiversx_sc::types::{RHListItemExec, TxProxyEvent};
+
+use crate::{
+    multiversx_sc::types::{RHListItem, TxEnv},
+    scenario_model::TxResponse,
+};
+
+/// Returns the logs of one event type, decoded into the event struct generated in the proxy.
+///
+/// Only the logs emitted by the target contract are considered, logs with other identifiers are skipped.
+pub struct ReturnsEvents<E> {
+    _phantom: PhantomData<E>,
+}
+
+impl<E> Default for ReturnsEvents<E> {
+    fn default() -> Self {
+        Self {
+            _phantom: Default::default(),
+        }
+    }
+}
+
+impl<E> ReturnsEvents<E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<Env, Original, E> RHListItem<Env, Original> for ReturnsEvents<E>
+where
+    Env: TxEnv,
+{
+    type Returns = Vec<E>;
+}
+
+impl<Env, Original, E> RHListItemExec<TxResponse, Env, Original> for ReturnsEvents<E>
+where
+    Env: TxEnv,
+    E: TxProxyEvent,
+{
+    fn item_process_result(self, raw_result: &TxResponse) -> Self::Returns {
+        decode_events(raw_result)
+    }
+}
+
+/// Decodes all logs with the identifier of `E` emitted by the called or the newly deployed contract,
+/// panics if any of them cannot be decoded.
+///
+/// If the response does not record the contract address, all logs are considered.
+pub(super) fn decode_events<E: TxProxyEvent>(raw_result: &TxResponse) -> Vec<E> {
+    let contract_address = raw_result
+        .receiver
+        .as_ref()
+        .or(raw_result.new_deployed_address.as_ref());
+    raw_result
+        .logs
+        .iter()
+        .filter(|log| contract_address.is_none_or(|address| &log.address == address))
+        .filter_map(|log| log.decode_event::<E>())
+        .map(|result| {
+            result.unwrap_or_else(|err| {
+                panic!(
+                    "cannot decode event {}: {}",
+                    E::IDENTIFIER,
+                    err.message_str()
+                )
+            })
+        })
+        .collect()
+}
@@ -1,7 +1,11 @@
-use multiversx_chain_vm::{blockchain::state::BlockchainState, schedule::GasScheduleVersion};
+use multiversx_chain_vm::{
+    blockchain::state::BlockchainState, chain_core::types::ShardConfig,
+    schedule::GasScheduleVersion,
+};
 
 use crate::{
     scenario::{
+        run_gas_profile::ScenarioGasProfile,
         run_trace::ScenarioTrace,
         run_vm::{ExecutorConfig, ScenarioVMRunner},
     },
@@ -41,6 +45,7 @@ impl ScenarioWorld {
             backend: Backend::Debugger(Box::new(DebuggerBackend {
                 vm_runner: ScenarioVMRunner::new(),
                 trace: None,
+                gas_profile: None,
             })),
         }
     }
@@ -70,6 +75,42 @@ impl ScenarioWorld {
         self
     }
 
+    /// Flag that stops async calls and callbacks between different shards from being executed right away.
+    ///
+    /// They become pending cross-shard messages instead, which tests can then deliver, reorder or fail,
+    /// using `deliver_cross_shard_message` and the related methods.
+    ///
+    /// Disabled by default, the whole async flow is then executed as part of the original transaction.
+    pub fn enable_cross_shard(mut self, shard_config: ShardConfig) -> Self {
+        self.get_mut_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .enable_cross_shard(shard_config);
+        self
+    }
+
+    /// Flag that makes the debugger charge gas for VM hooks and builtin functions, based on the current gas schedule.
+    ///
+    /// Transactions that exceed their gas limit fail with "not enough gas",
+    /// and `ReturnsGasUsed` yields the gas consumed.
+    ///
+    /// Disabled by default. Instructions are never metered in the debugger, so the values are lower than on-chain.
+    pub fn enable_gas_metering(mut self) -> Self {
+        let vm = &mut self.get_mut_debugger_backend().vm_runner.blockchain_mock.vm;
+        vm.set_gas_metering(true);
+        self
+    }
+
+    /// Flag that gives calls and deploys without tx id the hash of their canonical encoding,
+    /// as echoed by the tx hash VM hooks, and returned by `ReturnsTxHash`.
+    ///
+    /// Disabled by default, all these transactions then share the hash derived from the empty tx id,
+    /// and with it, the seed of their random numbers.
+    pub fn enable_canonical_tx_hash(mut self) -> Self {
+        self.get_mut_debugger_backend().vm_runner.canonical_tx_hash = true;
+        self
+    }
+
     pub fn vm_go() -> Self {
         ScenarioWorld {
             current_dir: std::env::current_dir().unwrap(),
@@ -121,11 +162,32 @@ impl ScenarioWorld {
             .state
     }
 
+    /// Deterministic commitment to all accounts, their balances, tokens and storage.
+    ///
+    /// Two worlds with the same accounts have the same state root hash, useful for comparing snapshots.
+    pub fn state_root_hash(&self) -> [u8; 32] {
+        self.get_state().state_root_hash()
+    }
+
     pub fn start_trace(&mut self) -> &mut Self {
         self.get_mut_debugger_backend().trace = Some(ScenarioTrace::default());
         self
     }
 
+    /// Starts collecting the gas used by contract calls and deploys, per contract and endpoint.
+    ///
+    /// Also enables gas metering, since the debugger does not report any gas without it.
+    pub fn start_gas_profile(&mut self) -> &mut Self {
+        let debugger_backend = self.get_mut_debugger_backend();
+        debugger_backend
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .set_gas_metering(true);
+        debugger_backend.gas_profile = Some(ScenarioGasProfile::default());
+        self
+    }
+
     /// Tells the tests where the crate lies relative to the workspace.
     /// This ensures that the paths are set correctly, including in debug mode.
     pub fn set_current_dir_from_workspace(&mut self, relative_path: &str) -> &mut Self {
@@ -148,6 +210,19 @@ impl ScenarioWorld {
         }
     }
 
+    /// Adds the gas profile collected so far to a JSON report, and also renders it as Markdown.
+    ///
+    /// If the file already exists, the data gets merged into it,
+    /// so that all the tests in a suite can contribute to the same report.
+    /// Remove the old report before running the suite again.
+    pub fn write_gas_profile<P: AsRef<Path>>(&mut self, file_path: P) {
+        if let Some(gas_profile) = &mut self.get_mut_debugger_backend().gas_profile {
+            gas_profile.write_gas_profile(file_path);
+        } else {
+            panic!("gas profile not initialized")
+        }
+    }
+
     #[deprecated(
         since = "0.39.0",
         note = "Renamed, use `write_scenario_trace` instead."
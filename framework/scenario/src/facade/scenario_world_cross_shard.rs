@@ -0,0 +1,54 @@
+use multiversx_chain_vm::blockchain::state::CrossShardMessage;
+
+use crate::{facade::ScenarioWorld, scenario_model::TxResponse};
+
+/// Controls the messages travelling between shards, in cross-shard mode.
+///
+/// Messages are not recorded in the scenario trace.
+impl ScenarioWorld {
+    /// The calls and callbacks waiting to be executed on their destination shard, in the order they were sent.
+    pub fn cross_shard_messages(&self) -> &[CrossShardMessage] {
+        self.get_state().cross_shard_queue.messages()
+    }
+
+    /// Executes one pending message, regardless of its position in the queue.
+    ///
+    /// Delivering a call queues its callback. Funds sent back to the caller only arrive with the callback.
+    pub fn deliver_cross_shard_message(&mut self, message_id: u64) -> TxResponse {
+        let tx_result = self
+            .get_mut_debugger_backend()
+            .vm_runner
+            .perform_deliver_cross_shard_message(message_id);
+        TxResponse::from_tx_result(tx_result)
+    }
+
+    /// Makes a pending call fail on its destination shard, without executing it.
+    ///
+    /// The callback is queued, and will receive the error. The payment of the call travels back with it.
+    pub fn fail_cross_shard_message(&mut self, message_id: u64, error_message: &str) -> TxResponse {
+        let tx_result = self
+            .get_mut_debugger_backend()
+            .vm_runner
+            .perform_fail_cross_shard_message(message_id, error_message);
+        TxResponse::from_tx_result(tx_result)
+    }
+
+    /// Delivers all the messages pending at this point, in order.
+    ///
+    /// The callbacks and new calls they produce wait for the next block.
+    pub fn deliver_cross_shard_block(&mut self) -> Vec<TxResponse> {
+        self.get_state()
+            .cross_shard_queue
+            .ids()
+            .into_iter()
+            .map(|message_id| self.deliver_cross_shard_message(message_id))
+            .collect()
+    }
+
+    /// Delivers blocks until no more messages are left.
+    pub fn deliver_all_cross_shard_messages(&mut self) {
+        while !self.get_state().cross_shard_queue.is_empty() {
+            self.deliver_cross_shard_block();
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use crate::facade::ScenarioWorld;
+
+/// Controls the random numbers served to contracts, to make tests involving randomness reproducible.
+impl ScenarioWorld {
+    /// All subsequent transactions draw their random numbers from a stream seeded with this value,
+    /// instead of the block random seeds and the tx hash.
+    pub fn pin_random_seed(&mut self, seed: &[u8]) {
+        self.get_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .randomness_lock()
+            .pinned_seed = Some(seed.to_vec());
+    }
+
+    /// Reverts to random streams seeded from the block random seeds and the tx hash.
+    pub fn unpin_random_seed(&mut self) {
+        self.get_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .randomness_lock()
+            .pinned_seed = None;
+    }
+
+    /// The next random bytes requested by contracts will be exactly these, in order.
+    ///
+    /// Once they are used up, contracts get random bytes from the usual random stream again.
+    pub fn script_random_bytes(&mut self, bytes: &[u8]) {
+        self.get_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .randomness_lock()
+            .script_bytes(bytes);
+    }
+
+    /// The next calls to `RandomnessSource::next_u64` will return these values, in order.
+    ///
+    /// The values are scripted as big-endian bytes,
+    /// so they also determine the results of `next_u64_in_range`.
+    pub fn script_random_u64s(&mut self, values: &[u64]) {
+        for value in values {
+            self.script_random_bytes(&value.to_be_bytes());
+        }
+    }
+
+    /// From now on, keeps a log of the random bytes served to contracts, one entry per request.
+    ///
+    /// Nothing is recorded by default.
+    pub fn record_random_bytes(&mut self) {
+        self.get_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .randomness_lock()
+            .start_recording();
+    }
+
+    /// Retrieves and clears the log of random bytes consumed since `record_random_bytes` was called.
+    ///
+    /// Concatenated and passed to `script_random_bytes`, they replay the same outcomes.
+    pub fn take_consumed_random_bytes(&mut self) -> Vec<Vec<u8>> {
+        self.get_debugger_backend()
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .randomness_lock()
+            .take_consumed()
+    }
+}
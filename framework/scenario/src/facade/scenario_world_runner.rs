@@ -13,6 +13,9 @@ impl ScenarioWorld {
                 if let Some(trace) = &mut cd_debugger.trace {
                     f(trace);
                 }
+                if let Some(gas_profile) = &mut cd_debugger.gas_profile {
+                    f(gas_profile);
+                }
             }
             Backend::VmGoBackend => {
                 panic!("the VM Go backend does not support step-by-step execution")
@@ -2,8 +2,9 @@ use multiversx_sc::{
     tuple_util::NestedTupleFlatten,
     types::{
         Code, FunctionCall, ManagedAddress, ManagedBuffer, NotPayable, RHListExec, Tx,
-        TxBaseWithEnv, TxEnv, TxEnvMockDeployAddress, TxEnvWithTxHash, TxFromSpecified, TxGas,
-        TxId, TxPayment, TxToSpecified, UpgradeCall, heap::H256,
+        TxBaseWithEnv, TxEnv, TxEnvMockDeployAddress, TxEnvWithRelayer, TxEnvWithTxHash,
+        TxFromSpecified, TxGas, TxId, TxPayment, TxToSpecified, UpgradeCall,
+        heap::{Address, H256},
     },
 };
 
@@ -133,6 +134,16 @@ impl TxEnvWithTxHash for ScenarioEnvExec<'_> {
     }
 }
 
+impl TxEnvWithRelayer for ScenarioEnvExec<'_> {
+    fn set_relayer(&mut self, relayer: Address) {
+        self.data.set_relayer(relayer);
+    }
+
+    fn take_relayer(&mut self) -> Option<Address> {
+        self.data.take_relayer()
+    }
+}
+
 impl ScenarioWorld {
     pub fn tx(&mut self) -> TxBaseWithEnv<ScenarioEnvExec<'_>> {
         let data = self.new_env_data();
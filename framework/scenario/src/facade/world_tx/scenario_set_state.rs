@@ -15,10 +15,10 @@ use crate::{
 use multiversx_chain_vm::blockchain::state::EsdtInstanceMetadata;
 use multiversx_sc::{
     proxy_imports::TopEncode,
-    types::{AnnotatedValue, BigUint, EsdtLocalRole, ManagedAddress},
+    types::{AnnotatedValue, BigUint, DurationMillis, EsdtLocalRole, ManagedAddress},
 };
 use scenario_set_account::AccountItem;
-use scenario_set_block::BlockItem;
+use scenario_set_block::{BlockItem, block_info_from_vm};
 use scenario_set_new_address::NewAddressItem;
 
 impl ScenarioWorld {
@@ -209,6 +209,41 @@ impl ScenarioWorld {
 
         self.get_mut_state().block_config.block_round_time_ms = block_round_time_ms_value.value;
     }
+
+    /// Sets the number of rounds in an epoch, used when advancing blocks.
+    ///
+    /// Does not trace to Mandos, since Mandos doesn't have this field.
+    pub fn epoch_length_rounds(&mut self, epoch_length_rounds: u64) {
+        self.get_mut_state().block_config.epoch_length_rounds = epoch_length_rounds;
+    }
+
+    /// Produces `num_blocks` new blocks, one per round.
+    ///
+    /// Nonce, round, timestamp, epoch and random seed all move forward consistently,
+    /// based on the configured block round time and epoch length.
+    /// The resulting block info is traced as a regular set state step,
+    /// the hashes of the new blocks are not traced.
+    pub fn advance_blocks(&mut self, num_blocks: u64) {
+        let mut block_config = self.get_state().block_config.clone();
+        let epoch_before = block_config.current_block_info.block_epoch;
+        block_config.advance_blocks(num_blocks);
+
+        let mut step = SetStateStep::new();
+        *step.previous_block_info = Some(block_info_from_vm(&block_config.previous_block_info));
+        *step.current_block_info = Some(block_info_from_vm(&block_config.current_block_info));
+        if block_config.current_block_info.block_epoch != epoch_before {
+            *step.epoch_start_block_info =
+                Some(block_info_from_vm(&block_config.epoch_start_block_info));
+        }
+        self.run_set_state_step(&step);
+        self.get_mut_state().block_config.block_hashes = block_config.block_hashes;
+    }
+
+    /// Produces as many blocks as needed for at least `duration` to pass.
+    pub fn advance_time(&mut self, duration: DurationMillis) {
+        let num_blocks = self.get_state().block_config.rounds_for_duration(duration);
+        self.advance_blocks(num_blocks);
+    }
 }
 
 pub trait SetStateBuilderItem {
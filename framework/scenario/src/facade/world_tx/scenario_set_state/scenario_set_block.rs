@@ -1,11 +1,12 @@
 use multiversx_chain_scenario_format::serde_raw::ValueSubTree;
+use multiversx_chain_vm::blockchain::state::BlockInfo as VmBlockInfo;
 use multiversx_sc::types::{AnnotatedValue, ManagedBuffer, TimestampMillis, TimestampSeconds};
 
 use crate::{
     ScenarioTxEnvData,
     imports::StaticApi,
     scenario::tx_to_step::{bytes_annotated, u64_annotated},
-    scenario_model::{BlockInfo, SetStateStep, U64Value},
+    scenario_model::{BlockInfo, BytesValue, SetStateStep, U64Value},
 };
 
 use super::{SetStateBuilder, SetStateBuilderItem};
@@ -150,3 +151,17 @@ impl SetStateBuilder<'_, BlockItem> {
         self
     }
 }
+
+/// Full scenario representation of a VM block info, used when producing blocks.
+pub(super) fn block_info_from_vm(block_info: &VmBlockInfo) -> BlockInfo {
+    BlockInfo {
+        block_timestamp: None,
+        block_timestamp_ms: Some(U64Value::from(
+            block_info.block_timestamp_millis.as_u64_millis(),
+        )),
+        block_nonce: Some(U64Value::from(block_info.block_nonce)),
+        block_round: Some(U64Value::from(block_info.block_round)),
+        block_epoch: Some(U64Value::from(block_info.block_epoch)),
+        block_random_seed: Some(BytesValue::from(&block_info.block_random_seed[..])),
+    }
+}
@@ -29,7 +29,9 @@ pub use crate::{
     whitebox_legacy::*,
 };
 
-pub use crate::multiversx_sc::chain_core::types::{BLSKey, BLSSignature, ReturnCode};
+pub use crate::multiversx_sc::chain_core::types::{
+    BLSKey, BLSSignature, ReturnCode, ShardConfig, ShardId,
+};
 
 pub use multiversx_chain_vm::schedule::GasScheduleVersion;
 
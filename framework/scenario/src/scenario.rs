@@ -1,5 +1,6 @@
 pub mod model;
 mod parse_util;
+pub mod run_gas_profile;
 pub mod run_list;
 pub mod run_trace;
 pub mod run_vm;
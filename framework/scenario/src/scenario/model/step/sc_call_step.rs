@@ -60,6 +60,15 @@ impl ScCallStep {
         self
     }
 
+    /// Makes it a relayed transaction (relayed v3): the relayer pays the gas.
+    pub fn relayer<A>(mut self, address: A) -> Self
+    where
+        AddressValue: From<A>,
+    {
+        self.tx.relayer = Some(AddressValue::from(address));
+        self
+    }
+
     pub fn egld_value<A>(mut self, amount: A) -> Self
     where
         BigUintValue: From<A>,
@@ -166,6 +175,9 @@ impl ScCallStep {
                 .as_ref()
                 .map(|vm_hash| vm_hash.as_array().into());
         }
+        if tx_response.receiver.is_none() {
+            tx_response.receiver = Some(self.tx.to.to_address());
+        }
         self.response = Some(tx_response);
     }
 }
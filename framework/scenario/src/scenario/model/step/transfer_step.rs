@@ -39,6 +39,15 @@ impl TransferStep {
         self
     }
 
+    /// Makes it a relayed transaction (relayed v3): the relayer pays the gas.
+    pub fn relayer<A>(mut self, address: A) -> Self
+    where
+        AddressValue: From<A>,
+    {
+        self.tx.relayer = Some(AddressValue::from(address));
+        self
+    }
+
     pub fn egld_value<A>(mut self, amount: A) -> Self
     where
         BigUintValue: From<A>,
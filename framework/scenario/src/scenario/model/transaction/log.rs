@@ -1,4 +1,7 @@
-use multiversx_sc::types::Address;
+use multiversx_sc::{
+    codec::DecodeError,
+    types::{Address, TxProxyEvent},
+};
 
 #[derive(Debug, Clone)]
 pub struct Log {
@@ -7,3 +10,12 @@ pub struct Log {
     pub topics: Vec<Vec<u8>>,
     pub data: Vec<Vec<u8>>,
 }
+
+impl Log {
+    /// Decodes the log into an event struct from a generated proxy.
+    ///
+    /// Returns `None` if the log has a different event identifier.
+    pub fn decode_event<E: TxProxyEvent>(&self) -> Option<Result<E, DecodeError>> {
+        E::decode_log(&self.topics, &self.data)
+    }
+}
@@ -19,6 +19,7 @@ pub const DEFAULT_GAS_EXPR: &str = "5,000,000";
 pub struct TxCall {
     pub from: AddressValue,
     pub to: AddressValue,
+    pub relayer: Option<AddressValue>,
     pub egld_value: BigUintValue,
     pub esdt_value: Vec<TxESDT>,
     pub function: String,
@@ -32,6 +33,7 @@ impl Default for TxCall {
         Self {
             from: Default::default(),
             to: Default::default(),
+            relayer: None,
             egld_value: Default::default(),
             esdt_value: Default::default(),
             function: Default::default(),
@@ -47,6 +49,9 @@ impl InterpretableFrom<TxCallRaw> for TxCall {
         TxCall {
             from: AddressValue::interpret_from(from.from, context),
             to: AddressValue::interpret_from(from.to, context),
+            relayer: from
+                .relayer
+                .map(|relayer| AddressValue::interpret_from(relayer, context)),
             egld_value: interpret_egld_value(from.value, from.egld_value, context),
             esdt_value: from
                 .esdt_value
@@ -70,6 +75,7 @@ impl IntoRaw<TxCallRaw> for TxCall {
         TxCallRaw {
             from: self.from.into_raw(),
             to: self.to.into_raw(),
+            relayer: self.relayer.map(|relayer| relayer.into_raw()),
             value: None,
             egld_value: self.egld_value.into_raw_opt(),
             esdt_value: self
@@ -148,6 +154,7 @@ impl TxCall {
             } else {
                 self.to.clone()
             },
+            relayer: self.relayer.clone(),
             egld_value: self.egld_value.clone(),
             esdt_value: Vec::new(),
             function,
@@ -1,8 +1,19 @@
-use multiversx_chain_vm::{host::context::TxResult, types::H256};
+use std::collections::BTreeMap;
+
+use multiversx_chain_vm::{host::context::TxResult, schedule::GasCostCategory, types::H256};
 use multiversx_sc::types::Address;
 
 use super::{Log, TxExpect, TxResponseStatus};
 
+/// The system SC functions that issue new tokens, which are also the identifiers of their logs.
+const ISSUE_LOG_IDENTIFIERS: &[&str] = &[
+    "issue",
+    "issueSemiFungible",
+    "issueNonFungible",
+    "registerMetaESDT",
+    "registerAndSetAllRoles",
+];
+
 #[derive(Debug, Default, Clone)]
 /// The response of a transaction.
 pub struct TxResponse {
@@ -10,6 +21,8 @@ pub struct TxResponse {
     pub out: Vec<Vec<u8>>,
     /// The address of the newly deployed smart contract.
     pub new_deployed_address: Option<Address>,
+    /// The address of the called smart contract, for calls.
+    pub receiver: Option<Address>,
     /// The identifier of the newly issued token.
     pub new_issued_token_identifier: Option<String>,
     /// The status of the transaction.
@@ -18,6 +31,10 @@ pub struct TxResponse {
     pub logs: Vec<Log>,
     /// The gas used by the transaction.
     pub gas_used: u64,
+    /// The gas used by the transaction, grouped by category.
+    ///
+    /// Only available in the debugger, when gas metering is enabled.
+    pub gas_breakdown: BTreeMap<GasCostCategory, u64>,
     /// The refund of the transaction.
     pub refund: u64,
     /// The transaction hash, if available.
@@ -27,6 +44,7 @@ pub struct TxResponse {
 impl TxResponse {
     /// Creates a [`TxResponse`] from a [`TxResult`].
     pub fn from_tx_result(tx_result: TxResult) -> Self {
+        let new_issued_token_identifier = process_new_issued_token_identifier(&tx_result);
         TxResponse {
             out: tx_result.result_values,
             tx_error: TxResponseStatus {
@@ -43,7 +61,9 @@ impl TxResponse {
                     data: tx_log.data.clone(),
                 })
                 .collect(),
+            new_issued_token_identifier,
             gas_used: tx_result.gas_used.as_u64(),
+            gas_breakdown: tx_result.gas_breakdown,
             ..Default::default()
         }
     }
@@ -84,3 +104,13 @@ impl TxResponse {
         self.tx_error.is_success()
     }
 }
+
+/// The issue log of the system SC has the new token identifier as first topic.
+fn process_new_issued_token_identifier(tx_result: &TxResult) -> Option<String> {
+    tx_result
+        .result_logs
+        .iter()
+        .find(|tx_log| ISSUE_LOG_IDENTIFIERS.contains(&tx_log.endpoint.as_str()))
+        .and_then(|tx_log| tx_log.topics.first())
+        .map(|token_identifier| String::from_utf8_lossy(token_identifier).into_owned())
+}
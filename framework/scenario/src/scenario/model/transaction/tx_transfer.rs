@@ -12,6 +12,7 @@ use super::{TxCall, TxESDT, tx_interpret_util::interpret_egld_value};
 pub struct TxTransfer {
     pub from: AddressValue,
     pub to: AddressValue,
+    pub relayer: Option<AddressValue>,
     pub egld_value: BigUintValue,
     pub esdt_value: Vec<TxESDT>,
     pub gas_limit: U64Value,
@@ -23,6 +24,9 @@ impl InterpretableFrom<TxTransferRaw> for TxTransfer {
         TxTransfer {
             from: AddressValue::interpret_from(from.from, context),
             to: AddressValue::interpret_from(from.to, context),
+            relayer: from
+                .relayer
+                .map(|relayer| AddressValue::interpret_from(relayer, context)),
             egld_value: interpret_egld_value(from.value, from.egld_value, context),
             esdt_value: from
                 .esdt_value
@@ -40,6 +44,7 @@ impl IntoRaw<TxTransferRaw> for TxTransfer {
         TxTransferRaw {
             from: self.from.into_raw(),
             to: self.to.into_raw(),
+            relayer: self.relayer.map(|relayer| relayer.into_raw()),
             value: None,
             egld_value: self.egld_value.into_raw_opt(),
             esdt_value: self
@@ -59,6 +64,7 @@ impl TxTransfer {
         TxCall {
             from: self.from.clone(),
             to: self.to.clone(),
+            relayer: self.relayer.clone(),
             egld_value: self.egld_value.clone(),
             esdt_value: self.esdt_value.clone(),
             function: String::new(),
@@ -0,0 +1,6 @@
+mod gas_profile_markdown;
+mod gas_profile_report;
+mod scenario_gas_profile;
+
+pub use gas_profile_report::*;
+pub use scenario_gas_profile::*;
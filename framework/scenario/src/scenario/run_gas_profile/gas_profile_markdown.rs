@@ -0,0 +1,45 @@
+use std::fmt::Write;
+
+use super::GasProfileReport;
+
+impl GasProfileReport {
+    /// Renders the report as Markdown, one section per contract.
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::new();
+        writeln!(md, "# Gas profile").unwrap();
+
+        for (contract_name, contract) in &self.contracts {
+            writeln!(md).unwrap();
+            writeln!(md, "## {contract_name}").unwrap();
+            writeln!(md).unwrap();
+            writeln!(md, "| Endpoint | Calls | Min | Avg | Max | Total |").unwrap();
+            writeln!(md, "| :-- | --: | --: | --: | --: | --: |").unwrap();
+            for (endpoint_name, endpoint) in &contract.endpoints {
+                writeln!(
+                    md,
+                    "| {endpoint_name} | {} | {} | {} | {} | {} |",
+                    endpoint.calls,
+                    endpoint.min_gas,
+                    endpoint.avg_gas(),
+                    endpoint.max_gas,
+                    endpoint.total_gas,
+                )
+                .unwrap();
+            }
+
+            let categories = contract.categories();
+            if categories.is_empty() {
+                continue;
+            }
+
+            writeln!(md).unwrap();
+            writeln!(md, "| Category | Total |").unwrap();
+            writeln!(md, "| :-- | --: |").unwrap();
+            for (category, gas) in &categories {
+                writeln!(md, "| {category} | {gas} |").unwrap();
+            }
+        }
+
+        md
+    }
+}
@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufReader, Write},
+    path::Path,
+};
+
+/// Gas used by contract endpoints, aggregated over one or more scenario runs.
+///
+/// Saved as JSON, so that reports from different runs can be merged and compared.
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GasProfileReport {
+    /// Contracts, indexed by name.
+    #[serde(default)]
+    pub contracts: BTreeMap<String, ContractGasProfile>,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractGasProfile {
+    /// Endpoints, indexed by name. Deploys are recorded as `init`.
+    #[serde(default)]
+    pub endpoints: BTreeMap<String, EndpointGasProfile>,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointGasProfile {
+    pub calls: u64,
+    pub total_gas: u64,
+    pub min_gas: u64,
+    pub max_gas: u64,
+
+    /// Total gas, grouped by category (VM hook group, builtin functions, nested calls).
+    #[serde(default)]
+    pub categories: BTreeMap<String, u64>,
+}
+
+impl EndpointGasProfile {
+    pub fn avg_gas(&self) -> u64 {
+        self.total_gas.checked_div(self.calls).unwrap_or_default()
+    }
+
+    fn record(&mut self, gas_used: u64, categories: &BTreeMap<String, u64>) {
+        let single_call = EndpointGasProfile {
+            calls: 1,
+            total_gas: gas_used,
+            min_gas: gas_used,
+            max_gas: gas_used,
+            categories: categories.clone(),
+        };
+        self.merge(&single_call);
+    }
+
+    fn merge(&mut self, other: &EndpointGasProfile) {
+        if other.calls == 0 {
+            return;
+        }
+
+        if self.calls == 0 {
+            self.min_gas = other.min_gas;
+            self.max_gas = other.max_gas;
+        } else {
+            self.min_gas = self.min_gas.min(other.min_gas);
+            self.max_gas = self.max_gas.max(other.max_gas);
+        }
+        self.calls += other.calls;
+        self.total_gas += other.total_gas;
+        for (category, gas) in &other.categories {
+            *self.categories.entry(category.clone()).or_default() += gas;
+        }
+    }
+}
+
+impl ContractGasProfile {
+    pub fn total_gas(&self) -> u64 {
+        self.endpoints
+            .values()
+            .map(|endpoint| endpoint.total_gas)
+            .sum()
+    }
+
+    /// Sums up the categories of all endpoints.
+    pub fn categories(&self) -> BTreeMap<String, u64> {
+        let mut categories = BTreeMap::<String, u64>::new();
+        for endpoint in self.endpoints.values() {
+            for (category, gas) in &endpoint.categories {
+                *categories.entry(category.clone()).or_default() += gas;
+            }
+        }
+        categories
+    }
+}
+
+impl GasProfileReport {
+    pub fn is_empty(&self) -> bool {
+        self.contracts.is_empty()
+    }
+
+    /// Adds a single successful transaction to the report.
+    pub fn record(
+        &mut self,
+        contract: &str,
+        endpoint: &str,
+        gas_used: u64,
+        categories: &BTreeMap<String, u64>,
+    ) {
+        self.contracts
+            .entry(contract.to_string())
+            .or_default()
+            .endpoints
+            .entry(endpoint.to_string())
+            .or_default()
+            .record(gas_used, categories);
+    }
+
+    /// Adds all the data from another report into this one.
+    pub fn merge(&mut self, other: &GasProfileReport) {
+        for (contract_name, other_contract) in &other.contracts {
+            let contract = self.contracts.entry(contract_name.clone()).or_default();
+            for (endpoint_name, other_endpoint) in &other_contract.endpoints {
+                contract
+                    .endpoints
+                    .entry(endpoint_name.clone())
+                    .or_default()
+                    .merge(other_endpoint);
+            }
+        }
+    }
+
+    pub fn find_endpoint(&self, contract: &str, endpoint: &str) -> Option<&EndpointGasProfile> {
+        self.contracts.get(contract)?.endpoints.get(endpoint)
+    }
+
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Self {
+        let file = File::open(path.as_ref())
+            .unwrap_or_else(|_| panic!("gas profile file {} not found", path.as_ref().display()));
+        serde_json::from_reader(BufReader::new(file)).expect("invalid gas profile JSON")
+    }
+
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) {
+        let mut file = File::create(path).expect("could not create gas profile file");
+        let json = serde_json::to_string_pretty(self).expect("gas profile serialization failed");
+        file.write_all(json.as_bytes()).unwrap();
+        file.write_all(b"\n").unwrap();
+    }
+}
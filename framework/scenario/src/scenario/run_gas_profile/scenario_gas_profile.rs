@@ -0,0 +1,146 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::Path,
+    sync::Mutex,
+};
+
+use crate::{
+    multiversx_sc::types::Address,
+    scenario::{ScenarioRunner, model::*},
+};
+
+use super::GasProfileReport;
+
+/// Tests in the same binary run in parallel and can write to the same report file.
+static REPORT_FILE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Collects the gas used by each successful contract call and deploy.
+///
+/// Relies on the responses saved in the steps, so it needs to run after the VM.
+#[derive(Default, Debug)]
+pub struct ScenarioGasProfile {
+    pub report: GasProfileReport,
+    pub addr_to_pretty_string_map: HashMap<Address, String>,
+}
+
+impl ScenarioGasProfile {
+    /// Merges the collected data into the JSON report at the given path, creating it if missing.
+    ///
+    /// A Markdown rendering of the merged report is written next to it, with the `.md` extension.
+    pub fn write_gas_profile<P: AsRef<Path>>(&mut self, file_path: P) {
+        let file_path = file_path.as_ref();
+        let _guard = REPORT_FILE_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let mut report = if file_path.exists() {
+            GasProfileReport::load_from_file(file_path)
+        } else {
+            GasProfileReport::default()
+        };
+        report.merge(&core::mem::take(&mut self.report));
+
+        report.save_to_file(file_path);
+        std::fs::write(file_path.with_extension("md"), report.to_markdown())
+            .expect("could not write gas profile Markdown file");
+    }
+
+    fn contract_name(&self, address: &Address) -> String {
+        self.addr_to_pretty_string_map
+            .get(address)
+            .cloned()
+            .unwrap_or_else(|| format!("0x{}", hex::encode(address)))
+    }
+
+    fn record(&mut self, contract: String, endpoint: &str, response: &Option<TxResponse>) {
+        let Some(response) = response else {
+            return;
+        };
+        if !response.tx_error.is_success() {
+            return;
+        }
+
+        let categories: BTreeMap<String, u64> = response
+            .gas_breakdown
+            .iter()
+            .map(|(category, gas)| (category.name().to_string(), *gas))
+            .collect();
+        self.report
+            .record(&contract, endpoint, response.gas_used, &categories);
+    }
+
+    fn record_sc_call(&mut self, step: &ScCallStep) {
+        let to = step.tx.to.to_address();
+        if !to.is_smart_contract_address() || step.tx.function.is_empty() {
+            return;
+        }
+
+        self.addr_to_pretty_string_map
+            .entry(to.clone())
+            .or_insert_with(|| step.tx.to.original.to_concatenated_string());
+        let contract = self.contract_name(&to);
+        self.record(contract, &step.tx.function, &step.response);
+    }
+
+    fn record_sc_deploy(&mut self, step: &ScDeployStep) {
+        let Some(new_address) = step
+            .response
+            .as_ref()
+            .and_then(|response| response.new_deployed_address.as_ref())
+        else {
+            return;
+        };
+
+        let contract = self.contract_name(new_address);
+        self.record(contract, "init", &step.response);
+    }
+}
+
+impl ScenarioRunner for ScenarioGasProfile {
+    fn run_external_steps(&mut self, _step: &ExternalStepsStep) {}
+
+    fn run_set_state_step(&mut self, step: &SetStateStep) {
+        for (address_key, account) in &step.accounts {
+            if account.code.is_some() {
+                self.addr_to_pretty_string_map
+                    .entry(address_key.value.clone())
+                    .or_insert_with(|| address_key.original.clone());
+            }
+        }
+        for new_address in &step.new_addresses {
+            self.addr_to_pretty_string_map
+                .entry(new_address.new_address.value.clone())
+                .or_insert_with(|| new_address.new_address.original.to_concatenated_string());
+        }
+    }
+
+    fn run_sc_call_step(&mut self, step: &mut ScCallStep) {
+        self.record_sc_call(step);
+    }
+
+    fn run_multi_sc_call_step(&mut self, steps: &mut [ScCallStep]) {
+        for step in steps.iter() {
+            self.record_sc_call(step);
+        }
+    }
+
+    fn run_multi_sc_deploy_step(&mut self, steps: &mut [ScDeployStep]) {
+        for step in steps.iter() {
+            self.record_sc_deploy(step);
+        }
+    }
+
+    fn run_sc_query_step(&mut self, _step: &mut ScQueryStep) {}
+
+    fn run_sc_deploy_step(&mut self, step: &mut ScDeployStep) {
+        self.record_sc_deploy(step);
+    }
+
+    fn run_transfer_step(&mut self, _step: &TransferStep) {}
+
+    fn run_validator_reward_step(&mut self, _step: &ValidatorRewardStep) {}
+
+    fn run_check_state_step(&mut self, _step: &CheckStateStep) {}
+
+    fn run_dump_state_step(&mut self) {}
+}
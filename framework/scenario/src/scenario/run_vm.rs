@@ -1,4 +1,5 @@
 mod check_state;
+mod cross_shard;
 mod errors;
 mod executor_config;
 pub mod sc_call;
@@ -11,5 +12,6 @@ mod tx_output_check;
 mod vm_runner;
 
 pub use executor_config::ExecutorConfig;
+pub(crate) use tx_input_util::pin_tx_id_hash;
 use tx_output_check::*;
 pub use vm_runner::ScenarioVMRunner;
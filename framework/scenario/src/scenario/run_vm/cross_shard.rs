@@ -0,0 +1,26 @@
+use multiversx_chain_vm::host::{context::TxResult, execution};
+
+use super::ScenarioVMRunner;
+
+impl ScenarioVMRunner {
+    pub fn perform_deliver_cross_shard_message(&mut self, message_id: u64) -> TxResult {
+        let runtime = self.create_debugger_runtime();
+        execution::deliver_cross_shard_message(
+            message_id,
+            &mut self.blockchain_mock.state,
+            &runtime,
+        )
+    }
+
+    pub fn perform_fail_cross_shard_message(
+        &mut self,
+        message_id: u64,
+        error_message: &str,
+    ) -> TxResult {
+        execution::fail_cross_shard_message(
+            message_id,
+            error_message,
+            &mut self.blockchain_mock.state,
+        )
+    }
+}
@@ -1,32 +1,86 @@
 use crate::{
-    scenario::model::{ScCallStep, TxESDT},
+    scenario::model::{AddressValue, ScCallStep, TxESDT},
     scenario_model::TxResponse,
 };
 
-use multiversx_chain_vm::host::{
-    context::{TxFunctionName, TxInput, TxResult, TxTokenTransfer},
-    execution,
-    runtime::{RuntimeInstanceCallLambda, RuntimeInstanceCallLambdaDefault},
+use multiversx_chain_vm::{
+    chain_core::std::TxHashInput,
+    host::{
+        context::{TxFunctionName, TxInput, TxResult, TxTokenTransfer},
+        execution,
+        runtime::{RuntimeInstanceCallLambda, RuntimeInstanceCallLambdaDefault},
+    },
+    types::H256,
 };
 
-use super::{ScenarioVMRunner, check_tx_output, tx_input_util::generate_tx_hash};
+use super::{
+    ScenarioVMRunner, check_tx_output,
+    tx_input_util::{call_data_field, canonical_tx_hash, generate_tx_hash},
+};
 
 impl ScenarioVMRunner {
     /// Adds a SC call step, as specified in the `step` argument, then executes it.
     ///
     /// The result of the operation gets saved back in the step's response field.
     pub fn perform_sc_call_update_results(&mut self, step: &mut ScCallStep) {
-        let tx_result =
-            self.perform_sc_call_lambda_and_check(step, RuntimeInstanceCallLambdaDefault);
-        let response = TxResponse::from_tx_result(tx_result);
+        let tx_hash = self.sc_call_tx_hash(step);
+        let tx_result = self.perform_sc_call_with_tx_hash(
+            step,
+            tx_hash.clone(),
+            RuntimeInstanceCallLambdaDefault,
+        );
+        if let Some(tx_expect) = &step.expect {
+            check_tx_output(step.get_tx_id(), tx_expect, &tx_result);
+        }
+        let mut response = TxResponse::from_tx_result(tx_result);
+        response.tx_hash = Some(tx_hash);
         step.save_response(response);
     }
 
+    /// The hash of the transaction, computed before its execution increases the sender nonce.
+    fn sc_call_tx_hash(&self, sc_call_step: &ScCallStep) -> H256 {
+        if !self.uses_canonical_tx_hash(sc_call_step.get_tx_id(), &sc_call_step.explicit_tx_hash) {
+            return generate_tx_hash(sc_call_step.get_tx_id(), &sc_call_step.explicit_tx_hash);
+        }
+
+        let tx = &sc_call_step.tx;
+        let from = tx.from.to_address();
+        let to = tx.to.to_address();
+        let relayer = tx.relayer.as_ref().map(AddressValue::to_address);
+        let value = tx.egld_value.value.to_bytes_be();
+        let args: Vec<Vec<u8>> = tx.arguments.iter().map(|arg| arg.value.clone()).collect();
+        let data = call_data_field(&tx.function, &args);
+        canonical_tx_hash(TxHashInput {
+            nonce: self.sender_nonce(&from),
+            value: &value,
+            receiver: to.as_bytes(),
+            sender: from.as_bytes(),
+            gas_price: tx.gas_price.value,
+            gas_limit: tx.gas_limit.value,
+            data: &data,
+            relayer: relayer.as_ref().map(|r| r.as_bytes()).unwrap_or_default(),
+            ..Default::default()
+        })
+    }
+
     pub fn perform_sc_call_lambda<F>(&mut self, sc_call_step: &ScCallStep, f: F) -> TxResult
     where
         F: RuntimeInstanceCallLambda,
     {
-        let tx_input = tx_input_from_call(sc_call_step, f.override_function_name());
+        let tx_hash = self.sc_call_tx_hash(sc_call_step);
+        self.perform_sc_call_with_tx_hash(sc_call_step, tx_hash, f)
+    }
+
+    fn perform_sc_call_with_tx_hash<F>(
+        &mut self,
+        sc_call_step: &ScCallStep,
+        tx_hash: H256,
+        f: F,
+    ) -> TxResult
+    where
+        F: RuntimeInstanceCallLambda,
+    {
+        let tx_input = tx_input_from_call(sc_call_step, f.override_function_name(), tx_hash);
 
         // nonce gets increased irrespective of whether the tx fails or not
         self.blockchain_mock
@@ -61,6 +115,7 @@ impl ScenarioVMRunner {
 fn tx_input_from_call(
     sc_call_step: &ScCallStep,
     override_func_name: Option<TxFunctionName>,
+    tx_hash: H256,
 ) -> TxInput {
     let tx = &sc_call_step.tx;
     TxInput {
@@ -76,7 +131,8 @@ fn tx_input_from_call(
             .collect(),
         gas_limit: tx.gas_limit.value,
         gas_price: tx.gas_price.value,
-        tx_hash: generate_tx_hash(sc_call_step.get_tx_id(), &sc_call_step.explicit_tx_hash),
+        tx_hash,
+        relayer: tx.relayer.as_ref().map(AddressValue::to_address),
         ..Default::default()
     }
 }
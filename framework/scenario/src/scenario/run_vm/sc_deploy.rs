@@ -3,31 +3,69 @@ use crate::{
 };
 
 use multiversx_chain_vm::{
+    chain_core::std::TxHashInput,
     host::{
         context::{TxFunctionName, TxInput, TxResult},
         execution,
         runtime::{RuntimeInstanceCallLambda, RuntimeInstanceCallLambdaDefault},
     },
-    types::VMCodeMetadata,
+    types::{H256, VMCodeMetadata},
 };
 
-use super::{ScenarioVMRunner, check_tx_output, tx_input_util::generate_tx_hash};
+use super::{
+    ScenarioVMRunner, check_tx_output,
+    tx_input_util::{canonical_tx_hash, deploy_data_field, generate_tx_hash},
+};
 
 impl ScenarioVMRunner {
     /// Adds a SC deploy step, as specified in the `step` argument, then executes it.
     ///
     /// The result of the operation gets saved back in the step's response field.
     pub fn perform_sc_deploy_update_results(&mut self, step: &mut ScDeployStep) {
-        let (new_address, tx_result) =
-            self.perform_sc_deploy_lambda(step, RuntimeInstanceCallLambdaDefault);
+        let tx_hash = self.sc_deploy_tx_hash(step);
+        let (new_address, tx_result) = self.perform_sc_deploy_with_tx_hash(
+            step,
+            tx_hash.clone(),
+            RuntimeInstanceCallLambdaDefault,
+        );
         if let Some(tx_expect) = &step.expect {
             check_tx_output(step.get_tx_id(), tx_expect, &tx_result);
         }
         let mut response = TxResponse::from_tx_result(tx_result);
         response.new_deployed_address = Some(new_address);
+        response.tx_hash = Some(tx_hash);
         step.save_response(response);
     }
 
+    /// The hash of the deploy transaction, computed before its execution increases the sender nonce.
+    fn sc_deploy_tx_hash(&self, sc_deploy_step: &ScDeployStep) -> H256 {
+        if !self
+            .uses_canonical_tx_hash(sc_deploy_step.get_tx_id(), &sc_deploy_step.explicit_tx_hash)
+        {
+            return generate_tx_hash(sc_deploy_step.get_tx_id(), &sc_deploy_step.explicit_tx_hash);
+        }
+
+        let tx = &sc_deploy_step.tx;
+        let from = tx.from.to_address();
+        let value = tx.egld_value.value.to_bytes_be();
+        let args: Vec<Vec<u8>> = tx.arguments.iter().map(|arg| arg.value.clone()).collect();
+        let data = deploy_data_field(
+            &tx.contract_code.value,
+            &tx.code_metadata.bits().to_be_bytes(),
+            &args,
+        );
+        canonical_tx_hash(TxHashInput {
+            nonce: self.sender_nonce(&from),
+            value: &value,
+            receiver: multiversx_chain_vm::types::Address::zero().as_bytes(),
+            sender: from.as_bytes(),
+            gas_price: tx.gas_price.value,
+            gas_limit: tx.gas_limit.value,
+            data: &data,
+            ..Default::default()
+        })
+    }
+
     pub fn perform_sc_deploy_lambda<F>(
         &mut self,
         sc_deploy_step: &ScDeployStep,
@@ -36,7 +74,20 @@ impl ScenarioVMRunner {
     where
         F: RuntimeInstanceCallLambda,
     {
-        let tx_input = tx_input_from_deploy(sc_deploy_step, f.override_function_name());
+        let tx_hash = self.sc_deploy_tx_hash(sc_deploy_step);
+        self.perform_sc_deploy_with_tx_hash(sc_deploy_step, tx_hash, f)
+    }
+
+    fn perform_sc_deploy_with_tx_hash<F>(
+        &mut self,
+        sc_deploy_step: &ScDeployStep,
+        tx_hash: H256,
+        f: F,
+    ) -> (Address, TxResult)
+    where
+        F: RuntimeInstanceCallLambda,
+    {
+        let tx_input = tx_input_from_deploy(sc_deploy_step, f.override_function_name(), tx_hash);
         let runtime = self.create_debugger_runtime();
         let contract_code = &sc_deploy_step.tx.contract_code.value;
         let (new_address, tx_result) = execution::commit_deploy(
@@ -58,6 +109,7 @@ impl ScenarioVMRunner {
 fn tx_input_from_deploy(
     sc_deploy_step: &ScDeployStep,
     override_func_name: Option<TxFunctionName>,
+    tx_hash: H256,
 ) -> TxInput {
     let tx = &sc_deploy_step.tx;
     TxInput {
@@ -73,7 +125,7 @@ fn tx_input_from_deploy(
             .collect(),
         gas_limit: tx.gas_limit.value,
         gas_price: tx.gas_price.value,
-        tx_hash: generate_tx_hash(sc_deploy_step.get_tx_id(), &sc_deploy_step.explicit_tx_hash),
+        tx_hash,
         ..Default::default()
     }
 }
@@ -88,6 +88,13 @@ fn execute(state: &mut BlockchainState, set_state_step: &SetStateStep) {
     for new_token_identifier in set_state_step.new_token_identifiers.iter().cloned() {
         state.put_new_token_identifier(new_token_identifier)
     }
+    if !set_state_step.block_hashes.is_empty() {
+        state.block_config.block_hashes = set_state_step
+            .block_hashes
+            .iter()
+            .map(|block_hash| block_hash.value.clone())
+            .collect();
+    }
     if let Some(block_info_obj) = &*set_state_step.previous_block_info {
         update_block_info(&mut state.block_config.previous_block_info, block_info_obj);
     }
@@ -180,6 +187,7 @@ fn convert_scenario_esdt_instance_to_world_mock(
                 .map(|attributes| attributes.value.clone())
                 .unwrap_or_default(),
         },
+        frozen: false,
     }
 }
 
@@ -1,5 +1,5 @@
 use super::{ScenarioVMRunner, sc_call::tx_esdt_transfers_from_scenario};
-use crate::scenario::model::{TransferStep, TxTransfer, ValidatorRewardStep};
+use crate::scenario::model::{AddressValue, TransferStep, TxTransfer, ValidatorRewardStep};
 use multiversx_chain_vm::{
     blockchain::state::BlockchainStateRef,
     host::{
@@ -33,6 +33,7 @@ fn tx_input_from_transfer(tx_transfer: &TxTransfer) -> TxInput {
         args: Vec::new(),
         gas_limit: tx_transfer.gas_limit.value,
         gas_price: tx_transfer.gas_price.value,
+        relayer: tx_transfer.relayer.as_ref().map(AddressValue::to_address),
         ..Default::default()
     }
 }
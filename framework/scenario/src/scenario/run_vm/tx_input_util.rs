@@ -1,3 +1,19 @@
+use multiversx_chain_vm::{
+    chain_core::std::{TxHashInput, tx_hash},
+    types::{Address, H256},
+};
+
+use super::ScenarioVMRunner;
+
+/// Chain ID used when computing transaction hashes, the same one as in the chain simulator.
+const SCENARIO_CHAIN_ID: &[u8] = b"chain";
+
+/// Transaction version used when computing transaction hashes.
+const SCENARIO_TX_VERSION: u32 = 2;
+
+/// VM type prefix of the code metadata, in the data field of a deploy transaction.
+const WASM_VM_TYPE: &[u8] = &[0x05, 0x00];
+
 pub fn generate_tx_hash(
     tx_id: &str,
     explicit_tx_hash: &Option<multiversx_sc::types::H256>,
@@ -15,3 +31,69 @@ pub fn generate_tx_hash(
         result.into()
     }
 }
+
+/// Scenario files are also run by the Go scenario runner, which always derives the tx hash from the tx id.
+///
+/// Fixing it upfront keeps steps without a tx id consistent between the two runners.
+pub(crate) fn pin_tx_id_hash(
+    tx_id: &str,
+    explicit_tx_hash: &mut Option<multiversx_sc::types::H256>,
+) {
+    if explicit_tx_hash.is_none() {
+        *explicit_tx_hash = Some((*generate_tx_hash(tx_id, &None).as_array()).into());
+    }
+}
+
+/// Hashes the unsigned transaction, on the scenario chain.
+///
+/// Scenario transactions are not signed, so unlike on the network, there is no signature in the hash.
+pub fn canonical_tx_hash(mut hash_input: TxHashInput<'_>) -> H256 {
+    hash_input.chain_id = SCENARIO_CHAIN_ID;
+    hash_input.version = SCENARIO_TX_VERSION;
+    tx_hash(&hash_input).into()
+}
+
+impl ScenarioVMRunner {
+    /// With the canonical tx hash enabled, transactions with neither tx id, nor explicit tx hash
+    /// get the hash of their canonical encoding.
+    ///
+    /// The others always keep the tx id hash, to stay consistent with the Go scenario runner.
+    pub(super) fn uses_canonical_tx_hash(
+        &self,
+        tx_id: &str,
+        explicit_tx_hash: &Option<multiversx_sc::types::H256>,
+    ) -> bool {
+        self.canonical_tx_hash && tx_id.is_empty() && explicit_tx_hash.is_none()
+    }
+
+    /// The nonce of the sender, before the transaction increases it.
+    pub(super) fn sender_nonce(&self, sender: &Address) -> u64 {
+        self.blockchain_mock
+            .state
+            .accounts
+            .get(sender)
+            .map_or(0, |account| account.nonce)
+    }
+}
+
+/// The data field of a contract call: the function name, followed by the hex-encoded arguments, separated by `@`.
+pub fn call_data_field(function: &str, args: &[Vec<u8>]) -> Vec<u8> {
+    let mut data = function.as_bytes().to_vec();
+    append_hex_args(&mut data, args);
+    data
+}
+
+/// The data field of a deploy: the hex-encoded code, the VM type and code metadata, then the arguments.
+pub fn deploy_data_field(code: &[u8], code_metadata: &[u8], args: &[Vec<u8>]) -> Vec<u8> {
+    let mut data = hex::encode(code).into_bytes();
+    append_hex_args(&mut data, &[WASM_VM_TYPE.to_vec(), code_metadata.to_vec()]);
+    append_hex_args(&mut data, args);
+    data
+}
+
+fn append_hex_args(data: &mut Vec<u8>, args: &[Vec<u8>]) {
+    for arg in args {
+        data.push(b'@');
+        data.extend_from_slice(hex::encode(arg).as_bytes());
+    }
+}
@@ -22,6 +22,13 @@ pub struct ScenarioVMRunner {
     pub contract_map_ref: ContractMapRef,
     pub blockchain_mock: BlockchainMock,
     pub executor_config: ExecutorConfig,
+
+    /// Calls and deploys without tx id get the hash of their canonical encoding,
+    /// instead of the one derived from the (empty) tx id.
+    ///
+    /// Since the tx hash also seeds the random number generator of the transaction,
+    /// these steps then also get different random bytes.
+    pub canonical_tx_hash: bool,
 }
 
 impl ScenarioVMRunner {
@@ -32,6 +39,7 @@ impl ScenarioVMRunner {
             contract_map_ref,
             blockchain_mock,
             executor_config: ExecutorConfig::default(),
+            canonical_tx_hash: false,
         }
     }
 
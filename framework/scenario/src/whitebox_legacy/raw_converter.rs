@@ -118,6 +118,7 @@ pub(crate) fn tx_call_as_raw(tx_call: &ScCallMandos) -> TxCallRaw {
     TxCallRaw {
         from: address_as_raw(&tx_call.from),
         to: address_as_raw(&tx_call.to),
+        relayer: None,
         value: None, // this is the old "value" field, which is now "egld_value". Only kept for backwards compatibility
         egld_value: rust_biguint_as_opt_raw(&tx_call.egld_value),
         esdt_value: all_esdt_raw,
@@ -148,6 +148,11 @@ fn builtin_func_esdt_transfer() {
     world().run("tests/scenarios-self/builtin-func-esdt-transfer.scen.json");
 }
 
+#[test]
+fn builtin_func_esdt_dynamic_nft() {
+    world().run("tests/scenarios-self/builtin-func-esdt-dynamic-nft.scen.json");
+}
+
 #[test]
 #[should_panic]
 fn esdt_non_zero_balance_check_err_rs() {
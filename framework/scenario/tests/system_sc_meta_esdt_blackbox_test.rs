@@ -0,0 +1,183 @@
+use multiversx_sc_scenario::imports::*;
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const NEW_OWNER_ADDRESS: TestAddress = TestAddress::new("new-owner");
+const ISSUE_COST: u64 = 50_000_000_000_000_000;
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world
+        .account(OWNER_ADDRESS)
+        .nonce(1)
+        .balance(2 * ISSUE_COST);
+    world.account(NEW_OWNER_ADDRESS).nonce(1);
+    world
+}
+
+fn token_properties(world: &mut ScenarioWorld, token_id: &str) -> TokenPropertiesResult {
+    world
+        .query()
+        .to(ESDTSystemSCAddress)
+        .typed(system_proxy::ESDTSystemSCProxy)
+        .get_token_properties(TestTokenIdentifier::new(token_id))
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn system_sc_register_meta_esdt_test() {
+    let mut world = world();
+
+    let token_id = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(system_proxy::ESDTSystemSCProxy)
+        .register_meta_esdt(
+            BigUint::from(ISSUE_COST),
+            "MetaToken",
+            "META",
+            MetaTokenProperties {
+                num_decimals: 18,
+                ..Default::default()
+            },
+        )
+        .returns(ReturnsNewTokenIdentifier)
+        .run();
+    assert!(token_id.starts_with("META-"));
+
+    let properties = token_properties(&mut world, &token_id);
+    assert_eq!(properties.token_name, "MetaToken");
+    assert_eq!(properties.token_type, EsdtTokenType::MetaFungible);
+    assert_eq!(properties.num_decimals, 18);
+    assert_eq!(properties.owner_address, OWNER_ADDRESS.to_address());
+}
+
+#[test]
+fn system_sc_change_sft_to_meta_esdt_test() {
+    let mut world = world();
+
+    let token_id = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(system_proxy::ESDTSystemSCProxy)
+        .issue_semi_fungible(
+            BigUint::from(ISSUE_COST),
+            "SemiFungible",
+            "SFT",
+            SemiFungibleTokenProperties::default(),
+        )
+        .returns(ReturnsNewTokenIdentifier)
+        .run();
+    assert_eq!(
+        token_properties(&mut world, &token_id).token_type,
+        EsdtTokenType::SemiFungible
+    );
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(system_proxy::ESDTSystemSCProxy)
+        .change_sft_to_meta_esdt(TestTokenIdentifier::new(&token_id), 6)
+        .run();
+
+    let properties = token_properties(&mut world, &token_id);
+    assert_eq!(properties.token_type, EsdtTokenType::MetaFungible);
+    assert_eq!(properties.num_decimals, 6);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(system_proxy::ESDTSystemSCProxy)
+        .change_sft_to_meta_esdt(TestTokenIdentifier::new(&token_id), 6)
+        .returns(ExpectError(
+            10,
+            "change can happen to semi fungible tokens only",
+        ))
+        .run();
+}
+
+#[test]
+fn system_sc_transfer_ownership_and_control_changes_test() {
+    let mut world = world();
+
+    let token_id = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(system_proxy::ESDTSystemSCProxy)
+        .register_meta_esdt(
+            BigUint::from(ISSUE_COST),
+            "MetaToken",
+            "META",
+            MetaTokenProperties::default(),
+        )
+        .returns(ReturnsNewTokenIdentifier)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(system_proxy::ESDTSystemSCProxy)
+        .transfer_ownership(TestTokenIdentifier::new(&token_id), NEW_OWNER_ADDRESS)
+        .run();
+    assert_eq!(
+        token_properties(&mut world, &token_id).owner_address,
+        NEW_OWNER_ADDRESS.to_address()
+    );
+
+    // the old owner is no longer allowed to make changes
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(system_proxy::ESDTSystemSCProxy)
+        .control_changes(
+            TestTokenIdentifier::new(&token_id),
+            &TokenPropertyArguments {
+                can_freeze: Some(false),
+                ..Default::default()
+            },
+        )
+        .returns(ExpectError(10, "can be called by owner only"))
+        .run();
+
+    world
+        .tx()
+        .from(NEW_OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(system_proxy::ESDTSystemSCProxy)
+        .control_changes(
+            TestTokenIdentifier::new(&token_id),
+            &TokenPropertyArguments {
+                can_freeze: Some(false),
+                can_upgrade: Some(false),
+                ..Default::default()
+            },
+        )
+        .run();
+
+    let properties = token_properties(&mut world, &token_id);
+    assert!(!properties.can_freeze);
+    assert!(!properties.can_upgrade);
+    assert!(properties.can_wipe);
+
+    world
+        .tx()
+        .from(NEW_OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(system_proxy::ESDTSystemSCProxy)
+        .control_changes(
+            TestTokenIdentifier::new(&token_id),
+            &TokenPropertyArguments {
+                can_freeze: Some(true),
+                ..Default::default()
+            },
+        )
+        .returns(ExpectError(10, "token is not upgradable"))
+        .run();
+}
@@ -0,0 +1,143 @@
+use multiversx_sc_scenario::imports::*;
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const ISSUE_COST: u64 = 50_000_000_000_000_000;
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.account(OWNER_ADDRESS).nonce(1).balance(ISSUE_COST);
+    world
+}
+
+#[test]
+fn system_sc_get_token_properties_test() {
+    let mut world = world();
+
+    let token_id = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(system_proxy::ESDTSystemSCProxy)
+        .issue_fungible(
+            BigUint::from(ISSUE_COST),
+            "TestToken",
+            "TEST",
+            1_000u64,
+            FungibleTokenProperties {
+                num_decimals: 6,
+                can_freeze: true,
+                can_wipe: false,
+                can_pause: true,
+                can_mint: false,
+                can_burn: true,
+                can_change_owner: false,
+                can_upgrade: false,
+                can_add_special_roles: true,
+            },
+        )
+        .returns(ReturnsResult)
+        .run();
+
+    let properties = world
+        .query()
+        .to(ESDTSystemSCAddress)
+        .typed(system_proxy::ESDTSystemSCProxy)
+        .get_token_properties(&token_id)
+        .returns(ReturnsResult)
+        .run();
+
+    assert_eq!(properties.token_name, "TestToken");
+    assert_eq!(properties.token_type, EsdtTokenType::Fungible);
+    assert_eq!(properties.owner_address, OWNER_ADDRESS.to_address());
+    assert_eq!(properties.num_decimals, 6);
+    assert!(!properties.is_paused);
+    assert!(properties.can_freeze);
+    assert!(!properties.can_wipe);
+    assert!(properties.can_pause);
+    assert!(!properties.can_mint);
+    assert!(properties.can_burn);
+    assert!(!properties.can_change_owner);
+    assert!(!properties.can_upgrade);
+    assert!(properties.can_add_special_roles);
+    assert_eq!(properties.num_wiped, 0);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(system_proxy::ESDTSystemSCProxy)
+        .pause(&token_id)
+        .run();
+
+    let properties = world
+        .query()
+        .to(ESDTSystemSCAddress)
+        .typed(system_proxy::ESDTSystemSCProxy)
+        .get_token_properties(&token_id)
+        .returns(ReturnsResult)
+        .run();
+    assert!(properties.is_paused);
+}
+
+#[test]
+fn system_sc_get_special_roles_test() {
+    let mut world = world();
+
+    let token_id = world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESDTSystemSCAddress)
+        .typed(system_proxy::ESDTSystemSCProxy)
+        .issue_fungible(
+            BigUint::from(ISSUE_COST),
+            "TestToken",
+            "TEST",
+            1_000u64,
+            FungibleTokenProperties::default(),
+        )
+        .returns(ReturnsResult)
+        .run();
+
+    let special_roles = world
+        .query()
+        .to(ESDTSystemSCAddress)
+        .typed(system_proxy::ESDTSystemSCProxy)
+        .get_special_roles(&token_id)
+        .returns(ReturnsRawResult)
+        .run();
+
+    assert_eq!(special_roles.len(), 1);
+    assert_eq!(
+        special_roles.get(0).to_vec(),
+        format!(
+            "{}:ESDTRoleLocalMint,ESDTRoleLocalBurn,ESDTRoleLocalTransfer",
+            OWNER_ADDRESS.to_address().to_bech32_default()
+        )
+        .into_bytes()
+    );
+
+    let addresses_and_roles = world
+        .query()
+        .to(ESDTSystemSCAddress)
+        .raw_call("getAllAddressesAndRoles")
+        .argument(&token_id)
+        .returns(ReturnsRawResult)
+        .run();
+    assert_eq!(addresses_and_roles.len(), 4);
+    assert_eq!(
+        addresses_and_roles.get(0).to_vec(),
+        OWNER_ADDRESS.to_address().to_vec()
+    );
+    assert_eq!(
+        addresses_and_roles.get(1).to_vec(),
+        b"ESDTRoleLocalMint".to_vec()
+    );
+
+    world
+        .query()
+        .to(ESDTSystemSCAddress)
+        .typed(system_proxy::ESDTSystemSCProxy)
+        .get_token_properties(TestTokenIdentifier::new("NOTEST-123456"))
+        .returns(ExpectError(10, "no ticker with given name"))
+        .run();
+}
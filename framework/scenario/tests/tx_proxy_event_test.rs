@@ -0,0 +1,100 @@
+use multiversx_sc::{
+    codec::DecodeError,
+    types::{
+        BigUint, EventFieldVisitor, EventLogInput, ManagedAddress, RHListItemExec, TxProxyEvent,
+    },
+};
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    imports::{Address, MultiValueEncoded, ReturnsEvents, ScenarioTxEnvData},
+    scenario_model::{Log, TxResponse},
+};
+
+#[derive(Debug, PartialEq)]
+struct TransferEvent {
+    from: ManagedAddress<StaticApi>,
+    tokens: MultiValueEncoded<StaticApi, u32>,
+    amount: BigUint<StaticApi>,
+}
+
+impl TxProxyEvent for TransferEvent {
+    const IDENTIFIER: &'static str = "transfer";
+
+    fn decode_event(input: &mut EventLogInput) -> Result<Self, DecodeError> {
+        Ok(TransferEvent {
+            from: input.next_topic()?,
+            tokens: input.next_topic()?,
+            amount: input.data()?,
+        })
+    }
+
+    fn visit_fields<V: EventFieldVisitor>(&self, visitor: &mut V) {
+        visitor.topic("from", &self.from);
+        visitor.topic("tokens", &self.tokens);
+        visitor.data("amount", &self.amount);
+    }
+}
+
+fn log(topics: Vec<Vec<u8>>, data: Vec<u8>) -> Log {
+    Log {
+        address: Address::zero(),
+        endpoint: "transfer".to_owned(),
+        topics,
+        data: vec![data],
+    }
+}
+
+#[test]
+fn tx_proxy_event_decode_test() {
+    let from = Address::new([1u8; 32]);
+    let log = log(
+        vec![b"transfer".to_vec(), from.to_vec(), vec![1], vec![2]],
+        vec![0x03, 0xe8],
+    );
+
+    let event = log.decode_event::<TransferEvent>().unwrap().unwrap();
+    assert_eq!(event.from, ManagedAddress::from(&from));
+    assert_eq!(event.tokens.to_vec().into_vec(), vec![1, 2]);
+    assert_eq!(event.amount, 1000u32);
+}
+
+#[test]
+fn tx_proxy_event_other_identifier_test() {
+    let other_log = log(vec![b"approve".to_vec()], Vec::new());
+    assert!(other_log.decode_event::<TransferEvent>().is_none());
+
+    let empty_log = log(Vec::new(), Vec::new());
+    assert!(empty_log.decode_event::<TransferEvent>().is_none());
+}
+
+#[test]
+fn tx_proxy_event_decode_error_test() {
+    // missing the address topic
+    let log = log(vec![b"transfer".to_vec()], Vec::new());
+    assert_eq!(
+        log.decode_event::<TransferEvent>().unwrap().unwrap_err(),
+        DecodeError::MULTI_TOO_FEW_ARGS
+    );
+}
+
+#[test]
+fn tx_proxy_event_target_contract_test() {
+    let contract = Address::new([2u8; 32]);
+    let from = Address::new([1u8; 32]);
+    let mut contract_log = log(vec![b"transfer".to_vec(), from.to_vec()], vec![0x05]);
+    contract_log.address = contract.clone();
+    let other_log = log(vec![b"transfer".to_vec(), from.to_vec()], vec![0x07]);
+
+    let response = TxResponse {
+        receiver: Some(contract),
+        logs: vec![other_log, contract_log],
+        ..Default::default()
+    };
+    let events: Vec<TransferEvent> =
+        RHListItemExec::<TxResponse, ScenarioTxEnvData, ()>::item_process_result(
+            ReturnsEvents::<TransferEvent>::new(),
+            &response,
+        );
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].amount, 5u32);
+}
@@ -0,0 +1,298 @@
+use std::{error::Error, path::Path};
+
+use anyhow::{Context, anyhow};
+use multiversx_sc_codec_human_readable::{
+    AnyValue, DecodedEvent, decode_event, decode_human_readable_value, encode_human_readable_value,
+    format::JsonValue, top_decode_multi_value, top_encode_multi_value,
+};
+use multiversx_sc_scenario::{
+    imports::{
+        Bech32Address, FunctionCall, ManagedArgBuffer, ManagedBuffer, ReturnsHandledOrError,
+        ReturnsLogs, ReturnsRawResult, StaticApi,
+    },
+    meta::abi_json::deserialize_abi_from_json,
+    multiversx_sc::abi::{ContractAbi, EndpointAbi},
+    scenario_model::Log,
+};
+use multiversx_sdk::gateway::GatewayAsyncService;
+
+use crate::{InteractorBase, InteractorRunAsync};
+
+/// Client for contracts that have no generated proxy, driven entirely by their ABI.
+///
+/// Arguments are given and results returned as human-readable JSON,
+/// using the conventions of the `multiversx-sc-codec-human-readable` crate.
+pub struct DynamicContract {
+    pub abi: ContractAbi,
+    pub address: Bech32Address,
+}
+
+/// The decoded outcome of a contract call.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicCallResult {
+    /// One value per endpoint output.
+    pub results: Vec<JsonValue>,
+
+    /// The events emitted during the call that are described in the ABI.
+    pub events: Vec<DecodedEvent>,
+}
+
+impl DynamicContract {
+    pub fn new(abi: ContractAbi, address: Bech32Address) -> Self {
+        DynamicContract { abi, address }
+    }
+
+    /// Loads the contract ABI from an `.abi.json` file.
+    pub fn from_abi_file<P: AsRef<Path>>(
+        abi_path: P,
+        address: Bech32Address,
+    ) -> anyhow::Result<Self> {
+        let abi_path = abi_path.as_ref();
+        let abi_json = std::fs::read_to_string(abi_path)
+            .with_context(|| format!("failed to read ABI file {}", abi_path.display()))?;
+        let abi_json = deserialize_abi_from_json(&abi_json)
+            .map_err(|err| anyhow!("invalid ABI file {}: {err}", abi_path.display()))?;
+        Ok(Self::new(abi_json.into(), address))
+    }
+
+    pub fn endpoint_abi(&self, endpoint_name: &str) -> anyhow::Result<&EndpointAbi> {
+        self.abi
+            .endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == endpoint_name)
+            .ok_or_else(|| anyhow!("endpoint {endpoint_name} not found in the ABI"))
+    }
+
+    /// Serializes the endpoint arguments, one JSON value per ABI input.
+    ///
+    /// Trailing multi-value inputs (e.g. `optional` or `variadic`) can be left out.
+    pub fn encode_arguments(
+        &self,
+        endpoint_name: &str,
+        args: &[JsonValue],
+    ) -> anyhow::Result<Vec<Vec<u8>>> {
+        let endpoint = self.endpoint_abi(endpoint_name)?;
+        check_arg_count(endpoint, args.len())?;
+
+        let mut any_values = Vec::with_capacity(args.len());
+        for (input, arg) in endpoint.inputs.iter().zip(args) {
+            let value =
+                decode_human_readable_value(&arg.clone().into(), &input.type_names.abi, &self.abi)
+                    .map_err(|err| argument_error(&input.arg_name, err))?;
+            any_values.push(value);
+        }
+        self.encode_any_value_arguments(endpoint_name, &any_values)
+    }
+
+    /// Same as [`Self::encode_arguments`], for arguments that are already converted to [`AnyValue`].
+    pub fn encode_any_value_arguments(
+        &self,
+        endpoint_name: &str,
+        args: &[AnyValue],
+    ) -> anyhow::Result<Vec<Vec<u8>>> {
+        let endpoint = self.endpoint_abi(endpoint_name)?;
+        check_arg_count(endpoint, args.len())?;
+
+        let mut raw_args = Vec::new();
+        for (input, arg) in endpoint.inputs.iter().zip(args) {
+            let encoded = top_encode_multi_value(arg, &input.type_names.abi, &self.abi)
+                .map_err(|err| argument_error(&input.arg_name, err))?;
+            raw_args.extend(encoded);
+        }
+        Ok(raw_args)
+    }
+
+    /// The endpoint call, with serialized arguments, ready to be added to a transaction via `.raw_data(...)`.
+    ///
+    /// Useful when the transaction needs more than [`Self::call`] offers, e.g. payments.
+    pub fn function_call(
+        &self,
+        endpoint_name: &str,
+        args: &[JsonValue],
+    ) -> anyhow::Result<FunctionCall<StaticApi>> {
+        let raw_args = self.encode_arguments(endpoint_name, args)?;
+        Ok(FunctionCall::new(endpoint_name).arguments_raw(raw_arg_buffer(&raw_args)))
+    }
+
+    /// Decodes the raw results of an endpoint, one JSON value per ABI output.
+    pub fn decode_results(
+        &self,
+        endpoint_name: &str,
+        raw_results: &[Vec<u8>],
+    ) -> anyhow::Result<Vec<JsonValue>> {
+        let endpoint = self.endpoint_abi(endpoint_name)?;
+        self.decode_outputs(endpoint, endpoint_name, raw_results)
+    }
+
+    /// Decodes the raw results of a deploy, by the constructor outputs.
+    pub fn decode_constructor_results(
+        &self,
+        raw_results: &[Vec<u8>],
+    ) -> anyhow::Result<Vec<JsonValue>> {
+        let constructor = self.abi.constructors.first().cloned().unwrap_or_default();
+        self.decode_outputs(&constructor, "the constructor", raw_results)
+    }
+
+    /// Decodes the raw results of an upgrade, by the upgrade constructor outputs.
+    pub fn decode_upgrade_results(
+        &self,
+        raw_results: &[Vec<u8>],
+    ) -> anyhow::Result<Vec<JsonValue>> {
+        let upgrade_constructor = self
+            .abi
+            .upgrade_constructors
+            .first()
+            .cloned()
+            .unwrap_or_default();
+        self.decode_outputs(&upgrade_constructor, "the upgrade constructor", raw_results)
+    }
+
+    fn decode_outputs(
+        &self,
+        endpoint: &EndpointAbi,
+        endpoint_name: &str,
+        raw_results: &[Vec<u8>],
+    ) -> anyhow::Result<Vec<JsonValue>> {
+        let mut remaining = raw_results;
+        let mut results = Vec::with_capacity(endpoint.outputs.len());
+        for (index, output) in endpoint.outputs.iter().enumerate() {
+            let type_name = output.type_names.abi.as_str();
+            let value = top_decode_multi_value(&mut remaining, type_name, &self.abi)
+                .and_then(|value| encode_human_readable_value(&value, type_name, &self.abi))
+                .map_err(|err| anyhow!("cannot decode result {index} of {endpoint_name}: {err}"))?;
+            results.push(value.get_value().to_owned());
+        }
+        if !remaining.is_empty() {
+            return Err(anyhow!("too many results for {endpoint_name}"));
+        }
+        Ok(results)
+    }
+
+    /// Decodes the logs emitted by this contract.
+    ///
+    /// Logs from other addresses and events missing from the ABI are skipped.
+    pub fn decode_events(&self, logs: &[Log]) -> anyhow::Result<Vec<DecodedEvent>> {
+        let mut events = Vec::new();
+        for log in logs {
+            if log.address != *self.address.as_address() {
+                continue;
+            }
+            let Some(identifier) = log.topics.first() else {
+                continue;
+            };
+            if !self
+                .abi
+                .events
+                .iter()
+                .any(|event| event.identifier.as_bytes() == identifier.as_slice())
+            {
+                continue;
+            }
+            let event = decode_event(&log.topics, &log.data, &self.abi)
+                .map_err(|err| anyhow!("cannot decode event: {err}"))?;
+            events.push(event);
+        }
+        Ok(events)
+    }
+
+    /// Calls an endpoint on the network, then decodes its results and events.
+    pub async fn call<GatewayProxy>(
+        &self,
+        interactor: &mut InteractorBase<GatewayProxy>,
+        from: &Bech32Address,
+        gas: u64,
+        endpoint_name: &str,
+        args: &[JsonValue],
+    ) -> anyhow::Result<DynamicCallResult>
+    where
+        GatewayProxy: GatewayAsyncService,
+    {
+        let function_call = self.function_call(endpoint_name, args)?;
+        let (raw_results, logs) = interactor
+            .tx()
+            .from(from)
+            .to(&self.address)
+            .gas(gas)
+            .raw_data(function_call)
+            .returns(
+                ReturnsHandledOrError::new()
+                    .returns(ReturnsRawResult)
+                    .returns(ReturnsLogs),
+            )
+            .run()
+            .await
+            .map_err(|status| anyhow!("call to {endpoint_name} failed: {status}"))?;
+
+        let raw_results = raw_results
+            .iter()
+            .map(|result| result.to_vec())
+            .collect::<Vec<_>>();
+        Ok(DynamicCallResult {
+            results: self.decode_results(endpoint_name, &raw_results)?,
+            events: self.decode_events(&logs)?,
+        })
+    }
+
+    /// Runs a VM query against a view endpoint and decodes its results.
+    pub async fn query<GatewayProxy>(
+        &self,
+        interactor: &mut InteractorBase<GatewayProxy>,
+        endpoint_name: &str,
+        args: &[JsonValue],
+    ) -> anyhow::Result<Vec<JsonValue>>
+    where
+        GatewayProxy: GatewayAsyncService,
+    {
+        let function_call = self.function_call(endpoint_name, args)?;
+        let raw_results = interactor
+            .query()
+            .to(&self.address)
+            .raw_data(function_call)
+            .returns(ReturnsHandledOrError::new().returns(ReturnsRawResult))
+            .run()
+            .await
+            .map_err(|status| anyhow!("query {endpoint_name} failed: {status}"))?;
+
+        let raw_results = raw_results
+            .iter()
+            .map(|result| result.to_vec())
+            .collect::<Vec<_>>();
+        self.decode_results(endpoint_name, &raw_results)
+    }
+}
+
+fn check_arg_count(endpoint: &EndpointAbi, count: usize) -> anyhow::Result<()> {
+    let required = endpoint
+        .inputs
+        .iter()
+        .rposition(|input| !input.multi_arg)
+        .map_or(0, |position| position + 1);
+    if count < required || count > endpoint.inputs.len() {
+        return Err(anyhow!(
+            "{} expects {} arguments, got {count}",
+            endpoint.name,
+            expected_arg_count(required, endpoint.inputs.len())
+        ));
+    }
+    Ok(())
+}
+
+fn expected_arg_count(required: usize, total: usize) -> String {
+    if required == total {
+        total.to_string()
+    } else {
+        format!("{required} to {total}")
+    }
+}
+
+fn argument_error(arg_name: &str, err: Box<dyn Error>) -> anyhow::Error {
+    anyhow!("invalid argument {arg_name}: {err}")
+}
+
+fn raw_arg_buffer(raw_args: &[Vec<u8>]) -> ManagedArgBuffer<StaticApi> {
+    let mut arg_buffer = ManagedArgBuffer::new();
+    for raw_arg in raw_args {
+        arg_buffer.push_arg_raw(ManagedBuffer::new_from_bytes(raw_arg));
+    }
+    arg_buffer
+}
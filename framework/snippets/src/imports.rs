@@ -5,8 +5,12 @@ pub use crate::{
     InteractorSimulateGasAsync, SimulateGas, StepBuffer, dns_address_for_name,
 };
 
+#[cfg(feature = "dynamic-contract")]
+pub use crate::DynamicContract;
+
 pub use crate::sdk::{
-    data::keystore::InsertPassword, test_wallets, validator::Validator, wallet::Wallet,
+    RetrievePolicy, data::keystore::InsertPassword, test_wallets, validator::Validator,
+    wallet::Wallet,
 };
 
 pub use env_logger;
@@ -5,7 +5,10 @@ use multiversx_sc_scenario::{
     meta::tools::find_current_workspace,
     multiversx_sc::types::Address,
 };
-use multiversx_sdk::gateway::{GatewayAsyncService, NetworkConfigRequest, SetStateAccount};
+use multiversx_sdk::{
+    RetrievePolicy,
+    gateway::{GatewayAsyncService, NetworkConfigRequest, SetStateAccount},
+};
 
 use super::ExplorerUrl;
 use std::{
@@ -32,6 +35,7 @@ where
     pub gas_price: u64,
 
     pub waiting_time_ms: u64,
+    pub retrieve_policy: RetrievePolicy,
     pub pre_runners: ScenarioRunnerList,
     pub post_runners: ScenarioRunnerList,
 
@@ -58,6 +62,7 @@ where
             network_config,
             sender_map: HashMap::new(),
             waiting_time_ms: 0,
+            retrieve_policy: RetrievePolicy::default(),
             pre_runners: ScenarioRunnerList::empty(),
             post_runners: ScenarioRunnerList::empty(),
             current_dir: PathBuf::default(),
@@ -71,6 +76,12 @@ where
         self
     }
 
+    /// Configures how long, and how often, the interactor polls the network for transaction results.
+    pub fn with_retrieve_policy(mut self, retrieve_policy: RetrievePolicy) -> Self {
+        self.retrieve_policy = retrieve_policy;
+        self
+    }
+
     pub async fn register_wallet(&mut self, wallet: Wallet) -> Address {
         let address = wallet.to_address();
 
@@ -85,11 +96,24 @@ where
                 hrp: self.network_config.address_hrp.clone(),
                 wallet,
                 current_nonce: None,
+                guardian: None,
             },
         );
         address
     }
 
+    /// Registers the guardian of an already registered wallet.
+    ///
+    /// From then on, all transactions sent from that wallet are guarded and co-signed by the guardian.
+    /// The guardian needs to already be active on the account, on the blockchain.
+    pub fn register_guardian(&mut self, sender_address: &Address, guardian: Wallet) {
+        let sender = self
+            .sender_map
+            .get_mut(sender_address)
+            .expect("the wallet to be guarded is not registered");
+        sender.guardian = Some(guardian);
+    }
+
     pub async fn sleep(&mut self, duration: Duration) {
         let millis = duration.as_millis() as u64;
         self.waiting_time_ms += millis;
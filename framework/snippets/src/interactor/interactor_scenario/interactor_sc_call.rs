@@ -12,7 +12,7 @@ use multiversx_sdk::{
 };
 use multiversx_sdk::{
     gateway::{GatewayAsyncService, SendTxRequest},
-    retrieve_tx_on_network,
+    retrieve_tx_on_network_with_policy,
 };
 
 impl<GatewayProxy> InteractorBase<GatewayProxy>
@@ -40,9 +40,10 @@ where
         self.generate_blocks_until_tx_processed(&tx_hash)
             .await
             .unwrap();
-        let (tx, return_code) = retrieve_tx_on_network(&self.proxy, tx_hash)
-            .await
-            .expect("failed to fetch transaction result");
+        let (tx, return_code) =
+            retrieve_tx_on_network_with_policy(&self.proxy, tx_hash, &self.retrieve_policy)
+                .await
+                .expect("failed to fetch transaction result");
 
         sc_call_step.save_response(network_response::parse_tx_response(tx, return_code));
 
@@ -135,6 +136,12 @@ where
             chain_id: self.network_config.chain_id.clone(),
             version: self.network_config.min_transaction_version,
             options: 0,
+            guardian: None,
+            guardian_signature: None,
+            relayer: normalized
+                .relayer
+                .map(|relayer| Bech32Address::encode_address(hrp, relayer.to_address())),
+            relayer_signature: None,
         }
     }
 }
@@ -12,7 +12,7 @@ use multiversx_sdk::{
 };
 use multiversx_sdk::{
     gateway::{GatewayAsyncService, SendTxRequest},
-    retrieve_tx_on_network,
+    retrieve_tx_on_network_with_policy,
 };
 
 impl<GatewayProxy> InteractorBase<GatewayProxy>
@@ -34,6 +34,10 @@ where
             chain_id: self.network_config.chain_id.clone(),
             version: self.network_config.min_transaction_version,
             options: 0,
+            guardian: None,
+            guardian_signature: None,
+            relayer: None,
+            relayer_signature: None,
         }
     }
 
@@ -93,9 +97,10 @@ where
         self.generate_blocks_until_tx_processed(&tx_hash)
             .await
             .unwrap();
-        let (tx, return_code) = retrieve_tx_on_network(&self.proxy, tx_hash.clone())
-            .await
-            .expect("failed to fetch transaction result");
+        let (tx, return_code) =
+            retrieve_tx_on_network_with_policy(&self.proxy, tx_hash.clone(), &self.retrieve_policy)
+                .await
+                .expect("failed to fetch transaction result");
 
         let addr = sc_deploy_step.tx.from.clone();
         let nonce = tx.nonce;
@@ -7,7 +7,7 @@ use multiversx_sc_scenario::{scenario::ScenarioRunner, scenario_model::TransferS
 use multiversx_sdk::{
     data::transaction::Transaction,
     gateway::{GatewayAsyncService, SendTxRequest, SimulateTxRequest},
-    retrieve_tx_on_network,
+    retrieve_tx_on_network_with_policy,
 };
 
 impl<GatewayProxy> InteractorBase<GatewayProxy>
@@ -31,7 +31,7 @@ where
         println!("transfer tx hash: {tx_hash}");
         info!("transfer tx hash: {}", tx_hash);
 
-        retrieve_tx_on_network(&self.proxy, tx_hash.clone())
+        retrieve_tx_on_network_with_policy(&self.proxy, tx_hash.clone(), &self.retrieve_policy)
             .await
             .expect("failed to fetch transfer tx result");
 
@@ -16,6 +16,25 @@ pub struct Sender {
     pub hrp: String,
     pub wallet: Wallet,
     pub current_nonce: Option<u64>,
+    /// If present, all transactions from this sender are guarded, and co-signed by the guardian.
+    pub guardian: Option<Wallet>,
+}
+
+impl Sender {
+    /// Signs the transaction, also co-signing it with the guardian, if the sender has one.
+    pub(crate) fn sign_tx(&self, transaction: &mut Transaction) {
+        if let Some(guardian) = &self.guardian {
+            transaction.set_guardian(guardian.to_address().to_bech32(&self.hrp));
+        }
+
+        let signature = self.wallet.sign_tx(transaction);
+        transaction.signature = Some(hex::encode(signature));
+
+        if let Some(guardian) = &self.guardian {
+            let guardian_signature = guardian.sign_tx_as_guardian(transaction);
+            transaction.guardian_signature = Some(hex::encode(guardian_signature));
+        }
+    }
 }
 
 impl<GatewayProxy> InteractorBase<GatewayProxy>
@@ -108,7 +127,24 @@ where
             .expect("the wallet that was supposed to sign is not registered");
 
         // sign
-        let signature = sender.wallet.sign_tx(transaction);
-        transaction.signature = Some(hex::encode(signature));
+        sender.sign_tx(transaction);
+
+        self.relayer_sign_tx(transaction);
+    }
+
+    /// Relayed transactions (relayed v3) also need to be co-signed by the relayer,
+    /// whose wallet needs to be registered too.
+    pub(crate) fn relayer_sign_tx(&self, transaction: &mut Transaction) {
+        let Some(relayer) = &transaction.relayer else {
+            return;
+        };
+
+        let relayer = self
+            .sender_map
+            .get(&relayer.to_address())
+            .expect("the relayer wallet is not registered");
+
+        let relayer_signature = relayer.wallet.sign_tx_as_relayer(transaction);
+        transaction.relayer_signature = Some(hex::encode(relayer_signature));
     }
 }
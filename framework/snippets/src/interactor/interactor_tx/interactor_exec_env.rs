@@ -3,7 +3,8 @@ use multiversx_sc_scenario::{
     api::StaticApi,
     imports::TxId,
     multiversx_sc::types::{
-        H256, ManagedAddress, ManagedBuffer, Tx, TxBaseWithEnv, TxEnv, TxEnvWithTxHash,
+        Address, H256, ManagedAddress, ManagedBuffer, Tx, TxBaseWithEnv, TxEnv, TxEnvWithRelayer,
+        TxEnvWithTxHash,
     },
     scenario_model::TxExpect,
 };
@@ -81,3 +82,16 @@ where
         self.data.take_tx_hash()
     }
 }
+
+impl<GatewayProxy> TxEnvWithRelayer for InteractorEnvExec<'_, GatewayProxy>
+where
+    GatewayProxy: GatewayAsyncService,
+{
+    fn set_relayer(&mut self, relayer: Address) {
+        self.data.set_relayer(relayer);
+    }
+
+    fn take_relayer(&mut self) -> Option<Address> {
+        self.data.take_relayer()
+    }
+}
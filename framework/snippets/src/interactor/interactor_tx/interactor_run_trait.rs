@@ -8,13 +8,14 @@ where
     GatewayProxy: GatewayAsyncService,
 {
     /// Creates a new [`ScenarioTxEnvData`] initialized with the interactor's current working
-    /// directory as the interpreter context. Both `tx_id` and `tx_hash` are left unset and
-    /// are expected to be populated later during transaction execution.
+    /// directory as the interpreter context. The `tx_id`, `tx_hash` and `relayer` are left unset
+    /// and are expected to be populated later during transaction execution.
     pub(crate) fn new_env_data(&self) -> ScenarioTxEnvData {
         ScenarioTxEnvData {
             interpreter_context: InterpreterContext::new().with_dir(self.current_dir.clone()),
             tx_id: None,
             tx_hash: None,
+            relayer: None,
         }
     }
 }
@@ -4,15 +4,21 @@
 #![allow(clippy::manual_is_multiple_of)]
 
 pub mod account_tool;
+#[cfg(feature = "dynamic-contract")]
+mod dynamic_contract;
 mod interactor;
 mod multi;
 pub mod network_response;
 
+#[cfg(feature = "dynamic-contract")]
+pub use dynamic_contract::*;
 pub use env_logger;
 pub use hex;
 pub use interactor::*;
 pub use log;
 pub use multi::*;
+#[cfg(feature = "dynamic-contract")]
+pub use multiversx_sc_codec_human_readable as human_readable;
 pub use multiversx_sc_scenario::{self, multiversx_sc};
 pub use multiversx_sdk as sdk_core;
 pub use multiversx_sdk as sdk;
@@ -41,6 +41,7 @@ where
                 .expect("sender not registered");
 
             update_nonces_and_sign_tx(&mut transaction, sender);
+            self.relayer_sign_tx(&mut transaction);
             txs.push(transaction);
         }
         txs
@@ -3,7 +3,7 @@ use crate::{InteractorBase, Sender, multiversx_sc::types::Address};
 use futures::future::join_all;
 use multiversx_sc_scenario::imports::ReturnCode;
 use multiversx_sdk::gateway::{GatewayAsyncService, SendTxRequest};
-use multiversx_sdk::retrieve_tx_on_network;
+use multiversx_sdk::retrieve_tx_on_network_with_policy;
 use std::collections::HashSet;
 
 pub(crate) type Txs = Vec<Transaction>;
@@ -40,7 +40,11 @@ where
                 .expect("failed to send transaction");
 
             println!("process tx hash: {tx_hash} with nonce: {}", tx.nonce);
-            futures.push(retrieve_tx_on_network(&self.proxy, tx_hash.clone()));
+            futures.push(retrieve_tx_on_network_with_policy(
+                &self.proxy,
+                tx_hash.clone(),
+                &self.retrieve_policy,
+            ));
         }
 
         self.generate_blocks(4).await.unwrap();
@@ -56,6 +60,5 @@ pub(crate) fn update_nonces_and_sign_tx(transaction: &mut Transaction, sender: &
     transaction.nonce = sender.current_nonce.unwrap();
     sender.current_nonce = Some(sender.current_nonce.unwrap() + 1);
 
-    let signature = sender.wallet.sign_tx(&*transaction);
-    transaction.signature = Some(hex::encode(signature));
+    sender.sign_tx(transaction);
 }
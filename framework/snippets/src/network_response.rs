@@ -79,6 +79,8 @@ fn process_out(tx: &ApiTransactionResult) -> Vec<Vec<u8>> {
     process_out_from_log(tx).unwrap_or_default()
 }
 
+/// Converts the logs received from the gateway, decoding their base64-encoded topics and data,
+/// so they have the same raw format as the logs produced in the debugger.
 fn process_logs(tx: &ApiTransactionResult) -> Vec<Log> {
     if let Some(api_logs) = &tx.logs {
         return api_logs
@@ -100,17 +102,12 @@ fn extract_data(event: &Events) -> Vec<Vec<u8>> {
     let mut out: Vec<Vec<u8>> = Vec::new();
     event
         .data
-        .for_each(|data_field| out.push(data_field.to_string().into_bytes()));
+        .for_each(|data_field| out.push(base64_decode(data_field)));
     out
 }
 
 fn extract_topics(event: &Events) -> Vec<Vec<u8>> {
-    event
-        .topics
-        .clone()
-        .into_iter()
-        .map(|s| s.into_bytes())
-        .collect()
+    event.topics.iter().map(base64_decode).collect()
 }
 
 fn process_out_from_log(tx: &ApiTransactionResult) -> Option<Vec<Vec<u8>>> {
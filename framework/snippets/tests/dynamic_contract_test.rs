@@ -0,0 +1,156 @@
+use multiversx_sc_scenario::{
+    imports::{Address, Bech32Address},
+    meta::abi_json::deserialize_abi_from_json,
+    scenario_model::Log,
+};
+use multiversx_sc_snippets::DynamicContract;
+use serde_json::json;
+
+const ABI_JSON: &str = r#"{
+    "name": "Vault",
+    "constructor": { "inputs": [], "outputs": [] },
+    "endpoints": [
+        {
+            "name": "deposit",
+            "mutability": "mutable",
+            "inputs": [
+                { "name": "owner", "type": "Address" },
+                { "name": "amount", "type": "BigUint" },
+                { "name": "memo", "type": "optional<bytes>", "multi_arg": true }
+            ],
+            "outputs": [{ "type": "u64" }]
+        },
+        {
+            "name": "getDeposits",
+            "mutability": "readonly",
+            "inputs": [],
+            "outputs": [
+                { "type": "Status" },
+                { "type": "variadic<multi<u64,BigUint>>", "multi_result": true }
+            ]
+        }
+    ],
+    "events": [
+        {
+            "identifier": "deposit",
+            "inputs": [
+                { "name": "owner", "type": "Address", "indexed": true },
+                { "name": "id", "type": "u64", "indexed": true },
+                { "name": "amount", "type": "BigUint" }
+            ]
+        }
+    ],
+    "types": {
+        "Status": {
+            "type": "enum",
+            "variants": [
+                { "name": "Closed", "discriminant": 0 },
+                { "name": "Open", "discriminant": 1 }
+            ]
+        }
+    }
+}"#;
+
+const OWNER: &str = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+
+fn contract() -> DynamicContract {
+    let abi = deserialize_abi_from_json(ABI_JSON).unwrap().into();
+    DynamicContract::new(abi, Bech32Address::from(Address::new([5u8; 32])))
+}
+
+fn owner_bytes() -> Vec<u8> {
+    Bech32Address::from_bech32_string(OWNER.to_owned())
+        .to_address()
+        .to_vec()
+}
+
+#[test]
+fn dynamic_contract_encode_arguments() {
+    let contract = contract();
+
+    let args = contract
+        .encode_arguments("deposit", &[json!(OWNER), json!("1000"), json!([1, 2])])
+        .unwrap();
+    assert_eq!(args, vec![owner_bytes(), vec![0x03, 0xe8], vec![1, 2]]);
+
+    // trailing optional arguments can be left out
+    let args = contract
+        .encode_arguments("deposit", &[json!(OWNER), json!(1000)])
+        .unwrap();
+    assert_eq!(args, vec![owner_bytes(), vec![0x03, 0xe8]]);
+}
+
+#[test]
+fn dynamic_contract_argument_errors() {
+    let contract = contract();
+
+    let err = contract
+        .encode_arguments("deposit", &[json!(OWNER)])
+        .unwrap_err();
+    assert_eq!(err.to_string(), "deposit expects 2 to 3 arguments, got 1");
+
+    let err = contract
+        .encode_arguments("deposit", &[json!(OWNER), json!(-1)])
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid argument amount: number out of range"
+    );
+
+    let err = contract.encode_arguments("withdraw", &[]).unwrap_err();
+    assert_eq!(err.to_string(), "endpoint withdraw not found in the ABI");
+}
+
+#[test]
+fn dynamic_contract_decode_results() {
+    let contract = contract();
+
+    let results = contract
+        .decode_results(
+            "getDeposits",
+            &[vec![1], vec![7], vec![0x03, 0xe8], vec![8], vec![]],
+        )
+        .unwrap();
+    assert_eq!(results, vec![json!("Open"), json!([[7, 1000], [8, 0]])]);
+
+    let err = contract.decode_results("deposit", &[]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "cannot decode result 0 of deposit: not enough arguments"
+    );
+}
+
+#[test]
+fn dynamic_contract_decode_events() {
+    let contract = contract();
+    let logs = vec![
+        Log {
+            address: contract.address.to_address(),
+            endpoint: "deposit".to_owned(),
+            topics: vec![b"deposit".to_vec(), owner_bytes(), vec![3]],
+            data: vec![vec![0x03, 0xe8]],
+        },
+        // not in the ABI
+        Log {
+            address: contract.address.to_address(),
+            endpoint: "deposit".to_owned(),
+            topics: vec![b"other".to_vec()],
+            data: vec![],
+        },
+        // emitted by another contract
+        Log {
+            address: Address::zero(),
+            endpoint: "deposit".to_owned(),
+            topics: vec![b"deposit".to_vec()],
+            data: vec![],
+        },
+    ];
+
+    let events = contract.decode_events(&logs).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].identifier, "deposit");
+    assert_eq!(
+        json!(events[0].fields),
+        json!({ "owner": OWNER, "id": 3, "amount": 1000 })
+    );
+}
@@ -20,6 +20,8 @@ unsafe extern "C" {
     fn getCaller(resultOffset: *mut u8);
 
     fn managedCaller(resultHandle: i32);
+    fn managedGetOriginalCallerAddr(resultHandle: i32);
+    fn managedGetRelayerAddr(resultHandle: i32);
 
     fn getShardOfAddress(address_ptr: *const u8) -> i32;
     fn isSmartContract(address_ptr: *const u8) -> i32;
@@ -126,6 +128,20 @@ impl BlockchainApiImpl for VmApiImpl {
         }
     }
 
+    #[inline]
+    fn load_original_caller_managed(&self, dest: Self::ManagedBufferHandle) {
+        unsafe {
+            managedGetOriginalCallerAddr(dest);
+        }
+    }
+
+    #[inline]
+    fn load_relayer_managed(&self, dest: Self::ManagedBufferHandle) {
+        unsafe {
+            managedGetRelayerAddr(dest);
+        }
+    }
+
     #[inline]
     fn get_sc_address_legacy(&self) -> Address {
         unsafe {
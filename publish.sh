@@ -109,6 +109,10 @@ cd framework/scenario || exit 1
 cargo publish || exit 1
 cd ../..
 
+cd data/human-readable || exit 1
+cargo publish || exit 1
+cd ../..
+
 cd framework/snippets || exit 1
 cargo publish || exit 1
 cd ../..
@@ -7,6 +7,7 @@ mod get_transaction_response;
 mod log_data;
 mod multiple_transactions;
 mod simulate_gas;
+mod simulate_tx;
 mod transaction_process_status;
 mod transaction_request;
 mod transaction_response_data;
@@ -22,8 +23,14 @@ pub use get_transaction_response::{GetTransactionResponse, GetTransactionRespons
 pub use log_data::LogData;
 pub use multiple_transactions::{MultipleTransactionsResponseData, ResponseMultipleTransactions};
 pub use simulate_gas::{SimulateGasTransactionData, SimulateGasTransactionResponse};
+pub use simulate_tx::{
+    SimulateTxResponse, SimulateTxResponseData, TransactionSimulationCrossShardResults,
+    TransactionSimulationResult, TransactionSimulationResults,
+};
 pub use transaction_process_status::{ProcessStatusResponse, TransactionProcessStatus};
-pub use transaction_request::Transaction;
+pub use transaction_request::{
+    TX_OPTION_GUARDED, TX_OPTION_HASH_SIGN, TX_VERSION_WITH_OPTIONS, Transaction,
+};
 pub use transaction_response_data::{ResponseTransaction, TransactionResponseData};
 pub use transaction_status::{ResponseTxStatus, TransactionStatus};
 pub use tx_cost::{ResponseTxCost, TxCostResponseData};
@@ -240,4 +247,69 @@ mod test {
             "34cd9c6d0f68c0975971352ed4dcaacc1acd9a2dbd8f5840a2866d09b1d72298"
         );
     }
+
+    #[test]
+    fn parse_simulate_tx_cross_shard() {
+        let data = r#"
+{
+    "data": {
+        "result": {
+            "senderShard": {
+                "status": "success",
+                "hash": "a3b7f4c1",
+                "scResults": {},
+                "logs": null
+            },
+            "receiverShard": {
+                "status": "fail",
+                "failReason": "user error",
+                "hash": "a3b7f4c1",
+                "logs": {
+                    "address": "erd1qqqqqqqqqqqqqpgq0628nau8zydgwu96fn8ksqklzhrggkcfq33sm4vmwv",
+                    "events": [
+                        {
+                            "address": "erd1qqqqqqqqqqqqqpgq0628nau8zydgwu96fn8ksqklzhrggkcfq33sm4vmwv",
+                            "identifier": "signalError",
+                            "topics": [],
+                            "data": null
+                        }
+                    ]
+                }
+            }
+        }
+    },
+    "error": "",
+    "code": "successful"
+}
+        "#;
+
+        let response = serde_json::from_str::<SimulateTxResponse>(data).unwrap();
+        let results = response.data.unwrap().result.into_merged();
+        assert_eq!(results.status, "fail");
+        assert_eq!(results.fail_reason, "user error");
+        assert!(results.sc_results.is_empty());
+        assert_eq!(results.logs.unwrap().events.len(), 1);
+    }
+
+    #[test]
+    fn parse_simulate_tx_intra_shard() {
+        let data = r#"
+{
+    "data": {
+        "result": {
+            "status": "success",
+            "hash": "a3b7f4c1"
+        }
+    },
+    "error": "",
+    "code": "successful"
+}
+        "#;
+
+        let response = serde_json::from_str::<SimulateTxResponse>(data).unwrap();
+        let results = response.data.unwrap().result.into_merged();
+        assert_eq!(results.status, "success");
+        assert!(results.fail_reason.is_empty());
+        assert!(results.logs.is_none());
+    }
 }
@@ -30,4 +30,6 @@ pub struct ApiSmartContractResult {
     pub return_message: Option<String>,
     pub original_sender: Option<String>,
     pub logs: Option<ApiLogs>,
+    #[serde(default)]
+    pub is_refund: bool,
 }
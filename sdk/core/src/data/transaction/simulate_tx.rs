@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{api_logs::ApiLogs, api_smart_contract_result::ApiSmartContractResult};
+
+/// Results of a transaction simulation, as produced by a single shard.
+///
+/// Corresponds to [`TransactionSimulationResults`](https://github.com/multiversx/mx-chain-go/blob/master/process/txsimulator/data/dtos.go) in mx-chain-go.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionSimulationResults {
+    pub status: String,
+    #[serde(default)]
+    pub fail_reason: String,
+    #[serde(default)]
+    pub sc_results: HashMap<String, ApiSmartContractResult>,
+    #[serde(default)]
+    pub hash: String,
+    pub logs: Option<ApiLogs>,
+}
+
+/// Simulation results of a cross-shard transaction, one set per involved shard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionSimulationCrossShardResults {
+    pub sender_shard: TransactionSimulationResults,
+    pub receiver_shard: TransactionSimulationResults,
+}
+
+/// The proxy returns a different result format, depending on whether the transaction is cross-shard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TransactionSimulationResult {
+    IntraShard(Box<TransactionSimulationResults>),
+    CrossShard(Box<TransactionSimulationCrossShardResults>),
+}
+
+impl TransactionSimulationResult {
+    /// Merges the results of both shards, in the cross-shard case.
+    ///
+    /// The status and fail reason are taken from the first shard that failed,
+    /// or from the receiver shard, if both succeeded.
+    pub fn into_merged(self) -> TransactionSimulationResults {
+        match self {
+            TransactionSimulationResult::IntraShard(results) => *results,
+            TransactionSimulationResult::CrossShard(cross_shard_results) => {
+                let TransactionSimulationCrossShardResults {
+                    sender_shard,
+                    receiver_shard,
+                } = *cross_shard_results;
+                let (status, fail_reason) = if !sender_shard.fail_reason.is_empty() {
+                    (sender_shard.status, sender_shard.fail_reason)
+                } else {
+                    (receiver_shard.status, receiver_shard.fail_reason)
+                };
+
+                let mut sc_results = sender_shard.sc_results;
+                sc_results.extend(receiver_shard.sc_results);
+
+                let logs = match (sender_shard.logs, receiver_shard.logs) {
+                    (Some(mut sender_logs), Some(receiver_logs)) => {
+                        sender_logs.events.extend(receiver_logs.events);
+                        Some(sender_logs)
+                    }
+                    (sender_logs, receiver_logs) => sender_logs.or(receiver_logs),
+                };
+
+                TransactionSimulationResults {
+                    status,
+                    fail_reason,
+                    sc_results,
+                    hash: sender_shard.hash,
+                    logs,
+                }
+            }
+        }
+    }
+}
+
+/// Data field of the `/transaction/simulate` response.
+///
+/// Corresponds to [`ResponseTransactionSimulation`](https://github.com/multiversx/mx-chain-proxy-go/blob/master/data/transaction.go) in mx-chain-proxy-go.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulateTxResponseData {
+    pub result: TransactionSimulationResult,
+}
+
+/// Response envelope for the `/transaction/simulate` endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulateTxResponse {
+    pub error: String,
+    pub code: String,
+    pub data: Option<SimulateTxResponseData>,
+}
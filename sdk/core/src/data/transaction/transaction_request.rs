@@ -1,6 +1,17 @@
-use multiversx_chain_core::std::Bech32Address;
+use anyhow::{Context, anyhow};
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use multiversx_chain_core::std::{Bech32Address, TX_HASH_LEN, TxHashInput, tx_hash};
 use serde::{Deserialize, Serialize};
 
+/// Transaction options bit: the signature is computed over the transaction hash.
+pub const TX_OPTION_HASH_SIGN: u32 = 0b01;
+
+/// Transaction options bit: the transaction is guarded, i.e. co-signed by the guardian of the sender.
+pub const TX_OPTION_GUARDED: u32 = 0b10;
+
+/// Transaction options are only taken into account starting with this version.
+pub const TX_VERSION_WITH_OPTIONS: u32 = 2;
+
 /// Represents the structure that maps and validates user input for publishing a new transaction.
 ///
 /// Corresponds to [`Transaction`](https://github.com/multiversx/mx-chain-proxy-go/blob/master/data/transaction.go) in mx-chain-proxy-go.
@@ -22,6 +33,110 @@ pub struct Transaction {
     pub version: u32,
     #[serde(skip_serializing_if = "is_zero", default)]
     pub options: u32,
+    /// Only set for guarded transactions, co-signed by the guardian of the sender.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub guardian: Option<Bech32Address>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub guardian_signature: Option<String>,
+    /// Only set for relayed transactions (relayed v3).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub relayer: Option<Bech32Address>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub relayer_signature: Option<String>,
+}
+
+impl Transaction {
+    pub fn is_hash_sign(&self) -> bool {
+        self.version >= TX_VERSION_WITH_OPTIONS && self.options & TX_OPTION_HASH_SIGN != 0
+    }
+
+    pub fn is_guarded(&self) -> bool {
+        self.version >= TX_VERSION_WITH_OPTIONS && self.options & TX_OPTION_GUARDED != 0
+    }
+
+    /// Turns the transaction into a guarded transaction: sets the guardian, the guarded option and the version.
+    ///
+    /// Needs to be called before signing, since all of these are part of the signed payload.
+    pub fn set_guardian(&mut self, guardian: Bech32Address) {
+        self.guardian = Some(guardian);
+        self.options |= TX_OPTION_GUARDED;
+        self.version = self.version.max(TX_VERSION_WITH_OPTIONS);
+    }
+
+    /// Computes the hash of the transaction, the same way the network does.
+    ///
+    /// The signatures are part of the hash, so it is only final once the transaction is signed.
+    pub fn hash(&self) -> anyhow::Result<[u8; TX_HASH_LEN]> {
+        let value = decimal_to_big_endian(&self.value)
+            .ok_or_else(|| anyhow!("invalid transaction value: {}", self.value))?;
+        let data = decode_base64_field(&self.data).context("invalid transaction data")?;
+        let signature = decode_hex_field(&self.signature).context("invalid signature")?;
+        let guardian_signature =
+            decode_hex_field(&self.guardian_signature).context("invalid guardian signature")?;
+        let relayer_signature =
+            decode_hex_field(&self.relayer_signature).context("invalid relayer signature")?;
+
+        Ok(tx_hash(&TxHashInput {
+            nonce: self.nonce,
+            value: &value,
+            receiver: self.receiver.as_address().as_bytes(),
+            sender: self.sender.as_address().as_bytes(),
+            gas_price: self.gas_price,
+            gas_limit: self.gas_limit,
+            data: &data,
+            chain_id: self.chain_id.as_bytes(),
+            version: self.version,
+            signature: &signature,
+            options: self.options,
+            guardian: optional_address_bytes(&self.guardian),
+            guardian_signature: &guardian_signature,
+            relayer: optional_address_bytes(&self.relayer),
+            relayer_signature: &relayer_signature,
+            ..Default::default()
+        }))
+    }
+}
+
+fn optional_address_bytes(address: &Option<Bech32Address>) -> &[u8] {
+    address
+        .as_ref()
+        .map(|address| address.as_address().as_bytes())
+        .unwrap_or_default()
+}
+
+fn decode_base64_field(field: &Option<String>) -> anyhow::Result<Vec<u8>> {
+    Ok(match field {
+        Some(encoded) => BASE64.decode(encoded)?,
+        None => Vec::new(),
+    })
+}
+
+fn decode_hex_field(field: &Option<String>) -> anyhow::Result<Vec<u8>> {
+    Ok(match field {
+        Some(encoded) => hex::decode(encoded)?,
+        None => Vec::new(),
+    })
+}
+
+/// Converts a decimal string into its big-endian magnitude, without leading zeros.
+fn decimal_to_big_endian(decimal: &str) -> Option<Vec<u8>> {
+    if decimal.is_empty() {
+        return None;
+    }
+
+    let mut result: Vec<u8> = Vec::new();
+    for c in decimal.chars() {
+        let mut carry = c.to_digit(10)?;
+        for byte in result.iter_mut().rev() {
+            let product = *byte as u32 * 10 + carry;
+            *byte = product as u8;
+            carry = product >> 8;
+        }
+        if carry > 0 {
+            result.insert(0, carry as u8);
+        }
+    }
+    Some(result)
 }
 
 /// This is only used for serialization.
@@ -40,7 +40,7 @@ pub use gateway_tx_info::GetTxInfo;
 pub use gateway_tx_process_status::GetTxProcessStatus;
 pub use gateway_tx_send::SendTxRequest;
 pub use gateway_tx_send_multi::SendMultiTxRequest;
-pub use gateway_tx_simulate::SimulateTxRequest;
+pub use gateway_tx_simulate::{SimulateTxExecutionRequest, SimulateTxRequest};
 pub use gateway_tx_status::GetTxStatus;
 pub use gateway_tx_vmquery::VMQueryRequest;
 
@@ -61,6 +61,7 @@ const GET_HYPER_BLOCK_BY_HASH_ENDPOINT: &str = "hyperblock/by-hash";
 const COST_TRANSACTION_ENDPOINT: &str = "transaction/cost";
 const SEND_TRANSACTION_ENDPOINT: &str = "transaction/send";
 const TRANSACTION_COST_ENDPOINT: &str = "transaction/cost";
+const SIMULATE_TRANSACTION_ENDPOINT: &str = "transaction/simulate";
 const SEND_MULTIPLE_TRANSACTIONS_ENDPOINT: &str = "transaction/send-multiple";
 const GET_TRANSACTION_INFO_ENDPOINT: &str = "transaction";
 const WITH_RESULTS_QUERY_PARAM: &str = "?withResults=true";
@@ -1,7 +1,11 @@
-use crate::data::transaction::{SimulateGasTransactionResponse, Transaction};
+use crate::data::transaction::{
+    SimulateGasTransactionResponse, SimulateTxResponse, Transaction, TransactionSimulationResults,
+};
 use anyhow::anyhow;
 
-use super::{GatewayRequest, GatewayRequestType, TRANSACTION_COST_ENDPOINT};
+use super::{
+    GatewayRequest, GatewayRequestType, SIMULATE_TRANSACTION_ENDPOINT, TRANSACTION_COST_ENDPOINT,
+};
 
 /// Sends a single transaction.
 pub struct SimulateTxRequest<'a>(pub &'a Transaction);
@@ -30,3 +34,33 @@ impl GatewayRequest for SimulateTxRequest<'_> {
         }
     }
 }
+
+/// Executes a signed transaction against the current network state, without committing it.
+///
+/// Cross-shard results are merged into a single set of results.
+pub struct SimulateTxExecutionRequest<'a>(pub &'a Transaction);
+
+impl GatewayRequest for SimulateTxExecutionRequest<'_> {
+    type Payload = Transaction;
+    type DecodedJson = SimulateTxResponse;
+    type Result = TransactionSimulationResults;
+
+    fn request_type(&self) -> GatewayRequestType {
+        GatewayRequestType::Post
+    }
+
+    fn get_payload(&self) -> Option<&Self::Payload> {
+        Some(self.0)
+    }
+
+    fn get_endpoint(&self) -> String {
+        SIMULATE_TRANSACTION_ENDPOINT.to_owned()
+    }
+
+    fn process_json(&self, decoded: Self::DecodedJson) -> anyhow::Result<Self::Result> {
+        match decoded.data {
+            None => Err(anyhow!("{}", decoded.error)),
+            Some(b) => Ok(b.result.into_merged()),
+        }
+    }
+}
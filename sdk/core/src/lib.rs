@@ -13,4 +13,7 @@ pub mod validator;
 pub mod wallet;
 
 pub use multiversx_chain_core as chain_core;
-pub use retrieve_tx_on_network::retrieve_tx_on_network;
+pub use retrieve_tx_on_network::{
+    RetrievePolicy, RetrieveTxTimeoutError, retrieve_tx_on_network,
+    retrieve_tx_on_network_with_policy,
+};
@@ -1,3 +1,5 @@
+use std::{fmt, time::Duration};
+
 use crate::{
     data::transaction::{ApiLogs, ApiTransactionResult, Events, LogData},
     gateway::{GetTxInfo, GetTxProcessStatus},
@@ -14,13 +16,99 @@ const MAX_RETRIES: usize = 8;
 const MAX_BACKOFF_DELAY: u64 = 6000;
 const LOG_IDENTIFIER_SIGNAL_ERROR: &str = "signalError";
 
-/// Retrieves a transaction from the network.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(100);
+const DEFAULT_POLLING_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Controls how long, and how often, the network is polled for a transaction result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetrievePolicy {
+    /// Overall deadline for the transaction to reach a final status.
+    ///
+    /// `None` means waiting indefinitely.
+    pub timeout: Option<Duration>,
+
+    /// Delay between two status checks, while the transaction is still pending.
+    pub polling_interval: Duration,
+}
+
+impl Default for RetrievePolicy {
+    fn default() -> Self {
+        RetrievePolicy {
+            timeout: Some(DEFAULT_TIMEOUT),
+            polling_interval: DEFAULT_POLLING_INTERVAL,
+        }
+    }
+}
+
+impl RetrievePolicy {
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn without_timeout(mut self) -> Self {
+        self.timeout = None;
+        self
+    }
+
+    pub fn with_polling_interval(mut self, polling_interval: Duration) -> Self {
+        self.polling_interval = polling_interval;
+        self
+    }
+
+    fn is_expired(&self, elapsed_seconds: f32) -> bool {
+        self.timeout
+            .is_some_and(|timeout| elapsed_seconds >= timeout.as_secs_f32())
+    }
+}
+
+/// Returned (wrapped in an [`anyhow::Error`]) when a transaction does not reach
+/// a final status before the [`RetrievePolicy`] timeout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetrieveTxTimeoutError {
+    pub tx_hash: String,
+
+    /// The last status reported by the network, if any status could be retrieved.
+    pub last_status: Option<String>,
+
+    pub elapsed_seconds: f32,
+}
+
+impl fmt::Display for RetrieveTxTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "timed out after {:.1}s waiting for transaction {}",
+            self.elapsed_seconds, self.tx_hash
+        )?;
+        if let Some(last_status) = &self.last_status {
+            write!(f, " (last status: {last_status})")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for RetrieveTxTimeoutError {}
+
+/// Retrieves a transaction from the network, using the default [`RetrievePolicy`].
 pub async fn retrieve_tx_on_network<GatewayProxy: GatewayAsyncService>(
     proxy: &GatewayProxy,
     tx_hash: String,
+) -> Result<(ApiTransactionResult, ReturnCode)> {
+    retrieve_tx_on_network_with_policy(proxy, tx_hash, &RetrievePolicy::default()).await
+}
+
+/// Retrieves a transaction from the network, polling until it reaches a final status.
+///
+/// Fails with a [`RetrieveTxTimeoutError`] if the policy timeout is reached first.
+pub async fn retrieve_tx_on_network_with_policy<GatewayProxy: GatewayAsyncService>(
+    proxy: &GatewayProxy,
+    tx_hash: String,
+    policy: &RetrievePolicy,
 ) -> Result<(ApiTransactionResult, ReturnCode)> {
     let mut retries = 0;
     let mut backoff_delay = INITIAL_BACKOFF_DELAY;
+    let mut last_status = None;
     let start_time = proxy.now();
 
     loop {
@@ -58,7 +146,11 @@ pub async fn retrieve_tx_on_network<GatewayProxy: GatewayAsyncService>(
                         return Ok((failed_transaction, error_code));
                     }
                     _ => {
-                        continue;
+                        last_status = Some(status);
+                        check_deadline(proxy, &start_time, policy, &tx_hash, &last_status)?;
+                        proxy
+                            .sleep(policy.polling_interval.as_millis() as u64)
+                            .await;
                     }
                 }
             }
@@ -68,6 +160,7 @@ pub async fn retrieve_tx_on_network<GatewayProxy: GatewayAsyncService>(
                     info!("Transaction failed, max retries exceeded: {}", err);
                     break;
                 }
+                check_deadline(proxy, &start_time, policy, &tx_hash, &last_status)?;
 
                 let backoff_time = backoff_delay.min(MAX_BACKOFF_DELAY);
                 proxy.sleep(backoff_time).await;
@@ -88,6 +181,25 @@ pub async fn retrieve_tx_on_network<GatewayProxy: GatewayAsyncService>(
     Ok((failed_transaction, ReturnCode::NetworkTimeout))
 }
 
+fn check_deadline<GatewayProxy: GatewayAsyncService>(
+    proxy: &GatewayProxy,
+    start_time: &GatewayProxy::Instant,
+    policy: &RetrievePolicy,
+    tx_hash: &str,
+    last_status: &Option<String>,
+) -> Result<()> {
+    let elapsed_seconds = proxy.elapsed_seconds(start_time);
+    if policy.is_expired(elapsed_seconds) {
+        return Err(RetrieveTxTimeoutError {
+            tx_hash: tx_hash.to_owned(),
+            last_status: last_status.clone(),
+            elapsed_seconds,
+        }
+        .into());
+    }
+    Ok(())
+}
+
 pub fn parse_reason(reason: &str) -> (ReturnCode, String) {
     if reason.is_empty() {
         return (ReturnCode::UserError, "invalid transaction".to_string());
@@ -206,14 +206,18 @@ impl Wallet {
         PublicKey::from(&self.priv_key).to_address()
     }
 
+    /// Signs the transaction as its sender.
+    ///
+    /// The signatures already present are not part of the signed payload.
     pub fn sign_tx(&self, unsign_tx: &Transaction) -> [u8; 64] {
         let mut unsign_tx = unsign_tx.clone();
         unsign_tx.signature = None;
+        unsign_tx.guardian_signature = None;
+        unsign_tx.relayer_signature = None;
 
         let mut tx_bytes = json!(unsign_tx).to_string().as_bytes().to_vec();
 
-        let should_sign_on_tx_hash = unsign_tx.version >= 2 && unsign_tx.options & 1 > 0;
-        if should_sign_on_tx_hash {
+        if unsign_tx.is_hash_sign() {
             let mut h = Keccak256::new();
             h.update(tx_bytes);
             tx_bytes = h.finalize().to_vec();
@@ -222,6 +226,31 @@ impl Wallet {
         self.priv_key.sign(tx_bytes)
     }
 
+    /// Co-signs a guarded transaction, as the guardian of the sender.
+    ///
+    /// The guardian signs the same payload as the sender.
+    pub fn sign_tx_as_guardian(&self, unsign_tx: &Transaction) -> [u8; 64] {
+        assert!(unsign_tx.is_guarded(), "transaction is not guarded");
+        assert_eq!(
+            unsign_tx.guardian.as_ref().map(Bech32Address::to_address),
+            Some(self.to_address()),
+            "wallet is not the guardian of the transaction"
+        );
+        self.sign_tx(unsign_tx)
+    }
+
+    /// Co-signs a relayed transaction (relayed v3), as its relayer.
+    ///
+    /// The relayer signs the same payload as the sender.
+    pub fn sign_tx_as_relayer(&self, unsign_tx: &Transaction) -> [u8; 64] {
+        assert_eq!(
+            unsign_tx.relayer.as_ref().map(Bech32Address::to_address),
+            Some(self.to_address()),
+            "wallet is not the relayer of the transaction"
+        );
+        self.sign_tx(unsign_tx)
+    }
+
     pub fn sign_bytes(&self, data: Vec<u8>) -> [u8; 64] {
         self.priv_key.sign(data)
     }
@@ -0,0 +1,140 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+
+use multiversx_chain_core::types::ReturnCode;
+use multiversx_sdk::{
+    RetrievePolicy, RetrieveTxTimeoutError,
+    gateway::{GatewayAsyncService, GatewayRequest},
+    retrieve_tx_on_network_with_policy,
+};
+
+const TX_HASH: &str = "d1b5a5d7b3a3c9b0e6a2b6c1b1c9f4e7a1d2e3f4a5b6c7d8e9f0a1b2c3d4e5f6";
+
+/// Replays a fixed sequence of process statuses, then keeps repeating the last one.
+///
+/// Time only advances when sleeping, so the tests run instantly.
+struct MockProxy {
+    statuses: RefCell<VecDeque<&'static str>>,
+    clock_seconds: Cell<f32>,
+}
+
+impl MockProxy {
+    fn new(statuses: &[&'static str]) -> Self {
+        MockProxy {
+            statuses: RefCell::new(statuses.iter().copied().collect()),
+            clock_seconds: Cell::new(0.0),
+        }
+    }
+
+    fn next_status(&self) -> &'static str {
+        let mut statuses = self.statuses.borrow_mut();
+        if statuses.len() > 1 {
+            statuses.pop_front().unwrap()
+        } else {
+            statuses[0]
+        }
+    }
+}
+
+impl GatewayAsyncService for MockProxy {
+    type Instant = f32;
+
+    fn from_uri(_uri: &str) -> Self {
+        unimplemented!()
+    }
+
+    fn request<G>(&self, request: G) -> impl Future<Output = anyhow::Result<G::Result>>
+    where
+        G: GatewayRequest,
+    {
+        let json = if request.get_endpoint().ends_with("/process-status") {
+            format!(
+                r#"{{"data":{{"status":"{}","reason":""}},"error":"","code":"successful"}}"#,
+                self.next_status()
+            )
+        } else {
+            include_str!("tx_with_large_scr_value.json").to_string()
+        };
+        let decoded = serde_json::from_str::<G::DecodedJson>(&json).unwrap();
+        std::future::ready(request.process_json(decoded))
+    }
+
+    fn sleep(&self, millis: u64) -> impl Future<Output = ()> {
+        self.clock_seconds
+            .set(self.clock_seconds.get() + millis as f32 / 1000.0);
+        std::future::ready(())
+    }
+
+    fn now(&self) -> Self::Instant {
+        self.clock_seconds.get()
+    }
+
+    fn elapsed_seconds(&self, instant: &Self::Instant) -> f32 {
+        self.clock_seconds.get() - instant
+    }
+}
+
+/// The mock proxy never actually suspends, so a single poll is enough.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut context = Context::from_waker(Waker::noop());
+    match pin!(future).poll(&mut context) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("mock future should be ready"),
+    }
+}
+
+#[test]
+fn retrieve_tx_pending_then_success_test() {
+    let proxy = MockProxy::new(&["pending", "pending", "success"]);
+    let policy = RetrievePolicy::default().with_polling_interval(Duration::from_millis(500));
+
+    let (_, return_code) = block_on(retrieve_tx_on_network_with_policy(
+        &proxy,
+        TX_HASH.to_string(),
+        &policy,
+    ))
+    .unwrap();
+    assert_eq!(return_code, ReturnCode::Success);
+    assert_eq!(proxy.clock_seconds.get(), 1.0);
+}
+
+#[test]
+fn retrieve_tx_timeout_test() {
+    let proxy = MockProxy::new(&["pending"]);
+    let policy = RetrievePolicy::default()
+        .with_timeout(Duration::from_secs(10))
+        .with_polling_interval(Duration::from_secs(3));
+
+    let err = block_on(retrieve_tx_on_network_with_policy(
+        &proxy,
+        TX_HASH.to_string(),
+        &policy,
+    ))
+    .unwrap_err();
+    let timeout_err = err.downcast::<RetrieveTxTimeoutError>().unwrap();
+    assert_eq!(timeout_err.tx_hash, TX_HASH);
+    assert_eq!(timeout_err.last_status.as_deref(), Some("pending"));
+    assert_eq!(timeout_err.elapsed_seconds, 12.0);
+}
+
+#[test]
+fn retrieve_tx_without_timeout_test() {
+    let mut statuses = vec!["pending"; 1000];
+    statuses.push("success");
+    let proxy = MockProxy::new(&statuses);
+    let policy = RetrievePolicy::default().without_timeout();
+
+    let (_, return_code) = block_on(retrieve_tx_on_network_with_policy(
+        &proxy,
+        TX_HASH.to_string(),
+        &policy,
+    ))
+    .unwrap();
+    assert_eq!(return_code, ReturnCode::Success);
+}
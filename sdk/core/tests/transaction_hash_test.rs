@@ -0,0 +1,56 @@
+use multiversx_chain_core::{
+    std::{Bech32Address, TxHashInput, tx_hash},
+    types::Address,
+};
+use multiversx_sdk::data::transaction::Transaction;
+
+fn transaction(value: &str) -> Transaction {
+    Transaction {
+        nonce: 5,
+        value: value.to_string(),
+        receiver: Bech32Address::encode_address_default_hrp(Address::from([2u8; 32])),
+        sender: Bech32Address::encode_address_default_hrp(Address::from([1u8; 32])),
+        gas_price: 1_000_000_000,
+        gas_limit: 50_000,
+        data: Some("dGVzdA==".to_string()),
+        signature: Some("abcd".to_string()),
+        chain_id: "D".to_string(),
+        version: 2,
+        options: 0,
+        guardian: None,
+        guardian_signature: None,
+        relayer: None,
+        relayer_signature: None,
+    }
+}
+
+fn expected_hash(value: &[u8]) -> [u8; 32] {
+    tx_hash(&TxHashInput {
+        nonce: 5,
+        value,
+        receiver: &[2u8; 32],
+        sender: &[1u8; 32],
+        gas_price: 1_000_000_000,
+        gas_limit: 50_000,
+        data: b"test",
+        chain_id: b"D",
+        version: 2,
+        signature: &[0xab, 0xcd],
+        ..Default::default()
+    })
+}
+
+#[test]
+fn transaction_hash_test() {
+    assert_eq!(transaction("0").hash().unwrap(), expected_hash(&[]));
+    assert_eq!(
+        transaction("1000000000000000000").hash().unwrap(),
+        expected_hash(&[0x0d, 0xe0, 0xb6, 0xb3, 0xa7, 0x64, 0x00, 0x00])
+    );
+}
+
+#[test]
+fn transaction_hash_invalid_value_test() {
+    assert!(transaction("1e18").hash().is_err());
+    assert!(transaction("").hash().is_err());
+}
@@ -1,7 +1,9 @@
 use bip39::Mnemonic;
 
 use multiversx_sdk::test_wallets;
-use multiversx_sdk::{crypto::public_key::PublicKey, wallet::Wallet};
+use multiversx_sdk::{
+    crypto::public_key::PublicKey, data::transaction::Transaction, wallet::Wallet,
+};
 
 #[test]
 fn test_private_key_from_mnemonic() {
@@ -66,3 +68,104 @@ fn test_get_shard() {
     let mike = test_wallets::mike(); // [227, 42, 254, 220, 144, 79, 225, 147, 151, 70, 173, 151, 59, 235, 56, 53, 99, 207, 99, 100, 43, 166, 105, 179, 4, 15, 155, 148, 40, 165, 237, 96]
     assert_eq!(0, mike.get_shard());
 }
+
+fn relayed_tx(sender: &Wallet, relayer: &Wallet) -> Transaction {
+    Transaction {
+        nonce: 7,
+        value: "1000".to_string(),
+        receiver: sender.to_address().to_bech32_default(),
+        sender: sender.to_address().to_bech32_default(),
+        gas_price: 1_000_000_000,
+        gas_limit: 100_000,
+        data: None,
+        signature: None,
+        chain_id: "D".to_string(),
+        version: 2,
+        options: 0,
+        guardian: None,
+        guardian_signature: None,
+        relayer: Some(relayer.to_address().to_bech32_default()),
+        relayer_signature: None,
+    }
+}
+
+#[test]
+fn test_sign_relayed_tx() {
+    let alice = test_wallets::alice();
+    let bob = test_wallets::bob();
+    let mut tx = relayed_tx(&alice, &bob);
+
+    // the relayer is part of the signed payload, signatures are not
+    let payload = format!(
+        r#"{{"nonce":7,"value":"1000","receiver":"{alice}","sender":"{alice}","gasPrice":1000000000,"gasLimit":100000,"chainID":"D","version":2,"relayer":"{bob}"}}"#,
+        alice = alice.to_address().to_bech32_default().bech32,
+        bob = bob.to_address().to_bech32_default().bech32,
+    );
+    let expected_signature = alice.sign_bytes(payload.into_bytes());
+
+    tx.signature = Some(hex::encode(alice.sign_tx(&tx)));
+    assert_eq!(tx.signature, Some(hex::encode(expected_signature)));
+
+    let relayer_signature = bob.sign_tx_as_relayer(&tx);
+    tx.relayer_signature = Some(hex::encode(relayer_signature));
+    assert_eq!(relayer_signature, bob.sign_tx(&tx));
+
+    let tx_json = serde_json::to_value(&tx).unwrap();
+    assert_eq!(tx_json["relayerSignature"], hex::encode(relayer_signature));
+}
+
+#[test]
+#[should_panic(expected = "wallet is not the relayer of the transaction")]
+fn test_sign_relayed_tx_wrong_relayer() {
+    let alice = test_wallets::alice();
+    let bob = test_wallets::bob();
+    let tx = relayed_tx(&alice, &bob);
+
+    let _ = alice.sign_tx_as_relayer(&tx);
+}
+
+#[test]
+fn test_sign_guarded_tx() {
+    let alice = test_wallets::alice();
+    let bob = test_wallets::bob();
+    let carol = test_wallets::carol();
+    let mut tx = relayed_tx(&alice, &bob);
+    tx.version = 1;
+    tx.set_guardian(carol.to_address().to_bech32_default());
+    assert!(tx.is_guarded());
+    assert!(!tx.is_hash_sign());
+    assert_eq!(tx.version, 2);
+
+    // the guardian and the guarded option are part of the signed payload
+    let payload = format!(
+        r#"{{"nonce":7,"value":"1000","receiver":"{alice}","sender":"{alice}","gasPrice":1000000000,"gasLimit":100000,"chainID":"D","version":2,"options":2,"guardian":"{carol}","relayer":"{bob}"}}"#,
+        alice = alice.to_address().to_bech32_default().bech32,
+        bob = bob.to_address().to_bech32_default().bech32,
+        carol = carol.to_address().to_bech32_default().bech32,
+    );
+    let expected_signature = alice.sign_bytes(payload.into_bytes());
+
+    tx.signature = Some(hex::encode(alice.sign_tx(&tx)));
+    assert_eq!(tx.signature, Some(hex::encode(expected_signature)));
+
+    let guardian_signature = carol.sign_tx_as_guardian(&tx);
+    tx.guardian_signature = Some(hex::encode(guardian_signature));
+    assert_eq!(guardian_signature, carol.sign_tx(&tx));
+
+    let tx_json = serde_json::to_value(&tx).unwrap();
+    assert_eq!(
+        tx_json["guardianSignature"],
+        hex::encode(guardian_signature)
+    );
+}
+
+#[test]
+#[should_panic(expected = "wallet is not the guardian of the transaction")]
+fn test_sign_guarded_tx_wrong_guardian() {
+    let alice = test_wallets::alice();
+    let bob = test_wallets::bob();
+    let mut tx = relayed_tx(&alice, &bob);
+    tx.set_guardian(test_wallets::carol().to_address().to_bech32_default());
+
+    let _ = bob.sign_tx_as_guardian(&tx);
+}
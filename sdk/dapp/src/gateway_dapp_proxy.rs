@@ -53,7 +53,7 @@ impl GatewayAsyncService for GatewayDappProxy {
     }
 
     fn sleep(&self, millis: u64) -> impl std::future::Future<Output = ()> {
-        sleep(millis as f32 * 1000f32)
+        sleep(millis as f32 / 1000f32)
     }
 
     fn now(&self) -> Self::Instant {
@@ -4,13 +4,14 @@ use multiversx_sdk::{
     data::{
         network_config::NetworkConfig,
         transaction::{
-            ApiTransactionResult, ArgCreateTransaction, Transaction, TxCostResponseData,
+            ApiTransactionResult, ArgCreateTransaction, Transaction, TransactionSimulationResults,
+            TxCostResponseData,
         },
         vm::{VMQueryInput, VmValuesResponseData},
     },
     gateway::{
         GetTxCost, GetTxInfo, GetTxProcessStatus, GetTxStatus, SendMultiTxRequest, SendTxRequest,
-        VMQueryRequest,
+        SimulateTxExecutionRequest, VMQueryRequest,
     },
 };
 
@@ -77,6 +78,14 @@ impl GatewayHttpProxy {
         self.http_request(SendTxRequest(tx)).await
     }
 
+    // simulate_transaction executes a signed transaction without committing its results
+    pub async fn simulate_transaction(
+        &self,
+        tx: &Transaction,
+    ) -> Result<TransactionSimulationResults> {
+        self.http_request(SimulateTxExecutionRequest(tx)).await
+    }
+
     #[allow(clippy::ptr_arg)]
     pub async fn send_transactions(&self, txs: &Vec<Transaction>) -> Result<Vec<String>> {
         self.http_request(SendMultiTxRequest(txs)).await
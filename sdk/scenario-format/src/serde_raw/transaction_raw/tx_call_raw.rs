@@ -9,6 +9,11 @@ pub struct TxCallRaw {
     pub from: ValueSubTree,
     pub to: ValueSubTree,
 
+    /// The relayer of a relayed transaction (relayed v3). The relayer pays the gas.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relayer: Option<ValueSubTree>,
+
     /// Backwards compatibility only.
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]